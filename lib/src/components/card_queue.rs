@@ -0,0 +1,86 @@
+use std::collections::VecDeque;
+
+use crate::types::{EnemyIndex, HandIndex};
+
+use super::state::CardCombatState;
+
+/// Where a queued card came from, which determines how it is paid for and disposed of.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CardPlaySource {
+    /// Chosen from the hand by the player, who has already paid for it.
+    Hand(HandIndex),
+
+    /// A free duplicate of a card just played (Double Tap, Burst, etc.), removed once resolved.
+    Copy,
+
+    /// Played for free from outside the hand (Havoc, Mayhem, Omniscience, etc.)
+    Autoplay,
+}
+
+/// A card waiting to be played, as with the game's `CardQueueItem`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CardPlay {
+    pub combat_card: CardCombatState,
+    pub source: CardPlaySource,
+    pub maybe_enemy_index: Option<EnemyIndex>, // None means a random target, if one is needed.
+    pub exhaust: bool,                         // Exhausted once played, whatever the card says.
+}
+
+impl CardPlay {
+    pub fn from_hand(
+        hand_index: HandIndex,
+        combat_card: CardCombatState,
+        maybe_enemy_index: Option<EnemyIndex>,
+    ) -> Self {
+        Self {
+            combat_card,
+            source: CardPlaySource::Hand(hand_index),
+            maybe_enemy_index,
+            exhaust: false,
+        }
+    }
+
+    pub fn copy_of(card_play: &CardPlay) -> Self {
+        Self {
+            source: CardPlaySource::Copy,
+            exhaust: false,
+            ..*card_play
+        }
+    }
+
+    pub fn autoplay(combat_card: CardCombatState, exhaust: bool) -> Self {
+        Self {
+            combat_card,
+            source: CardPlaySource::Autoplay,
+            maybe_enemy_index: None,
+            exhaust,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CardQueue {
+    queue: VecDeque<CardPlay>,
+}
+
+impl CardQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_front(&mut self, card_play: CardPlay) {
+        self.queue.push_front(card_play);
+    }
+
+    pub fn push_back(&mut self, card_play: CardPlay) {
+        self.queue.push_back(card_play);
+    }
+
+    pub fn pop_front(&mut self) -> Option<CardPlay> {
+        self.queue.pop_front()
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+}
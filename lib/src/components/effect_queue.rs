@@ -1,5 +1,7 @@
 use std::collections::VecDeque;
 
+use anyhow::{anyhow, Error};
+
 use crate::data::{EnemyEffect, PlayerEffect};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -10,6 +12,10 @@ pub enum Effect {
     PlayerState(PlayerEffect),
 }
 
+/// Effects waiting to be resolved, mirroring the game's action queue. `add_to_bottom` queues an
+/// effect behind everything already pending (the game's `addToBottom`), while `add_to_top` makes
+/// it the very next effect to resolve (`addToTop`). Effects may queue follow-up effects either way
+/// as they resolve.
 #[derive(Debug)]
 pub struct EffectQueue {
     queue: VecDeque<Effect>,
@@ -30,26 +36,95 @@ impl Default for EffectQueue {
 }
 
 impl EffectQueue {
-    pub fn push_front(&mut self, effect: Effect) {
+    /// Queues the effect to resolve next, ahead of everything already pending.
+    pub fn add_to_top(&mut self, effect: Effect) {
         self.queue.push_front(effect);
     }
 
-    pub fn push_back(&mut self, effect: Effect) {
+    /// Queues the effects to resolve next, in the order given.
+    pub fn add_all_to_top<T>(&mut self, effects: T)
+    where
+        T: IntoIterator<Item = Effect>,
+        T::IntoIter: DoubleEndedIterator,
+    {
+        for effect in effects.into_iter().rev() {
+            self.queue.push_front(effect);
+        }
+    }
+
+    /// Queues the effect to resolve after everything already pending.
+    pub fn add_to_bottom(&mut self, effect: Effect) {
         self.queue.push_back(effect);
     }
 
     pub fn pop_front(&mut self) -> Option<Effect> {
         self.queue.pop_front()
     }
-}
 
-impl Drop for EffectQueue {
-    fn drop(&mut self) {
-        if !self.queue.is_empty() {
-            eprintln!(
-                "EffectQueue dropped with {} effects remaining",
-                self.queue.len()
-            );
+    pub fn front(&self) -> Option<&Effect> {
+        self.queue.front()
+    }
+
+    /// Iterates over the pending effects in the order they will resolve.
+    pub fn iter(&self) -> impl Iterator<Item = &Effect> {
+        self.queue.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Keeps only the pending effects for which the predicate returns true.
+    pub fn retain(&mut self, predicate: impl FnMut(&Effect) -> bool) {
+        self.queue.retain(predicate);
+    }
+
+    /// Drops all pending effects, as the game does when combat ends.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    /// Returns an error describing any effects still pending, which would indicate that an
+    /// effect was queued somewhere it could never resolve.
+    pub fn ensure_empty(&self) -> Result<(), Error> {
+        if self.queue.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "Effect queue still has {} effect(s) pending: {:?}",
+                self.queue.len(),
+                self.queue
+            ))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_to_top_and_bottom() {
+        let mut effect_queue = EffectQueue::new();
+        effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Draw(1)));
+        effect_queue.add_to_top(Effect::PlayerState(PlayerEffect::Draw(2)));
+        effect_queue.add_all_to_top([
+            Effect::PlayerState(PlayerEffect::Draw(3)),
+            Effect::PlayerState(PlayerEffect::Draw(4)),
+        ]);
+        effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Draw(5)));
+        assert_eq!(
+            effect_queue.iter().cloned().collect::<Vec<_>>(),
+            [3, 4, 2, 1, 5]
+                .map(|draw_count| Effect::PlayerState(PlayerEffect::Draw(draw_count)))
+                .to_vec()
+        );
+        assert!(effect_queue.ensure_empty().is_err());
+        effect_queue.clear();
+        assert!(effect_queue.ensure_empty().is_ok());
+    }
+}
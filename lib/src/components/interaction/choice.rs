@@ -1,26 +1,44 @@
-use crate::data::{Card, Enemy, EnergyCost, NeowBlessing, Potion};
+use crate::data::{Card, Enemy, EnergyCost, NeowBlessing, PlayerEffect, Potion, Relic};
 use crate::types::{
-    CardRewardIndex, ColumnIndex, DeckIndex, DiscardIndex, EnemyIndex, Gold, HandIndex, PotionIndex,
+    CardRewardIndex, ColumnIndex, DeckIndex, DiscardIndex, DrawIndex, EnemyIndex, Gold, GridIndex,
+    HandIndex, PotionIndex,
 };
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Choice {
+    BottleCard(DeckIndex, Card),
+    ChooseEffect(PlayerEffect),
+    CreateCard(Card),
+    DuplicateCard(DeckIndex, Card),
     EndTurn,
     Event(usize, String), // Free-form text for events
+    ExhaustCard(HandIndex, Card),
     ExpendPotion(PotionAction),
     ClimbFloor(ColumnIndex),
     NeowBlessing(NeowBlessing),
     ObtainCard(CardRewardIndex, Card),
     ObtainGold(Gold),
     ObtainPotion(Potion),
+    ObtainRelic(Relic),
+    OfferCard(DeckIndex, Card),
+    PlayCardFromDrawPile(DrawIndex, Card),
     PlayCardFromHand(HandIndex, Card, EnergyCost),
+    PutBackOnDrawPile(HandIndex, Card),
+    PutOnBottomOfDrawPile(HandIndex, Card),
     PutOnTopOfDrawPile(DiscardIndex, Card),
     RemoveCard(DeckIndex, Card),
     Rest,
+    RetainCard(HandIndex, Card),
+    ReturnToHand(DiscardIndex, Card),
+    RevealCard(GridIndex),
+    ScryDiscard(DrawIndex, Card),
     Skip,
     Smith,
+    TakeFromDrawPile(DrawIndex, Card),
     TargetEnemy(EnemyIndex, Enemy),
+    TransformCard(DeckIndex, Card),
     UpgradeCard(DeckIndex, Card, Card),
+    UpgradeCardInHand(HandIndex, Card, Card),
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -1,7 +1,8 @@
 use crate::components::{CardCombatState, EnemyStatus, PlayerStatus};
-use crate::data::{Card, CardDestination, Enemy, PlayerCondition, Potion, Relic};
+use crate::data::{Card, CardDestination, Enemy, PlayerCondition, Potion, Relic, Stance};
 use crate::types::{
-    Block, Dexterity, EnemyIndex, Energy, Gold, HandIndex, Health, Hp, PotionIndex, Strength,
+    Block, Dexterity, DrawIndex, EnemyIndex, Energy, Gold, GridIndex, HandIndex, Health, Hp,
+    Mantra, PotionIndex, Strength,
 };
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -10,6 +11,7 @@ pub enum Notification {
     // State updates for the main game loop, outside of an encounter or event.
     CardObtained(Card),
     CardRemoved(Card),
+    CardRevealed(GridIndex, Card),
     CardUpgraded(Card, Card),
     Deck(Vec<Card>),
     Gold(Gold),
@@ -28,19 +30,27 @@ pub enum Notification {
     Block(Block),
     BlockGained(Block),
     CardCreated(CardCombatState, CardDestination),
+    CardCreatedInDrawPile(DrawIndex, CardCombatState), // Index 0 is the bottom of the pile.
     CardDiscarded(HandIndex, CardCombatState),
     CardDrawn(HandIndex, CardCombatState),
     CardExhausted(HandIndex, CardCombatState),
+    CardPutOnDrawPile(HandIndex, DrawIndex, CardCombatState), // Index 0 is the bottom of the pile.
+    CardReturnedFromStasis(CardCombatState, CardDestination),
     Conditions(Vec<PlayerCondition>),
     DamageBlocked(Hp),
     DamageTaken(Hp),
     Dexterity(Dexterity),
     DiscardPile(Vec<CardCombatState>),
     EnemyDied(EnemyIndex, Enemy),
+    EnemyEscaped(EnemyIndex, Enemy),
     EnemyParty(Vec<Option<EnemyStatus>>),
+    EnemySpawned(EnemyIndex, Enemy),
     Energy(Energy),
     Health(Health),
+    Mantra(Mantra),
+    PowerPlayed(HandIndex, CardCombatState), // The Power leaves play for the rest of combat.
     ShufflingDiscardPileIntoDrawPile,
+    Stance(Option<Stance>),
     Status(PlayerStatus),
     Strength(Strength),
 }
@@ -1,6 +1,14 @@
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Prompt {
+    BottleCard,
+    ChooseCardToCreate,
+    ChooseCardToExhaust,
+    ChooseCardToPlay,
+    ChooseCardToPutOnBottomOfDrawPile,
     ChooseCardToPutOnTopOfDrawPile,
+    ChooseCardToReturnToHand,
+    ChooseCardToTakeFromDrawPile,
+    ChooseCardsToRetain,
     ChooseCombatReward,
     ChooseForEvent,
     ChooseNeow,
@@ -8,9 +16,14 @@ pub enum Prompt {
     ChooseOne,  // Expectation is that the player can pick at most one of the Choices offered.
     ChooseRestSiteAction,
     CombatAction,
+    DuplicateCard,
+    OfferCard,
     ClimbFloor,
     ClimbFloorHasPotion,
     RemoveCard,
+    RevealCard,
+    Scry,
     TargetEnemy,
+    TransformCard,
     UpgradeCard,
 }
@@ -1,3 +1,4 @@
+mod card_queue;
 mod damage_taken;
 mod effect_queue;
 mod interaction;
@@ -5,9 +6,10 @@ pub mod map;
 mod state; // "state" is all internal state the simulator uses
 mod status; // "status" is the subset of state that is exposed to the player
 
+pub use card_queue::{CardPlay, CardPlaySource, CardQueue};
 pub use damage_taken::DamageTaken;
 pub use effect_queue::{Effect, EffectQueue};
 pub use interaction::{Choice, Interaction, Notification, PotionAction, Prompt, StsMessage};
 pub use map::Room;
-pub use state::{CardCombatState, PlayerCombatState, PlayerPersistentState};
+pub use state::{CardCombatState, DeckCard, PlayerCombatState, PlayerPersistentState};
 pub use status::{AttackerStatus, DefenderStatus, EnemyStatus, PlayerStatus};
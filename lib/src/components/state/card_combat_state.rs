@@ -1,28 +1,112 @@
-use crate::data::{Card, CardDetails, EnergyCost};
-use crate::types::{DeckIndex, Hp};
+use crate::data::{Card, CardDetails, CostModifier, EnergyCost};
+use crate::types::{Block, CardInstanceId, Energy, Hp, Misc};
+
+use super::deck_card::DeckCard;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct CardCombatState {
+    pub id: CardInstanceId,
     pub card: Card,
-    pub deck_index: Option<DeckIndex>,
     pub details: &'static CardDetails,
     pub cost_this_combat: EnergyCost,
     pub cost_this_turn: EnergyCost,
     pub cost_until_played: EnergyCost,
     pub additional_damage: Hp,
+    pub additional_block: Block,
+    pub retain_this_turn: bool,
+    pub misc: Misc, // Carried over from the deck and written back when combat ends.
+    pub bottled: bool,
+    pub x: Energy, // Fixed when an X-cost card is played, or when an Expunger is created.
 }
 
 impl CardCombatState {
-    pub fn new(card: Card, deck_index: Option<DeckIndex>) -> Self {
+    pub fn new(id: CardInstanceId, card: Card) -> Self {
         let details = CardDetails::for_card(card);
         Self {
+            id,
             card,
-            deck_index,
             details,
             cost_this_combat: details.cost,
             cost_this_turn: details.cost,
             cost_until_played: details.cost,
             additional_damage: 0,
+            additional_block: 0,
+            retain_this_turn: false,
+            misc: 0,
+            bottled: false,
+            x: 0,
+        }
+    }
+
+    /// Lowers the cost of the card for as long as the modifier says. X costs are left alone.
+    pub fn modify_cost(&mut self, cost_modifier: &CostModifier) {
+        if self.details.cost == EnergyCost::X {
+            return;
+        }
+        match cost_modifier {
+            CostModifier::None => {}
+            CostModifier::ReduceToOneThisCombat => {
+                self.set_cost_this_combat(Self::at_most_one(self.cost_this_combat));
+                self.cost_this_turn = Self::at_most_one(self.cost_this_turn);
+            }
+            CostModifier::ReduceToOneThisTurn => {
+                self.cost_this_turn = Self::at_most_one(self.cost_this_turn);
+            }
+            CostModifier::ZeroThisCombat => {
+                self.set_cost_this_combat(EnergyCost::Zero);
+                self.cost_this_turn = EnergyCost::Zero;
+            }
+            CostModifier::ZeroThisTurn => self.cost_this_turn = EnergyCost::Zero,
+            CostModifier::ZeroUntilPlayed => {
+                self.cost_until_played = EnergyCost::Zero;
+                self.cost_this_turn = EnergyCost::Zero;
+            }
+        }
+    }
+
+    /// Changes the cost of the card for the rest of combat. A card that is free until played
+    /// stays free.
+    pub fn set_cost_this_combat(&mut self, energy_cost: EnergyCost) {
+        if self.cost_until_played == self.cost_this_combat {
+            self.cost_until_played = energy_cost;
+        }
+        self.cost_this_combat = energy_cost;
+    }
+
+    /// Caps a fixed energy cost at one, as Enlightenment does.
+    fn at_most_one(energy_cost: EnergyCost) -> EnergyCost {
+        match energy_cost {
+            EnergyCost::Zero | EnergyCost::X => energy_cost,
+            _ => EnergyCost::One,
+        }
+    }
+
+    /// Upgrades the card in place, keeping any cost changes made during combat.
+    pub fn upgrade(&mut self) {
+        let Some(upgraded) = self.details.upgrade else {
+            return;
+        };
+        let base_cost = self.details.cost;
+        self.card = upgraded;
+        self.details = CardDetails::for_card(upgraded);
+        for cost in [
+            &mut self.cost_this_combat,
+            &mut self.cost_this_turn,
+            &mut self.cost_until_played,
+        ] {
+            if *cost == base_cost {
+                *cost = self.details.cost;
+            }
+        }
+    }
+}
+
+impl From<&DeckCard> for CardCombatState {
+    fn from(deck_card: &DeckCard) -> Self {
+        Self {
+            misc: deck_card.misc,
+            bottled: deck_card.bottled,
+            ..Self::new(deck_card.id, deck_card.card)
         }
     }
 }
@@ -1,7 +1,5 @@
-use crate::data::Card;
-use crate::types::HandIndex;
-
 use super::card_combat_state::CardCombatState;
+use super::deck_card::DeckCard;
 
 #[derive(Debug)]
 pub struct CombatCards {
@@ -9,22 +7,15 @@ pub struct CombatCards {
     pub draw_pile: Vec<CardCombatState>,
     pub discard_pile: Vec<CardCombatState>,
     pub exhaust_pile: Vec<CardCombatState>,
-    pub card_in_play: Option<HandIndex>,
 }
 
 impl CombatCards {
-    pub fn new(deck: &[Card]) -> Self {
+    pub fn new(deck: &[DeckCard]) -> Self {
         Self {
             hand: Vec::with_capacity(10),
-            draw_pile: deck
-                .iter()
-                .copied()
-                .enumerate()
-                .map(|(i, c)| CardCombatState::new(c, Some(i)))
-                .collect(),
+            draw_pile: deck.iter().map(CardCombatState::from).collect(),
             discard_pile: Vec::with_capacity(deck.len()),
             exhaust_pile: Vec::new(),
-            card_in_play: None,
         }
     }
 
@@ -46,7 +37,7 @@ impl CombatCards {
 
     fn sanitized(cards: &[CardCombatState]) -> Vec<CardCombatState> {
         let mut result = cards.to_vec();
-        result.sort_by_key(|c| c.deck_index);
+        result.sort_by_key(|c| c.id);
         result
     }
 
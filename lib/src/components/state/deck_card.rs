@@ -0,0 +1,49 @@
+use crate::data::{Card, CardDetails};
+use crate::types::{CardInstanceId, Misc};
+
+/// A card in the player's deck, along with the metadata that persists between combats.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DeckCard {
+    pub id: CardInstanceId,
+    pub card: Card,
+    pub upgrade_count: u32, // Searing Blow can be upgraded any number of times.
+    pub misc: Misc,         // Permanent growth, e.g. Ritual Dagger's extra damage.
+    pub bottled: bool,      // Starts each combat in the player's hand (Bottled Flame, etc.)
+}
+
+impl DeckCard {
+    pub fn new(id: CardInstanceId, card: Card) -> Self {
+        Self {
+            id,
+            card,
+            upgrade_count: 0,
+            misc: 0,
+            bottled: false,
+        }
+    }
+
+    /// Upgrades the card in place, returning the upgraded card if it could be upgraded.
+    pub fn upgrade(&mut self) -> Option<Card> {
+        let upgraded = CardDetails::for_card(self.card).upgrade?;
+        self.card = upgraded;
+        self.upgrade_count += 1;
+        Some(upgraded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_tracks_count() {
+        let mut searing_blow = DeckCard::new(0, Card::SearingBlow(0));
+        assert_eq!(searing_blow.upgrade(), Some(Card::SearingBlow(1)));
+        assert_eq!(searing_blow.upgrade(), Some(Card::SearingBlow(2)));
+        assert_eq!(searing_blow.upgrade_count, 2);
+
+        let mut bash = DeckCard::new(1, Card::Bash(true));
+        assert_eq!(bash.upgrade(), None);
+        assert_eq!(bash.upgrade_count, 0);
+    }
+}
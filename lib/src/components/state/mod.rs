@@ -1,8 +1,10 @@
 mod card_combat_state;
 mod combat_cards;
+mod deck_card;
 mod player_combat_state;
 mod player_persistent_state;
 
 pub use card_combat_state::CardCombatState;
+pub use deck_card::DeckCard;
 pub use player_combat_state::PlayerCombatState;
 pub use player_persistent_state::PlayerPersistentState;
@@ -1,7 +1,8 @@
 use crate::components::{AttackerStatus, DefenderStatus, PlayerStatus};
-use crate::data::{Card, PlayerCondition};
-use crate::types::{Block, Dexterity, Energy, Strength};
+use crate::data::{Card, CardType, PlayerCondition, Relic, Stance};
+use crate::types::{Block, Dexterity, Energy, Hp, Mantra, StackCount, Strength};
 
+use super::card_combat_state::CardCombatState;
 use super::combat_cards::CombatCards;
 use super::player_persistent_state::PlayerPersistentState;
 
@@ -17,6 +18,19 @@ pub struct PlayerCombatState<'a> {
     pub hp_loss_count: usize,
     pub strength: Strength,
     pub dexterity: Dexterity,
+    pub stance: Option<Stance>,
+    pub mantra: Mantra,
+    pub mantra_gained: Mantra, // Total mantra gained this combat, for Brilliance.
+    pub card_being_played: Option<CardCombatState>,
+    pub last_card_played: Option<CardType>,
+    pub cards_played_this_turn: usize,
+    pub copies_played_this_turn: usize, // Duplicate plays, which Echo Form doesn't count.
+    pub necronomicon_used_this_turn: bool,
+    pub unblocked_damage_dealt: Hp, // By the card currently being played.
+    pub end_turn_requested: bool,
+    pub extra_turn_pending: bool,
+    pub x_cost_unpaid: bool, // X-cost cards spend their energy when the X effect resolves.
+    pub escaped: bool,
 }
 
 impl<'a> PlayerCombatState<'a> {
@@ -31,11 +45,45 @@ impl<'a> PlayerCombatState<'a> {
             hp_loss_count: 0,
             strength: 0,
             dexterity: 0,
+            stance: None,
+            mantra: 0,
+            mantra_gained: 0,
+            card_being_played: None,
+            last_card_played: None,
+            cards_played_this_turn: 0,
+            copies_played_this_turn: 0,
+            necronomicon_used_this_turn: false,
+            unblocked_damage_dealt: 0,
+            end_turn_requested: false,
+            extra_turn_pending: false,
+            x_cost_unpaid: false,
+            escaped: false,
         }
     }
 }
 
 impl AttackerStatus for PlayerCombatState<'_> {
+    fn additional_damage(&self) -> Hp {
+        let vigor = self
+            .conditions
+            .iter()
+            .find_map(|c| match c {
+                PlayerCondition::Vigor(hp) => Some(*hp),
+                _ => None,
+            })
+            .unwrap_or(0);
+        vigor
+            + self.card_being_played.map_or(0, |combat_card| {
+                // Ritual Dagger is the only card whose misc value is its damage bonus.
+                combat_card.additional_damage
+                    + if matches!(combat_card.card, Card::RitualDagger(_)) {
+                        combat_card.misc
+                    } else {
+                        0
+                    }
+            })
+    }
+
     fn block(&self) -> Block {
         self.block
     }
@@ -48,12 +96,20 @@ impl AttackerStatus for PlayerCombatState<'_> {
         self.cards.hand.len()
     }
 
+    fn is_attacking_from_behind(&self) -> bool {
+        false
+    }
+
     fn is_weak(&self) -> bool {
         self.conditions
             .iter()
             .any(|c| matches!(c, PlayerCondition::Weak(_)))
     }
 
+    fn mantra_gained(&self) -> Mantra {
+        self.mantra_gained
+    }
+
     fn number_of_strike_cards_owned(&self) -> usize {
         self.cards
             .iter()
@@ -74,6 +130,10 @@ impl AttackerStatus for PlayerCombatState<'_> {
             .count()
     }
 
+    fn stance(&self) -> Option<Stance> {
+        self.stance
+    }
+
     fn strength(&self) -> Strength {
         self.strength
     }
@@ -84,17 +144,39 @@ impl DefenderStatus for PlayerCombatState<'_> {
         self.dexterity
     }
 
+    fn has_paper_krane(&self) -> bool {
+        self.pps.relics.contains(&Relic::PaperKrane)
+    }
+
+    fn is_flying(&self) -> bool {
+        false
+    }
+
     fn is_frail(&self) -> bool {
         self.conditions
             .iter()
             .any(|c| matches!(c, PlayerCondition::Frail(_)))
     }
 
+    fn is_in_wrath(&self) -> bool {
+        self.stance == Some(Stance::Wrath)
+    }
+
+    fn is_intangible(&self) -> bool {
+        self.conditions
+            .iter()
+            .any(|c| matches!(c, PlayerCondition::Intangible(_)))
+    }
+
     fn is_vulnerable(&self) -> bool {
         self.conditions
             .iter()
             .any(|c| matches!(c, PlayerCondition::Vulnerable(_)))
     }
+
+    fn slow_stacks(&self) -> StackCount {
+        0
+    }
 }
 
 impl<'a> From<&PlayerCombatState<'a>> for PlayerStatus {
@@ -104,7 +186,7 @@ impl<'a> From<&PlayerCombatState<'a>> for PlayerStatus {
             hp_max: pcs.pps.hp_max,
             gold: pcs.pps.gold,
             relics: pcs.pps.relics.clone(),
-            deck: pcs.pps.deck.clone(),
+            deck: pcs.pps.deck_cards(),
             potions: pcs.pps.potions.clone(),
             energy: pcs.energy,
             block: pcs.block,
@@ -116,6 +198,9 @@ impl<'a> From<&PlayerCombatState<'a>> for PlayerStatus {
             hp_loss_count: pcs.hp_loss_count,
             strength: pcs.strength,
             dexterity: pcs.dexterity,
+            stance: pcs.stance,
+            mantra: pcs.mantra,
+            mantra_gained: pcs.mantra_gained,
         }
     }
 }
@@ -1,5 +1,7 @@
 use crate::data::{Card, Character, Potion, Relic};
-use crate::types::{Gold, Hp, HpMax};
+use crate::types::{CardInstanceId, Gold, Hp, HpMax};
+
+use super::deck_card::DeckCard;
 
 /// Encapsulates the state of the player in the game, e.g. HP, gold, deck, etc., which persists
 /// between combat encounters.
@@ -10,14 +12,22 @@ pub struct PlayerPersistentState {
     pub hp_max: HpMax,
     pub gold: Gold,
     pub relics: Vec<Relic>,
-    pub deck: Vec<Card>,
+    pub deck: Vec<DeckCard>,
+    pub next_card_id: CardInstanceId,
     pub potions: Vec<Option<Potion>>,
 }
 
 impl PlayerPersistentState {
     pub fn new(character: &'static Character) -> Self {
         let relics = vec![character.starting_relic];
-        let deck = character.starting_deck.to_vec();
+        let deck = character
+            .starting_deck
+            .iter()
+            .copied()
+            .enumerate()
+            .map(|(id, card)| DeckCard::new(id, card))
+            .collect::<Vec<_>>();
+        let next_card_id = deck.len();
         Self {
             character,
             hp: character.starting_hp,
@@ -25,7 +35,20 @@ impl PlayerPersistentState {
             gold: 99,
             relics,
             deck,
+            next_card_id,
             potions: vec![None; 3],
         }
     }
+
+    /// Hands out a fresh card instance ID, for cards obtained or created during the run.
+    pub fn allocate_card_id(&mut self) -> CardInstanceId {
+        let id = self.next_card_id;
+        self.next_card_id += 1;
+        id
+    }
+
+    /// Returns the cards in the deck, without their metadata.
+    pub fn deck_cards(&self) -> Vec<Card> {
+        self.deck.iter().map(|deck_card| deck_card.card).collect()
+    }
 }
@@ -1,10 +1,15 @@
-use crate::types::{Block, Strength};
+use crate::data::Stance;
+use crate::types::{Block, Hp, Mantra, Strength};
 
 pub trait AttackerStatus {
+    fn additional_damage(&self) -> Hp;
     fn block(&self) -> Block;
     fn draw_pile_size(&self) -> usize;
     fn hand_size(&self) -> usize;
+    fn is_attacking_from_behind(&self) -> bool;
     fn is_weak(&self) -> bool;
+    fn mantra_gained(&self) -> Mantra;
     fn number_of_strike_cards_owned(&self) -> usize;
+    fn stance(&self) -> Option<Stance>;
     fn strength(&self) -> Strength;
 }
@@ -1,7 +1,12 @@
-use crate::types::Dexterity;
+use crate::types::{Dexterity, StackCount};
 
 pub trait DefenderStatus {
     fn dexterity(&self) -> Dexterity;
+    fn has_paper_krane(&self) -> bool;
+    fn is_flying(&self) -> bool;
     fn is_frail(&self) -> bool;
+    fn is_in_wrath(&self) -> bool;
+    fn is_intangible(&self) -> bool;
     fn is_vulnerable(&self) -> bool;
+    fn slow_stacks(&self) -> StackCount;
 }
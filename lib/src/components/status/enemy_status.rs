@@ -1,6 +1,6 @@
 use crate::components::{AttackerStatus, DefenderStatus};
-use crate::data::{Enemy, EnemyCondition, Intent};
-use crate::types::{Block, Dexterity, Hp, HpMax, Strength};
+use crate::data::{Enemy, EnemyCondition, Intent, Stance};
+use crate::types::{Block, Dexterity, Hp, HpMax, Mantra, StackCount, Strength};
 
 /// `EnemyStatus` is a small bundle of information about the enemy that is made available to
 /// the player. The player is not allowed to know anything else about the enemy, such as its
@@ -13,10 +13,14 @@ pub struct EnemyStatus {
     pub block: Block,
     pub strength: Strength,
     pub conditions: Vec<EnemyCondition>,
-    pub intent: Intent,
+    pub intent: Option<Intent>, // None when the player's Runic Dome hides it.
 }
 
 impl AttackerStatus for EnemyStatus {
+    fn additional_damage(&self) -> Hp {
+        0
+    }
+
     fn block(&self) -> Block {
         self.block
     }
@@ -29,16 +33,28 @@ impl AttackerStatus for EnemyStatus {
         0
     }
 
+    fn is_attacking_from_behind(&self) -> bool {
+        self.conditions.contains(&EnemyCondition::BackAttack)
+    }
+
     fn is_weak(&self) -> bool {
         self.conditions
             .iter()
             .any(|c| matches!(c, EnemyCondition::Weak(_)))
     }
 
+    fn mantra_gained(&self) -> Mantra {
+        0
+    }
+
     fn number_of_strike_cards_owned(&self) -> usize {
         0
     }
 
+    fn stance(&self) -> Option<Stance> {
+        None
+    }
+
     fn strength(&self) -> Strength {
         self.strength
     }
@@ -49,15 +65,45 @@ impl DefenderStatus for EnemyStatus {
         0
     }
 
+    fn has_paper_krane(&self) -> bool {
+        false
+    }
+
+    fn is_flying(&self) -> bool {
+        self.conditions
+            .iter()
+            .any(|c| matches!(c, EnemyCondition::Flight(_, _)))
+    }
+
     fn is_frail(&self) -> bool {
         false
     }
 
+    fn is_in_wrath(&self) -> bool {
+        false
+    }
+
+    fn is_intangible(&self) -> bool {
+        self.conditions
+            .iter()
+            .any(|c| matches!(c, EnemyCondition::Intangible(..)))
+    }
+
     fn is_vulnerable(&self) -> bool {
         self.conditions
             .iter()
             .any(|c| matches!(c, EnemyCondition::Vulnerable(_)))
     }
+
+    fn slow_stacks(&self) -> StackCount {
+        self.conditions
+            .iter()
+            .find_map(|c| match c {
+                EnemyCondition::Slow(stacks) => Some(*stacks),
+                _ => None,
+            })
+            .unwrap_or(0)
+    }
 }
 
 #[cfg(test)]
@@ -76,7 +122,7 @@ mod tests {
                 strength: 0,
                 block: 0,
                 conditions: Vec::new(),
-                intent,
+                intent: Some(intent),
             }
         }
 
@@ -1,6 +1,6 @@
 use crate::components::{AttackerStatus, CardCombatState, DefenderStatus};
-use crate::data::{Card, Character, PlayerCondition, Potion, Relic};
-use crate::types::{Block, Dexterity, Energy, Gold, Hp, HpMax, Strength};
+use crate::data::{Card, Character, PlayerCondition, Potion, Relic, Stance};
+use crate::types::{Block, Dexterity, Energy, Gold, Hp, HpMax, Mantra, StackCount, Strength};
 
 /// `PlayerStatus` is the information about the player that is made available to the client.
 /// Some information is sanitized, e.g. the specific order of the cards in the draw pile, etc.
@@ -22,6 +22,9 @@ pub struct PlayerStatus {
     pub hp_loss_count: usize,
     pub strength: Strength,
     pub dexterity: Dexterity,
+    pub stance: Option<Stance>,
+    pub mantra: Mantra,
+    pub mantra_gained: Mantra,
 }
 
 impl PlayerStatus {
@@ -43,6 +46,9 @@ impl PlayerStatus {
             hp_loss_count: 0,
             strength: 0,
             dexterity: 0,
+            stance: None,
+            mantra: 0,
+            mantra_gained: 0,
         }
     }
 
@@ -56,6 +62,16 @@ impl PlayerStatus {
 }
 
 impl AttackerStatus for PlayerStatus {
+    fn additional_damage(&self) -> Hp {
+        self.conditions
+            .iter()
+            .find_map(|c| match c {
+                PlayerCondition::Vigor(hp) => Some(*hp),
+                _ => None,
+            })
+            .unwrap_or(0)
+    }
+
     fn block(&self) -> Block {
         self.block
     }
@@ -68,12 +84,20 @@ impl AttackerStatus for PlayerStatus {
         self.hand.len()
     }
 
+    fn is_attacking_from_behind(&self) -> bool {
+        false
+    }
+
     fn is_weak(&self) -> bool {
         self.conditions
             .iter()
             .any(|c| matches!(c, PlayerCondition::Weak(_)))
     }
 
+    fn mantra_gained(&self) -> Mantra {
+        self.mantra_gained
+    }
+
     fn number_of_strike_cards_owned(&self) -> usize {
         self.cards_iter()
             .filter(|c| {
@@ -93,6 +117,10 @@ impl AttackerStatus for PlayerStatus {
             .count()
     }
 
+    fn stance(&self) -> Option<Stance> {
+        self.stance
+    }
+
     fn strength(&self) -> Strength {
         self.strength
     }
@@ -103,15 +131,37 @@ impl DefenderStatus for PlayerStatus {
         self.dexterity
     }
 
+    fn has_paper_krane(&self) -> bool {
+        self.relics.contains(&Relic::PaperKrane)
+    }
+
+    fn is_flying(&self) -> bool {
+        false
+    }
+
     fn is_frail(&self) -> bool {
         self.conditions
             .iter()
             .any(|c| matches!(c, PlayerCondition::Frail(_)))
     }
 
+    fn is_in_wrath(&self) -> bool {
+        self.stance == Some(Stance::Wrath)
+    }
+
+    fn is_intangible(&self) -> bool {
+        self.conditions
+            .iter()
+            .any(|c| matches!(c, PlayerCondition::Intangible(_)))
+    }
+
     fn is_vulnerable(&self) -> bool {
         self.conditions
             .iter()
             .any(|c| matches!(c, PlayerCondition::Vulnerable(_)))
     }
+
+    fn slow_stacks(&self) -> StackCount {
+        0
+    }
 }
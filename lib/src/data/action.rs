@@ -7,7 +7,7 @@ use std::collections::HashMap;
 
 use once_cell::sync::Lazy;
 
-use crate::types::Hp;
+use crate::types::{AttackCount, Hp};
 
 use super::card::Card;
 use super::condition::{EnemyCondition, PlayerCondition};
@@ -15,30 +15,209 @@ use super::damage::Damage;
 use super::effect::{
     CardDestination, CardPool, CardSelection, CostModifier, EnemyEffect, Resource,
 };
+use super::enemy::Enemy;
 use super::intent::Intent;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum EnemyAction {
+    AcidSlimeLCorrosiveSpit,
+    AcidSlimeLLick,
+    AcidSlimeLSplit,
+    AcidSlimeLTackle,
     AcidSlimeMCorrosiveSpit,
     AcidSlimeMLick,
     AcidSlimeMTackle,
     AcidSlimeSLick,
     AcidSlimeSTackle,
+    AwakenedOneDarkEcho,
+    AwakenedOneRebirth,
+    AwakenedOneSlash,
+    AwakenedOneSludge,
+    AwakenedOneSoulStrike,
+    AwakenedOneTackle,
+    BearBearHug,
+    BearLunge,
+    BearMaul,
+    BlueSlaverRake,
+    BlueSlaverStab,
+    BookOfStabbingMultiStab(AttackCount),
+    BookOfStabbingSingleStab,
+    BronzeAutomatonBoost,
+    BronzeAutomatonFlail,
+    BronzeAutomatonHyperBeam,
+    BronzeAutomatonSpawnOrbs,
+    BronzeAutomatonStunned,
+    BronzeOrbBeam,
+    BronzeOrbStasis,
+    BronzeOrbSupportBeam,
+    ByrdCaw,
+    ByrdFlyUp,
+    ByrdHeadbutt,
+    ByrdPeck,
+    ByrdStunned,
+    ByrdSwoop,
+    CenturionDefend,
+    CenturionFury,
+    CenturionSlash,
+    ChosenDebilitate,
+    ChosenDrain,
+    ChosenHex,
+    ChosenPoke,
+    ChosenZap,
+    CorruptHeartBloodShots,
+    CorruptHeartBuff(usize),
+    CorruptHeartDebilitate,
+    CorruptHeartEcho,
     CultistDarkStrike,
     CultistIncantation,
+    DaggerExplode,
+    DaggerStab,
+    DarklingChomp,
+    DarklingHarden,
+    DarklingNip(Hp),
+    DarklingRegrow,
+    DarklingReincarnate,
+    DecaBeam,
+    DecaSquareOfProtection,
+    DonuBeam,
+    DonuCircleOfPower,
+    ExploderExplode,
+    ExploderSlam,
+    FatGremlinSmash,
     FungiBeastBite,
     FungiBeastGrow,
+    GiantHeadCount,
+    GiantHeadGlare,
+    GiantHeadItIsTime(Hp),
     GreenLouseBite(Hp),
     GreenLouseSpitWeb,
+    GremlinLeaderEncourage,
+    GremlinLeaderRally,
+    GremlinLeaderStab,
     GremlinNobBellow,
     GremlinNobRush,
     GremlinNobSkullBash,
+    GremlinWizardCharging,
+    GremlinWizardUltimateBlast,
+    HexaghostActivate,
+    HexaghostDivider(Hp),
+    HexaghostInferno,
+    HexaghostInflame,
+    HexaghostSear,
+    HexaghostSearPlus,
+    HexaghostTackle,
     JawWormBellow,
     JawWormChomp,
     JawWormThrash,
+    LagavulinAttack,
+    LagavulinSiphonSoul,
+    LagavulinSleep,
+    LagavulinStunned,
+    LooterEscape,
+    LooterLunge,
+    LooterMug,
+    LooterSmokeBomb,
+    MadGremlinScratch,
+    MuggerEscape,
+    MuggerLunge,
+    MuggerMug,
+    MuggerSmokeBomb,
+    MysticAttack,
+    MysticBuff,
+    MysticHeal,
+    NemesisScythe,
+    NemesisTriAttack,
+    NemesisTriBurn,
+    OrbWalkerClaw,
+    OrbWalkerLaser,
+    PointyAttack,
+    RedLouseBite(Hp),
+    RedLouseGrow,
+    RedSlaverEntangle,
+    RedSlaverScrape,
+    RedSlaverStab,
+    ReptomancerBigBite,
+    ReptomancerSnakeStrike,
+    ReptomancerSummon,
+    RepulsorBash,
+    RepulsorRepulse,
+    RomeoAgonizingSlash,
+    RomeoCrossSlash,
+    RomeoMock,
+    SentryBeam,
+    SentryBolt,
+    ShelledParasiteDoubleStrike,
+    ShelledParasiteFell,
+    ShelledParasiteStunned,
+    ShelledParasiteSuck,
+    ShieldGremlinProtect,
+    ShieldGremlinShieldBash,
+    SlimeBossGoopSpray,
+    SlimeBossPreparing,
+    SlimeBossSlam,
+    SlimeBossSplit,
+    SnakePlantChomp,
+    SnakePlantEnfeeblingSpores,
+    SneakyGremlinPuncture,
+    SneckoBite,
+    SneckoPerplexingGlare,
+    SneckoTailWhip,
+    SphericGuardianActivate,
+    SphericGuardianAttack,
+    SphericGuardianHarden,
+    SphericGuardianSlam,
+    SpikeSlimeLFlameTackle,
+    SpikeSlimeLLick,
+    SpikeSlimeLSplit,
     SpikeSlimeMFlameTackle,
     SpikeSlimeMLick,
     SpikeSlimeSTackle,
+    SpikerCut,
+    SpikerSpike,
+    SpireGrowthConstrict,
+    SpireGrowthQuickTackle,
+    SpireGrowthSmash,
+    SpireShieldBash,
+    SpireShieldFortify,
+    SpireShieldSmash,
+    SpireSpearBurnStrike,
+    SpireSpearPiercer,
+    SpireSpearSkewer,
+    TaskmasterScouringWhip,
+    TheChampAnger,
+    TheChampDefensiveStance,
+    TheChampExecute,
+    TheChampFaceSlap,
+    TheChampGloat,
+    TheChampHeavySlash,
+    TheChampTaunt,
+    TheCollectorBuff,
+    TheCollectorFireball,
+    TheCollectorMegaDebuff,
+    TheCollectorRevive,
+    TheCollectorSpawn,
+    TheGuardianChargingUp,
+    TheGuardianDefensiveMode,
+    TheGuardianFierceBash,
+    TheGuardianRollAttack,
+    TheGuardianTwinSlam,
+    TheGuardianVentSteam,
+    TheGuardianWhirlwind,
+    TheMawDrool,
+    TheMawNomNom(AttackCount),
+    TheMawRoar,
+    TheMawSlam,
+    TimeEaterHaste,
+    TimeEaterHeadSlam,
+    TimeEaterReverberate,
+    TimeEaterRipple,
+    TorchHeadTackle,
+    TransientAttack(Hp),
+    WrithingMassFlail,
+    WrithingMassImplant,
+    WrithingMassMultiStrike,
+    WrithingMassStrongStrike,
+    WrithingMassWither,
 }
 
 impl EnemyAction {
@@ -61,6 +240,7 @@ pub struct EnemyActionDetails {
 
 pub struct EnemyActionDetailsBuilder {
     effect_chain: Vec<EnemyEffect>,
+    maybe_intent: Option<Intent>,
 }
 
 impl EnemyActionDetails {
@@ -75,6 +255,7 @@ impl EnemyActionDetailsBuilder {
     fn new() -> Self {
         Self {
             effect_chain: vec![],
+            maybe_intent: None,
         }
     }
 
@@ -83,64 +264,523 @@ impl EnemyActionDetailsBuilder {
         self
     }
 
+    /// Overrides the intent that would otherwise be inferred from the effect chain.
+    fn intent(mut self, intent: Intent) -> Self {
+        self.maybe_intent = Some(intent);
+        self
+    }
+
     fn build(self) -> EnemyActionDetails {
         EnemyActionDetails {
-            intent: Intent::from(self.effect_chain.as_slice()),
+            intent: self
+                .maybe_intent
+                .unwrap_or_else(|| Intent::from(self.effect_chain.as_slice())),
             effect_chain: self.effect_chain,
         }
     }
 }
 
 macro_rules! define_action {
-    ($variant:ident => [$($effect:ident $args:tt),*]) => {
+    (
+        $variant:ident $(($arg:literal))? => [$($effect:ident $(($($args:tt)*))?),*] $(as $intent:ident)?
+    ) => {
         (
-            EnemyAction::$variant,
-            EnemyActionDetailsBuilder::new()$(.push(EnemyEffect::$effect $args))*.build()
+            EnemyAction::$variant$(($arg))?,
+            EnemyActionDetailsBuilder::new()
+                $(.push(EnemyEffect::$effect $(($($args)*))?))*
+                $(.intent(Intent::$intent))?
+                .build()
         )
     };
 }
 
 macro_rules! define_actions {
-    ($($variant:ident => $e:tt,)*) => {
-        Lazy::new(
-            || vec![$(define_action!($variant => $e)),*].into_iter().collect::<HashMap<_, _>>()
-        )
+    ($($variant:ident $(($arg:literal))? => $e:tt $(as $intent:ident)?,)*) => {
+        vec![$(define_action!($variant $(($arg))? => $e $(as $intent)?)),*]
+            .into_iter()
+            .collect::<HashMap<_, _>>()
     }
 }
 
-static ALL_ENEMY_ACTIONS: Lazy<HashMap<EnemyAction, EnemyActionDetails>> = define_actions!(
-    AcidSlimeMCorrosiveSpit => [
-        Deal(Damage::Blockable(7)),
-        CreateCards(
-            CardPool::Fixed(&[Card::Slimed]),
-            CardSelection::All,
-            CardDestination::DiscardPile,
-            CostModifier::None,
-        )
-    ],
-    AcidSlimeMLick => [Inflict(PlayerCondition::Weak(1))],
-    AcidSlimeMTackle => [Deal(Damage::Blockable(10))],
-    AcidSlimeSLick => [Inflict(PlayerCondition::Weak(1))],
-    AcidSlimeSTackle => [Deal(Damage::Blockable(3))],
-    CultistDarkStrike => [Deal(Damage::Blockable(6))],
-    CultistIncantation => [Apply(EnemyCondition::Ritual(3, true))],
-    FungiBeastBite => [Deal(Damage::Blockable(6))],
-    FungiBeastGrow => [Gain(Resource::Strength(3))],
-    GremlinNobBellow => [Apply(EnemyCondition::Enrage(2))],
-    GremlinNobRush => [Deal(Damage::Blockable(14))],
-    GremlinNobSkullBash => [Deal(Damage::Blockable(6)), Inflict(PlayerCondition::Vulnerable(2))],
-    JawWormBellow => [Gain(Resource::Strength(3)), Gain(Resource::Block(6))],
-    JawWormChomp => [Deal(Damage::Blockable(11))],
-    JawWormThrash => [Deal(Damage::Blockable(7)), Gain(Resource::Block(5))],
-    SpikeSlimeMFlameTackle => [
-        Deal(Damage::Blockable(8)),
-        CreateCards(
-            CardPool::Fixed(&[Card::Slimed]),
-            CardSelection::All,
-            CardDestination::DiscardPile,
-            CostModifier::None,
+/// Book of Stabbing stabs once more with every Multi-Stab. A fight long enough to exceed this is
+/// not one the player is going to win.
+pub const MAX_BOOK_OF_STABBING_STAB_COUNT: AttackCount = 99;
+
+/// The Maw's Nom Nom bites once for every two turns the fight has lasted.
+pub const MAX_THE_MAW_NOM_NOM_BITE_COUNT: AttackCount = 99;
+
+/// Hexaghost's Divider hits for 1/12th of the player's HP plus one, which tops out at 84 for a
+/// player at 999 HP.
+const MAX_HEXAGHOST_DIVIDER_DAMAGE: Hp = 84;
+
+static ALL_ENEMY_ACTIONS: Lazy<HashMap<EnemyAction, EnemyActionDetails>> = Lazy::new(|| {
+    let mut actions = fixed_enemy_actions();
+    actions.extend((1..=MAX_HEXAGHOST_DIVIDER_DAMAGE).map(|damage| {
+        let mut builder = EnemyActionDetailsBuilder::new();
+        for _ in 0..6 {
+            builder = builder.push(EnemyEffect::Deal(Damage::Blockable(damage)));
+        }
+        (EnemyAction::HexaghostDivider(damage), builder.build())
+    }));
+    actions.extend((1..=MAX_BOOK_OF_STABBING_STAB_COUNT).map(|stab_count| {
+        let mut builder = EnemyActionDetailsBuilder::new();
+        for _ in 0..stab_count {
+            builder = builder.push(EnemyEffect::Deal(Damage::Blockable(6)));
+        }
+        (
+            EnemyAction::BookOfStabbingMultiStab(stab_count),
+            builder.build(),
         )
-    ],
-    SpikeSlimeMLick => [Inflict(PlayerCondition::Frail(1))],
-    SpikeSlimeSTackle => [Deal(Damage::Blockable(5))],
-);
+    }));
+    actions.extend((1..=MAX_THE_MAW_NOM_NOM_BITE_COUNT).map(|bite_count| {
+        let mut builder = EnemyActionDetailsBuilder::new();
+        for _ in 0..bite_count {
+            builder = builder.push(EnemyEffect::Deal(Damage::Blockable(5)));
+        }
+        (EnemyAction::TheMawNomNom(bite_count), builder.build())
+    }));
+    actions
+});
+
+fn fixed_enemy_actions() -> HashMap<EnemyAction, EnemyActionDetails> {
+    define_actions!(
+        AcidSlimeLCorrosiveSpit => [
+            Deal(Damage::Blockable(11)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Slimed, Card::Slimed]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        AcidSlimeLLick => [Inflict(PlayerCondition::Weak(2))],
+        AcidSlimeLSplit => [Split(Enemy::AcidSlimeM, Enemy::AcidSlimeM)],
+        AcidSlimeLTackle => [Deal(Damage::Blockable(16))],
+        AcidSlimeMCorrosiveSpit => [
+            Deal(Damage::Blockable(7)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Slimed]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        AcidSlimeMLick => [Inflict(PlayerCondition::Weak(1))],
+        AcidSlimeMTackle => [Deal(Damage::Blockable(10))],
+        AcidSlimeSLick => [Inflict(PlayerCondition::Weak(1))],
+        AcidSlimeSTackle => [Deal(Damage::Blockable(3))],
+        AwakenedOneDarkEcho => [Deal(Damage::Blockable(40))],
+        AwakenedOneRebirth => [ReviveAtFullHp] as Unknown,
+        AwakenedOneSlash => [Deal(Damage::Blockable(20))],
+        AwakenedOneSludge => [
+            Deal(Damage::Blockable(18)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Void]),
+                CardSelection::All,
+                CardDestination::ShuffledIntoDrawPile,
+                CostModifier::None,
+            )
+        ],
+        AwakenedOneSoulStrike => [
+            Deal(Damage::Blockable(6)),
+            Deal(Damage::Blockable(6)),
+            Deal(Damage::Blockable(6)),
+            Deal(Damage::Blockable(6))
+        ],
+        AwakenedOneTackle => [
+            Deal(Damage::Blockable(10)),
+            Deal(Damage::Blockable(10)),
+            Deal(Damage::Blockable(10))
+        ],
+        BearBearHug => [SapDexterity(2)],
+        BearLunge => [Deal(Damage::Blockable(9)), Gain(Resource::Block(9))],
+        BearMaul => [Deal(Damage::Blockable(18))],
+        BlueSlaverRake => [Deal(Damage::Blockable(7)), Inflict(PlayerCondition::Weak(1))],
+        BlueSlaverStab => [Deal(Damage::Blockable(12))],
+        BookOfStabbingSingleStab => [Deal(Damage::Blockable(21))],
+        BronzeAutomatonBoost => [Gain(Resource::Block(9)), Gain(Resource::Strength(3))],
+        BronzeAutomatonFlail => [Deal(Damage::Blockable(7)), Deal(Damage::Blockable(7))],
+        BronzeAutomatonHyperBeam => [Deal(Damage::Blockable(45))],
+        BronzeAutomatonSpawnOrbs => [Summon(Enemy::BronzeOrb, 2)] as Unknown,
+        BronzeAutomatonStunned => [] as Stunned,
+        BronzeOrbBeam => [Deal(Damage::Blockable(8))],
+        BronzeOrbStasis => [Stasis],
+        BronzeOrbSupportBeam => [ShieldLeader(12)],
+        ByrdCaw => [Gain(Resource::Strength(1))],
+        ByrdFlyUp => [Apply(EnemyCondition::Flight(3, 3))] as Unknown,
+        ByrdHeadbutt => [Deal(Damage::Blockable(3))],
+        ByrdPeck => [Deal(Damage::Blockable(1)), Deal(Damage::Blockable(1)), Deal(Damage::Blockable(1)), Deal(Damage::Blockable(1)), Deal(Damage::Blockable(1))],
+        ByrdStunned => [] as Stunned,
+        ByrdSwoop => [Deal(Damage::Blockable(12))],
+        CenturionDefend => [ShieldAlly(15)],
+        CenturionFury => [Deal(Damage::Blockable(6)), Deal(Damage::Blockable(6)), Deal(Damage::Blockable(6))],
+        CenturionSlash => [Deal(Damage::Blockable(12))],
+        ChosenDebilitate => [Deal(Damage::Blockable(10)), Inflict(PlayerCondition::Vulnerable(2))],
+        ChosenDrain => [Inflict(PlayerCondition::Weak(3)), Gain(Resource::Strength(3))],
+        ChosenHex => [Inflict(PlayerCondition::Hex(1))],
+        ChosenPoke => [Deal(Damage::Blockable(5)), Deal(Damage::Blockable(5))],
+        ChosenZap => [Deal(Damage::Blockable(18))],
+        CorruptHeartBloodShots => [
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2))
+        ],
+        CorruptHeartBuff(0) => [ClearNegativeStrength, Gain(Resource::Strength(2)), Apply(EnemyCondition::Artifact(2))],
+        CorruptHeartBuff(1) => [ClearNegativeStrength, Gain(Resource::Strength(2)), Apply(EnemyCondition::BeatOfDeath(1))],
+        CorruptHeartBuff(2) => [ClearNegativeStrength, Gain(Resource::Strength(2)), Apply(EnemyCondition::PainfulStabs)],
+        CorruptHeartBuff(3) => [ClearNegativeStrength, Gain(Resource::Strength(2)), Gain(Resource::Strength(10))],
+        CorruptHeartBuff(4) => [ClearNegativeStrength, Gain(Resource::Strength(2)), Gain(Resource::Strength(50))],
+        CorruptHeartDebilitate => [
+            Inflict(PlayerCondition::Vulnerable(2)),
+            Inflict(PlayerCondition::Weak(2)),
+            Inflict(PlayerCondition::Frail(2)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Dazed, Card::Slimed, Card::Wound, Card::Burn(false), Card::Void]),
+                CardSelection::All,
+                CardDestination::ShuffledIntoDrawPile,
+                CostModifier::None,
+            )
+        ],
+        CorruptHeartEcho => [Deal(Damage::Blockable(40))],
+        CultistDarkStrike => [Deal(Damage::Blockable(6))],
+        CultistIncantation => [Apply(EnemyCondition::Ritual(3, true))],
+        DaggerExplode => [Deal(Damage::Blockable(25)), SelfDestruct],
+        DaggerStab => [
+            Deal(Damage::Blockable(9)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Wound]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        DarklingChomp => [Deal(Damage::Blockable(8)), Deal(Damage::Blockable(8))],
+        DarklingHarden => [Gain(Resource::Block(12))],
+        DarklingNip(7) => [Deal(Damage::Blockable(7))],
+        DarklingNip(8) => [Deal(Damage::Blockable(8))],
+        DarklingNip(9) => [Deal(Damage::Blockable(9))],
+        DarklingNip(10) => [Deal(Damage::Blockable(10))],
+        DarklingNip(11) => [Deal(Damage::Blockable(11))],
+        DarklingRegrow => [] as Unknown,
+        DarklingReincarnate => [ReviveAtHalfHp, Apply(EnemyCondition::Regrow)],
+        DecaBeam => [
+            Deal(Damage::Blockable(10)),
+            Deal(Damage::Blockable(10)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Dazed, Card::Dazed]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        DecaSquareOfProtection => [Gain(Resource::Block(16)), ShieldAllies(16)],
+        DonuBeam => [Deal(Damage::Blockable(10)), Deal(Damage::Blockable(10))],
+        DonuCircleOfPower => [StrengthenParty(3)],
+        ExploderExplode => [Deal(Damage::BlockableNonAttack(30)), SelfDestruct] as Unknown,
+        ExploderSlam => [Deal(Damage::Blockable(9))],
+        FatGremlinSmash => [Deal(Damage::Blockable(4)), Inflict(PlayerCondition::Weak(1))],
+        FungiBeastBite => [Deal(Damage::Blockable(6))],
+        FungiBeastGrow => [Gain(Resource::Strength(3))],
+        GiantHeadCount => [Deal(Damage::Blockable(13))],
+        GiantHeadGlare => [Inflict(PlayerCondition::Weak(1))],
+        GiantHeadItIsTime(30) => [Deal(Damage::Blockable(30))],
+        GiantHeadItIsTime(35) => [Deal(Damage::Blockable(35))],
+        GiantHeadItIsTime(40) => [Deal(Damage::Blockable(40))],
+        GiantHeadItIsTime(45) => [Deal(Damage::Blockable(45))],
+        GiantHeadItIsTime(50) => [Deal(Damage::Blockable(50))],
+        GiantHeadItIsTime(55) => [Deal(Damage::Blockable(55))],
+        GiantHeadItIsTime(60) => [Deal(Damage::Blockable(60))],
+        GreenLouseBite(5) => [Deal(Damage::Blockable(5))],
+        GreenLouseBite(6) => [Deal(Damage::Blockable(6))],
+        GreenLouseBite(7) => [Deal(Damage::Blockable(7))],
+        GreenLouseSpitWeb => [Inflict(PlayerCondition::Weak(2))],
+        GremlinLeaderEncourage => [StrengthenParty(3), ShieldAllies(6)],
+        GremlinLeaderRally => [SummonGremlins(2)] as Unknown,
+        GremlinLeaderStab => [Deal(Damage::Blockable(6)), Deal(Damage::Blockable(6)), Deal(Damage::Blockable(6))],
+        GremlinNobBellow => [Apply(EnemyCondition::Enrage(2))],
+        GremlinNobRush => [Deal(Damage::Blockable(14))],
+        GremlinNobSkullBash => [Deal(Damage::Blockable(6)), Inflict(PlayerCondition::Vulnerable(2))],
+        GremlinWizardCharging => [],
+        GremlinWizardUltimateBlast => [Deal(Damage::Blockable(25))],
+        HexaghostActivate => [],
+        HexaghostInferno => [
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            Deal(Damage::Blockable(2)),
+            UpgradeBurns,
+            CreateCards(
+                CardPool::Fixed(&[Card::Burn(true), Card::Burn(true), Card::Burn(true)]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        HexaghostInflame => [Gain(Resource::Block(12)), Gain(Resource::Strength(2))],
+        HexaghostSear => [
+            Deal(Damage::Blockable(6)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Burn(false)]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        HexaghostSearPlus => [
+            Deal(Damage::Blockable(6)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Burn(true)]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        HexaghostTackle => [Deal(Damage::Blockable(5)), Deal(Damage::Blockable(5))],
+        JawWormBellow => [Gain(Resource::Strength(3)), Gain(Resource::Block(6))],
+        JawWormChomp => [Deal(Damage::Blockable(11))],
+        JawWormThrash => [Deal(Damage::Blockable(7)), Gain(Resource::Block(5))],
+        LagavulinAttack => [Deal(Damage::Blockable(18))],
+        LagavulinSiphonSoul => [SapDexterity(1), SapStrength(1)],
+        LagavulinSleep => [] as Sleeping,
+        LagavulinStunned => [] as Stunned,
+        LooterEscape => [Escape] as Cowardly,
+        LooterLunge => [Deal(Damage::Blockable(12))],
+        LooterMug => [Deal(Damage::Blockable(10))],
+        LooterSmokeBomb => [Gain(Resource::Block(6))],
+        MadGremlinScratch => [Deal(Damage::Blockable(4))],
+        MuggerEscape => [Escape] as Cowardly,
+        MuggerLunge => [Deal(Damage::Blockable(16))],
+        MuggerMug => [Deal(Damage::Blockable(10))],
+        MuggerSmokeBomb => [Gain(Resource::Block(11))],
+        MysticAttack => [Deal(Damage::Blockable(8)), Inflict(PlayerCondition::Frail(2))],
+        MysticBuff => [StrengthenParty(2)],
+        MysticHeal => [HealParty(16)],
+        NemesisScythe => [Deal(Damage::Blockable(45))],
+        NemesisTriAttack => [
+            Deal(Damage::Blockable(6)),
+            Deal(Damage::Blockable(6)),
+            Deal(Damage::Blockable(6))
+        ],
+        NemesisTriBurn => [
+            CreateCards(
+                CardPool::Fixed(&[Card::Burn(false), Card::Burn(false), Card::Burn(false)]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        OrbWalkerClaw => [Deal(Damage::Blockable(15))],
+        OrbWalkerLaser => [
+            Deal(Damage::Blockable(10)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Burn(false)]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            ),
+            CreateCards(
+                CardPool::Fixed(&[Card::Burn(false)]),
+                CardSelection::All,
+                CardDestination::ShuffledIntoDrawPile,
+                CostModifier::None,
+            )
+        ],
+        PointyAttack => [Deal(Damage::Blockable(5)), Deal(Damage::Blockable(5))],
+        RedLouseBite(5) => [Deal(Damage::Blockable(5))],
+        RedLouseBite(6) => [Deal(Damage::Blockable(6))],
+        RedLouseBite(7) => [Deal(Damage::Blockable(7))],
+        RedLouseGrow => [Gain(Resource::Strength(3))],
+        RedSlaverEntangle => [Inflict(PlayerCondition::Entangled)],
+        RedSlaverScrape => [Deal(Damage::Blockable(8)), Inflict(PlayerCondition::Vulnerable(1))],
+        RedSlaverStab => [Deal(Damage::Blockable(13))],
+        ReptomancerBigBite => [Deal(Damage::Blockable(30))],
+        ReptomancerSnakeStrike => [
+            Deal(Damage::Blockable(13)),
+            Deal(Damage::Blockable(13)),
+            Inflict(PlayerCondition::Weak(1))
+        ],
+        ReptomancerSummon => [SummonDaggers(1)] as Unknown,
+        RepulsorBash => [Deal(Damage::Blockable(11))],
+        RepulsorRepulse => [
+            CreateCards(
+                CardPool::Fixed(&[Card::Dazed, Card::Dazed]),
+                CardSelection::All,
+                CardDestination::ShuffledIntoDrawPile,
+                CostModifier::None,
+            )
+        ],
+        RomeoAgonizingSlash => [Deal(Damage::Blockable(10)), Inflict(PlayerCondition::Weak(2))],
+        RomeoCrossSlash => [Deal(Damage::Blockable(15))],
+        RomeoMock => [] as Unknown,
+        SentryBeam => [Deal(Damage::Blockable(9))],
+        SentryBolt => [
+            CreateCards(
+                CardPool::Fixed(&[Card::Dazed, Card::Dazed]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        ShelledParasiteDoubleStrike => [Deal(Damage::Blockable(6)), Deal(Damage::Blockable(6))],
+        ShelledParasiteFell => [Deal(Damage::Blockable(18)), Inflict(PlayerCondition::Frail(2))],
+        ShelledParasiteStunned => [] as Stunned,
+        ShelledParasiteSuck => [DealAndHeal(Damage::Blockable(10))],
+        ShieldGremlinProtect => [ShieldAlly(7)],
+        ShieldGremlinShieldBash => [Deal(Damage::Blockable(6))],
+        SlimeBossGoopSpray => [
+            CreateCards(
+                CardPool::Fixed(&[Card::Slimed, Card::Slimed, Card::Slimed]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        SlimeBossPreparing => [],
+        SlimeBossSlam => [Deal(Damage::Blockable(35))],
+        SlimeBossSplit => [Split(Enemy::SpikeSlimeL, Enemy::AcidSlimeL)],
+        SnakePlantChomp => [Deal(Damage::Blockable(7)), Deal(Damage::Blockable(7)), Deal(Damage::Blockable(7))],
+        SnakePlantEnfeeblingSpores => [Inflict(PlayerCondition::Frail(2)), Inflict(PlayerCondition::Weak(2))],
+        SneakyGremlinPuncture => [Deal(Damage::Blockable(9))],
+        SneckoBite => [Deal(Damage::Blockable(15))],
+        SneckoPerplexingGlare => [Inflict(PlayerCondition::Confused)],
+        SneckoTailWhip => [Deal(Damage::Blockable(8)), Inflict(PlayerCondition::Vulnerable(2))],
+        SphericGuardianActivate => [Gain(Resource::Block(25))],
+        SphericGuardianAttack => [Deal(Damage::Blockable(10)), Inflict(PlayerCondition::Frail(5))],
+        SphericGuardianHarden => [Gain(Resource::Block(15)), Deal(Damage::Blockable(10))],
+        SphericGuardianSlam => [Deal(Damage::Blockable(10)), Deal(Damage::Blockable(10))],
+        SpikeSlimeLFlameTackle => [
+            Deal(Damage::Blockable(16)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Slimed, Card::Slimed]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        SpikeSlimeLLick => [Inflict(PlayerCondition::Frail(2))],
+        SpikeSlimeLSplit => [Split(Enemy::SpikeSlimeM, Enemy::SpikeSlimeM)],
+        SpikeSlimeMFlameTackle => [
+            Deal(Damage::Blockable(8)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Slimed]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        SpikeSlimeMLick => [Inflict(PlayerCondition::Frail(1))],
+        SpikeSlimeSTackle => [Deal(Damage::Blockable(5))],
+        SpikerCut => [Deal(Damage::Blockable(7))],
+        SpikerSpike => [Apply(EnemyCondition::Thorns(2))],
+        SpireGrowthConstrict => [Inflict(PlayerCondition::Constricted(10))],
+        SpireGrowthQuickTackle => [Deal(Damage::Blockable(16))],
+        SpireGrowthSmash => [Deal(Damage::Blockable(22))],
+        SpireShieldBash => [Deal(Damage::Blockable(12)), SapStrength(1)],
+        SpireShieldFortify => [Gain(Resource::Block(30)), ShieldAllies(30)],
+        SpireShieldSmash => [DealAndBlock(Damage::Blockable(34))],
+        SpireSpearBurnStrike => [
+            Deal(Damage::Blockable(5)),
+            Deal(Damage::Blockable(5)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Burn(false), Card::Burn(false)]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        SpireSpearPiercer => [StrengthenParty(2)],
+        SpireSpearSkewer => [Deal(Damage::Blockable(10)), Deal(Damage::Blockable(10)), Deal(Damage::Blockable(10))],
+        TaskmasterScouringWhip => [
+            Deal(Damage::Blockable(7)),
+            CreateCards(
+                CardPool::Fixed(&[Card::Wound]),
+                CardSelection::All,
+                CardDestination::DiscardPile,
+                CostModifier::None,
+            )
+        ],
+        TheChampAnger => [RemoveDebuffs, Gain(Resource::Strength(6))],
+        TheChampDefensiveStance => [Gain(Resource::Block(15)), Apply(EnemyCondition::Metallicize(5))],
+        TheChampExecute => [Deal(Damage::Blockable(10)), Deal(Damage::Blockable(10))],
+        TheChampFaceSlap => [
+            Deal(Damage::Blockable(12)),
+            Inflict(PlayerCondition::Frail(2)),
+            Inflict(PlayerCondition::Vulnerable(2))
+        ],
+        TheChampGloat => [Gain(Resource::Strength(2))],
+        TheChampHeavySlash => [Deal(Damage::Blockable(16))],
+        TheChampTaunt => [Inflict(PlayerCondition::Weak(2)), Inflict(PlayerCondition::Vulnerable(2))],
+        TheCollectorBuff => [Gain(Resource::Block(15)), StrengthenParty(3)],
+        TheCollectorFireball => [Deal(Damage::Blockable(18))],
+        TheCollectorMegaDebuff => [
+            Inflict(PlayerCondition::Weak(3)),
+            Inflict(PlayerCondition::Vulnerable(3)),
+            Inflict(PlayerCondition::Frail(3))
+        ],
+        TheCollectorRevive => [Summon(Enemy::TorchHead, 2)] as Unknown,
+        TheCollectorSpawn => [Summon(Enemy::TorchHead, 2)] as Unknown,
+        TheGuardianChargingUp => [Gain(Resource::Block(9))],
+        TheGuardianDefensiveMode => [Apply(EnemyCondition::SharpHide(3))],
+        TheGuardianFierceBash => [Deal(Damage::Blockable(32))],
+        TheGuardianRollAttack => [Deal(Damage::Blockable(9))],
+        TheGuardianTwinSlam => [Deal(Damage::Blockable(8)), Deal(Damage::Blockable(8))],
+        TheGuardianVentSteam => [
+            Inflict(PlayerCondition::Weak(2)),
+            Inflict(PlayerCondition::Vulnerable(2))
+        ],
+        TheGuardianWhirlwind => [
+            Deal(Damage::Blockable(5)),
+            Deal(Damage::Blockable(5)),
+            Deal(Damage::Blockable(5)),
+            Deal(Damage::Blockable(5))
+        ],
+        TheMawDrool => [Gain(Resource::Strength(3))],
+        TheMawRoar => [Inflict(PlayerCondition::Weak(3)), Inflict(PlayerCondition::Frail(3))],
+        TheMawSlam => [Deal(Damage::Blockable(25))],
+        TimeEaterHaste => [RemoveDebuffs, HealToHalfHp],
+        TimeEaterHeadSlam => [
+            Deal(Damage::Blockable(26)),
+            Inflict(PlayerCondition::DrawReduction(1))
+        ],
+        TimeEaterReverberate => [
+            Deal(Damage::Blockable(7)),
+            Deal(Damage::Blockable(7)),
+            Deal(Damage::Blockable(7))
+        ],
+        TimeEaterRipple => [
+            Gain(Resource::Block(20)),
+            Inflict(PlayerCondition::Vulnerable(1)),
+            Inflict(PlayerCondition::Weak(1))
+        ],
+        TorchHeadTackle => [Deal(Damage::Blockable(7))],
+        TransientAttack(30) => [Deal(Damage::Blockable(30))],
+        TransientAttack(40) => [Deal(Damage::Blockable(40))],
+        TransientAttack(50) => [Deal(Damage::Blockable(50))],
+        TransientAttack(60) => [Deal(Damage::Blockable(60))],
+        TransientAttack(70) => [Deal(Damage::Blockable(70))],
+        WrithingMassFlail => [Deal(Damage::Blockable(15)), Gain(Resource::Block(15))],
+        WrithingMassImplant => [AddToDeck(Card::Parasite)],
+        WrithingMassMultiStrike => [
+            Deal(Damage::Blockable(7)),
+            Deal(Damage::Blockable(7)),
+            Deal(Damage::Blockable(7))
+        ],
+        WrithingMassStrongStrike => [Deal(Damage::Blockable(32))],
+        WrithingMassWither => [
+            Deal(Damage::Blockable(10)),
+            Inflict(PlayerCondition::Weak(2)),
+            Inflict(PlayerCondition::Vulnerable(2))
+        ],
+    )
+}
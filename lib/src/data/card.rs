@@ -10,6 +10,7 @@ use super::effect::{
     CardDestination, CardPool, CardSelection, CardSource, CostModifier, PlayerEffect,
     PlayerEffectCondition, Resource, TargetCondition, TargetEffect,
 };
+use super::stance::Stance;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Card {
@@ -399,6 +400,63 @@ pub const UNCOMMON_COLORLESS_CARD_POOL: &[Card] = &[
     Card::Trip(false),
 ];
 
+pub const RARE_COLORLESS_CARD_POOL: &[Card] = &[
+    Card::Apotheosis(false),
+    Card::Chrysalis(false),
+    Card::HandOfGreed(false),
+    Card::Magnetism(false),
+    Card::MasterOfStrategy(false),
+    Card::Mayhem(false),
+    Card::Metamorphosis(false),
+    Card::Panache(false),
+    Card::SadisticNature(false),
+    Card::SecretTechnique(false),
+    Card::SecretWeapon(false),
+    Card::TheBomb(false),
+    Card::ThinkingAhead(false),
+    Card::Transmutation(false),
+    Card::Violence(false),
+];
+
+/// The colorless cards that can be created during combat (e.g. by Jack Of All Trades). Ordered
+/// appropriately for fidelity with the game's rng.
+pub const COMBAT_COLORLESS_CARD_POOL: &[Card] = &[
+    Card::Madness(false),
+    Card::ThinkingAhead(false),
+    Card::MindBlast(false),
+    Card::Metamorphosis(false),
+    Card::JackOfAllTrades(false),
+    Card::SwiftStrike(false),
+    Card::GoodInstincts(false),
+    Card::MasterOfStrategy(false),
+    Card::Magnetism(false),
+    Card::Finesse(false),
+    Card::Discovery(false),
+    Card::Chrysalis(false),
+    Card::Transmutation(false),
+    Card::Panacea(false),
+    Card::Purity(false),
+    Card::Enlightenment(false),
+    Card::Forethought(false),
+    Card::FlashOfSteel(false),
+    Card::HandOfGreed(false),
+    Card::Mayhem(false),
+    Card::Apotheosis(false),
+    Card::SecretWeapon(false),
+    Card::Panache(false),
+    Card::Violence(false),
+    Card::DeepBreath(false),
+    Card::SecretTechnique(false),
+    Card::Blind(false),
+    Card::TheBomb(false),
+    Card::Impatience(false),
+    Card::DramaticEntrance(false),
+    Card::Trip(false),
+    Card::PanicButton(false),
+    Card::SadisticNature(false),
+    Card::DarkShackles(false),
+];
+
 pub const CURSE_CARD_POOL: &[Card] = &[
     Card::Regret,
     Card::Injury,
@@ -462,8 +520,10 @@ pub struct CardDetails {
     pub pain: bool,     // Lose 1 HP (unblockable) when other cards are played.
     pub parasite: bool, // If transformed or removed from your deck, lose 3 max HP.
     pub playable_only_if_all_cards_in_hand_are_attacks: bool,
+    pub playable_only_if_only_attack_in_hand: bool,
     pub requires_target: bool,
     pub retain: bool,
+    pub shuffle_back_into_draw_pile: bool,
     pub upgrade: Option<Card>,
     pub unplayable: bool,
 }
@@ -471,9 +531,12 @@ pub struct CardDetails {
 #[allow(dead_code)] // Clippy doesn't seem to notice that all methods are used in the macro.
 impl CardDetails {
     pub fn for_card(card: Card) -> &'static Self {
-        CARD_DETAILS
-            .get(&card)
-            .unwrap_or_else(|| panic!("No details for card {:?}", card))
+        Self::try_for_card(card).unwrap_or_else(|| panic!("No details for card {:?}", card))
+    }
+
+    /// Returns None for cards that aren't defined yet, such as those of the Silent and Defect.
+    pub fn try_for_card(card: Card) -> Option<&'static Self> {
+        CARD_DETAILS.get(&card).copied()
     }
 
     fn new(card: Card, type_: CardType, rarity: CardRarity, cost: EnergyCost) -> Self {
@@ -493,8 +556,10 @@ impl CardDetails {
             pain: false,
             parasite: false,
             playable_only_if_all_cards_in_hand_are_attacks: false,
+            playable_only_if_only_attack_in_hand: false,
             requires_target: false,
             retain: false,
+            shuffle_back_into_draw_pile: false,
             upgrade: calculate_upgrade(card),
             unplayable: false,
         }
@@ -530,6 +595,11 @@ impl CardDetails {
                 self.requires_target = true;
             }
         }
+        if let PlayerEffect::RepeatX(effect_chain) = effect {
+            if Self::effect_chain_requires_target(effect_chain) {
+                self.requires_target = true;
+            }
+        }
         if let PlayerEffect::ForEachExhausted(effect_chain) = effect {
             if Self::effect_chain_requires_target(effect_chain) {
                 self.requires_target = true;
@@ -574,11 +644,21 @@ impl CardDetails {
         self
     }
 
+    fn playable_only_if_only_attack_in_hand(mut self) -> Self {
+        self.playable_only_if_only_attack_in_hand = true;
+        self
+    }
+
     fn retain(mut self) -> Self {
         self.retain = true;
         self
     }
 
+    fn shuffle_back_into_draw_pile(mut self) -> Self {
+        self.shuffle_back_into_draw_pile = true;
+        self
+    }
+
     fn unplayable(mut self) -> Self {
         self.unplayable = true;
         self
@@ -676,6 +756,34 @@ macro_rules! define_card {
 // TODO: Fluent API
 static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
     vec![
+        define_card!(
+            (Alpha(false), Skill, Rare, One),
+            [CreateCards(
+                CardPool::Fixed(&[Card::Beta(false)]),
+                CardSelection::All,
+                CardDestination::ShuffledIntoDrawPile,
+                CostModifier::None
+            )],
+            exhaust
+        ),
+        define_card!(
+            (Alpha(true), Skill, Rare, One),
+            [CreateCards(
+                CardPool::Fixed(&[Card::Beta(false)]),
+                CardSelection::All,
+                CardDestination::ShuffledIntoDrawPile,
+                CostModifier::None
+            )],
+            [exhaust, innate]
+        ),
+        define_card!(
+            (Amplify(false), Skill, Uncommon, One),
+            [Apply(PlayerCondition::Amplify(1))]
+        ),
+        define_card!(
+            (Amplify(true), Skill, Uncommon, One),
+            [Apply(PlayerCondition::Amplify(2))]
+        ),
         define_card!(
             (Anger(false), Attack, Common, Zero),
             [
@@ -710,6 +818,16 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             [Upgrade(CardSource::AllCardsInCombat, CardSelection::All)],
             exhaust
         ),
+        define_card!(
+            (Apparition(false), Skill, Special, One),
+            [Apply(PlayerCondition::Intangible(1))],
+            [ethereal, exhaust]
+        ),
+        define_card!(
+            (Apparition(true), Skill, Special, One),
+            [Apply(PlayerCondition::Intangible(1))],
+            exhaust
+        ),
         define_card!(
             (Armaments(false), Skill, Common, One),
             [
@@ -724,6 +842,11 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 Upgrade(CardSource::Hand, CardSelection::All)
             ]
         ),
+        define_card!(
+            (AscendersBane, Curse, Special, Zero),
+            [],
+            [ethereal, irremovable, unplayable]
+        ),
         define_card!(
             (BandageUp(false), Skill, Uncommon, Zero),
             [Gain(Resource::Hp(4))],
@@ -756,6 +879,15 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 ToSingleTarget(TargetEffect::Inflict(EnemyCondition::Vulnerable(3)))
             ]
         ),
+        define_card!(
+            (BattleHymn(false), Power, Uncommon, One),
+            [Apply(PlayerCondition::BattleHymn(1))]
+        ),
+        define_card!(
+            (BattleHymn(true), Power, Uncommon, One),
+            [Apply(PlayerCondition::BattleHymn(1))],
+            innate
+        ),
         define_card!(
             (BattleTrance(false), Skill, Uncommon, Zero),
             [Draw(3), Apply(PlayerCondition::NoDraw)]
@@ -778,6 +910,56 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 Apply(PlayerCondition::Berserk(1))
             ]
         ),
+        define_card!(
+            (Beta(false), Skill, Special, Two),
+            [CreateCards(
+                CardPool::Fixed(&[Card::Omega(false)]),
+                CardSelection::All,
+                CardDestination::ShuffledIntoDrawPile,
+                CostModifier::None
+            )],
+            exhaust
+        ),
+        define_card!(
+            (Beta(true), Skill, Special, One),
+            [CreateCards(
+                CardPool::Fixed(&[Card::Omega(false)]),
+                CardSelection::All,
+                CardDestination::ShuffledIntoDrawPile,
+                CostModifier::None
+            )],
+            exhaust
+        ),
+        define_card!(
+            (Bite(false), Attack, Special, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(7))),
+                Gain(Resource::Hp(2))
+            ]
+        ),
+        define_card!(
+            (Bite(true), Attack, Special, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(8))),
+                Gain(Resource::Hp(3))
+            ]
+        ),
+        define_card!(
+            (Blasphemy(false), Skill, Rare, One),
+            [
+                EnterStance(Stance::Divinity),
+                Apply(PlayerCondition::Blasphemer)
+            ],
+            exhaust
+        ),
+        define_card!(
+            (Blasphemy(true), Skill, Rare, One),
+            [
+                EnterStance(Stance::Divinity),
+                Apply(PlayerCondition::Blasphemer)
+            ],
+            [exhaust, retain]
+        ),
         define_card!(
             (Blind(false), Skill, Uncommon, Zero),
             [ToSingleTarget(TargetEffect::Inflict(EnemyCondition::Weak(
@@ -826,6 +1008,30 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 Damage::BlockableEqualToPlayerBlock
             ))]
         ),
+        define_card!(
+            (BowlingBash(false), Attack, Common, One),
+            [ToSingleTarget(TargetEffect::DealOncePerEnemy(
+                Damage::Blockable(7)
+            ))]
+        ),
+        define_card!(
+            (BowlingBash(true), Attack, Common, One),
+            [ToSingleTarget(TargetEffect::DealOncePerEnemy(
+                Damage::Blockable(10)
+            ))]
+        ),
+        define_card!(
+            (Brilliance(false), Attack, Rare, One),
+            [ToSingleTarget(TargetEffect::Deal(
+                Damage::BlockablePlusMantraGained(12)
+            ))]
+        ),
+        define_card!(
+            (Brilliance(true), Attack, Rare, One),
+            [ToSingleTarget(TargetEffect::Deal(
+                Damage::BlockablePlusMantraGained(16)
+            ))]
+        ),
         define_card!(
             (Brutality(false), Power, Rare, Zero),
             [Apply(PlayerCondition::Brutality(1))]
@@ -875,6 +1081,14 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 Draw(3)
             ]
         ),
+        define_card!(
+            (Burst(false), Skill, Rare, One),
+            [Apply(PlayerCondition::Burst(1))]
+        ),
+        define_card!(
+            (Burst(true), Skill, Rare, One),
+            [Apply(PlayerCondition::Burst(2))]
+        ),
         define_card!(
             (Carnage(false), Attack, Uncommon, Two),
             [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(20)))],
@@ -885,6 +1099,30 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(28)))],
             ethereal
         ),
+        define_card!(
+            (CarveReality(false), Attack, Uncommon, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(6))),
+                CreateCards(
+                    CardPool::Fixed(&[Card::Smite(false)]),
+                    CardSelection::All,
+                    CardDestination::Hand,
+                    CostModifier::None
+                )
+            ]
+        ),
+        define_card!(
+            (CarveReality(true), Attack, Uncommon, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(10))),
+                CreateCards(
+                    CardPool::Fixed(&[Card::Smite(false)]),
+                    CardSelection::All,
+                    CardDestination::Hand,
+                    CostModifier::None
+                )
+            ]
+        ),
         define_card!(
             (Chrysalis(false), Skill, Rare, Two),
             [CreateCards(
@@ -938,6 +1176,19 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             ]
         ),
         define_card!((Clumsy, Curse, Special, Zero), [], [ethereal, unplayable]),
+        define_card!(
+            (Collect(false), Skill, Uncommon, X),
+            [RepeatX(&[PlayerEffect::Apply(PlayerCondition::Collect(1))])],
+            exhaust
+        ),
+        define_card!(
+            (Collect(true), Skill, Uncommon, X),
+            [
+                RepeatX(&[PlayerEffect::Apply(PlayerCondition::Collect(1))]),
+                Apply(PlayerCondition::Collect(1))
+            ],
+            exhaust
+        ),
         define_card!(
             (Combust(false), Power, Uncommon, One),
             [Apply(PlayerCondition::Combust(1, 5))]
@@ -946,6 +1197,38 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (Combust(true), Power, Uncommon, One),
             [Apply(PlayerCondition::Combust(1, 7))]
         ),
+        define_card!(
+            (Conclude(false), Attack, Uncommon, One),
+            [
+                ToAllEnemies(TargetEffect::Deal(Damage::Blockable(12))),
+                EndTurn
+            ]
+        ),
+        define_card!(
+            (Conclude(true), Attack, Uncommon, One),
+            [
+                ToAllEnemies(TargetEffect::Deal(Damage::Blockable(16))),
+                EndTurn
+            ]
+        ),
+        define_card!(
+            (ConjureBlade(false), Skill, Rare, X),
+            [CreateExpungerHittingXPlus(0)],
+            exhaust
+        ),
+        define_card!(
+            (ConjureBlade(true), Skill, Rare, X),
+            [CreateExpungerHittingXPlus(1)],
+            exhaust
+        ),
+        define_card!(
+            (Consecrate(false), Attack, Common, Zero),
+            [ToAllEnemies(TargetEffect::Deal(Damage::Blockable(5)))]
+        ),
+        define_card!(
+            (Consecrate(true), Attack, Common, Zero),
+            [ToAllEnemies(TargetEffect::Deal(Damage::Blockable(8)))]
+        ),
         define_card!(
             (Corruption(false), Power, Rare, Three),
             [Apply(PlayerCondition::Corruption)]
@@ -954,11 +1237,61 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (Corruption(true), Power, Rare, Two),
             [Apply(PlayerCondition::Corruption)]
         ),
+        define_card!(
+            (Crescendo(false), Skill, Common, One),
+            [EnterStance(Stance::Wrath)],
+            [exhaust, retain]
+        ),
+        define_card!(
+            (Crescendo(true), Skill, Common, Zero),
+            [EnterStance(Stance::Wrath)],
+            [exhaust, retain]
+        ),
+        define_card!(
+            (CrushJoints(false), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(8))),
+                Conditional(
+                    PlayerEffectCondition::IfLastCardPlayedWas(CardType::Skill),
+                    &[PlayerEffect::ToSingleTarget(TargetEffect::Inflict(
+                        EnemyCondition::Vulnerable(1)
+                    ))]
+                )
+            ]
+        ),
+        define_card!(
+            (CrushJoints(true), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(10))),
+                Conditional(
+                    PlayerEffectCondition::IfLastCardPlayedWas(CardType::Skill),
+                    &[PlayerEffect::ToSingleTarget(TargetEffect::Inflict(
+                        EnemyCondition::Vulnerable(2)
+                    ))]
+                )
+            ]
+        ),
         define_card!(
             (CurseOfTheBell, Curse, Special, Zero),
             [],
             [irremovable, unplayable]
         ),
+        define_card!(
+            (CutThroughFate(false), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(7))),
+                Scry(2),
+                Draw(1)
+            ]
+        ),
+        define_card!(
+            (CutThroughFate(true), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(9))),
+                Scry(3),
+                Draw(1)
+            ]
+        ),
         define_card!(
             (DarkEmbrace(false), Power, Uncommon, Two),
             [Apply(PlayerCondition::DarkEmbrace(1))]
@@ -990,6 +1323,30 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 unplayable
             ]
         ),
+        define_card!(
+            (DeceiveReality(false), Skill, Uncommon, One),
+            [
+                Gain(Resource::Block(4)),
+                CreateCards(
+                    CardPool::Fixed(&[Card::Safety(false)]),
+                    CardSelection::All,
+                    CardDestination::Hand,
+                    CostModifier::None
+                )
+            ]
+        ),
+        define_card!(
+            (DeceiveReality(true), Skill, Uncommon, One),
+            [
+                Gain(Resource::Block(7)),
+                CreateCards(
+                    CardPool::Fixed(&[Card::Safety(false)]),
+                    CardSelection::All,
+                    CardDestination::Hand,
+                    CostModifier::None
+                )
+            ]
+        ),
         define_card!(
             (DeepBreath(false), Skill, Uncommon, Zero),
             [
@@ -1030,6 +1387,55 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (DemonForm(true), Power, Rare, Three),
             [Apply(PlayerCondition::DemonForm(3))]
         ),
+        define_card!(
+            (DeusExMachina(false), Skill, Rare, Zero),
+            [],
+            [
+                on_draw(PlayerEffect::CreateCards(
+                    CardPool::Fixed(&[Card::Miracle(false), Card::Miracle(false)]),
+                    CardSelection::All,
+                    CardDestination::Hand,
+                    CostModifier::None
+                )),
+                exhaust,
+                unplayable
+            ]
+        ),
+        define_card!(
+            (DeusExMachina(true), Skill, Rare, Zero),
+            [],
+            [
+                on_draw(PlayerEffect::CreateCards(
+                    CardPool::Fixed(&[
+                        Card::Miracle(false),
+                        Card::Miracle(false),
+                        Card::Miracle(false)
+                    ]),
+                    CardSelection::All,
+                    CardDestination::Hand,
+                    CostModifier::None
+                )),
+                exhaust,
+                unplayable
+            ]
+        ),
+        define_card!(
+            (DevaForm(false), Power, Rare, Three),
+            [Apply(PlayerCondition::DevaForm(1, 1))],
+            ethereal
+        ),
+        define_card!(
+            (DevaForm(true), Power, Rare, Three),
+            [Apply(PlayerCondition::DevaForm(1, 1))]
+        ),
+        define_card!(
+            (Devotion(false), Power, Rare, One),
+            [Apply(PlayerCondition::Devotion(2))]
+        ),
+        define_card!(
+            (Devotion(true), Power, Rare, One),
+            [Apply(PlayerCondition::Devotion(3))]
+        ),
         define_card!(
             (Disarm(false), Skill, Uncommon, One),
             [ToSingleTarget(TargetEffect::SapStrength(2))],
@@ -1130,21 +1536,60 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             )]
         ),
         define_card!(
-            (Enlightenment(false), Skill, Uncommon, Zero),
-            [ManipulateCards(
-                CardSource::Hand,
-                CardSelection::All,
-                CardDestination::Hand,
-                CostModifier::ZeroThisTurn
-            )]
+            (EchoForm(false), Power, Rare, Three),
+            [Apply(PlayerCondition::EchoForm(1))],
+            ethereal
         ),
         define_card!(
-            (Enlightenment(true), Skill, Uncommon, Zero),
-            [ManipulateCards(
-                CardSource::Hand,
-                CardSelection::All,
+            (EchoForm(true), Power, Rare, Three),
+            [Apply(PlayerCondition::EchoForm(1))]
+        ),
+        define_card!(
+            (EmptyBody(false), Skill, Common, One),
+            [Gain(Resource::Block(7)), ExitStance]
+        ),
+        define_card!(
+            (EmptyBody(true), Skill, Common, One),
+            [Gain(Resource::Block(10)), ExitStance]
+        ),
+        define_card!(
+            (EmptyFist(false), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(9))),
+                ExitStance
+            ]
+        ),
+        define_card!(
+            (EmptyFist(true), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(14))),
+                ExitStance
+            ]
+        ),
+        define_card!(
+            (EmptyMind(false), Skill, Uncommon, One),
+            [ExitStance, Draw(2)]
+        ),
+        define_card!(
+            (EmptyMind(true), Skill, Uncommon, One),
+            [ExitStance, Draw(3)]
+        ),
+        define_card!(
+            (Enlightenment(false), Skill, Uncommon, Zero),
+            [ManipulateCards(
+                CardSource::Hand,
+                CardSelection::All,
                 CardDestination::Hand,
-                CostModifier::ZeroThisCombat
+                CostModifier::ReduceToOneThisTurn
+            )]
+        ),
+        define_card!(
+            (Enlightenment(true), Skill, Uncommon, Zero),
+            [ManipulateCards(
+                CardSource::Hand,
+                CardSelection::All,
+                CardDestination::Hand,
+                CostModifier::ReduceToOneThisCombat
             )]
         ),
         define_card!(
@@ -1155,6 +1600,67 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (Entrench(true), Skill, Uncommon, One),
             [Gain(Resource::CurrentBlockIsDoubled)]
         ),
+        define_card!(
+            (Eruption(false), Attack, Starter, Two),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(9))),
+                EnterStance(Stance::Wrath)
+            ]
+        ),
+        define_card!(
+            (Eruption(true), Attack, Starter, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(9))),
+                EnterStance(Stance::Wrath)
+            ]
+        ),
+        define_card!(
+            (Equilibrium(false), Skill, Uncommon, Two),
+            [
+                Gain(Resource::Block(13)),
+                Apply(PlayerCondition::Equilibrium(1))
+            ]
+        ),
+        define_card!(
+            (Equilibrium(true), Skill, Uncommon, Two),
+            [
+                Gain(Resource::Block(16)),
+                Apply(PlayerCondition::Equilibrium(1))
+            ]
+        ),
+        define_card!(
+            (Establishment(false), Power, Rare, One),
+            [Apply(PlayerCondition::Establishment(1))]
+        ),
+        define_card!(
+            (Establishment(true), Power, Rare, One),
+            [Apply(PlayerCondition::Establishment(1))],
+            innate
+        ),
+        define_card!(
+            (Evaluate(false), Skill, Common, One),
+            [
+                Gain(Resource::Block(6)),
+                CreateCards(
+                    CardPool::Fixed(&[Card::Insight(false)]),
+                    CardSelection::All,
+                    CardDestination::ShuffledIntoDrawPile,
+                    CostModifier::None
+                )
+            ]
+        ),
+        define_card!(
+            (Evaluate(true), Skill, Common, One),
+            [
+                Gain(Resource::Block(10)),
+                CreateCards(
+                    CardPool::Fixed(&[Card::Insight(false)]),
+                    CardSelection::All,
+                    CardDestination::ShuffledIntoDrawPile,
+                    CostModifier::None
+                )
+            ]
+        ),
         define_card!(
             (Evolve(false), Power, Uncommon, One),
             [Apply(PlayerCondition::Evolve(1))]
@@ -1183,6 +1689,54 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             )],
             exhaust
         ),
+        define_card!(
+            (Expunger(false), Attack, Special, One),
+            [ToSingleTarget(TargetEffect::DealXTimes(Damage::Blockable(
+                9
+            )))]
+        ),
+        define_card!(
+            (Expunger(true), Attack, Special, One),
+            [ToSingleTarget(TargetEffect::DealXTimes(Damage::Blockable(
+                15
+            )))]
+        ),
+        define_card!(
+            (Fasting(false), Power, Uncommon, Two),
+            [
+                Gain(Resource::Strength(3)),
+                Gain(Resource::Dexterity(3)),
+                Apply(PlayerCondition::Fasting(1))
+            ]
+        ),
+        define_card!(
+            (Fasting(true), Power, Uncommon, Two),
+            [
+                Gain(Resource::Strength(4)),
+                Gain(Resource::Dexterity(4)),
+                Apply(PlayerCondition::Fasting(1))
+            ]
+        ),
+        define_card!(
+            (FearNoEvil(false), Attack, Uncommon, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(8))),
+                ToSingleTarget(TargetEffect::Conditional(
+                    TargetCondition::IntendsToAttack,
+                    &[PlayerEffect::EnterStance(Stance::Calm)]
+                ))
+            ]
+        ),
+        define_card!(
+            (FearNoEvil(true), Attack, Uncommon, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(11))),
+                ToSingleTarget(TargetEffect::Conditional(
+                    TargetCondition::IntendsToAttack,
+                    &[PlayerEffect::EnterStance(Stance::Calm)]
+                ))
+            ]
+        ),
         define_card!(
             (Feed(false), Attack, Rare, One),
             [
@@ -1301,6 +1855,78 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 Apply(PlayerCondition::StrengthDown(4))
             ],
         ),
+        define_card!(
+            (FlurryOfBlows(false), Attack, Common, Zero),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(4)))]
+        ),
+        define_card!(
+            (FlurryOfBlows(true), Attack, Common, Zero),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(6)))]
+        ),
+        define_card!(
+            (FlyingSleeves(false), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(4))),
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(4)))
+            ],
+            retain
+        ),
+        define_card!(
+            (FlyingSleeves(true), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(6))),
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(6)))
+            ],
+            retain
+        ),
+        define_card!(
+            (FollowUp(false), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(7))),
+                Conditional(
+                    PlayerEffectCondition::IfLastCardPlayedWas(CardType::Attack),
+                    &[PlayerEffect::Gain(Resource::Energy(1))]
+                )
+            ]
+        ),
+        define_card!(
+            (FollowUp(true), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(11))),
+                Conditional(
+                    PlayerEffectCondition::IfLastCardPlayedWas(CardType::Attack),
+                    &[PlayerEffect::Gain(Resource::Energy(1))]
+                )
+            ]
+        ),
+        define_card!(
+            (ForeignInfluence(false), Skill, Uncommon, Zero),
+            [CreateCards(
+                CardPool::AnyColorAttackPool,
+                CardSelection::RandomThenPlayerChoice(3, 1),
+                CardDestination::Hand,
+                CostModifier::None
+            )],
+            exhaust
+        ),
+        define_card!(
+            (ForeignInfluence(true), Skill, Uncommon, Zero),
+            [CreateCards(
+                CardPool::AnyColorAttackPool,
+                CardSelection::RandomThenPlayerChoice(3, 1),
+                CardDestination::Hand,
+                CostModifier::ZeroThisTurn
+            )],
+            exhaust
+        ),
+        define_card!(
+            (Foresight(false), Power, Uncommon, One),
+            [Apply(PlayerCondition::Foresight(3))]
+        ),
+        define_card!(
+            (Foresight(true), Power, Uncommon, One),
+            [Apply(PlayerCondition::Foresight(4))]
+        ),
         define_card!(
             (Forethought(false), Skill, Uncommon, Zero),
             [ManipulateCards(
@@ -1337,6 +1963,26 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (GoodInstincts(true), Skill, Uncommon, Zero),
             [Gain(Resource::Block(9))]
         ),
+        define_card!(
+            (Halt(false), Skill, Common, Zero),
+            [
+                Gain(Resource::Block(3)),
+                Conditional(
+                    PlayerEffectCondition::IfInStance(Stance::Wrath),
+                    &[PlayerEffect::Gain(Resource::Block(9))]
+                )
+            ]
+        ),
+        define_card!(
+            (Halt(true), Skill, Common, Zero),
+            [
+                Gain(Resource::Block(4)),
+                Conditional(
+                    PlayerEffectCondition::IfInStance(Stance::Wrath),
+                    &[PlayerEffect::Gain(Resource::Block(14))]
+                )
+            ]
+        ),
         define_card!(
             (HandOfGreed(false), Attack, Rare, Two),
             [
@@ -1463,6 +2109,36 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             [Gain(Resource::Block(40))],
             exhaust
         ),
+        define_card!(
+            (Indignation(false), Skill, Uncommon, One),
+            [
+                Conditional(
+                    PlayerEffectCondition::IfInStance(Stance::Wrath),
+                    &[PlayerEffect::ToAllEnemies(TargetEffect::Inflict(
+                        EnemyCondition::Vulnerable(3)
+                    ))]
+                ),
+                Conditional(
+                    PlayerEffectCondition::IfNotInStance(Stance::Wrath),
+                    &[PlayerEffect::EnterStance(Stance::Wrath)]
+                )
+            ]
+        ),
+        define_card!(
+            (Indignation(true), Skill, Uncommon, One),
+            [
+                Conditional(
+                    PlayerEffectCondition::IfInStance(Stance::Wrath),
+                    &[PlayerEffect::ToAllEnemies(TargetEffect::Inflict(
+                        EnemyCondition::Vulnerable(5)
+                    ))]
+                ),
+                Conditional(
+                    PlayerEffectCondition::IfNotInStance(Stance::Wrath),
+                    &[PlayerEffect::EnterStance(Stance::Wrath)]
+                )
+            ]
+        ),
         define_card!(
             (InfernalBlade(false), Skill, Uncommon, One),
             [CreateCards(
@@ -1492,6 +2168,42 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             [Gain(Resource::Strength(3))]
         ),
         define_card!((Injury, Curse, Special, Zero), [], [unplayable]),
+        define_card!(
+            (InnerPeace(false), Skill, Uncommon, One),
+            [
+                Conditional(
+                    PlayerEffectCondition::IfInStance(Stance::Calm),
+                    &[PlayerEffect::Draw(3)]
+                ),
+                Conditional(
+                    PlayerEffectCondition::IfNotInStance(Stance::Calm),
+                    &[PlayerEffect::EnterStance(Stance::Calm)]
+                )
+            ]
+        ),
+        define_card!(
+            (InnerPeace(true), Skill, Uncommon, One),
+            [
+                Conditional(
+                    PlayerEffectCondition::IfInStance(Stance::Calm),
+                    &[PlayerEffect::Draw(4)]
+                ),
+                Conditional(
+                    PlayerEffectCondition::IfNotInStance(Stance::Calm),
+                    &[PlayerEffect::EnterStance(Stance::Calm)]
+                )
+            ]
+        ),
+        define_card!(
+            (Insight(false), Skill, Special, Zero),
+            [Draw(2)],
+            [exhaust, retain]
+        ),
+        define_card!(
+            (Insight(true), Skill, Special, Zero),
+            [Draw(3)],
+            [exhaust, retain]
+        ),
         define_card!(
             (Intimidate(false), Skill, Uncommon, Zero),
             [ToAllEnemies(TargetEffect::Inflict(EnemyCondition::Weak(1)))],
@@ -1536,6 +2248,22 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             )],
             exhaust
         ),
+        define_card!(
+            (Jax(false), Skill, Special, Zero),
+            [TakeDamage(Damage::HpLoss(3)), Gain(Resource::Strength(2))]
+        ),
+        define_card!(
+            (Jax(true), Skill, Special, Zero),
+            [TakeDamage(Damage::HpLoss(3)), Gain(Resource::Strength(3))]
+        ),
+        define_card!(
+            (Judgment(false), Skill, Rare, One),
+            [ToSingleTarget(TargetEffect::ExecuteIfHpAtMost(30))]
+        ),
+        define_card!(
+            (Judgment(true), Skill, Rare, One),
+            [ToSingleTarget(TargetEffect::ExecuteIfHpAtMost(40))]
+        ),
         define_card!(
             (Juggernaut(false), Power, Rare, Two),
             [Apply(PlayerCondition::Juggernaut(5))],
@@ -1544,6 +2272,58 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (Juggernaut(true), Power, Rare, Two),
             [Apply(PlayerCondition::Juggernaut(7))],
         ),
+        define_card!(
+            (JustLucky(false), Attack, Common, Zero),
+            [
+                Scry(1),
+                Gain(Resource::Block(2)),
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(3)))
+            ]
+        ),
+        define_card!(
+            (JustLucky(true), Attack, Common, Zero),
+            [
+                Scry(2),
+                Gain(Resource::Block(3)),
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(4)))
+            ]
+        ),
+        define_card!(
+            (LessonLearned(false), Attack, Rare, Two),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(10))),
+                ToSingleTarget(TargetEffect::Conditional(
+                    TargetCondition::AttackWasFatal,
+                    &[PlayerEffect::Upgrade(
+                        CardSource::Deck,
+                        CardSelection::Random(1)
+                    )]
+                ))
+            ],
+            exhaust
+        ),
+        define_card!(
+            (LessonLearned(true), Attack, Rare, Two),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(13))),
+                ToSingleTarget(TargetEffect::Conditional(
+                    TargetCondition::AttackWasFatal,
+                    &[PlayerEffect::Upgrade(
+                        CardSource::Deck,
+                        CardSelection::Random(1)
+                    )]
+                ))
+            ],
+            exhaust
+        ),
+        define_card!(
+            (LikeWater(false), Power, Uncommon, One),
+            [Apply(PlayerCondition::LikeWater(5))]
+        ),
+        define_card!(
+            (LikeWater(true), Power, Uncommon, One),
+            [Apply(PlayerCondition::LikeWater(7))]
+        ),
         define_card!(
             (LimitBreak(false), Skill, Rare, One),
             [Gain(Resource::CurrentStrengthIsDoubled)],
@@ -1581,6 +2361,26 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (Magnetism(true), Power, Rare, One),
             [Apply(PlayerCondition::Magnetism(1))]
         ),
+        define_card!(
+            (Malaise(false), Skill, Rare, X),
+            [RepeatX(&[
+                PlayerEffect::ToSingleTarget(TargetEffect::SapStrength(1)),
+                PlayerEffect::ToSingleTarget(TargetEffect::Inflict(EnemyCondition::Weak(1)))
+            ])],
+            exhaust
+        ),
+        define_card!(
+            (Malaise(true), Skill, Rare, X),
+            [
+                RepeatX(&[
+                    PlayerEffect::ToSingleTarget(TargetEffect::SapStrength(1)),
+                    PlayerEffect::ToSingleTarget(TargetEffect::Inflict(EnemyCondition::Weak(1)))
+                ]),
+                ToSingleTarget(TargetEffect::SapStrength(1)),
+                ToSingleTarget(TargetEffect::Inflict(EnemyCondition::Weak(1)))
+            ],
+            exhaust
+        ),
         define_card!(
             (MasterOfStrategy(false), Skill, Rare, Zero),
             [Draw(3)],
@@ -1591,6 +2391,14 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             [Draw(4)],
             exhaust
         ),
+        define_card!(
+            (MasterReality(false), Power, Rare, One),
+            [Apply(PlayerCondition::MasterReality)]
+        ),
+        define_card!(
+            (MasterReality(true), Power, Rare, Zero),
+            [Apply(PlayerCondition::MasterReality)]
+        ),
         define_card!(
             (Mayhem(false), Power, Rare, Two),
             [Apply(PlayerCondition::Mayhem(1))]
@@ -1599,6 +2407,40 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (Mayhem(true), Power, Rare, One),
             [Apply(PlayerCondition::Mayhem(1))]
         ),
+        define_card!(
+            (Meditate(false), Skill, Uncommon, One),
+            [
+                ManipulateCards(
+                    CardSource::DiscardPile,
+                    CardSelection::PlayerChoice(1),
+                    CardDestination::HandAndRetain,
+                    CostModifier::None
+                ),
+                EnterStance(Stance::Calm),
+                EndTurn
+            ]
+        ),
+        define_card!(
+            (Meditate(true), Skill, Uncommon, One),
+            [
+                ManipulateCards(
+                    CardSource::DiscardPile,
+                    CardSelection::PlayerChoice(2),
+                    CardDestination::HandAndRetain,
+                    CostModifier::None
+                ),
+                EnterStance(Stance::Calm),
+                EndTurn
+            ]
+        ),
+        define_card!(
+            (MentalFortress(false), Power, Uncommon, One),
+            [Apply(PlayerCondition::MentalFortress(4))]
+        ),
+        define_card!(
+            (MentalFortress(true), Power, Uncommon, One),
+            [Apply(PlayerCondition::MentalFortress(6))]
+        ),
         define_card!(
             (Metallicize(false), Power, Uncommon, One),
             [Apply(PlayerCondition::Metallicize(3))]
@@ -1641,6 +2483,16 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             ))],
             innate
         ),
+        define_card!(
+            (Miracle(false), Skill, Special, Zero),
+            [Gain(Resource::Energy(1))],
+            [exhaust, retain]
+        ),
+        define_card!(
+            (Miracle(true), Skill, Special, Zero),
+            [Gain(Resource::Energy(2))],
+            [exhaust, retain]
+        ),
         define_card!(
             (Necronomicurse, Curse, Special, Zero),
             [],
@@ -1651,9 +2503,18 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                     CardDestination::Hand,
                     CostModifier::None
                 )),
+                irremovable,
                 unplayable
             ]
         ),
+        define_card!(
+            (Nirvana(false), Power, Uncommon, One),
+            [Apply(PlayerCondition::Nirvana(3))]
+        ),
+        define_card!(
+            (Nirvana(true), Power, Uncommon, One),
+            [Apply(PlayerCondition::Nirvana(4))]
+        ),
         define_card!((Normality, Curse, Special, Zero), [], [unplayable]),
         define_card!(
             (Offering(false), Skill, Rare, Zero),
@@ -1673,6 +2534,24 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             ],
             exhaust
         ),
+        define_card!(
+            (Omega(false), Power, Special, Three),
+            [Apply(PlayerCondition::Omega(50))]
+        ),
+        define_card!(
+            (Omega(true), Power, Special, Three),
+            [Apply(PlayerCondition::Omega(60))]
+        ),
+        define_card!(
+            (Omniscience(false), Skill, Rare, Four),
+            [PlayTwiceThenExhaust(CardSource::DrawPile)],
+            exhaust
+        ),
+        define_card!(
+            (Omniscience(true), Skill, Rare, Three),
+            [PlayTwiceThenExhaust(CardSource::DrawPile)],
+            exhaust
+        ),
         define_card!((Pain, Curse, Special, Zero), [], [pain, unplayable]),
         define_card!(
             (Panacea(false), Skill, Uncommon, Zero),
@@ -1715,6 +2594,16 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 Damage::BlockableCountingStrikeCards(6, 3)
             ))]
         ),
+        define_card!(
+            (Perserverance(false), Skill, Uncommon, Two),
+            [Gain(Resource::Block(5))],
+            retain
+        ),
+        define_card!(
+            (Perserverance(true), Skill, Uncommon, Two),
+            [Gain(Resource::Block(7))],
+            retain
+        ),
         define_card!(
             (PommelStrike(false), Attack, Common, One),
             [
@@ -1753,6 +2642,90 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 Gain(Resource::Block(20))
             ]
         ),
+        define_card!(
+            (Pray(false), Skill, Uncommon, One),
+            [
+                Gain(Resource::Mantra(3)),
+                CreateCards(
+                    CardPool::Fixed(&[Card::Insight(false)]),
+                    CardSelection::All,
+                    CardDestination::ShuffledIntoDrawPile,
+                    CostModifier::None
+                )
+            ]
+        ),
+        define_card!(
+            (Pray(true), Skill, Uncommon, One),
+            [
+                Gain(Resource::Mantra(4)),
+                CreateCards(
+                    CardPool::Fixed(&[Card::Insight(false)]),
+                    CardSelection::All,
+                    CardDestination::ShuffledIntoDrawPile,
+                    CostModifier::None
+                )
+            ]
+        ),
+        define_card!(
+            (PressurePoints(false), Skill, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Inflict(EnemyCondition::Mark(8))),
+                ToAllEnemies(TargetEffect::LoseHpEqualToMark)
+            ]
+        ),
+        define_card!(
+            (PressurePoints(true), Skill, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Inflict(EnemyCondition::Mark(11))),
+                ToAllEnemies(TargetEffect::LoseHpEqualToMark)
+            ]
+        ),
+        define_card!(
+            (Pride(false), Curse, Special, One),
+            [],
+            [
+                exhaust,
+                innate,
+                on_linger(PlayerEffect::CreateCards(
+                    CardPool::Fixed(&[Card::Pride(false)]),
+                    CardSelection::All,
+                    CardDestination::TopOfDrawPile,
+                    CostModifier::None
+                ))
+            ]
+        ),
+        define_card!(
+            (Pride(true), Curse, Special, One),
+            [],
+            [
+                exhaust,
+                innate,
+                on_linger(PlayerEffect::CreateCards(
+                    CardPool::Fixed(&[Card::Pride(true)]),
+                    CardSelection::All,
+                    CardDestination::TopOfDrawPile,
+                    CostModifier::None
+                ))
+            ]
+        ),
+        define_card!(
+            (Prostrate(false), Skill, Common, Zero),
+            [Gain(Resource::Mantra(2)), Gain(Resource::Block(4))]
+        ),
+        define_card!(
+            (Prostrate(true), Skill, Common, Zero),
+            [Gain(Resource::Mantra(3)), Gain(Resource::Block(4))]
+        ),
+        define_card!(
+            (Protect(false), Skill, Common, Two),
+            [Gain(Resource::Block(12))],
+            retain
+        ),
+        define_card!(
+            (Protect(true), Skill, Common, Two),
+            [Gain(Resource::Block(16))],
+            retain
+        ),
         define_card!(
             (Pummel(false), Attack, Uncommon, One),
             [
@@ -1800,6 +2773,51 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (Rage(true), Skill, Uncommon, Zero),
             [Apply(PlayerCondition::Rage(5))]
         ),
+        define_card!(
+            (Ragnarok(false), Attack, Rare, Three),
+            [
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(5))),
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(5))),
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(5))),
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(5))),
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(5)))
+            ]
+        ),
+        define_card!(
+            (Ragnarok(true), Attack, Rare, Three),
+            [
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(6))),
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(6))),
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(6))),
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(6))),
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(6))),
+                ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(6)))
+            ]
+        ),
+        define_card!(
+            (ReachHeaven(false), Attack, Uncommon, Two),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(10))),
+                CreateCards(
+                    CardPool::Fixed(&[Card::ThroughViolence(false)]),
+                    CardSelection::All,
+                    CardDestination::ShuffledIntoDrawPile,
+                    CostModifier::None
+                )
+            ]
+        ),
+        define_card!(
+            (ReachHeaven(true), Attack, Uncommon, Two),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(15))),
+                CreateCards(
+                    CardPool::Fixed(&[Card::ThroughViolence(false)]),
+                    CardSelection::All,
+                    CardDestination::ShuffledIntoDrawPile,
+                    CostModifier::None
+                )
+            ]
+        ),
         define_card!(
             (Regret, Curse, Special, Zero),
             [],
@@ -1862,6 +2880,36 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 )
             ]
         ),
+        define_card!(
+            (ReinforcedBody(false), Skill, Uncommon, X),
+            [RepeatX(&[PlayerEffect::Gain(Resource::Block(7))])]
+        ),
+        define_card!(
+            (ReinforcedBody(true), Skill, Uncommon, X),
+            [RepeatX(&[PlayerEffect::Gain(Resource::Block(9))])]
+        ),
+        define_card!(
+            (RitualDagger(false), Attack, Special, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(15))),
+                ToSingleTarget(TargetEffect::Conditional(
+                    TargetCondition::AttackWasFatal,
+                    &[PlayerEffect::RampUpCardDamagePermanently(3)]
+                ))
+            ],
+            exhaust
+        ),
+        define_card!(
+            (RitualDagger(true), Attack, Special, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(15))),
+                ToSingleTarget(TargetEffect::Conditional(
+                    TargetCondition::AttackWasFatal,
+                    &[PlayerEffect::RampUpCardDamagePermanently(5)]
+                ))
+            ],
+            exhaust
+        ),
         define_card!(
             (Rupture(false), Power, Uncommon, One),
             [Apply(PlayerCondition::Rupture(1))]
@@ -1870,6 +2918,14 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (Rupture(true), Power, Uncommon, One),
             [Apply(PlayerCondition::Rupture(2))]
         ),
+        define_card!(
+            (Rushdown(false), Power, Uncommon, One),
+            [Apply(PlayerCondition::Rushdown(2))]
+        ),
+        define_card!(
+            (Rushdown(true), Power, Uncommon, Zero),
+            [Apply(PlayerCondition::Rushdown(2))]
+        ),
         define_card!(
             (SadisticNature(false), Power, Rare, Zero),
             [Apply(PlayerCondition::Sadistic(5))]
@@ -1879,6 +2935,72 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             [Apply(PlayerCondition::Sadistic(7))]
         ),
         // SearingBlow defined in the lazy constructor of CARD_DETAILS
+        define_card!(
+            (Safety(false), Skill, Special, One),
+            [Gain(Resource::Block(12))],
+            [exhaust, retain]
+        ),
+        define_card!(
+            (Safety(true), Skill, Special, One),
+            [Gain(Resource::Block(16))],
+            [exhaust, retain]
+        ),
+        define_card!(
+            (Sanctity(false), Skill, Uncommon, One),
+            [
+                Gain(Resource::Block(6)),
+                Conditional(
+                    PlayerEffectCondition::IfLastCardPlayedWas(CardType::Skill),
+                    &[PlayerEffect::Draw(2)]
+                )
+            ]
+        ),
+        define_card!(
+            (Sanctity(true), Skill, Uncommon, One),
+            [
+                Gain(Resource::Block(9)),
+                Conditional(
+                    PlayerEffectCondition::IfLastCardPlayedWas(CardType::Skill),
+                    &[PlayerEffect::Draw(2)]
+                )
+            ]
+        ),
+        define_card!(
+            (SandsOfTime(false), Attack, Uncommon, Four),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(20)))],
+            retain
+        ),
+        define_card!(
+            (SandsOfTime(true), Attack, Uncommon, Four),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(26)))],
+            retain
+        ),
+        define_card!(
+            (SashWhip(false), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(8))),
+                Conditional(
+                    PlayerEffectCondition::IfLastCardPlayedWas(CardType::Attack),
+                    &[PlayerEffect::ToSingleTarget(TargetEffect::Inflict(
+                        EnemyCondition::Weak(1)
+                    ))]
+                )
+            ]
+        ),
+        define_card!(
+            (SashWhip(true), Attack, Common, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(10))),
+                Conditional(
+                    PlayerEffectCondition::IfLastCardPlayedWas(CardType::Attack),
+                    &[PlayerEffect::ToSingleTarget(TargetEffect::Inflict(
+                        EnemyCondition::Weak(2)
+                    ))]
+                )
+            ]
+        ),
+        define_card!((Scrawl(false), Skill, Rare, One), [Draw(10)], exhaust),
+        define_card!((Scrawl(true), Skill, Rare, Zero), [Draw(10)], exhaust),
         define_card!(
             (SecondWind(false), Skill, Uncommon, One),
             [
@@ -1903,6 +3025,25 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 ForEachExhausted(&[PlayerEffect::Gain(Resource::Block(7))])
             ]
         ),
+        define_card!(
+            (SecretTechnique(false), Skill, Rare, Zero),
+            [ManipulateCards(
+                CardSource::SkillsInDrawPile,
+                CardSelection::PlayerChoice(1),
+                CardDestination::Hand,
+                CostModifier::None
+            )],
+            exhaust
+        ),
+        define_card!(
+            (SecretTechnique(true), Skill, Rare, Zero),
+            [ManipulateCards(
+                CardSource::SkillsInDrawPile,
+                CardSelection::PlayerChoice(1),
+                CardDestination::Hand,
+                CostModifier::None
+            )]
+        ),
         define_card!(
             (SecretWeapon(false), Skill, Rare, Zero),
             [ManipulateCards(
@@ -1920,8 +3061,7 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 CardSelection::PlayerChoice(1),
                 CardDestination::Hand,
                 CostModifier::None
-            )],
-            exhaust
+            )]
         ),
         define_card!(
             (SeeingRed(false), Skill, Uncommon, One),
@@ -1975,6 +3115,16 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 unplayable
             ]
         ),
+        define_card!(
+            (Shiv(false), Attack, Special, Zero),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(4)))],
+            exhaust
+        ),
+        define_card!(
+            (Shiv(true), Attack, Special, Zero),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(6)))],
+            exhaust
+        ),
         define_card!(
             (Shockwave(false), Skill, Uncommon, Two),
             [
@@ -1997,7 +3147,55 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (ShrugItOff(true), Skill, Common, One),
             [Gain(Resource::Block(11)), Draw(1)]
         ),
+        define_card!(
+            (SignatureMove(false), Attack, Uncommon, Two),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(30)))],
+            playable_only_if_only_attack_in_hand
+        ),
+        define_card!(
+            (SignatureMove(true), Attack, Uncommon, Two),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(40)))],
+            playable_only_if_only_attack_in_hand
+        ),
+        define_card!(
+            (SimmeringFury(false), Skill, Uncommon, One),
+            [Apply(PlayerCondition::SimmeringFury(2))]
+        ),
+        define_card!(
+            (SimmeringFury(true), Skill, Uncommon, One),
+            [Apply(PlayerCondition::SimmeringFury(3))]
+        ),
+        define_card!(
+            (Skewer(false), Attack, Uncommon, X),
+            [RepeatX(&[PlayerEffect::ToSingleTarget(
+                TargetEffect::Deal(Damage::Blockable(7))
+            )])]
+        ),
+        define_card!(
+            (Skewer(true), Attack, Uncommon, X),
+            [RepeatX(&[PlayerEffect::ToSingleTarget(
+                TargetEffect::Deal(Damage::Blockable(10))
+            )])]
+        ),
         define_card!((Slimed, Status, Common, One), [], [exhaust]),
+        define_card!(
+            (Smite(false), Attack, Special, One),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(12)))],
+            [exhaust, retain]
+        ),
+        define_card!(
+            (Smite(true), Attack, Special, One),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(16)))],
+            [exhaust, retain]
+        ),
+        define_card!(
+            (SpiritShield(false), Skill, Rare, Two),
+            [Gain(Resource::BlockPerCardInHand(3))]
+        ),
+        define_card!(
+            (SpiritShield(true), Skill, Rare, Two),
+            [Gain(Resource::BlockPerCardInHand(4))]
+        ),
         define_card!(
             (SpotWeakness(false), Skill, Uncommon, One),
             [ToSingleTarget(TargetEffect::Conditional(
@@ -2020,6 +3218,14 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (Strike(true), Attack, Starter, One),
             [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(9)))]
         ),
+        define_card!(
+            (Study(false), Power, Uncommon, Two),
+            [Apply(PlayerCondition::Study(1))]
+        ),
+        define_card!(
+            (Study(true), Power, Uncommon, One),
+            [Apply(PlayerCondition::Study(1))]
+        ),
         define_card!(
             (SwiftStrike(false), Attack, Common, Zero),
             [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(7)))]
@@ -2028,6 +3234,20 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             (SwiftStrike(true), Attack, Common, Zero),
             [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(10)))]
         ),
+        define_card!(
+            (Swivel(false), Skill, Uncommon, Two),
+            [
+                Gain(Resource::Block(8)),
+                Apply(PlayerCondition::FreeAttack(1))
+            ]
+        ),
+        define_card!(
+            (Swivel(true), Skill, Uncommon, Two),
+            [
+                Gain(Resource::Block(11)),
+                Apply(PlayerCondition::FreeAttack(1))
+            ]
+        ),
         define_card!(
             (SwordBoomerang(false), Attack, Common, One),
             [
@@ -2045,6 +3265,51 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 ToRandomEnemy(TargetEffect::Deal(Damage::Blockable(3))),
             ],
         ),
+        define_card!(
+            (TalkToTheHand(false), Attack, Uncommon, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(5))),
+                ToSingleTarget(TargetEffect::Inflict(EnemyCondition::BlockReturn(2)))
+            ],
+            exhaust
+        ),
+        define_card!(
+            (TalkToTheHand(true), Attack, Uncommon, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(7))),
+                ToSingleTarget(TargetEffect::Inflict(EnemyCondition::BlockReturn(3)))
+            ],
+            exhaust
+        ),
+        define_card!(
+            (Tantrum(false), Attack, Uncommon, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(3))),
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(3))),
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(3))),
+                EnterStance(Stance::Wrath)
+            ],
+            shuffle_back_into_draw_pile
+        ),
+        define_card!(
+            (Tantrum(true), Attack, Uncommon, One),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(3))),
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(3))),
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(3))),
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(3))),
+                EnterStance(Stance::Wrath)
+            ],
+            shuffle_back_into_draw_pile
+        ),
+        define_card!(
+            (TheBomb(false), Skill, Rare, Two),
+            [Apply(PlayerCondition::TheBomb(3, 40))]
+        ),
+        define_card!(
+            (TheBomb(true), Skill, Rare, Two),
+            [Apply(PlayerCondition::TheBomb(3, 50))]
+        ),
         define_card!(
             (ThinkingAhead(false), Skill, Rare, Zero),
             [
@@ -2070,6 +3335,24 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 )
             ]
         ),
+        define_card!(
+            (ThirdEye(false), Skill, Common, One),
+            [Gain(Resource::Block(7)), Scry(3)]
+        ),
+        define_card!(
+            (ThirdEye(true), Skill, Common, One),
+            [Gain(Resource::Block(9)), Scry(5)]
+        ),
+        define_card!(
+            (ThroughViolence(false), Attack, Special, Zero),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(20)))],
+            [exhaust, retain]
+        ),
+        define_card!(
+            (ThroughViolence(true), Attack, Special, Zero),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(30)))],
+            [exhaust, retain]
+        ),
         define_card!(
             (Thunderclap(false), Attack, Common, One),
             [
@@ -2084,6 +3367,16 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 ToAllEnemies(TargetEffect::Inflict(EnemyCondition::Vulnerable(1)))
             ]
         ),
+        define_card!(
+            (Tranquility(false), Skill, Common, One),
+            [EnterStance(Stance::Calm)],
+            [exhaust, retain]
+        ),
+        define_card!(
+            (Tranquility(true), Skill, Common, Zero),
+            [EnterStance(Stance::Calm)],
+            [exhaust, retain]
+        ),
         define_card!(
             (Transmutation(false), Skill, Rare, X),
             [CreateCards(
@@ -2170,6 +3463,24 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 ToSingleTarget(TargetEffect::Inflict(EnemyCondition::Vulnerable(2)))
             ]
         ),
+        define_card!(
+            (Vault(false), Skill, Rare, Three),
+            [TakeExtraTurn, EndTurn],
+            exhaust
+        ),
+        define_card!(
+            (Vault(true), Skill, Rare, Two),
+            [TakeExtraTurn, EndTurn],
+            exhaust
+        ),
+        define_card!(
+            (Vigilance(false), Skill, Starter, Two),
+            [Gain(Resource::Block(8)), EnterStance(Stance::Calm)]
+        ),
+        define_card!(
+            (Vigilance(true), Skill, Starter, Two),
+            [Gain(Resource::Block(12)), EnterStance(Stance::Calm)]
+        ),
         define_card!(
             (Violence(false), Skill, Rare, Zero),
             [ManipulateCards(
@@ -2199,6 +3510,20 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 unplayable
             ]
         ),
+        define_card!(
+            (Wallop(false), Attack, Uncommon, Two),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(9))),
+                Gain(Resource::BlockEqualToUnblockedDamage)
+            ]
+        ),
+        define_card!(
+            (Wallop(true), Attack, Uncommon, Two),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(12))),
+                Gain(Resource::BlockEqualToUnblockedDamage)
+            ]
+        ),
         define_card!(
             (Warcry(false), Skill, Common, Zero),
             [
@@ -2225,6 +3550,44 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
             ],
             exhaust
         ),
+        define_card!(
+            (WaveOfTheHand(false), Skill, Uncommon, One),
+            [Apply(PlayerCondition::WaveOfTheHand(1))]
+        ),
+        define_card!(
+            (WaveOfTheHand(true), Skill, Uncommon, One),
+            [Apply(PlayerCondition::WaveOfTheHand(2))]
+        ),
+        define_card!(
+            (Weave(false), Attack, Uncommon, Zero),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(4)))]
+        ),
+        define_card!(
+            (Weave(true), Attack, Uncommon, Zero),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(6)))]
+        ),
+        define_card!(
+            (WellLaidPlans(false), Power, Uncommon, One),
+            [Apply(PlayerCondition::WellLaidPlans(1))]
+        ),
+        define_card!(
+            (WellLaidPlans(true), Power, Uncommon, One),
+            [Apply(PlayerCondition::WellLaidPlans(2))]
+        ),
+        define_card!(
+            (WheelKick(false), Attack, Uncommon, Two),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(15))),
+                Draw(2)
+            ]
+        ),
+        define_card!(
+            (WheelKick(true), Attack, Uncommon, Two),
+            [
+                ToSingleTarget(TargetEffect::Deal(Damage::Blockable(20))),
+                Draw(2)
+            ]
+        ),
         define_card!(
             (Whirlwind(false), Attack, Uncommon, X),
             [ToAllEnemies(TargetEffect::DealXTimes(Damage::Blockable(5)))]
@@ -2257,7 +3620,52 @@ static ALL_CARDS: Lazy<Vec<CardDetails>> = Lazy::new(|| {
                 )
             ]
         ),
+        define_card!(
+            (WindmillStrike(false), Attack, Uncommon, Two),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(7)))],
+            retain
+        ),
+        define_card!(
+            (WindmillStrike(true), Attack, Uncommon, Two),
+            [ToSingleTarget(TargetEffect::Deal(Damage::Blockable(10)))],
+            retain
+        ),
+        define_card!(
+            (Wish(false), Skill, Rare, Three),
+            [ChooseOne(&[
+                PlayerEffect::Apply(PlayerCondition::PlatedArmor(6)),
+                PlayerEffect::Gain(Resource::Strength(3)),
+                PlayerEffect::Gain(Resource::Gold(25))
+            ])],
+            exhaust
+        ),
+        define_card!(
+            (Wish(true), Skill, Rare, Three),
+            [ChooseOne(&[
+                PlayerEffect::Apply(PlayerCondition::PlatedArmor(8)),
+                PlayerEffect::Gain(Resource::Strength(4)),
+                PlayerEffect::Gain(Resource::Gold(30))
+            ])],
+            exhaust
+        ),
+        define_card!(
+            (Worship(false), Skill, Uncommon, Two),
+            [Gain(Resource::Mantra(5))]
+        ),
+        define_card!(
+            (Worship(true), Skill, Uncommon, Two),
+            [Gain(Resource::Mantra(5))],
+            retain
+        ),
         define_card!((Wound, Status, Special, Zero), [], [unplayable]),
+        define_card!(
+            (WreathOfFlame(false), Skill, Uncommon, One),
+            [Apply(PlayerCondition::Vigor(5))]
+        ),
+        define_card!(
+            (WreathOfFlame(true), Skill, Uncommon, One),
+            [Apply(PlayerCondition::Vigor(8))]
+        ),
         define_card!((Writhe, Curse, Special, Zero), [], [innate, unplayable]),
     ]
 });
@@ -2662,6 +4070,8 @@ mod test {
                 PlayerEffect::ToAllEnemies(TargetEffect::DealXTimes(_))
                     | PlayerEffect::CreateCards(_, CardSelection::RandomX, _, _)
                     | PlayerEffect::ManipulateCards(_, CardSelection::RandomX, _, _)
+                    | PlayerEffect::RepeatX(_)
+                    | PlayerEffect::CreateExpungerHittingXPlus(_)
             )
         })
     }
@@ -2686,4 +4096,20 @@ mod test {
             assert!(seen.insert(card.card), "Duplicate card: {:?}", card.card);
         }
     }
+
+    #[test]
+    fn test_colorless_and_curse_pools_are_defined() {
+        for card in UNCOMMON_COLORLESS_CARD_POOL
+            .iter()
+            .chain(RARE_COLORLESS_CARD_POOL)
+            .chain(COMBAT_COLORLESS_CARD_POOL)
+            .chain(CURSE_CARD_POOL)
+        {
+            assert!(
+                CARD_DETAILS.contains_key(card),
+                "Undefined card: {:?}",
+                card
+            );
+        }
+    }
 }
@@ -16,6 +16,9 @@ pub struct Character {
     /// The character's starting deck in the order displayed in-game.
     pub starting_deck: &'static [Card],
 
+    /// The starter card that events such as Match and Keep offer, e.g. Bash for the Ironclad.
+    pub start_card_for_event: Card,
+
     /// The character's common cards, available from shops, encounters, etc. Ordered appropriately
     /// for fidelity with the game's rng.
     pub common_card_pool: &'static [Card],
@@ -30,10 +33,10 @@ pub struct Character {
     /// For when a random attack card is needed. Omits healing cards like Feed and Reaper.
     pub attack_card_pool: &'static [Card],
 
-    /// For when a random skill card is needed.
+    /// For when a random skill card is needed. Omits healing cards like Alchemize.
     pub skill_card_pool: &'static [Card],
 
-    /// For when a random power card is needed.
+    /// For when a random power card is needed. Omits healing cards like Self Repair.
     pub power_card_pool: &'static [Card],
 
     /// Potions, again the same order as the game's rng.
@@ -80,6 +83,7 @@ pub const IRONCLAD: &Character = &Character {
         Card::Defend(false),
         Card::Bash(false),
     ],
+    start_card_for_event: Card::Bash(false),
     common_card_pool: &[
         Card::Anger(false),
         Card::Cleave(false),
@@ -428,6 +432,7 @@ pub const SILENT: &Character = &Character {
         Card::Survivor(false),
         Card::Neutralize(false),
     ],
+    start_card_for_event: Card::Neutralize(false),
     common_card_pool: &[
         Card::CloakAndDagger(false),
         Card::SneakyStrike(false),
@@ -505,9 +510,82 @@ pub const SILENT: &Character = &Character {
         Card::CorpseExplosion(false),
         Card::Alchemize(false),
     ],
-    attack_card_pool: &[], // TODO: Add attack cards
-    skill_card_pool: &[],  // TODO: Add skill cards
-    power_card_pool: &[],  // TODO: Add power cards
+    attack_card_pool: &[
+        Card::FlyingKnee(false),
+        Card::SuckerPunch(false),
+        Card::Slice(false),
+        Card::QuickSlash(false),
+        Card::PoisonedStab(false),
+        Card::DaggerThrow(false),
+        Card::Bane(false),
+        Card::DaggerSpray(false),
+        Card::SneakyStrike(false),
+        Card::Predator(false),
+        Card::AllOutAttack(false),
+        Card::MasterfulStab(false),
+        Card::Flechettes(false),
+        Card::Backstab(false),
+        Card::Dash(false),
+        Card::Eviscerate(false),
+        Card::HeelHook(false),
+        Card::Finisher(false),
+        Card::Skewer(false),
+        Card::RiddleWithHoles(false),
+        Card::EndlessAgony(false),
+        Card::Choke(false),
+        Card::DieDieDie(false),
+        Card::Unload(false),
+        Card::GlassKnife(false),
+        Card::GrandFinale(false),
+    ],
+    skill_card_pool: &[
+        Card::DodgeAndRoll(false),
+        Card::PiercingWail(false),
+        Card::Prepared(false),
+        Card::Outmaneuver(false),
+        Card::Backflip(false),
+        Card::Acrobatics(false),
+        Card::Deflect(false),
+        Card::BladeDance(false),
+        Card::DeadlyPoison(false),
+        Card::CloakAndDagger(false),
+        Card::Distraction(false),
+        Card::Concentrate(false),
+        Card::BouncingFlask(false),
+        Card::Reflex(false),
+        Card::Terror(false),
+        Card::EscapePlan(false),
+        Card::CalculatedGamble(false),
+        Card::Setup(false),
+        Card::Blur(false),
+        Card::Expertise(false),
+        Card::Tactician(false),
+        Card::Catalyst(false),
+        Card::LegSweep(false),
+        Card::CripplingCloud(false),
+        Card::CorpseExplosion(false),
+        Card::Malaise(false),
+        Card::PhantasmalKiller(false),
+        Card::Adrenaline(false),
+        Card::Doppelganger(false),
+        Card::Burst(false),
+        Card::Nightmare(false),
+        Card::BulletTime(false),
+        Card::StormOfSteel(false),
+    ],
+    power_card_pool: &[
+        Card::Footwork(false),
+        Card::Accuracy(false),
+        Card::InfiniteBlades(false),
+        Card::NoxiousFumes(false),
+        Card::WellLaidPlans(false),
+        Card::Caltrops(false),
+        Card::Envenom(false),
+        Card::WraithForm(false),
+        Card::ToolsOfTheTrade(false),
+        Card::AfterImage(false),
+        Card::AThousandCuts(false),
+    ],
     potion_pool: &[
         Potion::PoisonPotion,
         Potion::CunningPotion,
@@ -700,6 +778,7 @@ pub const DEFECT: &Character = &Character {
         Card::Zap(false),
         Card::Dualcast(false),
     ],
+    start_card_for_event: Card::Zap(false),
     common_card_pool: &[
         Card::GoForTheEyes(false),
         Card::BallLightning(false),
@@ -777,9 +856,81 @@ pub const DEFECT: &Character = &Character {
         Card::Hyperbeam(false),
         Card::MultiCast(false),
     ],
-    attack_card_pool: &[], // TODO: Add attack cards
-    skill_card_pool: &[],  // TODO: Add skill cards
-    power_card_pool: &[],  // TODO: Add power cards
+    attack_card_pool: &[
+        Card::ColdSnap(false),
+        Card::BeamCell(false),
+        Card::SweepingBeam(false),
+        Card::Claw(false),
+        Card::Rebound(false),
+        Card::Barrage(false),
+        Card::CompileDriver(false),
+        Card::Streamline(false),
+        Card::BallLightning(false),
+        Card::GoForTheEyes(false),
+        Card::DoomAndGloom(false),
+        Card::Scrape(false),
+        Card::Bullseye(false),
+        Card::RipAndTear(false),
+        Card::Ftl(false),
+        Card::Melter(false),
+        Card::Blizzard(false),
+        Card::Sunder(false),
+        Card::Hyperbeam(false),
+        Card::ThunderStrike(false),
+        Card::MeteorStrike(false),
+        Card::AllForOne(false),
+        Card::CoreSurge(false),
+    ],
+    skill_card_pool: &[
+        Card::SteamBarrier(false),
+        Card::Leap(false),
+        Card::Hologram(false),
+        Card::ChargeBattery(false),
+        Card::Turbo(false),
+        Card::Coolheaded(false),
+        Card::Stack(false),
+        Card::Recursion(false),
+        Card::WhiteNoise(false),
+        Card::Skim(false),
+        Card::Recycle(false),
+        Card::Reprogram(false),
+        Card::AutoShields(false),
+        Card::ReinforcedBody(false),
+        Card::DoubleEnergy(false),
+        Card::Darkness(false),
+        Card::ForceField(false),
+        Card::Equilibrium(false),
+        Card::Tempest(false),
+        Card::BootSequence(false),
+        Card::Chill(false),
+        Card::Chaos(false),
+        Card::Aggregate(false),
+        Card::Fusion(false),
+        Card::Consume(false),
+        Card::Glacier(false),
+        Card::Overclock(false),
+        Card::MultiCast(false),
+        Card::Rainbow(false),
+        Card::Seek(false),
+        Card::Reboot(false),
+        Card::Amplify(false),
+        Card::Fission(false),
+    ],
+    power_card_pool: &[
+        Card::Defragment(false),
+        Card::Capacitor(false),
+        Card::Heatsinks(false),
+        Card::StaticDischarge(false),
+        Card::Loop(false),
+        Card::HelloWorld(false),
+        Card::Storm(false),
+        Card::BiasedCognition(false),
+        Card::MachineLearning(false),
+        Card::Electrodynamics(false),
+        Card::Buffer(false),
+        Card::EchoForm(false),
+        Card::CreativeAi(false),
+    ],
     potion_pool: &[
         Potion::FocusPotion,
         Potion::PotionOfCapacity,
@@ -970,6 +1121,7 @@ pub const WATCHER: &Character = &Character {
         Card::Eruption(false),
         Card::Vigilance(false),
     ],
+    start_card_for_event: Card::Eruption(false),
     common_card_pool: &[
         Card::Consecrate(false),
         Card::BowlingBash(false),
@@ -1047,9 +1199,82 @@ pub const WATCHER: &Character = &Character {
         Card::ConjureBlade(false),
         Card::Judgment(false),
     ],
-    attack_card_pool: &[], // TODO: Add attack cards
-    skill_card_pool: &[],  // TODO: Add skill cards
-    power_card_pool: &[],  // TODO: Add power cards
+    attack_card_pool: &[
+        Card::EmptyFist(false),
+        Card::CrushJoints(false),
+        Card::FollowUp(false),
+        Card::CutThroughFate(false),
+        Card::SashWhip(false),
+        Card::FlurryOfBlows(false),
+        Card::JustLucky(false),
+        Card::FlyingSleeves(false),
+        Card::BowlingBash(false),
+        Card::Consecrate(false),
+        Card::SignatureMove(false),
+        Card::Weave(false),
+        Card::Tantrum(false),
+        Card::Conclude(false),
+        Card::SandsOfTime(false),
+        Card::FearNoEvil(false),
+        Card::ReachHeaven(false),
+        Card::Wallop(false),
+        Card::CarveReality(false),
+        Card::WindmillStrike(false),
+        Card::TalkToTheHand(false),
+        Card::WheelKick(false),
+        Card::Brilliance(false),
+        Card::Ragnarok(false),
+    ],
+    skill_card_pool: &[
+        Card::Prostrate(false),
+        Card::Evaluate(false),
+        Card::PressurePoints(false),
+        Card::EmptyBody(false),
+        Card::Tranquility(false),
+        Card::Crescendo(false),
+        Card::ThirdEye(false),
+        Card::Protect(false),
+        Card::Halt(false),
+        Card::Pray(false),
+        Card::EmptyMind(false),
+        Card::Worship(false),
+        Card::Swivel(false),
+        Card::Perserverance(false),
+        Card::Meditate(false),
+        Card::WaveOfTheHand(false),
+        Card::DeceiveReality(false),
+        Card::InnerPeace(false),
+        Card::Collect(false),
+        Card::WreathOfFlame(false),
+        Card::ForeignInfluence(false),
+        Card::Indignation(false),
+        Card::Sanctity(false),
+        Card::SimmeringFury(false),
+        Card::Judgment(false),
+        Card::ConjureBlade(false),
+        Card::Blasphemy(false),
+        Card::Scrawl(false),
+        Card::Vault(false),
+        Card::Alpha(false),
+        Card::Wish(false),
+        Card::Omniscience(false),
+        Card::SpiritShield(false),
+        Card::DeusExMachina(false),
+    ],
+    power_card_pool: &[
+        Card::Nirvana(false),
+        Card::Study(false),
+        Card::MentalFortress(false),
+        Card::Rushdown(false),
+        Card::Fasting(false),
+        Card::LikeWater(false),
+        Card::BattleHymn(false),
+        Card::Foresight(false),
+        Card::MasterReality(false),
+        Card::Devotion(false),
+        Card::Establishment(false),
+        Card::DevaForm(false),
+    ],
     potion_pool: &[
         Potion::BottledMiracle,
         Potion::StancePotion,
@@ -1244,4 +1469,34 @@ mod tests {
         assert!(<&'static Character>::try_from("Unknown").is_err());
         assert!(<&'static Character>::try_from("").is_err());
     }
+
+    #[test]
+    fn test_typed_card_pools_hold_every_card_but_healing_ones() {
+        let healing_cards = [
+            Card::Alchemize(false),
+            Card::Feed(false),
+            Card::GeneticAlgorithm(false),
+            Card::LessonLearned(false),
+            Card::Reaper(false),
+            Card::SelfRepair(false),
+        ];
+        for character in [IRONCLAD, SILENT, DEFECT, WATCHER] {
+            let mut by_rarity = character
+                .common_card_pool
+                .iter()
+                .chain(character.uncommon_card_pool)
+                .chain(character.rare_card_pool)
+                .filter(|card| !healing_cards.contains(card))
+                .collect::<Vec<_>>();
+            let mut by_type = character
+                .attack_card_pool
+                .iter()
+                .chain(character.skill_card_pool)
+                .chain(character.power_card_pool)
+                .collect::<Vec<_>>();
+            by_rarity.sort();
+            by_type.sort();
+            assert_eq!(by_type, by_rarity);
+        }
+    }
 }
@@ -1,30 +1,137 @@
 use crate::types::{
-    AttackCount, Block, DrawCount, Energy, Hp, JustApplied, StackCount, Strength, Turns,
+    AttackCount, Block, DrawCount, Energy, Gold, Hp, JustApplied, Mantra, ScryCount, StackCount,
+    Strength, Turns,
 };
 
+use super::card::Card;
+
 /// Source: Slay the Spire Wiki (https://slay-the-spire.fandom.com/wiki/Buffs)
 /// Source: Slay the Spire Wiki (https://slay-the-spire.fandom.com/wiki/Category:Debuffs)
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum EnemyCondition {
+    /// Upon losing HP to an attack, it gains X Strength.
+    Angry(Strength),
+
+    /// Negates the next X debuffs applied to it.
+    Artifact(StackCount),
+
+    /// It attacks from behind the player, dealing 50% more damage.
+    BackAttack,
+
+    /// Its Block is not removed at the start of its turn.
+    Barricade,
+
+    /// Whenever the player plays a card, the player takes X damage.
+    BeatOfDeath(Hp),
+
+    /// Whenever the player attacks it, the player gains X Block.
+    BlockReturn(Block),
+
     /// Upon receiving attack damage, it gains X Block, once per combat.
     CurlUp(Block),
 
+    /// Whenever the player plays a Power, it gains X Strength.
+    Curiosity(Strength),
+
     /// Whenever the player plays a skill, it gains X Strength.
     Enrage(Strength),
 
+    /// Counts down the turns until it explodes, dealing 30 damage to the player and dying.
+    Explosive(Turns),
+
+    /// Counts down the turns it has left; it dies at the end of its turn once X reaches 1.
+    Fading(Turns),
+
+    /// Takes 50% less attack damage. Loses a stack whenever an attack costs it HP, grounding it
+    /// at 0; the X stacks are restored to Y at the start of its turn.
+    Flight(StackCount, StackCount),
+
+    /// Any damage it takes is reduced to 1, for X turns.
+    Intangible(Turns, JustApplied),
+
+    /// It can lose only X more HP this turn. X resets to Y at the start of its turn.
+    Invincible(Hp, Hp),
+
+    /// Lightning and Dark orbs deal 50% more damage to it, for X turns.
+    LockOn(Turns),
+
+    /// Upon losing HP to an attack, it gains X Block and X increases by 1. X resets to Y at the
+    /// end of its turn.
+    Malleable(Block, Block),
+
+    /// Whenever the player plays Pressure Points, it loses X HP.
+    Mark(StackCount),
+
+    /// At the end of its turn, it gains X Block.
+    Metallicize(Block),
+
+    /// It leaves the fight when its leader dies.
+    Minion,
+
+    /// After receiving X more damage, it gains 20 Block and shifts to Defensive Mode.
+    ModeShift(Hp),
+
+    /// At the start of its turn, it loses X HP and X decreases by 1.
+    Poison(StackCount),
+
+    /// Whenever its attacks cost the player HP, a Wound is added to the player's discard pile.
+    PainfulStabs,
+
+    /// At the end of its turn, it gains X Block. Loses a stack whenever an attack costs it HP.
+    PlatedArmor(Block),
+
+    /// Upon losing HP to an attack, it picks a new intent.
+    Reactive,
+
+    /// At the end of its turn, it heals X HP.
+    Regenerate(Hp),
+
+    /// Upon dying while another of its kind still stands, it lies half-dead and revives with
+    /// half its max HP two turns later.
+    Regrow,
+
     /// At the end of its turn, it gains X strength.
     Ritual(Strength, JustApplied),
 
+    /// Whenever the player plays an attack, the player takes X damage.
+    SharpHide(Hp),
+
+    /// Upon losing HP, it loses that much Strength until the end of its turn.
+    Shifting,
+
+    /// It takes 10% more attack damage for each of the X cards the player has played this turn.
+    Slow(StackCount),
+
+    /// When its HP falls to half or below, it splits into two smaller slimes.
+    Split,
+
     /// On death, it applies X Vulnerable.
     SporeCloud(StackCount),
 
+    /// Holds a card taken from the player's draw pile, returning it to their hand on death.
+    Stasis(Card),
+
     /// Lose X Strength this turn.
     StrengthLossThisTurn(Strength),
 
+    /// At the end of its turn, it gains X Strength.
+    StrengthUp(Strength),
+
+    /// Whenever it attacks, it steals X of the player's Gold. The Y Gold stolen so far is
+    /// returned if it's killed.
+    Thievery(Gold, Gold),
+
     /// When attacked, deals X damage back.
     Thorns(Hp),
 
+    /// Counts the cards the player plays; on the 12th, it ends the player's turn and gains 2
+    /// Strength.
+    TimeWarp(StackCount),
+
+    /// Upon dying, it rises again at full HP in its awakened form.
+    Unawakened,
+
     /// It takes 50% more damage from the player's attacks.
     Vulnerable(Turns),
 
@@ -34,24 +141,42 @@ pub enum EnemyCondition {
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum PlayerCondition {
+    /// Your next X Power cards are played twice.
+    Amplify(StackCount),
+
     /// Negates X debuffs.
     Artifact(StackCount),
 
     /// Block is not removed at the start of turn.
     Barricade,
 
+    /// At the start of your turn, add X Smites into your hand.
+    BattleHymn(StackCount),
+
     /// At the start of your turn, gain X Energy.
     Berserk(Energy),
 
+    /// At the start of your turn, die.
+    Blasphemer,
+
     /// At the start of your turn, lose X HP and draw X cards.
     Brutality(DrawCount),
 
+    /// Your next X Skills are played twice.
+    Burst(StackCount),
+
+    /// Put a Miracle+ into your hand at the start of your next X turns.
+    Collect(StackCount),
+
     /// At the end of your turn, lose N HP and deal X damage to ALL enemies.
     Combust(Hp, Hp),
 
     /// The costs of your cards are randomized on draw, from 0 to 3.
     Confused,
 
+    /// At the end of your turn, take X damage.
+    Constricted(Hp),
+
     /// Skills cost 0. Whenever you play a Skill, Exhaust it.
     Corruption,
 
@@ -61,12 +186,39 @@ pub enum PlayerCondition {
     /// At the start of your turn, gain X Strength.
     DemonForm(Strength),
 
+    /// At the start of your turn, gain N Energy and increase this gain by X.
+    DevaForm(Energy, StackCount),
+
+    /// At the start of your turn, gain X Mantra.
+    Devotion(Mantra),
+
     /// Your next X Attacks are played twice.
     DoubleTap(AttackCount),
 
+    /// Draw 1 fewer card for the next X turns.
+    DrawReduction(Turns),
+
+    /// Your next X cards are played twice.
+    Duplication(StackCount),
+
+    /// The first X cards you play each turn are played twice.
+    EchoForm(StackCount),
+
+    /// You may not play any Attacks this turn.
+    Entangled,
+
+    /// Retain your hand for X turns.
+    Equilibrium(Turns),
+
+    /// Whenever a card is Retained, lower its cost by X.
+    Establishment(StackCount),
+
     /// Whenever you draw a Status card, draw X cards.
     Evolve(DrawCount),
 
+    /// Gain X less Energy at the start of each turn.
+    Fasting(Energy),
+
     /// Whenever a card is Exhausted, gain X Block.
     FeelNoPain(Block),
 
@@ -76,56 +228,104 @@ pub enum PlayerCondition {
     /// When attacked, deals X damage back. Wears off at the start of next turn.
     FlameBarrier(Hp),
 
+    /// At the start of your turn, Scry X.
+    Foresight(ScryCount),
+
     /// Block gained from cards is reduced by 25%.
     Frail(Turns),
 
+    /// The next X Attacks you play cost 0.
+    FreeAttack(StackCount),
+
+    /// Whenever you play a non-Attack card, shuffle X Dazed into your draw pile.
+    Hex(StackCount),
+
     /// Reduce ALL damage taken and HP losses to 1 this turn. Lasts X turns.
     Intangible(Turns),
 
     /// Whenever you gain Block, deal X damage to a random enemy.
     Juggernaut(Hp),
 
+    /// At the end of your turn, if you are in Calm, gain X Block.
+    LikeWater(Block),
+
     /// At the start of your turn, add X random colorless cards into your hand.
     Magnetism(StackCount),
 
+    /// Whenever a card is created during combat, Upgrade it.
+    MasterReality,
+
     /// At the start of your turn, play the top X cards of your draw pile.
     Mayhem(StackCount),
 
+    /// Whenever you switch Stances, gain X Block.
+    MentalFortress(Block),
+
     /// At the end of your turn, gain X Block.
     Metallicize(Block),
 
+    /// Whenever you Scry, gain X Block.
+    Nirvana(Block),
+
     /// You may not gain Block from cards for the next X turns.
     NoBlock(Turns),
 
     /// You may not draw any more cards this turn.
     NoDraw,
 
+    /// At the end of your turn, deal X damage to ALL enemies.
+    Omega(Hp),
+
     /// If you play N more cards this turn, deal X damage to all enemies.
     Panache(StackCount, Hp),
 
+    /// At the end of your turn, gain X Block. Receiving unblocked attack damage reduces this by 1.
+    PlatedArmor(Block),
+
     /// Whenever you play an Attack, gain X Block.
     Rage(Block),
 
     /// Whenever you lose HP from a card, gain X Strength.
     Rupture(Strength),
 
+    /// Whenever you enter Wrath, draw X cards.
+    Rushdown(DrawCount),
+
     /// Whenever you apply a Debuff to an enemy, deal X damage.
     Sadistic(Hp),
 
+    /// At the start of your next turn, enter Wrath and draw X cards.
+    SimmeringFury(DrawCount),
+
     /// At the end of your turn, lose X Strength.
     StrengthDown(Strength),
 
+    /// At the end of your turn, shuffle X Insights into your draw pile.
+    Study(StackCount),
+
+    /// Enemies on both sides; any enemy behind you deals 50% more damage.
+    Surrounded,
+
     /// At the end of N turns, deal X damage to all enemies.
     TheBomb(Turns, Hp),
 
     /// When attacked, deal X damage back.
     Thorns(Hp),
 
+    /// Your next Attack deals X additional damage.
+    Vigor(Hp),
+
     /// You take 50% more damage from attacks.
     Vulnerable(Turns),
 
+    /// Whenever you gain Block this turn, apply X Weak to ALL enemies.
+    WaveOfTheHand(StackCount),
+
     /// You deal 25% less attack damage.
     Weak(Turns),
+
+    /// At the end of your turn, Retain up to X cards.
+    WellLaidPlans(StackCount),
 }
 
 /*
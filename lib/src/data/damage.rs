@@ -7,6 +7,7 @@ pub enum Damage {
     BlockableEqualToDrawPileSize,
     BlockableEqualToPlayerBlock,
     BlockableNonAttack(Hp),
+    BlockablePlusMantraGained(Hp),
     BlockableWithStrengthMultiplier(Hp, Strength),
     HpLoss(Hp),
     HpLossEqualToHandSize,
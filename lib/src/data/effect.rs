@@ -1,22 +1,30 @@
-use crate::types::{AttackCount, Block, DrawCount, Energy, Gold, Hp, HpMax, Strength};
+use crate::types::{
+    AttackCount, Block, Dexterity, DrawCount, Energy, Gold, Hp, HpMax, Mantra, ScryCount, Strength,
+};
 
-use super::card::Card;
+use super::card::{Card, CardType};
 use super::condition::{EnemyCondition, PlayerCondition};
 use super::damage::Damage;
+use super::enemy::Enemy;
 use super::intent::Intent;
+use super::stance::Stance;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum CardSource {
     AllCardsInCombat,
     AttacksInDrawPile,
+    Deck,
     DiscardPile,
+    DrawPile,
     ExhaustPile,
     Hand,
     NonAttackCardsInHand,
+    SkillsInDrawPile,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum CardPool {
+    AnyColorAttackPool,
     AttacksAndPowersInHand,
     CardInPlay,
     CharacterAttackPool,
@@ -45,6 +53,7 @@ pub enum CardDestination {
     DiscardPile,
     ExhaustPile,
     Hand,
+    HandAndRetain,
     ShuffledIntoDrawPile,
     TopOfDrawPile,
     TwoCopiesInHand,
@@ -53,6 +62,8 @@ pub enum CardDestination {
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum CostModifier {
     None,
+    ReduceToOneThisCombat,
+    ReduceToOneThisTurn,
     ZeroThisCombat,
     ZeroThisTurn,
     ZeroUntilPlayed,
@@ -61,6 +72,9 @@ pub enum CostModifier {
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum PlayerEffectCondition {
     IfHandContainsNoAttackCards,
+    IfInStance(Stance),
+    IfLastCardPlayedWas(CardType),
+    IfNotInStance(Stance),
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -73,13 +87,17 @@ pub enum TargetCondition {
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Resource {
     Block(Block),
+    BlockEqualToUnblockedDamage,
+    BlockPerCardInHand(Block),
     CurrentBlockIsDoubled,
     CurrentStrengthIsDoubled,
+    Dexterity(Dexterity),
     Energy(Energy),
     Gold(Gold),
     Hp(Hp),
     HpEqualToUnblockedDamage,
     HpMax(HpMax),
+    Mantra(Mantra),
     Strength(Strength),
 }
 
@@ -87,33 +105,71 @@ pub enum Resource {
 pub enum TargetEffect {
     Conditional(TargetCondition, &'static [PlayerEffect]),
     Deal(Damage),
+    DealOncePerEnemy(Damage),
     DealXTimes(Damage),
+    ExecuteIfHpAtMost(Hp),
     Inflict(EnemyCondition),
+    LoseHpEqualToMark,
     SapStrength(Strength),
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum EnemyEffect {
+    AddToDeck(Card),
     Apply(EnemyCondition),
+    ClearNegativeStrength,
     CreateCards(CardPool, CardSelection, CardDestination, CostModifier),
     Deal(Damage),
+    DealAndBlock(Damage),
+    DealAndHeal(Damage),
+    Escape,
     Gain(Resource),
+    HealParty(Hp),
+    HealToHalfHp,
     Inflict(PlayerCondition),
+    RemoveDebuffs,
+    RerollIntent,
+    ReviveAtFullHp,
+    ReviveAtHalfHp,
+    SapDexterity(Dexterity),
+    SapStrength(Strength),
+    SelfDestruct,
+    ShieldAllies(Block),
+    ShieldAlly(Block),
+    ShieldLeader(Block),
+    Split(Enemy, Enemy),
+    Stasis,
+    StrengthenParty(Strength),
+    Summon(Enemy, usize),
+    SummonDaggers(usize),
+    SummonGremlins(usize),
+    UpgradeBurns,
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum PlayerEffect {
     Apply(PlayerCondition),
+    ChooseOne(&'static [PlayerEffect]),
     Conditional(PlayerEffectCondition, &'static [PlayerEffect]),
     CreateCards(CardPool, CardSelection, CardDestination, CostModifier),
+    CreateExpungerHittingXPlus(AttackCount),
     Draw(DrawCount),
+    EndTurn,
+    EnterStance(Stance),
+    ExitStance,
     ForEachExhausted(&'static [PlayerEffect]), // TODO: Merge this into ManipulateCards
     Gain(Resource),
     Lose(Resource),
     ManipulateCards(CardSource, CardSelection, CardDestination, CostModifier),
     PlayThenExhaustTopCardOfDrawPile,
+    PlayTopCardOfDrawPile,
+    PlayTwiceThenExhaust(CardSource),
     RampUpCardDamage(Hp),
+    RampUpCardDamagePermanently(Hp),
+    RepeatX(&'static [PlayerEffect]),
+    Scry(ScryCount),
     TakeDamage(Damage),
+    TakeExtraTurn,
     ToAllEnemies(TargetEffect),
     ToRandomEnemy(TargetEffect),
     ToSingleTarget(TargetEffect),
@@ -125,35 +181,60 @@ impl From<&[EnemyEffect]> for Intent {
         let has_debuff = effect_chain.iter().any(|effect| {
             matches!(
                 effect,
-                EnemyEffect::Inflict(_) | EnemyEffect::CreateCards(_, _, _, _)
+                EnemyEffect::AddToDeck(_)
+                    | EnemyEffect::Inflict(_)
+                    | EnemyEffect::CreateCards(_, _, _, _)
+                    | EnemyEffect::SapDexterity(_)
+                    | EnemyEffect::SapStrength(_)
+                    | EnemyEffect::Stasis
             )
         });
         let has_buff = effect_chain.iter().any(|effect| {
             matches!(
                 effect,
-                EnemyEffect::Apply(_) | EnemyEffect::Gain(Resource::Strength(_))
+                EnemyEffect::Apply(_)
+                    | EnemyEffect::Gain(Resource::Strength(_))
+                    | EnemyEffect::HealParty(_)
+                    | EnemyEffect::HealToHalfHp
+                    | EnemyEffect::RemoveDebuffs
+                    | EnemyEffect::ReviveAtFullHp
+                    | EnemyEffect::ReviveAtHalfHp
+                    | EnemyEffect::StrengthenParty(_)
+            )
+        });
+        let has_defense = effect_chain.iter().any(|effect| {
+            matches!(
+                effect,
+                EnemyEffect::DealAndBlock(_)
+                    | EnemyEffect::Gain(Resource::Block(_))
+                    | EnemyEffect::ShieldAllies(_)
+                    | EnemyEffect::ShieldAlly(_)
+                    | EnemyEffect::ShieldLeader(_)
             )
         });
-        let has_defense = effect_chain
-            .iter()
-            .any(|effect| matches!(effect, EnemyEffect::Gain(Resource::Block(_))));
 
         let attack_damage: Option<Hp> = effect_chain.iter().find_map(|effect| match effect {
             EnemyEffect::Deal(Damage::Blockable(amount))
-            | EnemyEffect::Deal(Damage::HpLoss(amount)) => Some(*amount),
+            | EnemyEffect::Deal(Damage::HpLoss(amount))
+            | EnemyEffect::DealAndBlock(Damage::Blockable(amount))
+            | EnemyEffect::DealAndHeal(Damage::Blockable(amount)) => Some(*amount),
             _ => None,
         });
         let attack_count: AttackCount = effect_chain
             .iter()
             .filter_map(|effect| {
-                if let EnemyEffect::Deal(_) = effect {
+                if let EnemyEffect::Deal(_)
+                | EnemyEffect::DealAndBlock(_)
+                | EnemyEffect::DealAndHeal(_) = effect
+                {
                     Some(1)
                 } else {
                     None
                 }
             })
             .sum();
-        // TODO: Cowardly, Sleeping, Stunned, Unknown special cases
+        // Moves that are Cowardly, Sleeping, Stunned or deliberately Unknown say so in the action
+        // table, overriding this inference.
         if attack_count > 0 {
             let attack_damage = attack_damage.expect("attack_count > 0");
             if has_buff {
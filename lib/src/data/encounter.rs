@@ -9,6 +9,8 @@ pub enum Encounter {
     CenturionAndMystic,
     Chosen,
     ChosenAndByrd,
+    ColosseumNobs,
+    ColosseumSlavers,
     CorruptHeart,
     Cultist,
     CultistAndChosen,
@@ -24,9 +26,11 @@ pub enum Encounter {
     JawWorm,
     JawWormHorde,
     Lagavulin,
+    LagavulinEvent,
     LargeSlime,
     Looter,
     LotsOfSlimes,
+    MaskedBandits,
     Maw,
     Nemesis,
     OrbWalker,
@@ -47,6 +51,7 @@ pub enum Encounter {
     TheChamp,
     TheCollector,
     TheGuardian,
+    TheMushroomLair,
     ThreeByrds,
     ThreeCultists,
     ThreeDarklings,
@@ -57,6 +62,26 @@ pub enum Encounter {
     Transient,
     TwoFungiBeasts,
     TwoLouses,
+    TwoOrbWalkers,
     TwoThieves,
     WrithingMass,
 }
+
+impl Encounter {
+    /// Returns true iff this is an Act's boss fight, which the player cannot escape.
+    pub fn is_boss(&self) -> bool {
+        matches!(
+            self,
+            Encounter::AwakenedOne
+                | Encounter::BronzeAutomaton
+                | Encounter::CorruptHeart
+                | Encounter::DonuAndDeca
+                | Encounter::Hexaghost
+                | Encounter::SlimeBoss
+                | Encounter::TheChamp
+                | Encounter::TheCollector
+                | Encounter::TheGuardian
+                | Encounter::TimeEater
+        )
+    }
+}
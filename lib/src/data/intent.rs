@@ -19,3 +19,17 @@ pub enum Intent {
     Stunned,
     Unknown,
 }
+
+impl Intent {
+    /// Returns the same intent with its per-hit attack damage replaced by the specified amount.
+    /// Intents without an attack are returned unchanged.
+    pub fn with_damage(self, damage: Hp) -> Self {
+        match self {
+            Intent::Aggressive(_, count) => Intent::Aggressive(damage, count),
+            Intent::AggressiveBuff(_, count) => Intent::AggressiveBuff(damage, count),
+            Intent::AggressiveDebuff(_, count) => Intent::AggressiveDebuff(damage, count),
+            Intent::AggressiveDefensive(_, count) => Intent::AggressiveDefensive(damage, count),
+            other => other,
+        }
+    }
+}
@@ -16,16 +16,17 @@ mod relic;
 mod stance;
 
 pub use act::Act;
-pub use action::EnemyAction;
+pub use action::{EnemyAction, MAX_BOOK_OF_STABBING_STAB_COUNT, MAX_THE_MAW_NOM_NOM_BITE_COUNT};
 pub use card::{
-    Card, CardDetails, CardType, EnergyCost, CURSE_CARD_POOL, UNCOMMON_COLORLESS_CARD_POOL,
+    Card, CardDetails, CardRarity, CardType, EnergyCost, COMBAT_COLORLESS_CARD_POOL,
+    CURSE_CARD_POOL, RARE_COLORLESS_CARD_POOL, UNCOMMON_COLORLESS_CARD_POOL,
 };
-pub use character::Character;
+pub use character::{Character, DEFECT, IRONCLAD, SILENT, WATCHER};
 pub use condition::{EnemyCondition, PlayerCondition};
 pub use damage::Damage;
 pub use effect::{
-    CardDestination, CardPool, CardSelection, CostModifier, EnemyEffect, PlayerEffect, Resource,
-    TargetEffect,
+    CardDestination, CardPool, CardSelection, CardSource, CostModifier, EnemyEffect, PlayerEffect,
+    PlayerEffectCondition, Resource, TargetCondition, TargetEffect,
 };
 pub use encounter::Encounter;
 pub use enemy::Enemy;
@@ -39,6 +40,3 @@ pub use orb::Orb;
 pub use potion::{Potion, PotionRarity};
 pub use relic::Relic;
 pub use stance::Stance;
-
-#[cfg(test)]
-pub use character::{DEFECT, IRONCLAD, SILENT, WATCHER};
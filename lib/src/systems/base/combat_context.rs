@@ -1,18 +1,24 @@
-use crate::components::{EffectQueue, Interaction, PlayerCombatState, PlayerPersistentState};
-use crate::data::Encounter;
+use crate::components::{
+    CardQueue, EffectQueue, Interaction, PlayerCombatState, PlayerPersistentState,
+};
+use crate::data::{Encounter, PlayerCondition};
 use crate::systems::rng::{Seed, StsRandom};
 use crate::types::EnemyIndex;
 
+use super::enemy_characteristics::Battlefield;
 use super::enemy_party::EnemyParty;
 
 #[derive(Debug)]
 pub struct CombatContext<'a, I: Interaction> {
     pub comms: &'a I,
+    pub encounter: Encounter,
     pub pcs: PlayerCombatState<'a>,
     pub enemy_party: EnemyParty,
     pub maybe_enemy_index: Option<EnemyIndex>,
     pub effect_queue: EffectQueue,
+    pub card_queue: CardQueue,
     pub misc_rng: &'a mut StsRandom,
+    pub hp_rng: StsRandom,
     pub enemy_rng: StsRandom,
     pub shuffle_rng: StsRandom,
     pub card_randomizer_rng: StsRandom,
@@ -27,27 +33,55 @@ impl<'a, I: Interaction> CombatContext<'a, I> {
         misc_rng: &'a mut StsRandom,
     ) -> Self {
         let pcs = PlayerCombatState::new(pps);
+        let mut hp_rng = StsRandom::from(seed_for_floor);
         let mut enemy_rng = StsRandom::from(seed_for_floor);
-        let enemy_party = EnemyParty::generate(seed_for_floor, encounter, &mut enemy_rng, misc_rng);
+        let enemy_party = EnemyParty::generate(&mut hp_rng, encounter, &mut enemy_rng, misc_rng);
         let maybe_enemy_index = None;
         let effect_queue = EffectQueue::new();
+        let card_queue = CardQueue::new();
         let shuffle_rng = StsRandom::from(seed_for_floor);
         let card_randomizer_rng = StsRandom::from(seed_for_floor);
         Self {
             comms,
+            encounter,
             pcs,
             enemy_party,
             maybe_enemy_index,
             effect_queue,
+            card_queue,
             misc_rng,
+            hp_rng,
             enemy_rng,
             shuffle_rng,
             card_randomizer_rng,
         }
     }
 
-    /// Returns true iff either the player is dead or all enemies are dead.
+    /// Returns true iff the player is dead or has escaped, or no enemies remain (whether they
+    /// died or fled).
     pub fn combat_should_end(&self) -> bool {
-        self.pcs.pps.hp == 0 || self.enemy_party.0.iter().all(|enemy| enemy.is_none())
+        self.pcs.pps.hp == 0
+            || self.pcs.escaped
+            || self.enemy_party.0.iter().all(|enemy| enemy.is_none())
+    }
+
+    /// Sums up what the enemies can see of the fight when they pick their next moves.
+    pub fn battlefield(&self) -> Battlefield {
+        Battlefield {
+            player_hp: self.pcs.pps.hp,
+            player_constricted: self
+                .pcs
+                .conditions
+                .iter()
+                .any(|c| matches!(c, PlayerCondition::Constricted(_))),
+            enemy_count: self.enemy_party.0.iter().flatten().count(),
+            enemy_hp_missing: self
+                .enemy_party
+                .0
+                .iter()
+                .flatten()
+                .map(|enemy| enemy.hp_max - enemy.hp)
+                .sum(),
+        }
     }
 }
@@ -2,8 +2,17 @@ use std::iter::once;
 
 use anyhow::Error;
 
-use crate::components::{Choice, Interaction, Notification, PlayerPersistentState, Prompt};
-use crate::data::{Card, CardDetails};
+use crate::components::{
+    Choice, DeckCard, Interaction, Notification, PlayerPersistentState, Prompt,
+};
+use crate::data::{
+    Card, CardDetails, CardType, CURSE_CARD_POOL, RARE_COLORLESS_CARD_POOL,
+    UNCOMMON_COLORLESS_CARD_POOL,
+};
+use crate::systems::rng::StsRandom;
+use crate::types::DeckIndex;
+
+use super::health_system::HealthSystem;
 
 pub struct DeckSystem;
 
@@ -13,7 +22,7 @@ impl DeckSystem {
         comms: &I,
         pps: &PlayerPersistentState,
     ) -> Result<(), Error> {
-        comms.send_notification(Notification::Deck(pps.deck.to_vec()))
+        comms.send_notification(Notification::Deck(pps.deck_cards()))
     }
 
     /// Prompts the player to obtain a card and notifies them of the change.
@@ -42,12 +51,14 @@ impl DeckSystem {
         pps: &mut PlayerPersistentState,
         card: Card,
     ) -> Result<(), Error> {
-        pps.deck.push(card);
+        let id = pps.allocate_card_id();
+        pps.deck.push(DeckCard::new(id, card));
         comms.send_notification(Notification::CardObtained(card))?;
         Self::notify_player(comms, pps)
     }
 
     /// Prompts the player to remove a card from the deck and notifies them of the change.
+    /// Irremovable cards such as Curse of the Bell are not offered.
     pub fn choose_card_to_remove<I: Interaction>(
         comms: &I,
         pps: &mut PlayerPersistentState,
@@ -55,20 +66,33 @@ impl DeckSystem {
         let choices = pps
             .deck
             .iter()
-            .copied()
+            .map(|deck_card| deck_card.card)
             .enumerate()
+            .filter(|(_, card)| !CardDetails::for_card(*card).irremovable)
             .map(|(deck_index, card)| Choice::RemoveCard(deck_index, card))
             .collect::<Vec<_>>();
         match comms.prompt_for_choice(Prompt::RemoveCard, &choices)? {
-            Choice::RemoveCard(deck_index, _) => {
-                let card = pps.deck.remove(*deck_index);
-                comms.send_notification(Notification::CardRemoved(card))?;
-            }
+            Choice::RemoveCard(deck_index, _) => Self::remove_card(comms, pps, *deck_index)?,
             invalid => unreachable!("{:?}", invalid),
         }
         Self::notify_player(comms, pps)
     }
 
+    /// Removes the indicated card from the deck, applying any removal penalty (e.g. Parasite),
+    /// and notifies the player of the change.
+    pub fn remove_card<I: Interaction>(
+        comms: &I,
+        pps: &mut PlayerPersistentState,
+        deck_index: DeckIndex,
+    ) -> Result<(), Error> {
+        let card = pps.deck.remove(deck_index).card;
+        comms.send_notification(Notification::CardRemoved(card))?;
+        if CardDetails::for_card(card).parasite {
+            HealthSystem::decrease_hp_max(comms, pps, 3)?;
+        }
+        Ok(())
+    }
+
     /// Prompts the player to upgrade a card and notifies them of the change.
     pub fn choose_card_to_upgrade<I: Interaction>(
         comms: &I,
@@ -77,7 +101,7 @@ impl DeckSystem {
         let choices = pps
             .deck
             .iter()
-            .copied()
+            .map(|deck_card| deck_card.card)
             .enumerate()
             .filter_map(|(deck_index, card)| {
                 let details = CardDetails::for_card(card);
@@ -87,15 +111,213 @@ impl DeckSystem {
             })
             .collect::<Vec<_>>();
         match comms.prompt_for_choice(Prompt::UpgradeCard, &choices)? {
-            Choice::UpgradeCard(deck_index, _, upgraded) => {
-                // TODO: Check if the upgraded card goes in place of the current card or is
-                // added to the end of the deck
-                let card = pps.deck.remove(*deck_index);
-                comms.send_notification(Notification::CardRemoved(card))?;
-                Self::obtain_card(comms, pps, *upgraded)?;
+            Choice::UpgradeCard(deck_index, card, upgraded) => {
+                // Upgrading in place keeps the card's identity and metadata intact.
+                pps.deck[*deck_index].upgrade();
+                comms.send_notification(Notification::CardUpgraded(*card, *upgraded))?;
             }
             invalid => unreachable!("{:?}", invalid),
         }
         Self::notify_player(comms, pps)
     }
+
+    /// Prompts the player to bottle a card of the given type, so that it starts each combat in
+    /// their hand, and notifies them of the change.
+    pub fn choose_card_to_bottle<I: Interaction>(
+        comms: &I,
+        pps: &mut PlayerPersistentState,
+        card_type: CardType,
+    ) -> Result<(), Error> {
+        let choices = pps
+            .deck
+            .iter()
+            .enumerate()
+            .filter(|(_, deck_card)| {
+                !deck_card.bottled && CardDetails::for_card(deck_card.card).type_ == card_type
+            })
+            .map(|(deck_index, deck_card)| Choice::BottleCard(deck_index, deck_card.card))
+            .collect::<Vec<_>>();
+        if choices.is_empty() {
+            return Ok(());
+        }
+        match comms.prompt_for_choice(Prompt::BottleCard, &choices)? {
+            Choice::BottleCard(deck_index, _) => pps.deck[*deck_index].bottled = true,
+            invalid => unreachable!("{:?}", invalid),
+        }
+        Self::notify_player(comms, pps)
+    }
+
+    /// Prompts the player to duplicate a card, keeping its upgrades and any growth, and notifies
+    /// them of the change.
+    pub fn choose_card_to_duplicate<I: Interaction>(
+        comms: &I,
+        pps: &mut PlayerPersistentState,
+    ) -> Result<(), Error> {
+        let choices = pps
+            .deck
+            .iter()
+            .map(|deck_card| deck_card.card)
+            .enumerate()
+            .map(|(deck_index, card)| Choice::DuplicateCard(deck_index, card))
+            .collect::<Vec<_>>();
+        match comms.prompt_for_choice(Prompt::DuplicateCard, &choices)? {
+            Choice::DuplicateCard(deck_index, card) => {
+                let mut duplicate = pps.deck[*deck_index];
+                duplicate.id = pps.allocate_card_id();
+                duplicate.bottled = false;
+                pps.deck.push(duplicate);
+                comms.send_notification(Notification::CardObtained(*card))?;
+            }
+            invalid => unreachable!("{:?}", invalid),
+        }
+        Self::notify_player(comms, pps)
+    }
+
+    /// Prompts the player to transform `count` cards, replacing each with a random card of the
+    /// same color drawn from `rng`, and notifies them of the changes.
+    pub fn choose_cards_to_transform<I: Interaction>(
+        comms: &I,
+        pps: &mut PlayerPersistentState,
+        count: usize,
+        rng: &mut StsRandom,
+    ) -> Result<(), Error> {
+        let mut chosen: Vec<(DeckIndex, Card)> = Vec::with_capacity(count);
+        while chosen.len() < count {
+            let choices = pps
+                .deck
+                .iter()
+                .map(|deck_card| deck_card.card)
+                .enumerate()
+                .filter(|(deck_index, card)| {
+                    !CardDetails::for_card(*card).irremovable
+                        && !chosen
+                            .iter()
+                            .any(|(chosen_index, _)| chosen_index == deck_index)
+                })
+                .map(|(deck_index, card)| Choice::TransformCard(deck_index, card))
+                .collect::<Vec<_>>();
+            if choices.is_empty() {
+                break;
+            }
+            match comms.prompt_for_choice(Prompt::TransformCard, &choices)? {
+                Choice::TransformCard(deck_index, card) => chosen.push((*deck_index, *card)),
+                invalid => unreachable!("{:?}", invalid),
+            }
+        }
+        Self::transform_cards(comms, pps, chosen, rng)
+    }
+
+    /// Transforms up to `count` cards at random, picked as the game does by shuffling the
+    /// removable cards with `rng`, and notifies the player of the changes.
+    pub fn transform_random_cards<I: Interaction>(
+        comms: &I,
+        pps: &mut PlayerPersistentState,
+        count: usize,
+        rng: &mut StsRandom,
+    ) -> Result<(), Error> {
+        let mut transformable = pps
+            .deck
+            .iter()
+            .enumerate()
+            .filter(|(_, deck_card)| !CardDetails::for_card(deck_card.card).irremovable)
+            .map(|(deck_index, _)| deck_index)
+            .collect::<Vec<_>>();
+        rng.java_compat_shuffle(&mut transformable);
+        let chosen = transformable
+            .into_iter()
+            .take(count)
+            .map(|deck_index| (deck_index, pps.deck[deck_index].card))
+            .collect::<Vec<_>>();
+        Self::transform_cards(comms, pps, chosen, rng)
+    }
+
+    /// Upgrades every upgradable card in the deck and notifies the player of the changes.
+    pub fn upgrade_all_cards<I: Interaction>(
+        comms: &I,
+        pps: &mut PlayerPersistentState,
+    ) -> Result<(), Error> {
+        for deck_card in pps.deck.iter_mut() {
+            let card = deck_card.card;
+            if let Some(upgraded) = deck_card.upgrade() {
+                comms.send_notification(Notification::CardUpgraded(card, upgraded))?;
+            }
+        }
+        Self::notify_player(comms, pps)
+    }
+
+    /// Upgrades up to `count` cards at random, picked as the game does by shuffling the
+    /// upgradable cards with `rng`, and notifies the player of the changes.
+    pub fn upgrade_random_cards<I: Interaction>(
+        comms: &I,
+        pps: &mut PlayerPersistentState,
+        count: usize,
+        rng: &mut StsRandom,
+    ) -> Result<(), Error> {
+        let mut upgradable = pps
+            .deck
+            .iter()
+            .enumerate()
+            .filter(|(_, deck_card)| CardDetails::for_card(deck_card.card).upgrade.is_some())
+            .map(|(deck_index, _)| deck_index)
+            .collect::<Vec<_>>();
+        rng.java_compat_shuffle(&mut upgradable);
+        for deck_index in upgradable.into_iter().take(count) {
+            let card = pps.deck[deck_index].card;
+            if let Some(upgraded) = pps.deck[deck_index].upgrade() {
+                comms.send_notification(Notification::CardUpgraded(card, upgraded))?;
+            }
+        }
+        Self::notify_player(comms, pps)
+    }
+
+    /// Removes the chosen cards from the deck, then adds a transformed card for each of them.
+    fn transform_cards<I: Interaction>(
+        comms: &I,
+        pps: &mut PlayerPersistentState,
+        chosen: Vec<(DeckIndex, Card)>,
+        rng: &mut StsRandom,
+    ) -> Result<(), Error> {
+        // Removing from the back keeps the remaining indices valid.
+        let mut deck_indices = chosen
+            .iter()
+            .map(|(deck_index, _)| *deck_index)
+            .collect::<Vec<_>>();
+        deck_indices.sort_unstable_by(|a, b| b.cmp(a));
+        for deck_index in deck_indices {
+            Self::remove_card(comms, pps, deck_index)?;
+        }
+        for (_, card) in chosen {
+            let transformed = Self::transformed_card(pps, card, rng);
+            Self::obtain_card(comms, pps, transformed)?;
+        }
+        Ok(())
+    }
+
+    /// Picks the card that `card` transforms into: any other card of the same color, or any
+    /// other curse.
+    fn transformed_card(pps: &PlayerPersistentState, card: Card, rng: &mut StsRandom) -> Card {
+        let is_colorless = |card| {
+            UNCOMMON_COLORLESS_CARD_POOL.contains(card) || RARE_COLORLESS_CARD_POOL.contains(card)
+        };
+        let pool = if CardDetails::for_card(card).type_ == CardType::Curse {
+            CURSE_CARD_POOL.to_vec()
+        } else if is_colorless(&card) {
+            [UNCOMMON_COLORLESS_CARD_POOL, RARE_COLORLESS_CARD_POOL].concat()
+        } else {
+            [
+                pps.character.common_card_pool,
+                pps.character.uncommon_card_pool,
+                pps.character.rare_card_pool,
+            ]
+            .concat()
+        };
+        // An upgraded card can't turn into its own unupgraded self either.
+        let candidates = pool
+            .into_iter()
+            .filter(|candidate| {
+                *candidate != card && CardDetails::for_card(*candidate).upgrade != Some(card)
+            })
+            .collect::<Vec<_>>();
+        *rng.choose(&candidates)
+    }
 }
@@ -1,8 +1,20 @@
 use std::fmt;
 
-use crate::data::{Enemy, EnemyAction, EnemyCondition};
+use crate::data::{
+    Enemy, EnemyAction, EnemyCondition, MAX_BOOK_OF_STABBING_STAB_COUNT,
+    MAX_THE_MAW_NOM_NOM_BITE_COUNT,
+};
 use crate::systems::rng::StsRandom;
-use crate::types::{Hp, HpMax, StackCount};
+use crate::types::{AttackCount, Block, EnemyIndex, Hp, HpMax, StackCount, Strength};
+
+/// What an enemy can see of the fight when it picks its next action.
+#[derive(Clone, Copy, Debug)]
+pub struct Battlefield {
+    pub player_hp: Hp,
+    pub player_constricted: bool,
+    pub enemy_count: usize,
+    pub enemy_hp_missing: Hp, // Across every living enemy, for healers.
+}
 
 pub trait EnemyCharacteristics: fmt::Debug {
     fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction);
@@ -12,21 +24,177 @@ pub trait EnemyCharacteristics: fmt::Debug {
         last_action: EnemyAction,
         run_length: usize,
     ) -> EnemyAction;
+
+    /// Block the enemy brings into the fight, e.g. from Lagavulin's Metallicize.
+    fn starting_block(&self) -> Block {
+        0
+    }
+
+    /// Strength the enemy brings into the fight, e.g. the Jaw Worms of Act 3's horde.
+    fn starting_strength(&self) -> Strength {
+        0
+    }
+
+    /// Rolls any conditions the game only determines once the whole party has spawned, such as
+    /// the Louses' Curl Up.
+    fn on_pre_battle(&mut self, _: &mut StsRandom) -> Vec<EnemyCondition> {
+        vec![]
+    }
+
+    /// Takes note of the slot the enemy occupies, for the few whose moves depend on it.
+    fn on_placed(&mut self, _: EnemyIndex) {}
+
+    /// Takes note of the rest of the fight just before the next action is picked.
+    fn survey(&mut self, _: &Battlefield) {}
+
+    /// Picks a new action in place of `current_action` before it was ever taken.
+    fn reroll_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        current_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        self.next_action(enemy_rng, current_action, run_length)
+    }
+
+    /// Reacts to the enemy losing HP, returning the action that replaces its next one, if any.
+    /// The conditions have already reacted to the damage, e.g. by losing a stack of Flight.
+    fn on_hp_lost(
+        &mut self,
+        _hp_lost: Hp,
+        _hp: Hp,
+        _hp_max: HpMax,
+        _conditions: &[EnemyCondition],
+    ) -> Option<EnemyAction> {
+        None
+    }
+
+    /// Brings the enemy's conditions and Block in line with its internal state, e.g. Lagavulin
+    /// shedding Metallicize once it wakes up.
+    fn update_status(&mut self, _: &mut Vec<EnemyCondition>, _: &mut Block) {}
+
+    /// Decides whether the enemy lies half-dead rather than dying, returning what it does while
+    /// half-dead. `kin_standing` is true iff another enemy of its type is still standing. Its
+    /// death triggers have already fired, so it may shed whatever conditions it doesn't keep.
+    fn on_death(
+        &mut self,
+        _: &mut Vec<EnemyCondition>,
+        _kin_standing: bool,
+    ) -> Option<EnemyAction> {
+        None
+    }
 }
 
 /// Generates a new enemy characteristics object for the specified enemy type.
 pub fn gen_characteristics(enemy: Enemy, hp_rng: &mut StsRandom) -> Box<dyn EnemyCharacteristics> {
     match enemy {
+        Enemy::AcidSlimeL => Box::new(AcidSlimeL::new(hp_rng)),
         Enemy::AcidSlimeM => Box::new(AcidSlimeM::new(hp_rng)),
         Enemy::AcidSlimeS => Box::new(AcidSlimeS::new(hp_rng)),
+        Enemy::AwakenedOne => Box::new(AwakenedOne::new(hp_rng)),
+        Enemy::Bear => Box::new(Bear::new(hp_rng)),
+        Enemy::BlueSlaver => Box::new(BlueSlaver::new(hp_rng)),
+        Enemy::BookOfStabbing => Box::new(BookOfStabbing::new(hp_rng)),
+        Enemy::BronzeAutomaton => Box::new(BronzeAutomaton::new(hp_rng)),
+        Enemy::BronzeOrb => Box::new(BronzeOrb::new(hp_rng)),
+        Enemy::Byrd => Box::new(Byrd::new(hp_rng)),
+        Enemy::Centurion => Box::new(Centurion::new(hp_rng)),
+        Enemy::Chosen => Box::new(Chosen::new(hp_rng)),
+        Enemy::CorruptHeart => Box::new(CorruptHeart::new(hp_rng)),
         Enemy::Cultist => Box::new(Cultist::new(hp_rng)),
+        Enemy::Dagger => Box::new(Dagger::new(hp_rng)),
+        Enemy::Darkling => Box::new(Darkling::new(hp_rng)),
+        Enemy::Deca => Box::new(Deca::new(hp_rng)),
+        Enemy::Donu => Box::new(Donu::new(hp_rng)),
+        Enemy::Exploder => Box::new(Exploder::new(hp_rng)),
+        Enemy::FatGremlin => Box::new(FatGremlin::new(hp_rng)),
         Enemy::FungiBeast => Box::new(FungiBeast::new(hp_rng)),
+        Enemy::GiantHead => Box::new(GiantHead::new(hp_rng)),
         Enemy::GreenLouse => Box::new(GreenLouse::new(hp_rng)),
+        Enemy::GremlinLeader => Box::new(GremlinLeader::new(hp_rng)),
         Enemy::GremlinNob => Box::new(GremlinNob::new(hp_rng)),
+        Enemy::GremlinWizard => Box::new(GremlinWizard::new(hp_rng)),
+        Enemy::Hexaghost => Box::new(Hexaghost::new(hp_rng)),
         Enemy::JawWorm => Box::new(JawWorm::new(hp_rng)),
+        Enemy::Lagavulin => Box::new(Lagavulin::new(hp_rng)),
+        Enemy::Looter => Box::new(Looter::new(hp_rng)),
+        Enemy::MadGremlin => Box::new(MadGremlin::new(hp_rng)),
+        Enemy::Mugger => Box::new(Mugger::new(hp_rng)),
+        Enemy::Mystic => Box::new(Mystic::new(hp_rng)),
+        Enemy::Nemesis => Box::new(Nemesis::new(hp_rng)),
+        Enemy::OrbWalker => Box::new(OrbWalker::new(hp_rng)),
+        Enemy::Pointy => Box::new(Pointy),
+        Enemy::RedLouse => Box::new(RedLouse::new(hp_rng)),
+        Enemy::RedSlaver => Box::new(RedSlaver::new(hp_rng)),
+        Enemy::Reptomancer => Box::new(Reptomancer::new(hp_rng)),
+        Enemy::Repulsor => Box::new(Repulsor::new(hp_rng)),
+        Enemy::Romeo => Box::new(Romeo::new(hp_rng)),
+        Enemy::Sentry => Box::new(Sentry::new(hp_rng)),
+        Enemy::ShelledParasite => Box::new(ShelledParasite::new(hp_rng)),
+        Enemy::ShieldGremlin => Box::new(ShieldGremlin::new(hp_rng)),
+        Enemy::SlimeBoss => Box::new(SlimeBoss::new(hp_rng)),
+        Enemy::SnakePlant => Box::new(SnakePlant::new(hp_rng)),
+        Enemy::SneakyGremlin => Box::new(SneakyGremlin::new(hp_rng)),
+        Enemy::Snecko => Box::new(Snecko::new(hp_rng)),
+        Enemy::SphericGuardian => Box::new(SphericGuardian::new(hp_rng)),
+        Enemy::Spiker => Box::new(Spiker::new(hp_rng)),
+        Enemy::SpikeSlimeL => Box::new(SpikeSlimeL::new(hp_rng)),
         Enemy::SpikeSlimeM => Box::new(SpikeSlimeM::new(hp_rng)),
         Enemy::SpikeSlimeS => Box::new(SpikeSlimeS::new(hp_rng)),
-        unavailable => todo!("Unavailable enemy: {:?}", unavailable),
+        Enemy::SpireGrowth => Box::new(SpireGrowth::new(hp_rng)),
+        Enemy::SpireShield => Box::new(SpireShield::new(hp_rng)),
+        Enemy::SpireSpear => Box::new(SpireSpear::new(hp_rng)),
+        Enemy::Taskmaster => Box::new(Taskmaster::new(hp_rng)),
+        Enemy::TheChamp => Box::new(TheChamp::new(hp_rng)),
+        Enemy::TheCollector => Box::new(TheCollector::new(hp_rng)),
+        Enemy::TheGuardian => Box::new(TheGuardian::new(hp_rng)),
+        Enemy::TheMaw => Box::new(TheMaw::new(hp_rng)),
+        Enemy::TimeEater => Box::new(TimeEater::new(hp_rng)),
+        Enemy::TorchHead => Box::new(TorchHead::new(hp_rng)),
+        Enemy::Transient => Box::new(Transient::new(hp_rng)),
+        Enemy::WrithingMass => Box::new(WrithingMass::new(hp_rng)),
+    }
+}
+
+/// Generates characteristics for an enemy that would normally start the fight asleep but is
+/// already awake, as with the Lagavulin that the Dead Adventurer event sends after the player.
+pub fn gen_awake_characteristics(
+    enemy: Enemy,
+    hp_rng: &mut StsRandom,
+) -> Box<dyn EnemyCharacteristics> {
+    match enemy {
+        Enemy::Lagavulin => Box::new(Lagavulin {
+            awake: true,
+            ..Lagavulin::new(hp_rng)
+        }),
+        invalid => unreachable!("{:?} is never asleep", invalid),
+    }
+}
+
+/// Generates characteristics for an enemy in its tougher guise, as the Jaw Worms of Act 3's horde
+/// appear.
+pub fn gen_hard_mode_characteristics(
+    enemy: Enemy,
+    hp_rng: &mut StsRandom,
+) -> Box<dyn EnemyCharacteristics> {
+    match enemy {
+        Enemy::JawWorm => Box::new(JawWorm {
+            hard_mode: true,
+            ..JawWorm::new(hp_rng)
+        }),
+        invalid => unreachable!("{:?} has no hard mode", invalid),
+    }
+}
+
+/// Generates characteristics for a slime split off from a larger one, which takes its parent's
+/// current HP as its max HP rather than rolling its own.
+pub fn gen_split_characteristics(enemy: Enemy, hp_max: HpMax) -> Box<dyn EnemyCharacteristics> {
+    match enemy {
+        Enemy::AcidSlimeL => Box::new(AcidSlimeL::with_hp_max(hp_max)),
+        Enemy::AcidSlimeM => Box::new(AcidSlimeM { hp_max }),
+        Enemy::SpikeSlimeL => Box::new(SpikeSlimeL::with_hp_max(hp_max)),
+        Enemy::SpikeSlimeM => Box::new(SpikeSlimeM { hp_max }),
+        invalid => unreachable!("{:?} does not split off from anything", invalid),
     }
 }
 
@@ -39,6 +207,106 @@ pub fn gen_characteristics(enemy: Enemy, hp_rng: &mut StsRandom) -> Box<dyn Enem
 // Special thanks to gamerpuppy for figuring out the quirks of the game's use of rng.
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// AcidSlimeL
+// - 65 to 69 HP
+// - Corrosive Spit: Deal 11 damage, add 2 Slimed to the discard pile
+// - Lick: Inflict 2 Weak
+// - Tackle: Deal 16 damage
+// - Split: When its HP is at or below 50%, splits into 2 Acid Slime (M) with its current HP
+// - 30% Corrosive Spit, 40% Tackle, 30% Lick
+//  -- Cannot use Corrosive Spit or Lick three times in a row
+//  -- Cannot use Tackle twice in a row
+// - https://slay-the-spire.fandom.com/wiki/Acid_Slime
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct AcidSlimeL {
+    hp_max: HpMax,
+    splitting: bool,
+}
+
+impl AcidSlimeL {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self::with_hp_max(hp_rng.gen_range(65..=69))
+    }
+
+    fn with_hp_max(hp_max: HpMax) -> Self {
+        Self {
+            hp_max,
+            splitting: false,
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..30
+                if last_action != Some(EnemyAction::AcidSlimeLCorrosiveSpit) || run_length < 2 =>
+            {
+                EnemyAction::AcidSlimeLCorrosiveSpit
+            }
+            0..30 => {
+                if enemy_rng.next_bool() {
+                    EnemyAction::AcidSlimeLTackle
+                } else {
+                    EnemyAction::AcidSlimeLLick
+                }
+            }
+            30..70 if last_action != Some(EnemyAction::AcidSlimeLTackle) => {
+                EnemyAction::AcidSlimeLTackle
+            }
+            30..70 => *enemy_rng.weighted_choose(&[
+                (EnemyAction::AcidSlimeLCorrosiveSpit, 0.6),
+                (EnemyAction::AcidSlimeLLick, 0.4),
+            ]),
+            _ if last_action != Some(EnemyAction::AcidSlimeLLick) || run_length < 2 => {
+                EnemyAction::AcidSlimeLLick
+            }
+            _ => *enemy_rng.weighted_choose(&[
+                (EnemyAction::AcidSlimeLCorrosiveSpit, 0.4),
+                (EnemyAction::AcidSlimeLTackle, 0.6),
+            ]),
+        }
+    }
+}
+
+impl EnemyCharacteristics for AcidSlimeL {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![EnemyCondition::Split],
+            Self::next_action_helper(enemy_rng, None, 0),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(enemy_rng, Some(last_action), run_length)
+    }
+
+    fn on_hp_lost(
+        &mut self,
+        _: Hp,
+        hp: Hp,
+        hp_max: HpMax,
+        _: &[EnemyCondition],
+    ) -> Option<EnemyAction> {
+        if !self.splitting && hp * 2 <= hp_max {
+            self.splitting = true;
+            Some(EnemyAction::AcidSlimeLSplit)
+        } else {
+            None
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // AcidSlimeM
 // - 28 to 32 HP
@@ -164,52 +432,161 @@ impl EnemyCharacteristics for AcidSlimeS {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-// Cultist
-// - 48 to 54 HP
-// - Incantation: Gain 3 Ritual (first turn only)
-// - Dark Strike: Deal 6 damage (all turns after the first)
-// - https://slay-the-spire.fandom.com/wiki/Cultist
+// Awakened One
+// - 300 HP
+// - Spawns with 10 Regenerate, 1 Curiosity and Unawakened
+// - First form:
+//  -- Slash: Deal 20 damage
+//  -- Soul Strike: Deal 6 damage 4 times
+//  -- Always starts with Slash
+//  -- 25% Soul Strike, 75% Slash. Cannot use Soul Strike twice in a row, or Slash three times.
+// - Rebirth: Upon dying in its first form, sheds its debuffs, Curiosity and Unawakened, and
+//   rises again at full HP
+// - Second form:
+//  -- Dark Echo: Deal 40 damage (first turn only)
+//  -- Sludge: Deal 18 damage and shuffle a Void into the draw pile
+//  -- Tackle: Deal 10 damage 3 times
+//  -- 50% Sludge, 50% Tackle. Cannot use either three times in a row.
+// - https://slay-the-spire.fandom.com/wiki/Awakened_One
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 #[derive(Debug)]
-struct Cultist {
+struct AwakenedOne {
     hp_max: HpMax,
+    awakened: bool,
 }
 
-impl Cultist {
+impl AwakenedOne {
     fn new(hp_rng: &mut StsRandom) -> Self {
         Self {
-            hp_max: hp_rng.gen_range(48..=54),
+            hp_max: hp_rng.gen_range(300..=300),
+            awakened: false,
         }
     }
 }
 
-impl EnemyCharacteristics for Cultist {
-    fn on_spawn(&self, _: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
-        (self.hp_max, vec![], EnemyAction::CultistIncantation)
+impl EnemyCharacteristics for AwakenedOne {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![
+                EnemyCondition::Regenerate(10),
+                EnemyCondition::Curiosity(1),
+                EnemyCondition::Unawakened,
+            ],
+            EnemyAction::AwakenedOneSlash,
+        )
     }
 
-    fn next_action(&mut self, _: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
-        EnemyAction::CultistDarkStrike
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        let roll = enemy_rng.gen_range(0..100);
+        if last_action == EnemyAction::AwakenedOneRebirth {
+            return EnemyAction::AwakenedOneDarkEcho;
+        }
+        if !self.awakened {
+            match roll {
+                0..25 if last_action != EnemyAction::AwakenedOneSoulStrike => {
+                    EnemyAction::AwakenedOneSoulStrike
+                }
+                0..25 => EnemyAction::AwakenedOneSlash,
+                _ if last_action != EnemyAction::AwakenedOneSlash || run_length < 2 => {
+                    EnemyAction::AwakenedOneSlash
+                }
+                _ => EnemyAction::AwakenedOneSoulStrike,
+            }
+        } else {
+            match roll {
+                0..50 if last_action != EnemyAction::AwakenedOneSludge || run_length < 2 => {
+                    EnemyAction::AwakenedOneSludge
+                }
+                0..50 => EnemyAction::AwakenedOneTackle,
+                _ if last_action != EnemyAction::AwakenedOneTackle || run_length < 2 => {
+                    EnemyAction::AwakenedOneTackle
+                }
+                _ => EnemyAction::AwakenedOneSludge,
+            }
+        }
+    }
+
+    fn on_death(&mut self, conditions: &mut Vec<EnemyCondition>, _: bool) -> Option<EnemyAction> {
+        if self.awakened {
+            return None;
+        }
+        self.awakened = true;
+        conditions.retain(|c| {
+            !c.is_debuff()
+                && !matches!(c, EnemyCondition::Curiosity(_) | EnemyCondition::Unawakened)
+        });
+        Some(EnemyAction::AwakenedOneRebirth)
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-// Fungi Beast
-// - 22 to 28 HP
-// - Bite: Deal 6 damage
-// - Grow: Gain 3 Strength
-// - Spore Cloud: On death, applies 2 Vulnerable to the player.
-// - https://slay-the-spire.fandom.com/wiki/Fungi_Beast
+// Bear
+// - 38 to 42 HP
+// - Bear Hug: Inflict -2 Dexterity (first turn only)
+// - Maul: Deal 18 damage
+// - Lunge: Deal 9 damage and gain 9 Block
+// - Alternates Maul and Lunge after the Bear Hug, starting with Maul
+// - https://slay-the-spire.fandom.com/wiki/Masked_Bandits
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 #[derive(Debug)]
-struct FungiBeast {
+struct Bear {
     hp_max: HpMax,
 }
 
-impl FungiBeast {
+impl Bear {
     fn new(hp_rng: &mut StsRandom) -> Self {
         Self {
-            hp_max: hp_rng.gen_range(22..=28),
+            hp_max: hp_rng.gen_range(38..=42),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Bear {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::BearBearHug)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        if last_action == EnemyAction::BearMaul {
+            EnemyAction::BearLunge
+        } else {
+            EnemyAction::BearMaul
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Blue Slaver
+// - 46 to 50 HP
+// - Stab: Deal 12 damage
+// - Rake: Deal 7 damage and inflict 1 Weak
+// - 60% Stab, 40% Rake
+//  -- Cannot use Stab or Rake three times in a row
+// - https://slay-the-spire.fandom.com/wiki/Slavers#Blue_Slaver
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct BlueSlaver {
+    hp_max: HpMax,
+}
+
+impl BlueSlaver {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(46..=50),
         }
     }
 
@@ -219,23 +596,23 @@ impl FungiBeast {
         run_length: usize,
     ) -> EnemyAction {
         match enemy_rng.gen_range(0..100) {
-            0..60 if last_action != Some(EnemyAction::FungiBeastBite) || run_length < 2 => {
-                EnemyAction::FungiBeastBite
+            40.. if last_action != Some(EnemyAction::BlueSlaverStab) || run_length < 2 => {
+                EnemyAction::BlueSlaverStab
             }
-            0..60 => EnemyAction::FungiBeastGrow,
-            _ if last_action != Some(EnemyAction::FungiBeastGrow) => EnemyAction::FungiBeastGrow,
-            _ => EnemyAction::FungiBeastBite,
+            _ if last_action != Some(EnemyAction::BlueSlaverRake) || run_length < 2 => {
+                EnemyAction::BlueSlaverRake
+            }
+            _ => EnemyAction::BlueSlaverStab,
         }
     }
 }
 
-impl EnemyCharacteristics for FungiBeast {
+impl EnemyCharacteristics for BlueSlaver {
     fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
-        let first_action = Self::next_action_helper(enemy_rng, None, 0);
         (
             self.hp_max,
-            vec![EnemyCondition::SporeCloud(2)],
-            first_action,
+            vec![],
+            Self::next_action_helper(enemy_rng, None, 0),
         )
     }
 
@@ -250,55 +627,55 @@ impl EnemyCharacteristics for FungiBeast {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-// Green Louse
-// - 11 to 17 HP
-// - Bite: Deal D damage (D between 5 and 7, chosen upon spawning)
-// - Spit Web: Apply 2 Weak
-// - Spawns with 3-7 Curl Up
-// - https://slay-the-spire.fandom.com/wiki/Louses#Green_Louse
+// Book of Stabbing
+// - 160 to 164 HP
+// - Painful Stabs: Whenever its attacks cost the player HP, adds a Wound to the discard pile
+// - Multi-Stab: Deal 6 damage N times, where N starts at 2 and grows by 1 with each use
+// - Single Stab: Deal 21 damage
+// - 85% Multi-Stab, 15% Single Stab
+//  -- Cannot use Multi-Stab three times in a row or Single Stab twice in a row
+// - https://slay-the-spire.fandom.com/wiki/Book_of_Stabbing
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 #[derive(Debug)]
-struct GreenLouse {
+struct BookOfStabbing {
     hp_max: HpMax,
-    bite_damage: Hp,
-    curl_up_stacks: StackCount,
+    stab_count: AttackCount,
+    multi_stabs_in_a_row: usize,
 }
 
-impl GreenLouse {
+impl BookOfStabbing {
     fn new(hp_rng: &mut StsRandom) -> Self {
         Self {
-            hp_max: hp_rng.gen_range(11..=17),
-            bite_damage: hp_rng.gen_range(5..=7),
-            curl_up_stacks: hp_rng.gen_range(3..=7),
+            hp_max: hp_rng.gen_range(160..=164),
+            stab_count: 1,
+            multi_stabs_in_a_row: 0,
         }
     }
 
     fn next_action_helper(
-        &self,
         enemy_rng: &mut StsRandom,
         last_action: Option<EnemyAction>,
-        run_length: usize,
+        stab_count: AttackCount,
+        multi_stabs_in_a_row: usize,
     ) -> EnemyAction {
+        let multi_stab = EnemyAction::BookOfStabbingMultiStab(
+            (stab_count + 1).min(MAX_BOOK_OF_STABBING_STAB_COUNT),
+        );
         match enemy_rng.gen_range(0..100) {
-            0..25 if last_action != Some(EnemyAction::GreenLouseSpitWeb) || run_length < 2 => {
-                EnemyAction::GreenLouseSpitWeb
-            }
-            0..25 => EnemyAction::GreenLouseBite(self.bite_damage),
-            _ if matches!(last_action, Some(EnemyAction::GreenLouseBite(_))) || run_length < 2 => {
-                EnemyAction::GreenLouseBite(self.bite_damage)
-            }
-            _ => EnemyAction::GreenLouseSpitWeb,
+            0..15 if last_action == Some(EnemyAction::BookOfStabbingSingleStab) => multi_stab,
+            0..15 => EnemyAction::BookOfStabbingSingleStab,
+            _ if multi_stabs_in_a_row >= 2 => EnemyAction::BookOfStabbingSingleStab,
+            _ => multi_stab,
         }
     }
 }
 
-impl EnemyCharacteristics for GreenLouse {
+impl EnemyCharacteristics for BookOfStabbing {
     fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
-        let first_action = self.next_action_helper(enemy_rng, None, 0);
         (
             self.hp_max,
-            vec![EnemyCondition::CurlUp(self.curl_up_stacks)],
-            first_action,
+            vec![EnemyCondition::PainfulStabs],
+            Self::next_action_helper(enemy_rng, None, self.stab_count, 0),
         )
     }
 
@@ -306,130 +683,270 @@ impl EnemyCharacteristics for GreenLouse {
         &mut self,
         enemy_rng: &mut StsRandom,
         last_action: EnemyAction,
-        run_length: usize,
+        _: usize,
     ) -> EnemyAction {
-        self.next_action_helper(enemy_rng, Some(last_action), run_length)
+        // Each Multi-Stab hits a different number of times, so the run length can't tell us how
+        // many came in a row.
+        if let EnemyAction::BookOfStabbingMultiStab(stab_count) = last_action {
+            self.stab_count = stab_count;
+            self.multi_stabs_in_a_row += 1;
+        } else {
+            self.multi_stabs_in_a_row = 0;
+        }
+        Self::next_action_helper(
+            enemy_rng,
+            Some(last_action),
+            self.stab_count,
+            self.multi_stabs_in_a_row,
+        )
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-// Gremlin Nob
-// - 82 to 86 HP
-// - Bellow: Gains 2 Enrage
-// - Rush: Deal 14 damage
-// - Skull Bash: Deal 6 damage and apply 2 Vulnerable
-// - Always starts with Bellow
-// - 33% Skull Bash, 67% Rush. Cannot use Rush three times in a row.
-// - https://slay-the-spire.fandom.com/wiki/Gremlin_Nob
+// Bronze Automaton
+// - 300 HP
+// - Spawns with 3 Artifact
+// - Spawn Orbs: Summons 2 Bronze Orbs (first turn only)
+// - Flail: Deal 7 damage twice
+// - Boost: Gain 9 Block and 3 Strength
+// - Hyper Beam: Deal 45 damage, then it is Stunned for a turn
+// - Alternates Flail and Boost, using Hyper Beam after every fourth of them
+// - Its Bronze Orbs die with it
+// - https://slay-the-spire.fandom.com/wiki/Bronze_Automaton
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 #[derive(Debug)]
-struct GremlinNob {
+struct BronzeAutomaton {
     hp_max: HpMax,
+    turns_until_hyper_beam: usize,
 }
 
-impl GremlinNob {
+impl BronzeAutomaton {
     fn new(hp_rng: &mut StsRandom) -> Self {
         Self {
-            hp_max: hp_rng.gen_range(82..=86),
+            hp_max: hp_rng.gen_range(300..=300),
+            turns_until_hyper_beam: 4,
         }
     }
 }
 
-impl EnemyCharacteristics for GremlinNob {
-    fn on_spawn(&self, _: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
-        (self.hp_max, vec![], EnemyAction::GremlinNobBellow)
+impl EnemyCharacteristics for BronzeAutomaton {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Artifact(3)],
+            EnemyAction::BronzeAutomatonSpawnOrbs,
+        )
     }
 
     fn next_action(
         &mut self,
         enemy_rng: &mut StsRandom,
         last_action: EnemyAction,
-        run_length: usize,
+        _: usize,
     ) -> EnemyAction {
-        match enemy_rng.gen_range(0..100) {
-            0..33 => EnemyAction::GremlinNobSkullBash,
-            _ if last_action != EnemyAction::GremlinNobRush || run_length < 2 => {
-                EnemyAction::GremlinNobRush
-            }
-            _ => EnemyAction::GremlinNobSkullBash,
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        if self.turns_until_hyper_beam == 0 {
+            self.turns_until_hyper_beam = 4;
+            return EnemyAction::BronzeAutomatonHyperBeam;
+        }
+        if last_action == EnemyAction::BronzeAutomatonHyperBeam {
+            return EnemyAction::BronzeAutomatonStunned;
+        }
+        self.turns_until_hyper_beam -= 1;
+        match last_action {
+            EnemyAction::BronzeAutomatonBoost
+            | EnemyAction::BronzeAutomatonSpawnOrbs
+            | EnemyAction::BronzeAutomatonStunned => EnemyAction::BronzeAutomatonFlail,
+            _ => EnemyAction::BronzeAutomatonBoost,
         }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-// Jaw Worm
-// - 40 to 44 HP
-// - Chomp: Deal 11 damage
-// - Thrash: Deal 7 damage and gain 5 Block
-// - Bellow: Gain 3 Strength and 6 Block
-// - https://slay-the-spire.fandom.com/wiki/Jaw_Worm
+// Bronze Orb
+// - 52 to 58 HP
+// - Beam: Deal 8 damage
+// - Support Beam: Give 12 Block to the Bronze Automaton
+// - Stasis: Take a card from the draw pile into Stasis, returning it to the hand on death
+//  -- Prefers rare cards, then uncommon, then common
+// - 75% Stasis, until it has been used once
+// - 30% Support Beam, 70% Beam
+//  -- Cannot use either three times in a row
+// - https://slay-the-spire.fandom.com/wiki/Bronze_Automaton#Bronze_Orb
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 #[derive(Debug)]
-struct JawWorm {
+struct BronzeOrb {
     hp_max: HpMax,
+    used_stasis: bool,
 }
 
-impl JawWorm {
+impl BronzeOrb {
     fn new(hp_rng: &mut StsRandom) -> Self {
         Self {
-            hp_max: hp_rng.gen_range(40..=44),
+            hp_max: hp_rng.gen_range(52..=58),
+            used_stasis: false,
         }
     }
-}
-
-impl EnemyCharacteristics for JawWorm {
-    fn on_spawn(&self, _: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
-        (self.hp_max, vec![], EnemyAction::JawWormChomp)
-    }
 
-    fn next_action(
-        &mut self,
+    fn next_action_helper(
         enemy_rng: &mut StsRandom,
-        last_action: EnemyAction,
+        last_action: Option<EnemyAction>,
         run_length: usize,
+        used_stasis: bool,
     ) -> EnemyAction {
         match enemy_rng.gen_range(0..100) {
-            0..25 if last_action != EnemyAction::JawWormChomp => EnemyAction::JawWormChomp,
-            0..25 => *enemy_rng.weighted_choose(&[
-                (EnemyAction::JawWormBellow, 0.5625),
-                (EnemyAction::JawWormThrash, 1. - 0.5625),
-            ]),
-            25..55 if last_action != EnemyAction::JawWormThrash || run_length < 2 => {
-                EnemyAction::JawWormThrash
+            25.. if !used_stasis => EnemyAction::BronzeOrbStasis,
+            70.. if last_action != Some(EnemyAction::BronzeOrbSupportBeam) || run_length < 2 => {
+                EnemyAction::BronzeOrbSupportBeam
             }
-            25..55 => *enemy_rng.weighted_choose(&[
-                (EnemyAction::JawWormChomp, 0.357),
-                (EnemyAction::JawWormBellow, 1. - 0.357),
+            _ if last_action != Some(EnemyAction::BronzeOrbBeam) || run_length < 2 => {
+                EnemyAction::BronzeOrbBeam
+            }
+            _ => EnemyAction::BronzeOrbSupportBeam,
+        }
+    }
+}
+
+impl EnemyCharacteristics for BronzeOrb {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![],
+            Self::next_action_helper(enemy_rng, None, 0, self.used_stasis),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        if last_action == EnemyAction::BronzeOrbStasis {
+            self.used_stasis = true;
+        }
+        Self::next_action_helper(enemy_rng, Some(last_action), run_length, self.used_stasis)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Byrd
+// - 25 to 31 HP
+// - Spawns with 3 Flight
+// - Caw: Gain 1 Strength
+// - Peck: Deal 1 damage 5 times
+// - Swoop: Deal 12 damage
+// - First turn: 37.5% Caw, 62.5% Peck
+// - While flying: 50% Peck, 20% Swoop, 30% Caw
+//  -- Cannot use Peck three times in a row, or Swoop or Caw twice in a row
+// - Once its Flight is gone, it is Stunned for a turn, then uses Headbutt and Fly Up
+//  -- Headbutt: Deal 3 damage
+//  -- Fly Up: Regain 3 Flight
+// - https://slay-the-spire.fandom.com/wiki/Byrd
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Byrd {
+    hp_max: HpMax,
+    flying: bool,
+}
+
+impl Byrd {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(25..=31),
+            flying: true,
+        }
+    }
+}
+
+impl EnemyCharacteristics for Byrd {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Flight(3, 3)],
+            *enemy_rng.weighted_choose(&[
+                (EnemyAction::ByrdCaw, 0.375),
+                (EnemyAction::ByrdPeck, 1. - 0.375),
             ]),
-            _ if last_action != EnemyAction::JawWormBellow || run_length < 2 => {
-                EnemyAction::JawWormBellow
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        match last_action {
+            EnemyAction::ByrdStunned => return EnemyAction::ByrdHeadbutt,
+            EnemyAction::ByrdHeadbutt => return EnemyAction::ByrdFlyUp,
+            EnemyAction::ByrdFlyUp => self.flying = true,
+            _ => (),
+        }
+        match enemy_rng.gen_range(0..100) {
+            0..50 if last_action != EnemyAction::ByrdPeck || run_length < 2 => {
+                EnemyAction::ByrdPeck
             }
+            0..50 => *enemy_rng.weighted_choose(&[
+                (EnemyAction::ByrdSwoop, 0.4),
+                (EnemyAction::ByrdCaw, 1. - 0.4),
+            ]),
+            50..70 if last_action != EnemyAction::ByrdSwoop => EnemyAction::ByrdSwoop,
+            50..70 => *enemy_rng.weighted_choose(&[
+                (EnemyAction::ByrdCaw, 0.375),
+                (EnemyAction::ByrdPeck, 1. - 0.375),
+            ]),
+            _ if last_action != EnemyAction::ByrdCaw => EnemyAction::ByrdCaw,
             _ => *enemy_rng.weighted_choose(&[
-                (EnemyAction::JawWormChomp, 0.416),
-                (EnemyAction::JawWormThrash, 1. - 0.416),
+                (EnemyAction::ByrdSwoop, 0.2857),
+                (EnemyAction::ByrdPeck, 1. - 0.2857),
             ]),
         }
     }
+
+    fn on_hp_lost(
+        &mut self,
+        _: Hp,
+        _: Hp,
+        _: HpMax,
+        conditions: &[EnemyCondition],
+    ) -> Option<EnemyAction> {
+        if self.flying
+            && !conditions
+                .iter()
+                .any(|c| matches!(c, EnemyCondition::Flight(_, _)))
+        {
+            self.flying = false;
+            Some(EnemyAction::ByrdStunned)
+        } else {
+            None
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-// SpikeSlimeM
-// - 28 to 32 HP
-// - Flame Tackle: Deal 8 damage, add a Slimed to the discard pile
-// - Lick: Inflict 1 Frail
-// - 30% Flame Tackle, 70% Lick
-//  -- Cannot use Flame Tackle or Lick three times in a row
-// - https://slay-the-spire.fandom.com/wiki/Spike_Slime
+// Centurion
+// - 76 to 80 HP
+// - Slash: Deal 12 damage
+// - Defend: Give 15 Block to a random ally
+// - Fury: Deal 6 damage 3 times (instead of Defend, once it is alone)
+// - 65% Slash, 35% Defend
+//  -- Cannot use any move three times in a row
+// - https://slay-the-spire.fandom.com/wiki/Centurion
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 #[derive(Debug)]
-struct SpikeSlimeM {
+struct Centurion {
     hp_max: HpMax,
+    alone: bool,
 }
 
-impl SpikeSlimeM {
+impl Centurion {
     fn new(hp_rng: &mut StsRandom) -> Self {
         Self {
-            hp_max: hp_rng.gen_range(28..=32),
+            hp_max: hp_rng.gen_range(76..=80),
+            alone: false,
         }
     }
 
@@ -437,26 +954,36 @@ impl SpikeSlimeM {
         enemy_rng: &mut StsRandom,
         last_action: Option<EnemyAction>,
         run_length: usize,
+        alone: bool,
     ) -> EnemyAction {
+        let guard = if alone {
+            EnemyAction::CenturionFury
+        } else {
+            EnemyAction::CenturionDefend
+        };
         match enemy_rng.gen_range(0..100) {
-            0..30 if last_action != Some(EnemyAction::SpikeSlimeMFlameTackle) || run_length < 2 => {
-                EnemyAction::SpikeSlimeMFlameTackle
+            65.. if run_length < 2
+                || !matches!(
+                    last_action,
+                    Some(EnemyAction::CenturionDefend | EnemyAction::CenturionFury)
+                ) =>
+            {
+                guard
             }
-            0..30 => EnemyAction::SpikeSlimeMLick,
-            _ if last_action != Some(EnemyAction::SpikeSlimeMLick) || run_length < 2 => {
-                EnemyAction::SpikeSlimeMLick
+            _ if last_action != Some(EnemyAction::CenturionSlash) || run_length < 2 => {
+                EnemyAction::CenturionSlash
             }
-            _ => EnemyAction::SpikeSlimeMFlameTackle,
+            _ => guard,
         }
     }
 }
 
-impl EnemyCharacteristics for SpikeSlimeM {
+impl EnemyCharacteristics for Centurion {
     fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
         (
             self.hp_max,
             vec![],
-            Self::next_action_helper(enemy_rng, None, 0),
+            Self::next_action_helper(enemy_rng, None, 0, self.alone),
         )
     }
 
@@ -466,38 +993,3303 @@ impl EnemyCharacteristics for SpikeSlimeM {
         last_action: EnemyAction,
         run_length: usize,
     ) -> EnemyAction {
-        Self::next_action_helper(enemy_rng, Some(last_action), run_length)
+        Self::next_action_helper(enemy_rng, Some(last_action), run_length, self.alone)
+    }
+
+    fn survey(&mut self, battlefield: &Battlefield) {
+        self.alone = battlefield.enemy_count <= 1;
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-// SpikeSlimeS
-// - 10 to 14 HP
-// - Tackle: Deal 5 damage
-// - 100% Tackle
-// - https://slay-the-spire.fandom.com/wiki/Spike_Slime
+// Chosen
+// - 95 to 99 HP
+// - Poke: Deal 5 damage twice (first turn only)
+// - Hex: Inflict 1 Hex (second turn only)
+// - Debilitate: Deal 10 damage and inflict 2 Vulnerable
+// - Drain: Inflict 3 Weak and gain 3 Strength
+// - Zap: Deal 18 damage
+// - Alternates between a 50/50 Debilitate or Drain and a 40/60 Zap or Poke
+// - https://slay-the-spire.fandom.com/wiki/Chosen
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 #[derive(Debug)]
-struct SpikeSlimeS {
+struct Chosen {
     hp_max: HpMax,
+    used_hex: bool,
 }
 
-impl SpikeSlimeS {
+impl Chosen {
     fn new(hp_rng: &mut StsRandom) -> Self {
         Self {
-            hp_max: hp_rng.gen_range(10..=14),
+            hp_max: hp_rng.gen_range(95..=99),
+            used_hex: false,
         }
     }
 }
 
-impl EnemyCharacteristics for SpikeSlimeS {
+impl EnemyCharacteristics for Chosen {
     fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
         let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
-        (self.hp_max, vec![], EnemyAction::SpikeSlimeSTackle)
+        (self.hp_max, vec![], EnemyAction::ChosenPoke)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let roll = enemy_rng.gen_range(0..100);
+        if !self.used_hex {
+            self.used_hex = true;
+            return EnemyAction::ChosenHex;
+        }
+        match roll {
+            0..50
+                if !matches!(
+                    last_action,
+                    EnemyAction::ChosenDebilitate | EnemyAction::ChosenDrain
+                ) =>
+            {
+                EnemyAction::ChosenDebilitate
+            }
+            _ if !matches!(
+                last_action,
+                EnemyAction::ChosenDebilitate | EnemyAction::ChosenDrain
+            ) =>
+            {
+                EnemyAction::ChosenDrain
+            }
+            0..40 => EnemyAction::ChosenZap,
+            _ => EnemyAction::ChosenPoke,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Corrupt Heart
+// - 750 HP
+// - Spawns with 300 Invincible and 1 Beat of Death
+// - Debilitate: Apply 2 Vulnerable, 2 Weak and 2 Frail, and shuffle a Dazed, Slimed, Wound, Burn
+//   and Void into the draw pile
+// - Blood Shots: Deal 2 damage 12 times
+// - Echo: Deal 40 damage
+// - Buff: Remove negative Strength and gain 2 Strength, then in turn gain 2 Artifact, 1 Beat of
+//   Death, Painful Stabs, 10 Strength and 50 Strength every Buff thereafter
+// - Opens with Debilitate, then repeats a three-turn cycle:
+//  -- 50% Blood Shots, 50% Echo
+//  -- Whichever of Blood Shots and Echo wasn't just used, favoring Echo
+//  -- Buff
+// - https://slay-the-spire.fandom.com/wiki/Corrupt_Heart
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct CorruptHeart {
+    hp_max: HpMax,
+    move_count: usize,
+    buff_count: usize,
+}
+
+impl CorruptHeart {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(750..=750),
+            move_count: 0,
+            buff_count: 0,
+        }
+    }
+}
+
+impl EnemyCharacteristics for CorruptHeart {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![
+                EnemyCondition::Invincible(300, 300),
+                EnemyCondition::BeatOfDeath(1),
+            ],
+            EnemyAction::CorruptHeartDebilitate,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        let action = match self.move_count % 3 {
+            0 if enemy_rng.next_bool() => EnemyAction::CorruptHeartBloodShots,
+            0 => EnemyAction::CorruptHeartEcho,
+            1 if last_action != EnemyAction::CorruptHeartEcho => EnemyAction::CorruptHeartEcho,
+            1 => EnemyAction::CorruptHeartBloodShots,
+            _ => {
+                // Every Buff from the fifth on is the same 50 Strength.
+                let buff = EnemyAction::CorruptHeartBuff(self.buff_count.min(4));
+                self.buff_count += 1;
+                buff
+            }
+        };
+        self.move_count += 1;
+        action
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Cultist
+// - 48 to 54 HP
+// - Incantation: Gain 3 Ritual (first turn only)
+// - Dark Strike: Deal 6 damage (all turns after the first)
+// - https://slay-the-spire.fandom.com/wiki/Cultist
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Cultist {
+    hp_max: HpMax,
+}
+
+impl Cultist {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(48..=54),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Cultist {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::CultistIncantation)
     }
 
     fn next_action(&mut self, enemy_rng: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
         let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
-        EnemyAction::SpikeSlimeSTackle
+        EnemyAction::CultistDarkStrike
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Dagger
+// - 20 to 25 HP
+// - Stab: Deal 9 damage and add a Wound to the discard pile (first turn only)
+// - Explode: Deal 25 damage and die
+// - https://slay-the-spire.fandom.com/wiki/Reptomancer#Dagger
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Dagger {
+    hp_max: HpMax,
+}
+
+impl Dagger {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(20..=25),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Dagger {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::DaggerStab)
+    }
+
+    fn next_action(&mut self, enemy_rng: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        EnemyAction::DaggerExplode
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Darkling
+// - 48 to 56 HP
+// - Spawns with Regrow
+// - Chomp: Deal 8 damage twice (only the first and third Darklings)
+// - Harden: Gain 12 Block
+// - Nip: Deal 7 to 11 damage (rolled once per Darkling)
+// - First turn: 50% Harden, 50% Nip
+// - 40% Chomp, 30% Harden, 30% Nip
+//  -- Cannot use Chomp or Harden twice in a row, or Nip three times in a row
+// - Regrow: Upon dying, lies half-dead while another Darkling stands, then Reincarnates at
+//   half HP
+// - https://slay-the-spire.fandom.com/wiki/Darkling
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Darkling {
+    hp_max: HpMax,
+    nip_damage: Hp,
+    position: EnemyIndex,
+}
+
+impl Darkling {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(48..=56),
+            nip_damage: hp_rng.gen_range(7..=11),
+            position: 0,
+        }
+    }
+}
+
+impl EnemyCharacteristics for Darkling {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let action = if enemy_rng.gen_range(0..100) < 50 {
+            EnemyAction::DarklingHarden
+        } else {
+            EnemyAction::DarklingNip(self.nip_damage)
+        };
+        (self.hp_max, vec![EnemyCondition::Regrow], action)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        let mut roll = enemy_rng.gen_range(0..100);
+        if last_action == EnemyAction::DarklingRegrow {
+            return EnemyAction::DarklingReincarnate;
+        }
+        loop {
+            roll = match roll {
+                0..40
+                    if last_action != EnemyAction::DarklingChomp
+                        && self.position.is_multiple_of(2) =>
+                {
+                    return EnemyAction::DarklingChomp;
+                }
+                0..40 => enemy_rng.gen_range(40..100),
+                40..70 if last_action != EnemyAction::DarklingHarden => {
+                    return EnemyAction::DarklingHarden;
+                }
+                40..70 => return EnemyAction::DarklingNip(self.nip_damage),
+                _ if !matches!(last_action, EnemyAction::DarklingNip(_)) || run_length < 2 => {
+                    return EnemyAction::DarklingNip(self.nip_damage);
+                }
+                _ => enemy_rng.gen_range(0..100),
+            };
+        }
+    }
+
+    fn on_placed(&mut self, enemy_index: EnemyIndex) {
+        self.position = enemy_index;
+    }
+
+    fn on_death(
+        &mut self,
+        conditions: &mut Vec<EnemyCondition>,
+        kin_standing: bool,
+    ) -> Option<EnemyAction> {
+        if kin_standing {
+            conditions.clear();
+            Some(EnemyAction::DarklingRegrow)
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Deca
+// - 250 HP
+// - Spawns with 2 Artifact
+// - Beam: Deal 10 damage twice and add 2 Dazed to the discard pile
+// - Square of Protection: All enemies gain 16 Block
+// - Alternates between Beam and Square of Protection, starting with Beam
+// - https://slay-the-spire.fandom.com/wiki/Donu_and_Deca
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Deca {
+    hp_max: HpMax,
+}
+
+impl Deca {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(250..=250),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Deca {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Artifact(2)],
+            EnemyAction::DecaBeam,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        if last_action == EnemyAction::DecaBeam {
+            EnemyAction::DecaSquareOfProtection
+        } else {
+            EnemyAction::DecaBeam
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Donu
+// - 250 HP
+// - Spawns with 2 Artifact
+// - Circle of Power: All enemies gain 3 Strength
+// - Beam: Deal 10 damage twice
+// - Alternates between Circle of Power and Beam, starting with Circle of Power
+// - https://slay-the-spire.fandom.com/wiki/Donu_and_Deca
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Donu {
+    hp_max: HpMax,
+}
+
+impl Donu {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(250..=250),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Donu {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Artifact(2)],
+            EnemyAction::DonuCircleOfPower,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        if last_action == EnemyAction::DonuBeam {
+            EnemyAction::DonuCircleOfPower
+        } else {
+            EnemyAction::DonuBeam
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Exploder
+// - 30 HP
+// - Spawns with 3 Explosive
+// - Slam: Deal 9 damage
+// - Explode: Deal 30 damage and die
+// - Slams twice, then explodes on its third turn
+// - https://slay-the-spire.fandom.com/wiki/Exploder
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Exploder {
+    hp_max: HpMax,
+}
+
+impl Exploder {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(30..=30),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Exploder {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Explosive(3)],
+            EnemyAction::ExploderSlam,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        if last_action == EnemyAction::ExploderSlam && run_length < 2 {
+            EnemyAction::ExploderSlam
+        } else {
+            EnemyAction::ExploderExplode
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Fat Gremlin
+// - 13 to 17 HP
+// - Smash: Deal 4 damage and inflict 1 Weak
+// - 100% Smash
+// - https://slay-the-spire.fandom.com/wiki/Gremlins#Fat_Gremlin
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct FatGremlin {
+    hp_max: HpMax,
+}
+
+impl FatGremlin {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(13..=17),
+        }
+    }
+}
+
+impl EnemyCharacteristics for FatGremlin {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::FatGremlinSmash)
+    }
+
+    fn next_action(&mut self, enemy_rng: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        EnemyAction::FatGremlinSmash
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Fungi Beast
+// - 22 to 28 HP
+// - Bite: Deal 6 damage
+// - Grow: Gain 3 Strength
+// - Spore Cloud: On death, applies 2 Vulnerable to the player.
+// - https://slay-the-spire.fandom.com/wiki/Fungi_Beast
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct FungiBeast {
+    hp_max: HpMax,
+}
+
+impl FungiBeast {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(22..=28),
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..60 if last_action != Some(EnemyAction::FungiBeastBite) || run_length < 2 => {
+                EnemyAction::FungiBeastBite
+            }
+            0..60 => EnemyAction::FungiBeastGrow,
+            _ if last_action != Some(EnemyAction::FungiBeastGrow) => EnemyAction::FungiBeastGrow,
+            _ => EnemyAction::FungiBeastBite,
+        }
+    }
+}
+
+impl EnemyCharacteristics for FungiBeast {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let first_action = Self::next_action_helper(enemy_rng, None, 0);
+        (
+            self.hp_max,
+            vec![EnemyCondition::SporeCloud(2)],
+            first_action,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(enemy_rng, Some(last_action), run_length)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Giant Head
+// - 500 HP
+// - Count: Deal 13 damage
+// - Glare: Inflict 1 Weak
+// - It Is Time: Deal 30 damage, increasing by 5 with each use up to 60
+// - 50% Count, 50% Glare for its first four turns; cannot use either three times in a row
+// - Uses It Is Time every turn from its fifth turn on
+// - Spawns with Slow
+// - https://slay-the-spire.fandom.com/wiki/Giant_Head
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct GiantHead {
+    hp_max: HpMax,
+    count: i32,
+}
+
+impl GiantHead {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(500..=500),
+            count: 4, // The opening move counts down from 5.
+        }
+    }
+
+    fn next_action_helper(
+        roll: usize,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        let used_twice = |action| last_action == Some(action) && run_length >= 2;
+        match roll {
+            0..50 if !used_twice(EnemyAction::GiantHeadGlare) => EnemyAction::GiantHeadGlare,
+            0..50 => EnemyAction::GiantHeadCount,
+            _ if !used_twice(EnemyAction::GiantHeadCount) => EnemyAction::GiantHeadCount,
+            _ => EnemyAction::GiantHeadGlare,
+        }
+    }
+}
+
+impl EnemyCharacteristics for GiantHead {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![EnemyCondition::Slow(0)],
+            Self::next_action_helper(enemy_rng.gen_range(0..100), None, 0),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        let roll = enemy_rng.gen_range(0..100);
+        if self.count <= 1 {
+            if self.count > -6 {
+                self.count -= 1;
+            }
+            return EnemyAction::GiantHeadItIsTime((30 - self.count * 5) as Hp);
+        }
+        self.count -= 1;
+        Self::next_action_helper(roll, Some(last_action), run_length)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Green Louse
+// - 11 to 17 HP
+// - Bite: Deal D damage (D between 5 and 7, chosen upon spawning)
+// - Spit Web: Apply 2 Weak
+// - Spawns with 3-7 Curl Up
+// - https://slay-the-spire.fandom.com/wiki/Louses#Green_Louse
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct GreenLouse {
+    hp_max: HpMax,
+    bite_damage: Hp,
+}
+
+impl GreenLouse {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(11..=17),
+            bite_damage: hp_rng.gen_range(5..=7),
+        }
+    }
+
+    fn next_action_helper(
+        &self,
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..25 if last_action != Some(EnemyAction::GreenLouseSpitWeb) || run_length < 2 => {
+                EnemyAction::GreenLouseSpitWeb
+            }
+            0..25 => EnemyAction::GreenLouseBite(self.bite_damage),
+            _ if !matches!(last_action, Some(EnemyAction::GreenLouseBite(_))) || run_length < 2 => {
+                EnemyAction::GreenLouseBite(self.bite_damage)
+            }
+            _ => EnemyAction::GreenLouseSpitWeb,
+        }
+    }
+}
+
+impl EnemyCharacteristics for GreenLouse {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let first_action = self.next_action_helper(enemy_rng, None, 0);
+        (self.hp_max, vec![], first_action)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        self.next_action_helper(enemy_rng, Some(last_action), run_length)
+    }
+
+    fn on_pre_battle(&mut self, hp_rng: &mut StsRandom) -> Vec<EnemyCondition> {
+        vec![EnemyCondition::CurlUp(hp_rng.gen_range(3..=7))]
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Gremlin Leader
+// - 140 to 148 HP
+// - Rally!: Summons 2 random gremlins
+// - Encourage: All enemies gain 3 Strength, and its gremlins gain 6 Block
+// - Stab: Deal 6 damage 3 times
+// - With no gremlins: 75% Rally!, 25% Stab
+// - With one gremlin: 50% Rally!, 30% Encourage, 20% Stab
+// - With two or more gremlins: 66% Encourage, 34% Stab
+//  -- Cannot use any move twice in a row
+// - Its gremlins flee once it dies
+// - https://slay-the-spire.fandom.com/wiki/Gremlin_Leader
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct GremlinLeader {
+    hp_max: HpMax,
+    gremlin_count: usize,
+}
+
+impl GremlinLeader {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(140..=148),
+            gremlin_count: 2,
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        gremlin_count: usize,
+    ) -> EnemyAction {
+        let mut roll = enemy_rng.gen_range(0..100);
+        loop {
+            // The game rerolls within a narrower range when one gremlin remains and the rolled
+            // move was just used.
+            roll = match (gremlin_count, roll) {
+                (0, 0..75) if last_action != Some(EnemyAction::GremlinLeaderRally) => {
+                    return EnemyAction::GremlinLeaderRally;
+                }
+                (0, 0..75) => return EnemyAction::GremlinLeaderStab,
+                (0, _) if last_action != Some(EnemyAction::GremlinLeaderStab) => {
+                    return EnemyAction::GremlinLeaderStab;
+                }
+                (0, _) => return EnemyAction::GremlinLeaderRally,
+                (1, 0..50) if last_action != Some(EnemyAction::GremlinLeaderRally) => {
+                    return EnemyAction::GremlinLeaderRally;
+                }
+                (1, 0..50) => enemy_rng.gen_range(50..100),
+                (1, 50..80) if last_action != Some(EnemyAction::GremlinLeaderEncourage) => {
+                    return EnemyAction::GremlinLeaderEncourage;
+                }
+                (1, 50..80) => return EnemyAction::GremlinLeaderStab,
+                (1, _) if last_action != Some(EnemyAction::GremlinLeaderStab) => {
+                    return EnemyAction::GremlinLeaderStab;
+                }
+                (1, _) => enemy_rng.gen_range(0..81),
+                (_, 0..66) if last_action != Some(EnemyAction::GremlinLeaderEncourage) => {
+                    return EnemyAction::GremlinLeaderEncourage;
+                }
+                (_, 0..66) => return EnemyAction::GremlinLeaderStab,
+                (_, _) if last_action != Some(EnemyAction::GremlinLeaderStab) => {
+                    return EnemyAction::GremlinLeaderStab;
+                }
+                (_, _) => return EnemyAction::GremlinLeaderEncourage,
+            };
+        }
+    }
+}
+
+impl EnemyCharacteristics for GremlinLeader {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![],
+            Self::next_action_helper(enemy_rng, None, self.gremlin_count),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(enemy_rng, Some(last_action), self.gremlin_count)
+    }
+
+    fn survey(&mut self, battlefield: &Battlefield) {
+        self.gremlin_count = battlefield.enemy_count.saturating_sub(1);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Gremlin Nob
+// - 82 to 86 HP
+// - Bellow: Gains 2 Enrage
+// - Rush: Deal 14 damage
+// - Skull Bash: Deal 6 damage and apply 2 Vulnerable
+// - Always starts with Bellow
+// - 33% Skull Bash, 67% Rush. Cannot use Rush three times in a row.
+// - https://slay-the-spire.fandom.com/wiki/Gremlin_Nob
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct GremlinNob {
+    hp_max: HpMax,
+}
+
+impl GremlinNob {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(82..=86),
+        }
+    }
+}
+
+impl EnemyCharacteristics for GremlinNob {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::GremlinNobBellow)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..33 => EnemyAction::GremlinNobSkullBash,
+            _ if last_action != EnemyAction::GremlinNobRush || run_length < 2 => {
+                EnemyAction::GremlinNobRush
+            }
+            _ => EnemyAction::GremlinNobSkullBash,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Gremlin Wizard
+// - 21 to 25 HP
+// - Charging: Does nothing
+// - Ultimate Blast: Deal 25 damage
+// - Charges twice before the first Ultimate Blast, then three times between each one
+// - https://slay-the-spire.fandom.com/wiki/Gremlins#Gremlin_Wizard
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct GremlinWizard {
+    hp_max: HpMax,
+    charge: StackCount,
+}
+
+impl GremlinWizard {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(21..=25),
+            charge: 1,
+        }
+    }
+}
+
+impl EnemyCharacteristics for GremlinWizard {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::GremlinWizardCharging)
+    }
+
+    fn next_action(
+        &mut self,
+        _: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        if last_action == EnemyAction::GremlinWizardUltimateBlast {
+            self.charge = 0;
+            return EnemyAction::GremlinWizardCharging;
+        }
+        self.charge += 1;
+        if self.charge >= 3 {
+            EnemyAction::GremlinWizardUltimateBlast
+        } else {
+            EnemyAction::GremlinWizardCharging
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Hexaghost
+// - 250 HP
+// - Activate: Does nothing (first turn only)
+// - Divider: Deal (H / 12 + 1) damage 6 times, where H is the player's HP as it Activates
+// - Sear: Deal 6 damage, add a Burn to the discard pile
+// - Tackle: Deal 5 damage twice
+// - Inflame: Gain 12 Block and 2 Strength
+// - Inferno: Deal 2 damage 6 times, upgrade all Burns, add 3 Burn+ to the discard pile
+// - After Divider, cycles through Sear, Tackle, Sear, Inflame, Tackle, Sear, Inferno
+//  -- Sear adds Burn+ once Inferno has been used
+// - https://slay-the-spire.fandom.com/wiki/Hexaghost
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Hexaghost {
+    hp_max: HpMax,
+    player_hp: Hp,
+    orbs_lit: usize,
+    burns_upgraded: bool,
+}
+
+impl Hexaghost {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(250..=250),
+            player_hp: 0,
+            orbs_lit: 0,
+            burns_upgraded: false,
+        }
+    }
+}
+
+impl EnemyCharacteristics for Hexaghost {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::HexaghostActivate)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        match last_action {
+            EnemyAction::HexaghostActivate => {
+                return EnemyAction::HexaghostDivider(self.player_hp / 12 + 1);
+            }
+            EnemyAction::HexaghostDivider(_) => self.orbs_lit = 0,
+            EnemyAction::HexaghostInferno => {
+                self.orbs_lit = 0;
+                self.burns_upgraded = true;
+            }
+            _ => self.orbs_lit += 1,
+        }
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        match self.orbs_lit {
+            1 | 4 => EnemyAction::HexaghostTackle,
+            3 => EnemyAction::HexaghostInflame,
+            6 => EnemyAction::HexaghostInferno,
+            _ if self.burns_upgraded => EnemyAction::HexaghostSearPlus,
+            _ => EnemyAction::HexaghostSear,
+        }
+    }
+
+    fn survey(&mut self, battlefield: &Battlefield) {
+        self.player_hp = battlefield.player_hp;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Jaw Worm
+// - 40 to 44 HP
+// - Chomp: Deal 11 damage
+// - Thrash: Deal 7 damage and gain 5 Block
+// - Bellow: Gain 3 Strength and 6 Block
+// - Always starts with Chomp, except in Act 3's horde, where each worm has already Bellowed
+// - https://slay-the-spire.fandom.com/wiki/Jaw_Worm
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct JawWorm {
+    hp_max: HpMax,
+    hard_mode: bool,
+}
+
+impl JawWorm {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(40..=44),
+            hard_mode: false,
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..25 if last_action != Some(EnemyAction::JawWormChomp) => EnemyAction::JawWormChomp,
+            0..25 => *enemy_rng.weighted_choose(&[
+                (EnemyAction::JawWormBellow, 0.5625),
+                (EnemyAction::JawWormThrash, 1. - 0.5625),
+            ]),
+            25..55 if last_action != Some(EnemyAction::JawWormThrash) || run_length < 2 => {
+                EnemyAction::JawWormThrash
+            }
+            25..55 => *enemy_rng.weighted_choose(&[
+                (EnemyAction::JawWormChomp, 0.357),
+                (EnemyAction::JawWormBellow, 1. - 0.357),
+            ]),
+            _ if last_action != Some(EnemyAction::JawWormBellow) || run_length < 2 => {
+                EnemyAction::JawWormBellow
+            }
+            _ => *enemy_rng.weighted_choose(&[
+                (EnemyAction::JawWormChomp, 0.416),
+                (EnemyAction::JawWormThrash, 1. - 0.416),
+            ]),
+        }
+    }
+}
+
+impl EnemyCharacteristics for JawWorm {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        if self.hard_mode {
+            return (
+                self.hp_max,
+                vec![],
+                Self::next_action_helper(enemy_rng, None, 0),
+            );
+        }
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::JawWormChomp)
+    }
+
+    fn starting_block(&self) -> Block {
+        if self.hard_mode {
+            6
+        } else {
+            0
+        }
+    }
+
+    fn starting_strength(&self) -> Strength {
+        if self.hard_mode {
+            3
+        } else {
+            0
+        }
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(enemy_rng, Some(last_action), run_length)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Lagavulin
+// - 109 to 111 HP
+// - Sleeps with 8 Metallicize (and 8 Block) for up to 3 turns
+//  -- Wakes up after its third turn asleep, or is Stunned for a turn if it loses HP before then
+//  -- Loses Metallicize once awake
+// - Attack: Deal 18 damage
+// - Siphon Soul: Inflict -1 Strength and -1 Dexterity
+// - Attacks twice, then Siphon Soul
+// - https://slay-the-spire.fandom.com/wiki/Lagavulin
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Lagavulin {
+    hp_max: HpMax,
+    awake: bool,
+    turns_asleep: usize,
+    attacks_since_siphon: usize,
+}
+
+impl Lagavulin {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(109..=111),
+            awake: false,
+            turns_asleep: 0,
+            attacks_since_siphon: 0,
+        }
+    }
+}
+
+impl EnemyCharacteristics for Lagavulin {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        if self.awake {
+            (self.hp_max, vec![], EnemyAction::LagavulinAttack)
+        } else {
+            (
+                self.hp_max,
+                vec![EnemyCondition::Metallicize(8)],
+                EnemyAction::LagavulinSleep,
+            )
+        }
+    }
+
+    fn starting_block(&self) -> Block {
+        if self.awake {
+            0
+        } else {
+            8
+        }
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        match last_action {
+            EnemyAction::LagavulinSleep => {
+                self.turns_asleep += 1;
+                if self.turns_asleep >= 3 {
+                    self.awake = true;
+                    EnemyAction::LagavulinAttack
+                } else {
+                    EnemyAction::LagavulinSleep
+                }
+            }
+            _ => {
+                match last_action {
+                    EnemyAction::LagavulinAttack => self.attacks_since_siphon += 1,
+                    EnemyAction::LagavulinSiphonSoul => self.attacks_since_siphon = 0,
+                    _ => {}
+                }
+                let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency
+                if self.attacks_since_siphon < 2
+                    && (last_action != EnemyAction::LagavulinAttack || run_length < 2)
+                {
+                    EnemyAction::LagavulinAttack
+                } else {
+                    EnemyAction::LagavulinSiphonSoul
+                }
+            }
+        }
+    }
+
+    fn on_hp_lost(&mut self, _: Hp, _: Hp, _: HpMax, _: &[EnemyCondition]) -> Option<EnemyAction> {
+        if self.awake {
+            None
+        } else {
+            self.awake = true;
+            Some(EnemyAction::LagavulinStunned)
+        }
+    }
+
+    fn update_status(&mut self, conditions: &mut Vec<EnemyCondition>, _: &mut Block) {
+        if self.awake {
+            conditions.retain(|c| !matches!(c, EnemyCondition::Metallicize(_)));
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Looter
+// - 44 to 48 HP
+// - Mug: Deal 10 damage and steal 15 Gold
+// - Lunge: Deal 12 damage and steal 15 Gold
+// - Smoke Bomb: Gain 6 Block
+// - Escape: Flees from combat with the stolen Gold
+// - Mugs twice, then 50% Lunge or 50% Smoke Bomb; Lunge is followed by Smoke Bomb, and Smoke
+//   Bomb by Escape
+// - https://slay-the-spire.fandom.com/wiki/Looter
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Looter {
+    hp_max: HpMax,
+}
+
+impl Looter {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(44..=48),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Looter {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Thievery(15, 0)],
+            EnemyAction::LooterMug,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        match last_action {
+            EnemyAction::LooterMug if run_length < 2 => EnemyAction::LooterMug,
+            EnemyAction::LooterMug => *enemy_rng.weighted_choose(&[
+                (EnemyAction::LooterSmokeBomb, 0.5),
+                (EnemyAction::LooterLunge, 0.5),
+            ]),
+            EnemyAction::LooterLunge => EnemyAction::LooterSmokeBomb,
+            _ => EnemyAction::LooterEscape,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Mad Gremlin
+// - 20 to 24 HP
+// - Scratch: Deal 4 damage
+// - 100% Scratch
+// - Spawns with 1 Angry
+// - https://slay-the-spire.fandom.com/wiki/Gremlins#Mad_Gremlin
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct MadGremlin {
+    hp_max: HpMax,
+}
+
+impl MadGremlin {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(20..=24),
+        }
+    }
+}
+
+impl EnemyCharacteristics for MadGremlin {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Angry(1)],
+            EnemyAction::MadGremlinScratch,
+        )
+    }
+
+    fn next_action(&mut self, enemy_rng: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        EnemyAction::MadGremlinScratch
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Mugger
+// - 48 to 52 HP
+// - Mug: Deal 10 damage and steal 15 Gold
+// - Lunge: Deal 16 damage and steal 15 Gold
+// - Smoke Bomb: Gain 11 Block
+// - Escape: Flees from combat with the stolen Gold
+// - Mugs twice, then 50% Lunge or 50% Smoke Bomb; Lunge is followed by Smoke Bomb, and Smoke
+//   Bomb by Escape
+// - https://slay-the-spire.fandom.com/wiki/Mugger
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Mugger {
+    hp_max: HpMax,
+}
+
+impl Mugger {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(48..=52),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Mugger {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Thievery(15, 0)],
+            EnemyAction::MuggerMug,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        match last_action {
+            EnemyAction::MuggerMug if run_length < 2 => EnemyAction::MuggerMug,
+            EnemyAction::MuggerMug => *enemy_rng.weighted_choose(&[
+                (EnemyAction::MuggerSmokeBomb, 0.5),
+                (EnemyAction::MuggerLunge, 0.5),
+            ]),
+            EnemyAction::MuggerLunge => EnemyAction::MuggerSmokeBomb,
+            _ => EnemyAction::MuggerEscape,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Mystic
+// - 48 to 56 HP
+// - Heal: Heal all enemies for 16 HP
+// - Attack: Deal 8 damage and inflict 2 Frail
+// - Buff: All enemies gain 2 Strength
+// - Heals whenever the enemies are missing more than 15 HP between them
+// - Otherwise 60% Attack, 40% Buff
+//  -- Cannot use any move three times in a row
+// - https://slay-the-spire.fandom.com/wiki/Mystic
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Mystic {
+    hp_max: HpMax,
+    enemy_hp_missing: Hp,
+}
+
+impl Mystic {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(48..=56),
+            enemy_hp_missing: 0,
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+        enemy_hp_missing: Hp,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            _ if enemy_hp_missing > 15
+                && (last_action != Some(EnemyAction::MysticHeal) || run_length < 2) =>
+            {
+                EnemyAction::MysticHeal
+            }
+            40.. if last_action != Some(EnemyAction::MysticAttack) || run_length < 2 => {
+                EnemyAction::MysticAttack
+            }
+            _ if last_action != Some(EnemyAction::MysticBuff) || run_length < 2 => {
+                EnemyAction::MysticBuff
+            }
+            _ => EnemyAction::MysticAttack,
+        }
+    }
+}
+
+impl EnemyCharacteristics for Mystic {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![],
+            Self::next_action_helper(enemy_rng, None, 0, 0),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(
+            enemy_rng,
+            Some(last_action),
+            run_length,
+            self.enemy_hp_missing,
+        )
+    }
+
+    fn survey(&mut self, battlefield: &Battlefield) {
+        self.enemy_hp_missing = battlefield.enemy_hp_missing;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Nemesis
+// - 185 HP
+// - Tri Attack: Deal 6 damage 3 times
+// - Scythe: Deal 45 damage
+// - Tri Burn: Add 3 Burns to the discard pile
+// - First turn: 50% Tri Attack, 50% Tri Burn
+// - 30% Scythe, 35% Tri Attack, 35% Tri Burn
+//  -- Cannot use Scythe twice in a row, or more than once every three turns
+//  -- Cannot use Tri Attack three times in a row, or Tri Burn twice in a row
+// - Gains 1 Intangible at the end of each of its turns, unless it already has some
+// - https://slay-the-spire.fandom.com/wiki/Nemesis
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Nemesis {
+    hp_max: HpMax,
+    scythe_cooldown: i32,
+    intangible_pending: bool,
+}
+
+impl Nemesis {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(185..=185),
+            scythe_cooldown: -1, // The opening move already counts down from 0.
+            intangible_pending: false,
+        }
+    }
+
+    fn scythe(&mut self) -> EnemyAction {
+        self.scythe_cooldown = 2;
+        EnemyAction::NemesisScythe
+    }
+}
+
+impl EnemyCharacteristics for Nemesis {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let action = if enemy_rng.gen_range(0..100) < 50 {
+            EnemyAction::NemesisTriAttack
+        } else {
+            EnemyAction::NemesisTriBurn
+        };
+        (self.hp_max, vec![], action)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        let roll = enemy_rng.gen_range(0..100);
+        self.intangible_pending = true;
+        self.scythe_cooldown -= 1;
+        let tri_attacked_twice = last_action == EnemyAction::NemesisTriAttack && run_length >= 2;
+        match roll {
+            0..30 if last_action != EnemyAction::NemesisScythe && self.scythe_cooldown <= 0 => {
+                self.scythe()
+            }
+            0..30 if enemy_rng.next_bool() => {
+                if tri_attacked_twice {
+                    EnemyAction::NemesisTriBurn
+                } else {
+                    EnemyAction::NemesisTriAttack
+                }
+            }
+            0..30 if last_action != EnemyAction::NemesisTriBurn => EnemyAction::NemesisTriBurn,
+            0..30 => EnemyAction::NemesisTriAttack,
+            30..65 if !tri_attacked_twice => EnemyAction::NemesisTriAttack,
+            30..65 if enemy_rng.next_bool() && self.scythe_cooldown <= 0 => self.scythe(),
+            30..65 => EnemyAction::NemesisTriBurn,
+            _ if last_action != EnemyAction::NemesisTriBurn => EnemyAction::NemesisTriBurn,
+            _ if enemy_rng.next_bool() && self.scythe_cooldown <= 0 => self.scythe(),
+            _ => EnemyAction::NemesisTriAttack,
+        }
+    }
+
+    fn update_status(&mut self, conditions: &mut Vec<EnemyCondition>, _: &mut Block) {
+        if self.intangible_pending
+            && !conditions
+                .iter()
+                .any(|c| matches!(c, EnemyCondition::Intangible(..)))
+        {
+            conditions.push(EnemyCondition::Intangible(1, true));
+        }
+        self.intangible_pending = false;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Orb Walker
+// - 90 to 96 HP
+// - Spawns with 3 Strength Up
+// - Claw: Deal 15 damage
+// - Laser: Deal 10 damage, add a Burn to the discard pile and shuffle one into the draw pile
+// - 40% Claw, 60% Laser. Cannot use either three times in a row.
+// - https://slay-the-spire.fandom.com/wiki/Orb_Walker
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct OrbWalker {
+    hp_max: HpMax,
+}
+
+impl OrbWalker {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(90..=96),
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        let used_twice = |action| last_action == Some(action) && run_length >= 2;
+        match enemy_rng.gen_range(0..100) {
+            0..40 if !used_twice(EnemyAction::OrbWalkerClaw) => EnemyAction::OrbWalkerClaw,
+            0..40 => EnemyAction::OrbWalkerLaser,
+            _ if !used_twice(EnemyAction::OrbWalkerLaser) => EnemyAction::OrbWalkerLaser,
+            _ => EnemyAction::OrbWalkerClaw,
+        }
+    }
+}
+
+impl EnemyCharacteristics for OrbWalker {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![EnemyCondition::StrengthUp(3)],
+            Self::next_action_helper(enemy_rng, None, 0),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(enemy_rng, Some(last_action), run_length)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Pointy
+// - 30 HP
+// - Attack: Deal 5 damage twice
+// - https://slay-the-spire.fandom.com/wiki/Masked_Bandits
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Pointy;
+
+impl EnemyCharacteristics for Pointy {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (30, vec![], EnemyAction::PointyAttack)
+    }
+
+    fn next_action(&mut self, enemy_rng: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        EnemyAction::PointyAttack
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Red Louse
+// - 10 to 15 HP
+// - Bite: Deal D damage (D between 5 and 7, chosen upon spawning)
+// - Grow: Gain 3 Strength
+// - Spawns with 3-7 Curl Up
+// - 25% Grow, 75% Bite
+//  -- Cannot use Grow or Bite three times in a row
+// - https://slay-the-spire.fandom.com/wiki/Louses#Red_Louse
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct RedLouse {
+    hp_max: HpMax,
+    bite_damage: Hp,
+}
+
+impl RedLouse {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(10..=15),
+            bite_damage: hp_rng.gen_range(5..=7),
+        }
+    }
+
+    fn next_action_helper(
+        &self,
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..25 if last_action != Some(EnemyAction::RedLouseGrow) || run_length < 2 => {
+                EnemyAction::RedLouseGrow
+            }
+            0..25 => EnemyAction::RedLouseBite(self.bite_damage),
+            _ if !matches!(last_action, Some(EnemyAction::RedLouseBite(_))) || run_length < 2 => {
+                EnemyAction::RedLouseBite(self.bite_damage)
+            }
+            _ => EnemyAction::RedLouseGrow,
+        }
+    }
+}
+
+impl EnemyCharacteristics for RedLouse {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let first_action = self.next_action_helper(enemy_rng, None, 0);
+        (self.hp_max, vec![], first_action)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        self.next_action_helper(enemy_rng, Some(last_action), run_length)
+    }
+
+    fn on_pre_battle(&mut self, hp_rng: &mut StsRandom) -> Vec<EnemyCondition> {
+        vec![EnemyCondition::CurlUp(hp_rng.gen_range(3..=7))]
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Red Slaver
+// - 46 to 50 HP
+// - Stab: Deal 13 damage
+// - Scrape: Deal 8 damage and inflict 1 Vulnerable
+// - Entangle: Inflict Entangled (once per combat)
+// - Always starts with Stab
+// - 25% Entangle (if not yet used), otherwise 45% Stab, 55% Scrape
+//  -- Cannot use Stab or Scrape three times in a row
+// - https://slay-the-spire.fandom.com/wiki/Slavers#Red_Slaver
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct RedSlaver {
+    hp_max: HpMax,
+    used_entangle: bool,
+}
+
+impl RedSlaver {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(46..=50),
+            used_entangle: false,
+        }
+    }
+}
+
+impl EnemyCharacteristics for RedSlaver {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::RedSlaverStab)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            75.. if !self.used_entangle => {
+                self.used_entangle = true;
+                EnemyAction::RedSlaverEntangle
+            }
+            55.. if self.used_entangle
+                && (last_action != EnemyAction::RedSlaverStab || run_length < 2) =>
+            {
+                EnemyAction::RedSlaverStab
+            }
+            _ if last_action != EnemyAction::RedSlaverScrape || run_length < 2 => {
+                EnemyAction::RedSlaverScrape
+            }
+            _ => EnemyAction::RedSlaverStab,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Reptomancer
+// - 180 to 190 HP
+// - Summon Dagger: Summons a Dagger (first turn, and only while fewer than 4 others remain)
+// - Snake Strike: Deal 13 damage twice and inflict 1 Weak
+// - Big Bite: Deal 30 damage
+// - Always starts with Summon Dagger
+// - 33% Snake Strike, 33% Summon Dagger, 34% Big Bite
+//  -- Cannot use Snake Strike or Big Bite twice in a row, or Summon Dagger three times in a row
+// - Its Daggers die with it
+// - https://slay-the-spire.fandom.com/wiki/Reptomancer
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Reptomancer {
+    hp_max: HpMax,
+    can_summon: bool,
+}
+
+impl Reptomancer {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(180..=190),
+            can_summon: true,
+        }
+    }
+}
+
+impl EnemyCharacteristics for Reptomancer {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::ReptomancerSummon)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        let mut roll = enemy_rng.gen_range(0..100);
+        loop {
+            roll = match roll {
+                0..33 if last_action != EnemyAction::ReptomancerSnakeStrike => {
+                    return EnemyAction::ReptomancerSnakeStrike;
+                }
+                0..33 => enemy_rng.gen_range(33..100),
+                33..66
+                    if self.can_summon
+                        && (last_action != EnemyAction::ReptomancerSummon || run_length < 2) =>
+                {
+                    return EnemyAction::ReptomancerSummon;
+                }
+                33..66 => return EnemyAction::ReptomancerSnakeStrike,
+                _ if last_action != EnemyAction::ReptomancerBigBite => {
+                    return EnemyAction::ReptomancerBigBite;
+                }
+                _ => enemy_rng.gen_range(0..66),
+            };
+        }
+    }
+
+    fn survey(&mut self, battlefield: &Battlefield) {
+        self.can_summon = battlefield.enemy_count <= 4;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Repulsor
+// - 29 to 35 HP
+// - Bash: Deal 11 damage
+// - Repulse: Shuffle 2 Dazed into the draw pile
+// - 20% Bash, 80% Repulse. Cannot use Bash twice in a row.
+// - https://slay-the-spire.fandom.com/wiki/Repulsor
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Repulsor {
+    hp_max: HpMax,
+}
+
+impl Repulsor {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(29..=35),
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..20 if last_action != Some(EnemyAction::RepulsorBash) => EnemyAction::RepulsorBash,
+            _ => EnemyAction::RepulsorRepulse,
+        }
+    }
+}
+
+impl EnemyCharacteristics for Repulsor {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![],
+            Self::next_action_helper(enemy_rng, None),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(enemy_rng, Some(last_action))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Romeo
+// - 35 to 39 HP
+// - Mock: Does nothing (first turn only)
+// - Agonizing Slash: Deal 10 damage and inflict 2 Weak
+// - Cross Slash: Deal 15 damage
+// - Agonizing Slash follows the Mock and every second Cross Slash in a row
+// - https://slay-the-spire.fandom.com/wiki/Masked_Bandits
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Romeo {
+    hp_max: HpMax,
+}
+
+impl Romeo {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(35..=39),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Romeo {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::RomeoMock)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        // The Mock sets up the Agonizing Slash directly, without rolling.
+        if last_action == EnemyAction::RomeoMock {
+            return EnemyAction::RomeoAgonizingSlash;
+        }
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        if last_action == EnemyAction::RomeoCrossSlash && run_length >= 2 {
+            EnemyAction::RomeoAgonizingSlash
+        } else {
+            EnemyAction::RomeoCrossSlash
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Sentry
+// - 38 to 42 HP
+// - Spawns with 1 Artifact
+// - Bolt: Add 2 Dazed to the discard pile
+// - Beam: Deal 9 damage
+// - Alternates between Bolt and Beam, with neighbouring Sentries out of step
+// - https://slay-the-spire.fandom.com/wiki/Sentry
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Sentry {
+    hp_max: HpMax,
+}
+
+impl Sentry {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(38..=42),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Sentry {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Artifact(1)],
+            EnemyAction::SentryBolt,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        if last_action == EnemyAction::SentryBeam {
+            EnemyAction::SentryBolt
+        } else {
+            EnemyAction::SentryBeam
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Shelled Parasite
+// - 68 to 72 HP
+// - Spawns with 14 Plated Armor and 14 Block
+// - Double Strike: Deal 6 damage twice
+// - Suck: Deal 10 damage and heal for the unblocked damage
+// - Fell: Deal 18 damage and inflict 2 Frail
+// - First turn: 50% Double Strike, 50% Suck
+// - 20% Fell, 40% Double Strike, 40% Suck
+//  -- Cannot use Fell twice in a row, rerolling among the other two instead
+//  -- Cannot use Double Strike or Suck three times in a row
+// - Once its Plated Armor is gone, it is Stunned for a turn, then uses Fell
+// - https://slay-the-spire.fandom.com/wiki/Shelled_Parasite
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct ShelledParasite {
+    hp_max: HpMax,
+    armored: bool,
+}
+
+impl ShelledParasite {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(68..=72),
+            armored: true,
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        roll: u32,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        match roll {
+            0..20 if last_action != EnemyAction::ShelledParasiteFell => {
+                EnemyAction::ShelledParasiteFell
+            }
+            0..20 => {
+                let reroll = enemy_rng.gen_range(20..100);
+                Self::next_action_helper(enemy_rng, reroll, last_action, run_length)
+            }
+            20..60 if last_action != EnemyAction::ShelledParasiteDoubleStrike || run_length < 2 => {
+                EnemyAction::ShelledParasiteDoubleStrike
+            }
+            20..60 => EnemyAction::ShelledParasiteSuck,
+            _ if last_action != EnemyAction::ShelledParasiteSuck || run_length < 2 => {
+                EnemyAction::ShelledParasiteSuck
+            }
+            _ => EnemyAction::ShelledParasiteDoubleStrike,
+        }
+    }
+}
+
+impl EnemyCharacteristics for ShelledParasite {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        let first_action = if enemy_rng.next_bool() {
+            EnemyAction::ShelledParasiteDoubleStrike
+        } else {
+            EnemyAction::ShelledParasiteSuck
+        };
+        (
+            self.hp_max,
+            vec![EnemyCondition::PlatedArmor(14)],
+            first_action,
+        )
+    }
+
+    fn starting_block(&self) -> Block {
+        14
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        if last_action == EnemyAction::ShelledParasiteStunned {
+            return EnemyAction::ShelledParasiteFell;
+        }
+        let roll = enemy_rng.gen_range(0..100);
+        Self::next_action_helper(enemy_rng, roll, last_action, run_length)
+    }
+
+    fn on_hp_lost(
+        &mut self,
+        _: Hp,
+        _: Hp,
+        _: HpMax,
+        conditions: &[EnemyCondition],
+    ) -> Option<EnemyAction> {
+        if self.armored
+            && !conditions
+                .iter()
+                .any(|c| matches!(c, EnemyCondition::PlatedArmor(_)))
+        {
+            self.armored = false;
+            Some(EnemyAction::ShelledParasiteStunned)
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Shield Gremlin
+// - 12 to 15 HP
+// - Protect: Give 7 Block to a random ally
+// - Shield Bash: Deal 6 damage
+// - Protects while it has allies, and bashes once it is alone
+// - https://slay-the-spire.fandom.com/wiki/Gremlins#Shield_Gremlin
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct ShieldGremlin {
+    hp_max: HpMax,
+    alone: bool,
+}
+
+impl ShieldGremlin {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(12..=15),
+            alone: false,
+        }
+    }
+}
+
+impl EnemyCharacteristics for ShieldGremlin {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::ShieldGremlinProtect)
+    }
+
+    fn next_action(&mut self, enemy_rng: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        if self.alone {
+            EnemyAction::ShieldGremlinShieldBash
+        } else {
+            EnemyAction::ShieldGremlinProtect
+        }
+    }
+
+    fn survey(&mut self, battlefield: &Battlefield) {
+        self.alone = battlefield.enemy_count <= 1;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Slime Boss
+// - 140 HP
+// - Goop Spray: Add 3 Slimed to the discard pile
+// - Preparing: Does nothing
+// - Slam: Deal 35 damage
+// - Split: When its HP is at or below 50%, splits into a Spike Slime (L) and an Acid Slime (L),
+//   each with its current HP
+// - Cycles through Goop Spray, Preparing and Slam
+// - https://slay-the-spire.fandom.com/wiki/Slime_Boss
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct SlimeBoss {
+    hp_max: HpMax,
+    splitting: bool,
+}
+
+impl SlimeBoss {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(140..=140),
+            splitting: false,
+        }
+    }
+}
+
+impl EnemyCharacteristics for SlimeBoss {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Split],
+            EnemyAction::SlimeBossGoopSpray,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        _: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        match last_action {
+            EnemyAction::SlimeBossGoopSpray => EnemyAction::SlimeBossPreparing,
+            EnemyAction::SlimeBossPreparing => EnemyAction::SlimeBossSlam,
+            _ => EnemyAction::SlimeBossGoopSpray,
+        }
+    }
+
+    fn on_hp_lost(
+        &mut self,
+        _: Hp,
+        hp: Hp,
+        hp_max: HpMax,
+        _: &[EnemyCondition],
+    ) -> Option<EnemyAction> {
+        if !self.splitting && hp * 2 <= hp_max {
+            self.splitting = true;
+            Some(EnemyAction::SlimeBossSplit)
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Snake Plant
+// - 75 to 79 HP
+// - Spawns with 3 Malleable
+// - Chomp: Deal 7 damage 3 times
+// - Enfeebling Spores: Inflict 2 Frail and 2 Weak
+// - 65% Chomp, 35% Enfeebling Spores
+//  -- Cannot use Chomp three times in a row
+//  -- Cannot use Enfeebling Spores twice in a row
+// - https://slay-the-spire.fandom.com/wiki/Snake_Plant
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct SnakePlant {
+    hp_max: HpMax,
+}
+
+impl SnakePlant {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(75..=79),
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..65 if last_action != Some(EnemyAction::SnakePlantChomp) || run_length < 2 => {
+                EnemyAction::SnakePlantChomp
+            }
+            0..65 => EnemyAction::SnakePlantEnfeeblingSpores,
+            _ if last_action != Some(EnemyAction::SnakePlantEnfeeblingSpores) => {
+                EnemyAction::SnakePlantEnfeeblingSpores
+            }
+            _ => EnemyAction::SnakePlantChomp,
+        }
+    }
+}
+
+impl EnemyCharacteristics for SnakePlant {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![EnemyCondition::Malleable(3, 3)],
+            Self::next_action_helper(enemy_rng, None, 0),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(enemy_rng, Some(last_action), run_length)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Sneaky Gremlin
+// - 10 to 14 HP
+// - Puncture: Deal 9 damage
+// - 100% Puncture
+// - https://slay-the-spire.fandom.com/wiki/Gremlins#Sneaky_Gremlin
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct SneakyGremlin {
+    hp_max: HpMax,
+}
+
+impl SneakyGremlin {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(10..=14),
+        }
+    }
+}
+
+impl EnemyCharacteristics for SneakyGremlin {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::SneakyGremlinPuncture)
+    }
+
+    fn next_action(&mut self, enemy_rng: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        EnemyAction::SneakyGremlinPuncture
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Snecko
+// - 114 to 120 HP
+// - Perplexing Glare: Inflict Confused (first turn only)
+// - Bite: Deal 15 damage
+// - Tail Whip: Deal 8 damage and inflict 2 Vulnerable
+// - 60% Bite, 40% Tail Whip
+//  -- Cannot use Bite three times in a row
+// - https://slay-the-spire.fandom.com/wiki/Snecko
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Snecko {
+    hp_max: HpMax,
+}
+
+impl Snecko {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(114..=120),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Snecko {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::SneckoPerplexingGlare)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..40 => EnemyAction::SneckoTailWhip,
+            _ if last_action != EnemyAction::SneckoBite || run_length < 2 => {
+                EnemyAction::SneckoBite
+            }
+            _ => EnemyAction::SneckoTailWhip,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Spheric Guardian
+// - 20 HP
+// - Spawns with Barricade, 3 Artifact and 40 Block
+// - Activate: Gain 25 Block (first turn only)
+// - Attack: Deal 10 damage and inflict 5 Frail (second turn only)
+// - Slam: Deal 10 damage twice
+// - Harden: Gain 15 Block and deal 10 damage
+// - Alternates between Slam and Harden after its second turn
+// - https://slay-the-spire.fandom.com/wiki/Spheric_Guardian
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct SphericGuardian {
+    hp_max: HpMax,
+}
+
+impl SphericGuardian {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(20..=20),
+        }
+    }
+}
+
+impl EnemyCharacteristics for SphericGuardian {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Barricade, EnemyCondition::Artifact(3)],
+            EnemyAction::SphericGuardianActivate,
+        )
+    }
+
+    fn starting_block(&self) -> Block {
+        40
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        match last_action {
+            EnemyAction::SphericGuardianActivate => EnemyAction::SphericGuardianAttack,
+            EnemyAction::SphericGuardianSlam => EnemyAction::SphericGuardianHarden,
+            _ => EnemyAction::SphericGuardianSlam,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Spiker
+// - 42 to 56 HP
+// - Spawns with 3 Thorns
+// - Cut: Deal 7 damage
+// - Spike: Gain 2 Thorns
+// - 50% Cut, 50% Spike. Cannot use Cut twice in a row.
+// - Only uses Cut once it has used Spike 6 times
+// - https://slay-the-spire.fandom.com/wiki/Spiker
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Spiker {
+    hp_max: HpMax,
+    spike_count: usize,
+}
+
+impl Spiker {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(42..=56),
+            spike_count: 0,
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        spike_count: usize,
+    ) -> EnemyAction {
+        let roll = enemy_rng.gen_range(0..100);
+        if spike_count > 5 || (roll < 50 && last_action != Some(EnemyAction::SpikerCut)) {
+            EnemyAction::SpikerCut
+        } else {
+            EnemyAction::SpikerSpike
+        }
+    }
+}
+
+impl EnemyCharacteristics for Spiker {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![EnemyCondition::Thorns(3)],
+            Self::next_action_helper(enemy_rng, None, 0),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        if last_action == EnemyAction::SpikerSpike {
+            self.spike_count += 1;
+        }
+        Self::next_action_helper(enemy_rng, Some(last_action), self.spike_count)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// SpikeSlimeL
+// - 64 to 70 HP
+// - Flame Tackle: Deal 16 damage, add 2 Slimed to the discard pile
+// - Lick: Inflict 2 Frail
+// - Split: When its HP is at or below 50%, splits into 2 Spike Slime (M) with its current HP
+// - 30% Flame Tackle, 70% Lick
+//  -- Cannot use Flame Tackle or Lick three times in a row
+// - https://slay-the-spire.fandom.com/wiki/Spike_Slime
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct SpikeSlimeL {
+    hp_max: HpMax,
+    splitting: bool,
+}
+
+impl SpikeSlimeL {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self::with_hp_max(hp_rng.gen_range(64..=70))
+    }
+
+    fn with_hp_max(hp_max: HpMax) -> Self {
+        Self {
+            hp_max,
+            splitting: false,
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..30 if last_action != Some(EnemyAction::SpikeSlimeLFlameTackle) || run_length < 2 => {
+                EnemyAction::SpikeSlimeLFlameTackle
+            }
+            0..30 => EnemyAction::SpikeSlimeLLick,
+            _ if last_action != Some(EnemyAction::SpikeSlimeLLick) || run_length < 2 => {
+                EnemyAction::SpikeSlimeLLick
+            }
+            _ => EnemyAction::SpikeSlimeLFlameTackle,
+        }
+    }
+}
+
+impl EnemyCharacteristics for SpikeSlimeL {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![EnemyCondition::Split],
+            Self::next_action_helper(enemy_rng, None, 0),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(enemy_rng, Some(last_action), run_length)
+    }
+
+    fn on_hp_lost(
+        &mut self,
+        _: Hp,
+        hp: Hp,
+        hp_max: HpMax,
+        _: &[EnemyCondition],
+    ) -> Option<EnemyAction> {
+        if !self.splitting && hp * 2 <= hp_max {
+            self.splitting = true;
+            Some(EnemyAction::SpikeSlimeLSplit)
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// SpikeSlimeM
+// - 28 to 32 HP
+// - Flame Tackle: Deal 8 damage, add a Slimed to the discard pile
+// - Lick: Inflict 1 Frail
+// - 30% Flame Tackle, 70% Lick
+//  -- Cannot use Flame Tackle or Lick three times in a row
+// - https://slay-the-spire.fandom.com/wiki/Spike_Slime
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct SpikeSlimeM {
+    hp_max: HpMax,
+}
+
+impl SpikeSlimeM {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(28..=32),
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        match enemy_rng.gen_range(0..100) {
+            0..30 if last_action != Some(EnemyAction::SpikeSlimeMFlameTackle) || run_length < 2 => {
+                EnemyAction::SpikeSlimeMFlameTackle
+            }
+            0..30 => EnemyAction::SpikeSlimeMLick,
+            _ if last_action != Some(EnemyAction::SpikeSlimeMLick) || run_length < 2 => {
+                EnemyAction::SpikeSlimeMLick
+            }
+            _ => EnemyAction::SpikeSlimeMFlameTackle,
+        }
+    }
+}
+
+impl EnemyCharacteristics for SpikeSlimeM {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![],
+            Self::next_action_helper(enemy_rng, None, 0),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(enemy_rng, Some(last_action), run_length)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// SpikeSlimeS
+// - 10 to 14 HP
+// - Tackle: Deal 5 damage
+// - 100% Tackle
+// - https://slay-the-spire.fandom.com/wiki/Spike_Slime
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct SpikeSlimeS {
+    hp_max: HpMax,
+}
+
+impl SpikeSlimeS {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(10..=14),
+        }
+    }
+}
+
+impl EnemyCharacteristics for SpikeSlimeS {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::SpikeSlimeSTackle)
+    }
+
+    fn next_action(&mut self, enemy_rng: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        EnemyAction::SpikeSlimeSTackle
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Spire Growth
+// - 170 HP
+// - Constrict: Inflict 10 Constricted (only while the player isn't Constricted)
+// - Quick Tackle: Deal 16 damage
+// - Smash: Deal 22 damage
+// - 50% Quick Tackle, otherwise Constrict if it can, else Smash
+//  -- Cannot use Constrict twice in a row, or Quick Tackle or Smash three times in a row
+// - https://slay-the-spire.fandom.com/wiki/Spire_Growth
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct SpireGrowth {
+    hp_max: HpMax,
+    player_constricted: bool,
+}
+
+impl SpireGrowth {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(170..=170),
+            player_constricted: false,
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+        player_constricted: bool,
+    ) -> EnemyAction {
+        let used_twice = |action| last_action == Some(action) && run_length >= 2;
+        match enemy_rng.gen_range(0..100) {
+            0..50 if !used_twice(EnemyAction::SpireGrowthQuickTackle) => {
+                EnemyAction::SpireGrowthQuickTackle
+            }
+            _ if !player_constricted && last_action != Some(EnemyAction::SpireGrowthConstrict) => {
+                EnemyAction::SpireGrowthConstrict
+            }
+            _ if !used_twice(EnemyAction::SpireGrowthSmash) => EnemyAction::SpireGrowthSmash,
+            _ => EnemyAction::SpireGrowthQuickTackle,
+        }
+    }
+}
+
+impl EnemyCharacteristics for SpireGrowth {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![],
+            Self::next_action_helper(enemy_rng, None, 0, false),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        Self::next_action_helper(
+            enemy_rng,
+            Some(last_action),
+            run_length,
+            self.player_constricted,
+        )
+    }
+
+    fn survey(&mut self, battlefield: &Battlefield) {
+        self.player_constricted = battlefield.player_constricted;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Spire Shield
+// - 110 HP
+// - Spawns with 1 Artifact, behind the player
+// - Bash: Deal 12 damage and apply -1 Strength
+// - Fortify: All enemies gain 30 Block
+// - Smash: Deal 34 damage and gain Block equal to the damage dealt
+// - Repeats a three-turn cycle:
+//  -- 50% Fortify, 50% Bash
+//  -- Bash, unless it was just used, in which case Fortify
+//  -- Smash
+// - https://slay-the-spire.fandom.com/wiki/Spire_Shield
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct SpireShield {
+    hp_max: HpMax,
+    move_count: usize,
+}
+
+impl SpireShield {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(110..=110),
+            move_count: 1, // The opening move takes the first turn of the cycle.
+        }
+    }
+}
+
+impl EnemyCharacteristics for SpireShield {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        let action = if enemy_rng.next_bool() {
+            EnemyAction::SpireShieldFortify
+        } else {
+            EnemyAction::SpireShieldBash
+        };
+        (
+            self.hp_max,
+            vec![EnemyCondition::Artifact(1), EnemyCondition::BackAttack],
+            action,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        let action = match self.move_count % 3 {
+            0 if enemy_rng.next_bool() => EnemyAction::SpireShieldFortify,
+            0 => EnemyAction::SpireShieldBash,
+            1 if last_action != EnemyAction::SpireShieldBash => EnemyAction::SpireShieldBash,
+            1 => EnemyAction::SpireShieldFortify,
+            _ => EnemyAction::SpireShieldSmash,
+        };
+        self.move_count += 1;
+        action
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Spire Spear
+// - 160 HP
+// - Spawns with 1 Artifact
+// - Burn Strike: Deal 5 damage twice and add 2 Burns to the discard pile
+// - Piercer: All enemies gain 2 Strength
+// - Skewer: Deal 10 damage 3 times
+// - Repeats a three-turn cycle:
+//  -- Burn Strike, unless it was just used, in which case Piercer
+//  -- Skewer
+//  -- 50% Piercer, 50% Burn Strike
+// - https://slay-the-spire.fandom.com/wiki/Spire_Spear
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct SpireSpear {
+    hp_max: HpMax,
+    move_count: usize,
+}
+
+impl SpireSpear {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(160..=160),
+            move_count: 1, // The opening move takes the first turn of the cycle.
+        }
+    }
+}
+
+impl EnemyCharacteristics for SpireSpear {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Artifact(1)],
+            EnemyAction::SpireSpearBurnStrike,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        let action = match self.move_count % 3 {
+            0 if last_action != EnemyAction::SpireSpearBurnStrike => {
+                EnemyAction::SpireSpearBurnStrike
+            }
+            0 => EnemyAction::SpireSpearPiercer,
+            1 => EnemyAction::SpireSpearSkewer,
+            _ if enemy_rng.next_bool() => EnemyAction::SpireSpearPiercer,
+            _ => EnemyAction::SpireSpearBurnStrike,
+        };
+        self.move_count += 1;
+        action
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Taskmaster
+// - 54 to 60 HP
+// - Scouring Whip: Deal 7 damage, add a Wound to the discard pile
+// - 100% Scouring Whip
+// - https://slay-the-spire.fandom.com/wiki/Slavers#Taskmaster
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Taskmaster {
+    hp_max: HpMax,
+}
+
+impl Taskmaster {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(54..=60),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Taskmaster {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::TaskmasterScouringWhip)
+    }
+
+    fn next_action(&mut self, enemy_rng: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        EnemyAction::TaskmasterScouringWhip
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// The Champ
+// - 420 HP
+// - Heavy Slash: Deal 16 damage
+// - Defensive Stance: Gain 15 Block and 5 Metallicize (at most twice, never twice in a row)
+// - Face Slap: Deal 12 damage, inflict 2 Frail and 2 Vulnerable
+// - Gloat: Gain 2 Strength (never right after Gloat or Defensive Stance)
+// - Taunt: Inflict 2 Weak and 2 Vulnerable, every fourth turn
+// - 15% Defensive Stance, 15% Gloat, 25% Face Slap, 45% Heavy Slash
+// - Execute phase: Below half HP, it uses Anger, then Execute every third turn
+//  -- Anger: Remove all debuffs and gain 6 Strength
+//  -- Execute: Deal 10 damage twice
+// - https://slay-the-spire.fandom.com/wiki/The_Champ
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct TheChamp {
+    hp_max: HpMax,
+    turn_count: usize,
+    defensive_stance_count: usize,
+    below_half_hp: bool,
+    enraged: bool,
+    previous_action: Option<EnemyAction>,
+}
+
+impl TheChamp {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(420..=420),
+            turn_count: 1, // The opening move counts towards Taunt.
+            defensive_stance_count: 0,
+            below_half_hp: false,
+            enraged: false,
+            previous_action: None,
+        }
+    }
+
+    fn next_action_helper(
+        roll: usize,
+        last_action: Option<EnemyAction>,
+        defensive_stance_count: usize,
+    ) -> EnemyAction {
+        match roll {
+            0..=15
+                if last_action != Some(EnemyAction::TheChampDefensiveStance)
+                    && defensive_stance_count < 2 =>
+            {
+                EnemyAction::TheChampDefensiveStance
+            }
+            0..=30
+                if !matches!(
+                    last_action,
+                    Some(EnemyAction::TheChampGloat | EnemyAction::TheChampDefensiveStance)
+                ) =>
+            {
+                EnemyAction::TheChampGloat
+            }
+            0..=55 if last_action != Some(EnemyAction::TheChampFaceSlap) => {
+                EnemyAction::TheChampFaceSlap
+            }
+            _ if last_action != Some(EnemyAction::TheChampHeavySlash) => {
+                EnemyAction::TheChampHeavySlash
+            }
+            _ => EnemyAction::TheChampFaceSlap,
+        }
+    }
+}
+
+impl EnemyCharacteristics for TheChamp {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![],
+            Self::next_action_helper(enemy_rng.gen_range(0..100), None, 0),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let roll = enemy_rng.gen_range(0..100);
+        let action_before_last = self.previous_action.replace(last_action);
+        self.turn_count += 1;
+        if last_action == EnemyAction::TheChampDefensiveStance {
+            self.defensive_stance_count += 1;
+        }
+        if self.below_half_hp && !self.enraged {
+            self.enraged = true;
+            return EnemyAction::TheChampAnger;
+        }
+        if self.enraged
+            && last_action != EnemyAction::TheChampExecute
+            && action_before_last != Some(EnemyAction::TheChampExecute)
+        {
+            return EnemyAction::TheChampExecute;
+        }
+        if self.turn_count == 4 && !self.enraged {
+            self.turn_count = 0;
+            return EnemyAction::TheChampTaunt;
+        }
+        Self::next_action_helper(roll, Some(last_action), self.defensive_stance_count)
+    }
+
+    fn on_hp_lost(
+        &mut self,
+        _: Hp,
+        hp: Hp,
+        hp_max: HpMax,
+        _: &[EnemyCondition],
+    ) -> Option<EnemyAction> {
+        // The game only checks its HP when it picks its next move.
+        self.below_half_hp = hp < hp_max / 2;
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// The Collector
+// - 282 HP
+// - Spawn: Summons 2 Torch Heads (first turn only)
+// - Fireball: Deal 18 damage
+// - Buff: Gain 15 Block, and all enemies gain 3 Strength
+// - Mega Debuff: Inflict 3 Weak, 3 Vulnerable and 3 Frail (fourth turn only)
+// - Revive: Summons a Torch Head in place of each one that has died
+// - 25% Revive (if a Torch Head has died), 45% Fireball, 30% Buff
+//  -- Cannot use Fireball three times in a row, or Revive or Buff twice in a row
+// - Its Torch Heads die with it
+// - https://slay-the-spire.fandom.com/wiki/The_Collector
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct TheCollector {
+    hp_max: HpMax,
+    turns_taken: usize,
+    used_mega_debuff: bool,
+    torch_head_died: bool,
+}
+
+impl TheCollector {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(282..=282),
+            turns_taken: 0,
+            used_mega_debuff: false,
+            torch_head_died: false,
+        }
+    }
+}
+
+impl EnemyCharacteristics for TheCollector {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::TheCollectorSpawn)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        let roll = enemy_rng.gen_range(0..100);
+        self.turns_taken += 1;
+        if self.turns_taken >= 3 && !self.used_mega_debuff {
+            self.used_mega_debuff = true;
+            return EnemyAction::TheCollectorMegaDebuff;
+        }
+        match roll {
+            0..=25 if self.torch_head_died && last_action != EnemyAction::TheCollectorRevive => {
+                EnemyAction::TheCollectorRevive
+            }
+            0..=70 if last_action != EnemyAction::TheCollectorFireball || run_length < 2 => {
+                EnemyAction::TheCollectorFireball
+            }
+            _ if last_action != EnemyAction::TheCollectorBuff => EnemyAction::TheCollectorBuff,
+            _ => EnemyAction::TheCollectorFireball,
+        }
+    }
+
+    fn survey(&mut self, battlefield: &Battlefield) {
+        self.torch_head_died = battlefield.enemy_count < 3;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// The Guardian
+// - 240 HP
+// - Offensive Mode: Cycles through Charging Up, Fierce Bash, Vent Steam and Whirlwind
+//  -- Charging Up: Gain 9 Block
+//  -- Fierce Bash: Deal 32 damage
+//  -- Vent Steam: Inflict 2 Weak and 2 Vulnerable
+//  -- Whirlwind: Deal 5 damage 4 times
+// - Mode Shift: After losing 30 HP in Offensive Mode, gains 20 Block and shifts to Defensive Mode
+//  -- The threshold grows by 10 with each shift
+// - Defensive Mode: Defensive Mode, Roll Attack, Twin Slam, then back to Offensive Mode
+//  -- Defensive Mode: Gain 3 Sharp Hide
+//  -- Roll Attack: Deal 9 damage
+//  -- Twin Slam: Deal 8 damage twice, lose Sharp Hide and return to Offensive Mode at Whirlwind
+// - https://slay-the-spire.fandom.com/wiki/The_Guardian
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct TheGuardian {
+    hp_max: HpMax,
+    defensive: bool,
+    mode_shift_threshold: Hp,
+    hp_lost_since_shift: Hp,
+    pending_block: Block,
+}
+
+impl TheGuardian {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(240..=240),
+            defensive: false,
+            mode_shift_threshold: 30,
+            hp_lost_since_shift: 0,
+            pending_block: 0,
+        }
+    }
+}
+
+impl EnemyCharacteristics for TheGuardian {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::ModeShift(self.mode_shift_threshold)],
+            EnemyAction::TheGuardianChargingUp,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        _: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        match last_action {
+            EnemyAction::TheGuardianChargingUp => EnemyAction::TheGuardianFierceBash,
+            EnemyAction::TheGuardianFierceBash => EnemyAction::TheGuardianVentSteam,
+            EnemyAction::TheGuardianVentSteam => EnemyAction::TheGuardianWhirlwind,
+            EnemyAction::TheGuardianDefensiveMode => EnemyAction::TheGuardianRollAttack,
+            EnemyAction::TheGuardianRollAttack => EnemyAction::TheGuardianTwinSlam,
+            EnemyAction::TheGuardianTwinSlam => {
+                self.defensive = false;
+                EnemyAction::TheGuardianWhirlwind
+            }
+            _ => EnemyAction::TheGuardianChargingUp,
+        }
+    }
+
+    fn on_hp_lost(
+        &mut self,
+        hp_lost: Hp,
+        _: Hp,
+        _: HpMax,
+        _: &[EnemyCondition],
+    ) -> Option<EnemyAction> {
+        if self.defensive {
+            return None;
+        }
+        self.hp_lost_since_shift += hp_lost;
+        if self.hp_lost_since_shift < self.mode_shift_threshold {
+            return None;
+        }
+        self.defensive = true;
+        self.hp_lost_since_shift = 0;
+        self.mode_shift_threshold += 10;
+        self.pending_block += 20;
+        Some(EnemyAction::TheGuardianDefensiveMode)
+    }
+
+    fn update_status(&mut self, conditions: &mut Vec<EnemyCondition>, block: &mut Block) {
+        *block += self.pending_block;
+        self.pending_block = 0;
+        conditions.retain(|c| !matches!(c, EnemyCondition::ModeShift(_)));
+        if !self.defensive {
+            conditions.retain(|c| !matches!(c, EnemyCondition::SharpHide(_)));
+            conditions.push(EnemyCondition::ModeShift(
+                self.mode_shift_threshold - self.hp_lost_since_shift,
+            ));
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// The Maw
+// - 300 HP
+// - Roar: Inflict 3 Weak and 3 Frail (first turn only)
+// - Drool: Gain 3 Strength
+// - Nom Nom: Deal 5 damage once for every two turns it has taken
+// - Slam: Deal 25 damage
+// - 50% Nom Nom (never twice in a row), otherwise Drool after Slam or Nom Nom, else Slam
+// - https://slay-the-spire.fandom.com/wiki/The_Maw
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct TheMaw {
+    hp_max: HpMax,
+    turn_count: AttackCount,
+}
+
+impl TheMaw {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(300..=300),
+            turn_count: 2, // The game starts counting at 1, and the opening move counts.
+        }
+    }
+}
+
+impl EnemyCharacteristics for TheMaw {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::TheMawRoar)
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let roll = enemy_rng.gen_range(0..100);
+        self.turn_count += 1;
+        match last_action {
+            EnemyAction::TheMawNomNom(_) => {}
+            _ if roll < 50 => {
+                return EnemyAction::TheMawNomNom(
+                    (self.turn_count / 2).clamp(1, MAX_THE_MAW_NOM_NOM_BITE_COUNT),
+                );
+            }
+            _ => {}
+        }
+        if matches!(
+            last_action,
+            EnemyAction::TheMawSlam | EnemyAction::TheMawNomNom(_)
+        ) {
+            EnemyAction::TheMawDrool
+        } else {
+            EnemyAction::TheMawSlam
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Time Eater
+// - 456 HP
+// - Spawns with Time Warp
+// - Head Slam: Deal 26 damage and inflict 1 Draw Reduction
+// - Haste: Remove all debuffs and heal to half HP (once, when below half HP)
+// - Reverberate: Deal 7 damage 3 times
+// - Ripple: Gain 20 Block and inflict 1 Vulnerable and 1 Weak
+// - 45% Reverberate, 35% Head Slam, 20% Ripple
+//  -- Cannot use Reverberate three times in a row, or Head Slam or Ripple twice in a row
+// - https://slay-the-spire.fandom.com/wiki/Time_Eater
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct TimeEater {
+    hp_max: HpMax,
+    below_half_hp: bool,
+    used_haste: bool,
+}
+
+impl TimeEater {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(456..=456),
+            below_half_hp: false,
+            used_haste: false,
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: Option<EnemyAction>,
+        run_length: usize,
+    ) -> EnemyAction {
+        let mut roll = enemy_rng.gen_range(0..100);
+        loop {
+            roll = match roll {
+                0..45
+                    if last_action != Some(EnemyAction::TimeEaterReverberate) || run_length < 2 =>
+                {
+                    return EnemyAction::TimeEaterReverberate;
+                }
+                0..45 => enemy_rng.gen_range(50..100),
+                45..80 if last_action != Some(EnemyAction::TimeEaterHeadSlam) => {
+                    return EnemyAction::TimeEaterHeadSlam;
+                }
+                45..80 => {
+                    return *enemy_rng.weighted_choose(&[
+                        (EnemyAction::TimeEaterReverberate, 0.66),
+                        (EnemyAction::TimeEaterRipple, 1. - 0.66),
+                    ]);
+                }
+                _ if last_action != Some(EnemyAction::TimeEaterRipple) => {
+                    return EnemyAction::TimeEaterRipple;
+                }
+                _ => enemy_rng.gen_range(0..75),
+            };
+        }
+    }
+}
+
+impl EnemyCharacteristics for TimeEater {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        (
+            self.hp_max,
+            vec![EnemyCondition::TimeWarp(0)],
+            Self::next_action_helper(enemy_rng, None, 0),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        run_length: usize,
+    ) -> EnemyAction {
+        if self.below_half_hp && !self.used_haste {
+            let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency
+            self.used_haste = true;
+            return EnemyAction::TimeEaterHaste;
+        }
+        Self::next_action_helper(enemy_rng, Some(last_action), run_length)
+    }
+
+    fn on_hp_lost(
+        &mut self,
+        _: Hp,
+        hp: Hp,
+        hp_max: HpMax,
+        _: &[EnemyCondition],
+    ) -> Option<EnemyAction> {
+        // The game only checks its HP when it picks its next move.
+        self.below_half_hp = hp < hp_max / 2;
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Torch Head
+// - 38 to 40 HP
+// - Tackle: Deal 7 damage
+// - 100% Tackle
+// - https://slay-the-spire.fandom.com/wiki/The_Collector#Torch_Head
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct TorchHead {
+    hp_max: HpMax,
+}
+
+impl TorchHead {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(38..=40),
+        }
+    }
+}
+
+impl EnemyCharacteristics for TorchHead {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (self.hp_max, vec![], EnemyAction::TorchHeadTackle)
+    }
+
+    fn next_action(&mut self, enemy_rng: &mut StsRandom, _: EnemyAction, _: usize) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        EnemyAction::TorchHeadTackle
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Transient
+// - 999 HP
+// - Spawns with 5 Fading and Shifting
+// - Attack: Deal 30 damage, increasing by 10 each turn
+// - https://slay-the-spire.fandom.com/wiki/Transient
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct Transient {
+    hp_max: HpMax,
+}
+
+impl Transient {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(999..=999),
+        }
+    }
+}
+
+impl EnemyCharacteristics for Transient {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        (
+            self.hp_max,
+            vec![EnemyCondition::Fading(5), EnemyCondition::Shifting],
+            EnemyAction::TransientAttack(30),
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        let _ = enemy_rng.gen_range(0..100); // Burn a random number for consistency with the game
+        match last_action {
+            // It fades away before it could ever hit harder than 70.
+            EnemyAction::TransientAttack(damage) => {
+                EnemyAction::TransientAttack((damage + 10).min(70))
+            }
+            _ => EnemyAction::TransientAttack(30),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Writhing Mass
+// - 160 HP
+// - Spawns with Reactive and 3 Malleable
+// - Flail: Deal 15 damage and gain 15 Block
+// - Implant: Add a Parasite to the player's deck (once per combat)
+// - Multi-Strike: Deal 7 damage 3 times
+// - Strong Strike: Deal 32 damage
+// - Wither: Deal 10 damage and inflict 2 Weak and 2 Vulnerable
+// - First turn: 33% Multi-Strike, 33% Flail, 34% Wither
+// - 10% Strong Strike, 10% Implant, 20% Wither, 30% Multi-Strike, 30% Flail
+//  -- Cannot use any move twice in a row, rerolling instead
+// - Reactive: Picks a new intent whenever it loses HP to an attack
+// - https://slay-the-spire.fandom.com/wiki/Writhing_Mass
+////////////////////////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+struct WrithingMass {
+    hp_max: HpMax,
+    used_implant: bool,
+}
+
+impl WrithingMass {
+    fn new(hp_rng: &mut StsRandom) -> Self {
+        Self {
+            hp_max: hp_rng.gen_range(160..=160),
+            used_implant: false,
+        }
+    }
+
+    fn next_action_helper(
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        used_implant: bool,
+    ) -> EnemyAction {
+        let mut roll = enemy_rng.gen_range(0..100);
+        loop {
+            roll = match roll {
+                0..10 if last_action != EnemyAction::WrithingMassStrongStrike => {
+                    return EnemyAction::WrithingMassStrongStrike;
+                }
+                0..10 => enemy_rng.gen_range(10..100),
+                10..20 if !used_implant && last_action != EnemyAction::WrithingMassImplant => {
+                    return EnemyAction::WrithingMassImplant;
+                }
+                10..20 if *enemy_rng.weighted_choose(&[(true, 0.1), (false, 0.9)]) => {
+                    return EnemyAction::WrithingMassStrongStrike;
+                }
+                10..20 => enemy_rng.gen_range(20..100),
+                20..40 if last_action != EnemyAction::WrithingMassWither => {
+                    return EnemyAction::WrithingMassWither;
+                }
+                20..40 if *enemy_rng.weighted_choose(&[(true, 0.4), (false, 0.6)]) => {
+                    enemy_rng.gen_range(0..20)
+                }
+                20..40 => enemy_rng.gen_range(40..100),
+                40..70 if last_action != EnemyAction::WrithingMassMultiStrike => {
+                    return EnemyAction::WrithingMassMultiStrike;
+                }
+                40..70 if *enemy_rng.weighted_choose(&[(true, 0.3), (false, 0.7)]) => {
+                    return EnemyAction::WrithingMassFlail;
+                }
+                40..70 => enemy_rng.gen_range(0..40),
+                _ if last_action != EnemyAction::WrithingMassFlail => {
+                    return EnemyAction::WrithingMassFlail;
+                }
+                _ => enemy_rng.gen_range(0..70),
+            };
+        }
+    }
+}
+
+impl EnemyCharacteristics for WrithingMass {
+    fn on_spawn(&self, enemy_rng: &mut StsRandom) -> (HpMax, Vec<EnemyCondition>, EnemyAction) {
+        let action = match enemy_rng.gen_range(0..100) {
+            0..33 => EnemyAction::WrithingMassMultiStrike,
+            33..66 => EnemyAction::WrithingMassFlail,
+            _ => EnemyAction::WrithingMassWither,
+        };
+        (
+            self.hp_max,
+            vec![EnemyCondition::Reactive, EnemyCondition::Malleable(3, 3)],
+            action,
+        )
+    }
+
+    fn next_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        last_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        if last_action == EnemyAction::WrithingMassImplant {
+            self.used_implant = true;
+        }
+        Self::next_action_helper(enemy_rng, last_action, self.used_implant)
+    }
+
+    fn reroll_action(
+        &mut self,
+        enemy_rng: &mut StsRandom,
+        current_action: EnemyAction,
+        _: usize,
+    ) -> EnemyAction {
+        // An Implant that gets rerolled away was never used.
+        Self::next_action_helper(enemy_rng, current_action, self.used_implant)
     }
 }
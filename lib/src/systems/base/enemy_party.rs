@@ -1,52 +1,109 @@
-use crate::data::{Encounter, Enemy};
-use crate::systems::rng::{Seed, StsRandom};
+use crate::data::{Encounter, Enemy, EnemyAction, EnemyCondition};
+use crate::systems::rng::StsRandom;
+use crate::types::EnemyIndex;
 
-use super::enemy_characteristics::gen_characteristics;
+use super::enemy_characteristics::{
+    gen_awake_characteristics, gen_characteristics, gen_hard_mode_characteristics,
+    gen_split_characteristics,
+};
 use super::enemy_state::EnemyState;
 
+/// The gremlins that gangs and the Gremlin Leader draw from, two of each except the Shield
+/// Gremlin and the Gremlin Wizard.
+const GREMLINS: [Enemy; 8] = [
+    Enemy::MadGremlin,
+    Enemy::MadGremlin,
+    Enemy::SneakyGremlin,
+    Enemy::SneakyGremlin,
+    Enemy::FatGremlin,
+    Enemy::FatGremlin,
+    Enemy::ShieldGremlin,
+    Enemy::GremlinWizard,
+];
+
 #[derive(Debug)]
 pub struct EnemyParty(pub [Option<EnemyState>; 5]);
 
 impl EnemyParty {
     /// Generates a new enemy party for the specified encounter.
     pub fn generate(
-        seed_for_floor: Seed,
+        hp_rng: &mut StsRandom,
         encounter: Encounter,
         enemy_rng: &mut StsRandom,
         misc_rng: &mut StsRandom,
     ) -> EnemyParty {
-        let mut hp_rng = StsRandom::from(seed_for_floor);
         let mut enemy_party: [Option<EnemyState>; 5] = [None, None, None, None, None];
         macro_rules! enemy_party {
             ( $( $enemy:ident ),* ) => {{
                 let mut iter = enemy_party.iter_mut();
                 $(
                     if let Some(slot) = iter.next() {
-                        let characteristics = gen_characteristics(Enemy::$enemy, &mut hp_rng);
-                        *slot = Some(EnemyState::new(Enemy::$enemy, characteristics, enemy_rng));
+                        *slot = Some(Self::spawn(Enemy::$enemy, hp_rng, enemy_rng));
                     }
                 )*
                 EnemyParty(enemy_party)
             }};
         }
-        match encounter {
-            Encounter::AwakenedOne => enemy_party!(AwakenedOne),
-            Encounter::BlueSlaver => todo!("{:?}", encounter),
-            Encounter::BookOfStabbing => todo!("{:?}", encounter),
-            Encounter::BronzeAutomaton => todo!("{:?}", encounter),
-            Encounter::CenturionAndMystic => todo!("{:?}", encounter),
-            Encounter::Chosen => todo!("{:?}", encounter),
-            Encounter::ChosenAndByrd => todo!("{:?}", encounter),
-            Encounter::CorruptHeart => todo!("{:?}", encounter),
+        let mut party = match encounter {
+            Encounter::AwakenedOne => {
+                let mut party = enemy_party!(Cultist, Cultist, AwakenedOne);
+                // The Cultists flee once the Awakened One is dead for good.
+                for cultist in party.0.iter_mut().take(2).flatten() {
+                    cultist.conditions.push(EnemyCondition::Minion);
+                }
+                party
+            }
+            Encounter::BlueSlaver => enemy_party!(BlueSlaver),
+            Encounter::BookOfStabbing => enemy_party!(BookOfStabbing),
+            Encounter::BronzeAutomaton => {
+                // The Automaton stands in the middle, with its orbs appearing on either side.
+                enemy_party[1] = Some(Self::spawn(Enemy::BronzeAutomaton, hp_rng, enemy_rng));
+                EnemyParty(enemy_party)
+            }
+            Encounter::CenturionAndMystic => enemy_party!(Centurion, Mystic),
+            Encounter::Chosen => enemy_party!(Chosen),
+            Encounter::ChosenAndByrd => enemy_party!(Byrd, Chosen),
+            Encounter::ColosseumNobs => enemy_party!(Taskmaster, GremlinNob),
+            Encounter::ColosseumSlavers => enemy_party!(BlueSlaver, RedSlaver),
+            Encounter::CorruptHeart => enemy_party!(CorruptHeart),
             Encounter::Cultist => enemy_party!(Cultist),
-            Encounter::CultistAndChosen => todo!("{:?}", encounter),
-            Encounter::DonuAndDeca => todo!("{:?}", encounter),
-            Encounter::ExordiumThugs => todo!("{:?}", encounter),
+            Encounter::CultistAndChosen => enemy_party!(Cultist, Chosen),
+            Encounter::DonuAndDeca => enemy_party!(Deca, Donu),
+            Encounter::ExordiumThugs => {
+                // Like Exordium Wildlife, every candidate is rolled before one is picked.
+                let louse = Self::random_louse(misc_rng);
+                let louse_characteristics = gen_characteristics(louse, hp_rng);
+                let spike_slime_m = gen_characteristics(Enemy::SpikeSlimeM, hp_rng);
+                let acid_slime_m = gen_characteristics(Enemy::AcidSlimeM, hp_rng);
+                let choice = misc_rng.gen_range(0..=2);
+                enemy_party[0] = Some(match choice {
+                    0 => EnemyState::new(louse, louse_characteristics, enemy_rng),
+                    1 => EnemyState::new(Enemy::SpikeSlimeM, spike_slime_m, enemy_rng),
+                    2 => EnemyState::new(Enemy::AcidSlimeM, acid_slime_m, enemy_rng),
+                    _ => unreachable!(),
+                });
+                let cultist = gen_characteristics(Enemy::Cultist, hp_rng);
+                let slaver = if misc_rng.next_bool() {
+                    Enemy::RedSlaver
+                } else {
+                    Enemy::BlueSlaver
+                };
+                let slaver_characteristics = gen_characteristics(slaver, hp_rng);
+                let looter = gen_characteristics(Enemy::Looter, hp_rng);
+                let choice = misc_rng.gen_range(0..=2);
+                enemy_party[1] = Some(match choice {
+                    0 => EnemyState::new(Enemy::Cultist, cultist, enemy_rng),
+                    1 => EnemyState::new(slaver, slaver_characteristics, enemy_rng),
+                    2 => EnemyState::new(Enemy::Looter, looter, enemy_rng),
+                    _ => unreachable!(),
+                });
+                EnemyParty(enemy_party)
+            }
             Encounter::ExordiumWildlife => {
                 // This must have been one of their earlier ideas for the game, as it's implemented
                 // in a more wasteful way than the other encounters.
-                let fungi_beast = gen_characteristics(Enemy::FungiBeast, &mut hp_rng);
-                let jaw_worm = gen_characteristics(Enemy::JawWorm, &mut hp_rng);
+                let fungi_beast = gen_characteristics(Enemy::FungiBeast, hp_rng);
+                let jaw_worm = gen_characteristics(Enemy::JawWorm, hp_rng);
                 let choice = misc_rng.gen_range(0..=1);
 
                 enemy_party[0] = if choice == 0 {
@@ -54,19 +111,10 @@ impl EnemyParty {
                 } else {
                     Some(EnemyState::new(Enemy::JawWorm, jaw_worm, enemy_rng))
                 };
-                let louse = if misc_rng.next_bool() {
-                    (
-                        Enemy::RedLouse,
-                        gen_characteristics(Enemy::RedLouse, &mut hp_rng),
-                    )
-                } else {
-                    (
-                        Enemy::GreenLouse,
-                        gen_characteristics(Enemy::GreenLouse, &mut hp_rng),
-                    )
-                };
-                let spike_slime_m = gen_characteristics(Enemy::SpikeSlimeM, &mut hp_rng);
-                let acid_slime_m = gen_characteristics(Enemy::AcidSlimeM, &mut hp_rng);
+                let louse = Self::random_louse(misc_rng);
+                let louse = (louse, gen_characteristics(louse, hp_rng));
+                let spike_slime_m = gen_characteristics(Enemy::SpikeSlimeM, hp_rng);
+                let acid_slime_m = gen_characteristics(Enemy::AcidSlimeM, hp_rng);
                 let choice = misc_rng.gen_range(0..=2);
                 enemy_party[1] = Some(match choice {
                     0 => EnemyState::new(louse.0, louse.1, enemy_rng),
@@ -76,27 +124,96 @@ impl EnemyParty {
                 });
                 EnemyParty(enemy_party)
             }
-            Encounter::FourShapes => todo!("{:?}", encounter),
-            Encounter::GiantHead => todo!("{:?}", encounter),
-            Encounter::GremlinGang => todo!("{:?}", encounter),
-            Encounter::GremlinLeader => todo!("{:?}", encounter),
+            Encounter::FourShapes => Self::shapes(4, hp_rng, enemy_rng, misc_rng),
+            Encounter::GiantHead => enemy_party!(GiantHead),
+            Encounter::GremlinGang => {
+                let mut pool = GREMLINS.to_vec();
+                for slot in enemy_party.iter_mut().take(4) {
+                    let enemy = pool.remove(misc_rng.gen_range(0..pool.len()));
+                    *slot = Some(Self::spawn(enemy, hp_rng, enemy_rng));
+                }
+                EnemyParty(enemy_party)
+            }
+            Encounter::GremlinLeader => {
+                // Unlike a gang, the leader's gremlins are drawn with replacement. As in the game,
+                // they stand in slots 1 and 2, leaving slot 0 free for the gremlins it rallies.
+                for slot in enemy_party.iter_mut().skip(1).take(2) {
+                    let enemy = GREMLINS[misc_rng.gen_range(0..GREMLINS.len())];
+                    let mut enemy_state = Self::spawn(enemy, hp_rng, enemy_rng);
+                    enemy_state.conditions.push(EnemyCondition::Minion);
+                    *slot = Some(enemy_state);
+                }
+                enemy_party[3] = Some(Self::spawn(Enemy::GremlinLeader, hp_rng, enemy_rng));
+                EnemyParty(enemy_party)
+            }
             Encounter::GremlinNob => enemy_party!(GremlinNob),
-            Encounter::Hexaghost => todo!("{:?}", encounter),
+            Encounter::Hexaghost => enemy_party!(Hexaghost),
             Encounter::JawWorm => enemy_party!(JawWorm),
-            Encounter::JawWormHorde => todo!("{:?}", encounter),
-            Encounter::Lagavulin => todo!("{:?}", encounter),
-            Encounter::LargeSlime => todo!("{:?}", encounter),
-            Encounter::Looter => todo!("{:?}", encounter),
-            Encounter::LotsOfSlimes => todo!("{:?}", encounter),
-            Encounter::Maw => todo!("{:?}", encounter),
-            Encounter::Nemesis => todo!("{:?}", encounter),
-            Encounter::OrbWalker => todo!("{:?}", encounter),
-            Encounter::RedSlaver => todo!("{:?}", encounter),
-            Encounter::Reptomancer => todo!("{:?}", encounter),
-            Encounter::SentryAndSphericGuardian => todo!("{:?}", encounter),
-            Encounter::ShelledParasite => todo!("{:?}", encounter),
-            Encounter::ShelledParasiteAndFungiBeast => todo!("{:?}", encounter),
-            Encounter::SlimeBoss => todo!("{:?}", encounter),
+            Encounter::JawWormHorde => {
+                for slot in enemy_party.iter_mut().take(3) {
+                    let characteristics = gen_hard_mode_characteristics(Enemy::JawWorm, hp_rng);
+                    *slot = Some(EnemyState::new(Enemy::JawWorm, characteristics, enemy_rng));
+                }
+                EnemyParty(enemy_party)
+            }
+            Encounter::Lagavulin => enemy_party!(Lagavulin),
+            Encounter::LagavulinEvent => {
+                // The Dead Adventurer's Lagavulin is already awake when the fight starts.
+                let characteristics = gen_awake_characteristics(Enemy::Lagavulin, hp_rng);
+                enemy_party[0] = Some(EnemyState::new(
+                    Enemy::Lagavulin,
+                    characteristics,
+                    enemy_rng,
+                ));
+                EnemyParty(enemy_party)
+            }
+            Encounter::LargeSlime => {
+                if misc_rng.next_bool() {
+                    enemy_party!(AcidSlimeL)
+                } else {
+                    enemy_party!(SpikeSlimeL)
+                }
+            }
+            Encounter::Looter => enemy_party!(Looter),
+            Encounter::LotsOfSlimes => {
+                let mut pool = vec![
+                    Enemy::SpikeSlimeS,
+                    Enemy::SpikeSlimeS,
+                    Enemy::SpikeSlimeS,
+                    Enemy::AcidSlimeS,
+                    Enemy::AcidSlimeS,
+                ];
+                // The game rolls for the last slime too, even though there is only one left.
+                for slot in enemy_party.iter_mut() {
+                    let enemy = pool.remove(misc_rng.gen_range(0..pool.len()));
+                    *slot = Some(Self::spawn(enemy, hp_rng, enemy_rng));
+                }
+                EnemyParty(enemy_party)
+            }
+            Encounter::MaskedBandits => enemy_party!(Pointy, Romeo, Bear),
+            Encounter::Maw => enemy_party!(TheMaw),
+            Encounter::Nemesis => enemy_party!(Nemesis),
+            Encounter::OrbWalker => enemy_party!(OrbWalker),
+            Encounter::RedSlaver => enemy_party!(RedSlaver),
+            Encounter::Reptomancer => {
+                // As in the game, slots 0 and 3 are left free for the Daggers it summons.
+                for (enemy_index, enemy) in [
+                    (1, Enemy::Dagger),
+                    (2, Enemy::Reptomancer),
+                    (4, Enemy::Dagger),
+                ] {
+                    let mut enemy_state = Self::spawn(enemy, hp_rng, enemy_rng);
+                    if enemy == Enemy::Dagger {
+                        enemy_state.conditions.push(EnemyCondition::Minion);
+                    }
+                    enemy_party[enemy_index] = Some(enemy_state);
+                }
+                EnemyParty(enemy_party)
+            }
+            Encounter::SentryAndSphericGuardian => enemy_party!(Sentry, SphericGuardian),
+            Encounter::ShelledParasite => enemy_party!(ShelledParasite),
+            Encounter::ShelledParasiteAndFungiBeast => enemy_party!(ShelledParasite, FungiBeast),
+            Encounter::SlimeBoss => enemy_party!(SlimeBoss),
             Encounter::SmallSlimes => {
                 if misc_rng.next_bool() {
                     enemy_party!(SpikeSlimeS, AcidSlimeM)
@@ -104,28 +221,1506 @@ impl EnemyParty {
                     enemy_party!(AcidSlimeS, SpikeSlimeM)
                 }
             }
-            Encounter::SnakePlant => todo!("{:?}", encounter),
-            Encounter::Snecko => todo!("{:?}", encounter),
-            Encounter::SphericGuardian => todo!("{:?}", encounter),
-            Encounter::SphericGuardianAndTwoShapes => todo!("{:?}", encounter),
-            Encounter::SpireGrowth => todo!("{:?}", encounter),
-            Encounter::SpireShieldAndSpireSpear => todo!("{:?}", encounter),
-            Encounter::Taskmaster => todo!("{:?}", encounter),
-            Encounter::TheChamp => todo!("{:?}", encounter),
-            Encounter::TheCollector => todo!("{:?}", encounter),
-            Encounter::TheGuardian => todo!("{:?}", encounter),
-            Encounter::ThreeByrds => todo!("{:?}", encounter),
-            Encounter::ThreeCultists => todo!("{:?}", encounter),
-            Encounter::ThreeDarklings => todo!("{:?}", encounter),
-            Encounter::ThreeLouses => todo!("{:?}", encounter),
-            Encounter::ThreeSentries => todo!("{:?}", encounter),
-            Encounter::ThreeShapes => todo!("{:?}", encounter),
-            Encounter::TimeEater => todo!("{:?}", encounter),
-            Encounter::Transient => todo!("{:?}", encounter),
-            Encounter::TwoFungiBeasts => todo!("{:?}", encounter),
-            Encounter::TwoLouses => todo!("{:?}", encounter),
-            Encounter::TwoThieves => todo!("{:?}", encounter),
-            Encounter::WrithingMass => todo!("{:?}", encounter),
+            Encounter::SnakePlant => enemy_party!(SnakePlant),
+            Encounter::Snecko => enemy_party!(Snecko),
+            Encounter::SphericGuardian => enemy_party!(SphericGuardian),
+            Encounter::SphericGuardianAndTwoShapes => {
+                for slot in enemy_party.iter_mut().take(2) {
+                    let shape = match misc_rng.gen_range(0..=2) {
+                        0 => Enemy::Spiker,
+                        1 => Enemy::Repulsor,
+                        _ => Enemy::Exploder,
+                    };
+                    *slot = Some(Self::spawn(shape, hp_rng, enemy_rng));
+                }
+                enemy_party[2] = Some(Self::spawn(Enemy::SphericGuardian, hp_rng, enemy_rng));
+                EnemyParty(enemy_party)
+            }
+            Encounter::SpireGrowth => enemy_party!(SpireGrowth),
+            Encounter::SpireShieldAndSpireSpear => enemy_party!(SpireShield, SpireSpear),
+            Encounter::Taskmaster => enemy_party!(BlueSlaver, Taskmaster, RedSlaver),
+            Encounter::TheChamp => enemy_party!(TheChamp),
+            Encounter::TheCollector => {
+                // The Collector stands behind the slots its Torch Heads will fill.
+                enemy_party[2] = Some(Self::spawn(Enemy::TheCollector, hp_rng, enemy_rng));
+                EnemyParty(enemy_party)
+            }
+            Encounter::TheGuardian => enemy_party!(TheGuardian),
+            Encounter::TheMushroomLair => enemy_party!(FungiBeast, FungiBeast, FungiBeast),
+            Encounter::ThreeByrds => enemy_party!(Byrd, Byrd, Byrd),
+            Encounter::ThreeCultists => enemy_party!(Cultist, Cultist, Cultist),
+            Encounter::ThreeDarklings => enemy_party!(Darkling, Darkling, Darkling),
+            Encounter::ThreeLouses => {
+                for slot in enemy_party.iter_mut().take(3) {
+                    let louse = Self::random_louse(misc_rng);
+                    *slot = Some(Self::spawn(louse, hp_rng, enemy_rng));
+                }
+                EnemyParty(enemy_party)
+            }
+            Encounter::ThreeSentries => {
+                let mut party = enemy_party!(Sentry, Sentry, Sentry);
+                // The game picks each Sentry's opening move by its position, so the middle one
+                // fires its Beam while its neighbours Bolt.
+                if let Some(sentry) = party.0[1].as_mut() {
+                    sentry.next_action = EnemyAction::SentryBeam;
+                }
+                party
+            }
+            Encounter::ThreeShapes => Self::shapes(3, hp_rng, enemy_rng, misc_rng),
+            Encounter::TimeEater => enemy_party!(TimeEater),
+            Encounter::Transient => enemy_party!(Transient),
+            Encounter::TwoFungiBeasts => enemy_party!(FungiBeast, FungiBeast),
+            Encounter::TwoLouses => {
+                for slot in enemy_party.iter_mut().take(2) {
+                    let louse = Self::random_louse(misc_rng);
+                    *slot = Some(Self::spawn(louse, hp_rng, enemy_rng));
+                }
+                EnemyParty(enemy_party)
+            }
+            Encounter::TwoOrbWalkers => enemy_party!(OrbWalker, OrbWalker),
+            Encounter::TwoThieves => enemy_party!(Looter, Mugger),
+            Encounter::WrithingMass => enemy_party!(WrithingMass),
+        };
+        for (enemy_index, enemy_state) in party
+            .0
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(enemy_index, maybe_enemy)| Some((enemy_index, maybe_enemy.as_mut()?)))
+        {
+            enemy_state.characteristics.on_placed(enemy_index);
+            let conditions = enemy_state.characteristics.on_pre_battle(hp_rng);
+            enemy_state.conditions.extend(conditions);
+        }
+        party
+    }
+
+    /// Replaces the enemy at the specified index with two smaller ones, each taking its current
+    /// HP as their max HP. They fill the lowest free slots and sit out the rest of the turn.
+    /// Returns the slots the new enemies were placed in.
+    pub fn split(
+        &mut self,
+        enemy_index: EnemyIndex,
+        first: Enemy,
+        second: Enemy,
+        enemy_rng: &mut StsRandom,
+    ) -> Vec<EnemyIndex> {
+        let Some(parent) = self.despawn(enemy_index) else {
+            return vec![];
+        };
+        [first, second]
+            .into_iter()
+            .filter_map(|enemy| {
+                let characteristics = gen_split_characteristics(enemy, parent.hp);
+                let mut enemy_state = EnemyState::new(enemy, characteristics, enemy_rng);
+                enemy_state.spawned_this_turn = true;
+                self.place(enemy_state)
+            })
+            .collect()
+    }
+
+    /// Brings a minion of the specified type into the lowest free slot, if there is one. Like
+    /// split-off slimes, it sits out the rest of the turn. Returns the slot it was placed in.
+    pub fn summon(
+        &mut self,
+        enemy: Enemy,
+        hp_rng: &mut StsRandom,
+        enemy_rng: &mut StsRandom,
+    ) -> Option<EnemyIndex> {
+        if self.0.iter().all(|slot| slot.is_some()) {
+            return None;
+        }
+        let mut enemy_state = Self::spawn(enemy, hp_rng, enemy_rng);
+        enemy_state.spawned_this_turn = true;
+        let enemy_index = self.place(enemy_state)?;
+        let enemy_state = self.0[enemy_index].as_mut()?;
+        let conditions = enemy_state.characteristics.on_pre_battle(hp_rng);
+        enemy_state.conditions.extend(conditions);
+        enemy_state.conditions.push(EnemyCondition::Minion);
+        Some(enemy_index)
+    }
+
+    /// Takes the enemy at the specified index out of the fight, leaving its slot free. The
+    /// other enemies keep their indices.
+    pub fn despawn(&mut self, enemy_index: EnemyIndex) -> Option<EnemyState> {
+        self.0[enemy_index].take()
+    }
+
+    /// Summons a random gremlin, as the Gremlin Leader does when it rallies. Returns the slot it
+    /// was placed in.
+    pub fn summon_gremlin(
+        &mut self,
+        hp_rng: &mut StsRandom,
+        enemy_rng: &mut StsRandom,
+    ) -> Option<EnemyIndex> {
+        let enemy = GREMLINS[enemy_rng.gen_range(0..GREMLINS.len())];
+        self.summon(enemy, hp_rng, enemy_rng)
+    }
+
+    /// Puts a newly spawned enemy into the lowest free slot, letting it know where it stands.
+    /// Returns that slot, or None if the party is already full.
+    fn place(&mut self, mut enemy_state: EnemyState) -> Option<EnemyIndex> {
+        let enemy_index = self.0.iter().position(|slot| slot.is_none())?;
+        enemy_state.characteristics.on_placed(enemy_index);
+        self.0[enemy_index] = Some(enemy_state);
+        Some(enemy_index)
+    }
+
+    /// Rolls the characteristics for the specified enemy and brings it into the fight.
+    fn spawn(enemy: Enemy, hp_rng: &mut StsRandom, enemy_rng: &mut StsRandom) -> EnemyState {
+        let characteristics = gen_characteristics(enemy, hp_rng);
+        EnemyState::new(enemy, characteristics, enemy_rng)
+    }
+
+    /// Draws the specified number of shapes, without replacement, from a pool of two each of
+    /// Repulsors, Exploders and Spikers.
+    fn shapes(
+        count: usize,
+        hp_rng: &mut StsRandom,
+        enemy_rng: &mut StsRandom,
+        misc_rng: &mut StsRandom,
+    ) -> EnemyParty {
+        let mut pool = vec![
+            Enemy::Repulsor,
+            Enemy::Repulsor,
+            Enemy::Exploder,
+            Enemy::Exploder,
+            Enemy::Spiker,
+            Enemy::Spiker,
+        ];
+        let mut enemy_party: [Option<EnemyState>; 5] = [None, None, None, None, None];
+        for slot in enemy_party.iter_mut().take(count) {
+            let enemy = pool.remove(misc_rng.gen_range(0..pool.len()));
+            *slot = Some(Self::spawn(enemy, hp_rng, enemy_rng));
+        }
+        EnemyParty(enemy_party)
+    }
+
+    /// Picks a Red or Green Louse with equal probability.
+    fn random_louse(misc_rng: &mut StsRandom) -> Enemy {
+        if misc_rng.next_bool() {
+            Enemy::RedLouse
+        } else {
+            Enemy::GreenLouse
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use crate::components::{DamageTaken, EffectQueue, PlayerCombatState, PlayerPersistentState};
+    use crate::data::{EnemyCondition, Intent, PlayerCondition, Relic, IRONCLAD};
+    use crate::systems::base::enemy_characteristics::Battlefield;
+    use crate::systems::rng::Seed;
+    use crate::types::HpMax;
+
+    use super::*;
+
+    const BATTLEFIELD: Battlefield = Battlefield {
+        player_hp: 80,
+        player_constricted: false,
+        enemy_count: 1,
+        enemy_hp_missing: 0,
+    };
+
+    fn generate(
+        seed: u64,
+        encounter: Encounter,
+    ) -> Vec<(Enemy, HpMax, EnemyAction, Vec<EnemyCondition>)> {
+        let mut enemy_rng = StsRandom::from(Seed::from(seed));
+        let mut misc_rng = StsRandom::from(Seed::from(seed + 1));
+        EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(seed)),
+            encounter,
+            &mut enemy_rng,
+            &mut misc_rng,
+        )
+        .0
+        .iter()
+        .filter_map(|maybe_enemy| maybe_enemy.as_ref())
+        .map(|enemy| {
+            (
+                enemy.enemy,
+                enemy.hp_max,
+                enemy.next_action,
+                enemy.conditions.clone(),
+            )
+        })
+        .collect()
+    }
+
+    /// Generates the party for the encounter, returning which enemy stands in each slot.
+    fn slots(seed: u64, encounter: Encounter) -> Vec<Option<Enemy>> {
+        EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(seed)),
+            encounter,
+            &mut StsRandom::from(Seed::from(seed)),
+            &mut StsRandom::from(Seed::from(seed + 1)),
+        )
+        .0
+        .iter()
+        .map(|maybe_enemy| maybe_enemy.as_ref().map(|enemy| enemy.enemy))
+        .collect()
+    }
+
+    #[test]
+    fn test_blue_slaver() {
+        assert_eq!(
+            generate(3, Encounter::BlueSlaver),
+            [(Enemy::BlueSlaver, 48, EnemyAction::BlueSlaverStab, vec![])]
+        );
+    }
+
+    #[test]
+    fn test_exordium_thugs() {
+        assert_eq!(
+            generate(3, Encounter::ExordiumThugs),
+            [
+                (Enemy::SpikeSlimeM, 31, EnemyAction::SpikeSlimeMLick, vec![]),
+                (Enemy::RedSlaver, 49, EnemyAction::RedSlaverStab, vec![])
+            ]
+        );
+        assert_eq!(
+            generate(7, Encounter::ExordiumThugs),
+            [
+                (Enemy::SpikeSlimeM, 28, EnemyAction::SpikeSlimeMLick, vec![]),
+                (Enemy::Cultist, 51, EnemyAction::CultistIncantation, vec![])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gremlin_gang() {
+        assert_eq!(
+            generate(7, Encounter::GremlinGang),
+            [
+                (Enemy::FatGremlin, 13, EnemyAction::FatGremlinSmash, vec![]),
+                (
+                    Enemy::MadGremlin,
+                    23,
+                    EnemyAction::MadGremlinScratch,
+                    vec![EnemyCondition::Angry(1)]
+                ),
+                (
+                    Enemy::GremlinWizard,
+                    21,
+                    EnemyAction::GremlinWizardCharging,
+                    vec![]
+                ),
+                (
+                    Enemy::SneakyGremlin,
+                    14,
+                    EnemyAction::SneakyGremlinPuncture,
+                    vec![]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_large_slime() {
+        assert_eq!(
+            generate(3, Encounter::LargeSlime),
+            [(
+                Enemy::SpikeSlimeL,
+                67,
+                EnemyAction::SpikeSlimeLLick,
+                vec![EnemyCondition::Split]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_looter() {
+        assert_eq!(
+            generate(3, Encounter::Looter),
+            [(
+                Enemy::Looter,
+                46,
+                EnemyAction::LooterMug,
+                vec![EnemyCondition::Thievery(15, 0)]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_intent_damage_reflects_player_state() {
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::Looter,
+            &mut StsRandom::from(Seed::from(3)),
+            &mut StsRandom::from(Seed::from(4)),
+        );
+        let looter = party.0[0].as_mut().unwrap();
+        let mut pps = PlayerPersistentState::new(IRONCLAD);
+        pps.relics = vec![];
+        let mut pcs = PlayerCombatState::new(&mut pps);
+        assert_eq!(looter.intent_against(&pcs), Intent::Aggressive(10, 1));
+
+        looter.strength = 3;
+        pcs.conditions.push(PlayerCondition::Vulnerable(1));
+        assert_eq!(looter.intent_against(&pcs), Intent::Aggressive(19, 1));
+
+        looter.conditions.push(EnemyCondition::Weak(1));
+        pcs.pps.relics.push(Relic::PaperKrane);
+        assert_eq!(looter.intent_against(&pcs), Intent::Aggressive(10, 1));
+
+        pcs.conditions.push(PlayerCondition::Intangible(1));
+        assert_eq!(looter.intent_against(&pcs), Intent::Aggressive(1, 1));
+    }
+
+    #[test]
+    fn test_looter_signals_its_escape() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::Looter,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let looter = party.0[0].as_mut().unwrap();
+        while looter.next_action != EnemyAction::LooterEscape {
+            looter.advance_action(&mut enemy_rng, &BATTLEFIELD);
+        }
+        assert_eq!(looter.next_action.intent(), Intent::Cowardly);
+    }
+
+    #[test]
+    fn test_lots_of_slimes() {
+        assert_eq!(
+            generate(3, Encounter::LotsOfSlimes),
+            [
+                (Enemy::AcidSlimeS, 10, EnemyAction::AcidSlimeSLick, vec![]),
+                (
+                    Enemy::SpikeSlimeS,
+                    14,
+                    EnemyAction::SpikeSlimeSTackle,
+                    vec![]
+                ),
+                (
+                    Enemy::SpikeSlimeS,
+                    13,
+                    EnemyAction::SpikeSlimeSTackle,
+                    vec![]
+                ),
+                (
+                    Enemy::SpikeSlimeS,
+                    12,
+                    EnemyAction::SpikeSlimeSTackle,
+                    vec![]
+                ),
+                (Enemy::AcidSlimeS, 11, EnemyAction::AcidSlimeSLick, vec![])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lots_of_slimes_rolls_for_every_slime() {
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::LotsOfSlimes,
+            &mut StsRandom::from(Seed::from(3)),
+            &mut misc_rng,
+        );
+        assert_eq!(misc_rng.get_counter(), 5);
+    }
+
+    #[test]
+    fn test_red_slaver() {
+        assert_eq!(
+            generate(3, Encounter::RedSlaver),
+            [(Enemy::RedSlaver, 48, EnemyAction::RedSlaverStab, vec![])]
+        );
+    }
+
+    #[test]
+    fn test_three_louses() {
+        assert_eq!(
+            generate(3, Encounter::ThreeLouses),
+            [
+                (
+                    Enemy::GreenLouse,
+                    14,
+                    EnemyAction::GreenLouseBite(7),
+                    vec![EnemyCondition::CurlUp(5)]
+                ),
+                (
+                    Enemy::GreenLouse,
+                    14,
+                    EnemyAction::GreenLouseBite(7),
+                    vec![EnemyCondition::CurlUp(6)]
+                ),
+                (
+                    Enemy::RedLouse,
+                    12,
+                    EnemyAction::RedLouseBite(7),
+                    vec![EnemyCondition::CurlUp(5)]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_two_fungi_beasts() {
+        assert_eq!(
+            generate(3, Encounter::TwoFungiBeasts),
+            [
+                (
+                    Enemy::FungiBeast,
+                    25,
+                    EnemyAction::FungiBeastGrow,
+                    vec![EnemyCondition::SporeCloud(2)]
+                ),
+                (
+                    Enemy::FungiBeast,
+                    28,
+                    EnemyAction::FungiBeastGrow,
+                    vec![EnemyCondition::SporeCloud(2)]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_two_louses() {
+        assert_eq!(
+            generate(7, Encounter::TwoLouses),
+            [
+                (
+                    Enemy::GreenLouse,
+                    14,
+                    EnemyAction::GreenLouseBite(7),
+                    vec![EnemyCondition::CurlUp(5)]
+                ),
+                (
+                    Enemy::GreenLouse,
+                    15,
+                    EnemyAction::GreenLouseBite(5),
+                    vec![EnemyCondition::CurlUp(5)]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lagavulin() {
+        assert_eq!(
+            generate(3, Encounter::Lagavulin),
+            [(
+                Enemy::Lagavulin,
+                109,
+                EnemyAction::LagavulinSleep,
+                vec![EnemyCondition::Metallicize(8)]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lagavulin_event() {
+        assert_eq!(
+            generate(3, Encounter::LagavulinEvent),
+            [(Enemy::Lagavulin, 109, EnemyAction::LagavulinAttack, vec![])]
+        );
+    }
+
+    #[test]
+    fn test_lagavulin_wakes_up() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::Lagavulin,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let lagavulin = party.0[0].as_mut().unwrap();
+        assert_eq!(lagavulin.block, 8);
+        let mut actions = vec![];
+        for _ in 0..6 {
+            lagavulin.advance_action(&mut enemy_rng, &BATTLEFIELD);
+            actions.push(lagavulin.next_action);
+        }
+        assert_eq!(
+            actions,
+            [
+                EnemyAction::LagavulinSleep,
+                EnemyAction::LagavulinSleep,
+                EnemyAction::LagavulinAttack,
+                EnemyAction::LagavulinAttack,
+                EnemyAction::LagavulinSiphonSoul,
+                EnemyAction::LagavulinAttack
+            ]
+        );
+        assert_eq!(lagavulin.conditions, []);
+    }
+
+    #[test]
+    fn test_lagavulin_stunned_when_hurt() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::Lagavulin,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let lagavulin = party.0[0].as_mut().unwrap();
+        lagavulin.on_hp_lost(1);
+        assert_eq!(lagavulin.next_action, EnemyAction::LagavulinStunned);
+        assert_eq!(lagavulin.conditions, []);
+        lagavulin.advance_action(&mut enemy_rng, &BATTLEFIELD);
+        assert_eq!(lagavulin.next_action, EnemyAction::LagavulinAttack);
+        lagavulin.on_hp_lost(1);
+        assert_eq!(lagavulin.next_action, EnemyAction::LagavulinAttack);
+    }
+
+    #[test]
+    fn test_three_sentries() {
+        assert_eq!(
+            generate(3, Encounter::ThreeSentries),
+            [
+                (
+                    Enemy::Sentry,
+                    40,
+                    EnemyAction::SentryBolt,
+                    vec![EnemyCondition::Artifact(1)]
+                ),
+                (
+                    Enemy::Sentry,
+                    42,
+                    EnemyAction::SentryBeam,
+                    vec![EnemyCondition::Artifact(1)]
+                ),
+                (
+                    Enemy::Sentry,
+                    41,
+                    EnemyAction::SentryBolt,
+                    vec![EnemyCondition::Artifact(1)]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hexaghost() {
+        assert_eq!(
+            generate(3, Encounter::Hexaghost),
+            [(
+                Enemy::Hexaghost,
+                250,
+                EnemyAction::HexaghostActivate,
+                vec![]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_hexaghost_cycle() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::Hexaghost,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let hexaghost = party.0[0].as_mut().unwrap();
+        let mut actions = vec![];
+        for _ in 0..10 {
+            hexaghost.advance_action(&mut enemy_rng, &BATTLEFIELD);
+            actions.push(hexaghost.next_action);
+        }
+        assert_eq!(
+            actions,
+            [
+                EnemyAction::HexaghostDivider(7),
+                EnemyAction::HexaghostSear,
+                EnemyAction::HexaghostTackle,
+                EnemyAction::HexaghostSear,
+                EnemyAction::HexaghostInflame,
+                EnemyAction::HexaghostTackle,
+                EnemyAction::HexaghostSear,
+                EnemyAction::HexaghostInferno,
+                EnemyAction::HexaghostSearPlus,
+                EnemyAction::HexaghostTackle
+            ]
+        );
+    }
+
+    #[test]
+    fn test_slime_boss() {
+        assert_eq!(
+            generate(3, Encounter::SlimeBoss),
+            [(
+                Enemy::SlimeBoss,
+                140,
+                EnemyAction::SlimeBossGoopSpray,
+                vec![EnemyCondition::Split]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_slime_boss_splits() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::SlimeBoss,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let slime_boss = party.0[0].as_mut().unwrap();
+        slime_boss.hp = 71;
+        slime_boss.on_hp_lost(69);
+        assert_eq!(slime_boss.next_action, EnemyAction::SlimeBossGoopSpray);
+        slime_boss.hp = 70;
+        slime_boss.on_hp_lost(1);
+        assert_eq!(slime_boss.next_action, EnemyAction::SlimeBossSplit);
+        assert_eq!(
+            party.split(0, Enemy::SpikeSlimeL, Enemy::AcidSlimeL, &mut enemy_rng),
+            [0, 1]
+        );
+        let children = party
+            .0
+            .iter()
+            .flatten()
+            .map(|enemy| (enemy.enemy, enemy.hp, enemy.spawned_this_turn))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            children,
+            [
+                (Enemy::SpikeSlimeL, 70, true),
+                (Enemy::AcidSlimeL, 70, true)
+            ]
+        );
+        let acid_slime = party.0[1].as_mut().unwrap();
+        acid_slime.hp = 35;
+        acid_slime.on_hp_lost(35);
+        assert_eq!(acid_slime.next_action, EnemyAction::AcidSlimeLSplit);
+    }
+
+    #[test]
+    fn test_the_guardian() {
+        assert_eq!(
+            generate(3, Encounter::TheGuardian),
+            [(
+                Enemy::TheGuardian,
+                240,
+                EnemyAction::TheGuardianChargingUp,
+                vec![EnemyCondition::ModeShift(30)]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_the_guardian_mode_shift() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::TheGuardian,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let guardian = party.0[0].as_mut().unwrap();
+        guardian.on_hp_lost(25);
+        assert_eq!(guardian.conditions, [EnemyCondition::ModeShift(5)]);
+        assert_eq!(guardian.next_action, EnemyAction::TheGuardianChargingUp);
+        guardian.on_hp_lost(10);
+        assert_eq!(guardian.conditions, []);
+        assert_eq!(guardian.block, 20);
+        assert_eq!(guardian.next_action, EnemyAction::TheGuardianDefensiveMode);
+        guardian.on_hp_lost(50);
+        assert_eq!(guardian.next_action, EnemyAction::TheGuardianDefensiveMode);
+        guardian.conditions.push(EnemyCondition::SharpHide(3));
+        let mut actions = vec![];
+        for _ in 0..3 {
+            guardian.advance_action(&mut enemy_rng, &BATTLEFIELD);
+            actions.push(guardian.next_action);
+        }
+        assert_eq!(
+            actions,
+            [
+                EnemyAction::TheGuardianRollAttack,
+                EnemyAction::TheGuardianTwinSlam,
+                EnemyAction::TheGuardianWhirlwind
+            ]
+        );
+        assert_eq!(guardian.conditions, [EnemyCondition::ModeShift(40)]);
+    }
+
+    #[test]
+    fn test_byrd_grounded() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::ThreeByrds,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let byrd = party.0[0].as_mut().unwrap();
+        byrd.on_hp_lost(5);
+        assert_eq!(byrd.next_action, EnemyAction::ByrdCaw);
+        byrd.conditions.clear();
+        byrd.on_hp_lost(5);
+        assert_eq!(byrd.next_action, EnemyAction::ByrdStunned);
+        let mut actions = vec![];
+        for _ in 0..2 {
+            byrd.advance_action(&mut enemy_rng, &BATTLEFIELD);
+            actions.push(byrd.next_action);
+        }
+        assert_eq!(actions, [EnemyAction::ByrdHeadbutt, EnemyAction::ByrdFlyUp]);
+    }
+
+    #[test]
+    fn test_centurion_and_mystic() {
+        assert_eq!(
+            generate(3, Encounter::CenturionAndMystic),
+            [
+                (Enemy::Centurion, 78, EnemyAction::CenturionDefend, vec![]),
+                (Enemy::Mystic, 53, EnemyAction::MysticAttack, vec![])
+            ]
+        );
+        assert_eq!(
+            generate(7, Encounter::CenturionAndMystic),
+            [
+                (Enemy::Centurion, 76, EnemyAction::CenturionSlash, vec![]),
+                (Enemy::Mystic, 50, EnemyAction::MysticBuff, vec![])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chosen_and_byrd() {
+        assert_eq!(
+            generate(3, Encounter::ChosenAndByrd),
+            [
+                (
+                    Enemy::Byrd,
+                    28,
+                    EnemyAction::ByrdCaw,
+                    vec![EnemyCondition::Flight(3, 3)]
+                ),
+                (Enemy::Chosen, 99, EnemyAction::ChosenPoke, vec![])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chosen_hexes_on_its_second_turn() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::Chosen,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let chosen = party.0[0].as_mut().unwrap();
+        chosen.advance_action(&mut enemy_rng, &BATTLEFIELD);
+        assert_eq!(chosen.next_action, EnemyAction::ChosenHex);
+        chosen.advance_action(&mut enemy_rng, &BATTLEFIELD);
+        assert!(matches!(
+            chosen.next_action,
+            EnemyAction::ChosenDebilitate | EnemyAction::ChosenDrain
+        ));
+    }
+
+    #[test]
+    fn test_reptomancer_slots() {
+        assert_eq!(
+            slots(3, Encounter::Reptomancer),
+            [
+                None,
+                Some(Enemy::Dagger),
+                Some(Enemy::Reptomancer),
+                None,
+                Some(Enemy::Dagger)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sentry_and_spheric_guardian() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::SentryAndSphericGuardian,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let spheric_guardian = party.0[1].as_ref().unwrap();
+        assert_eq!(spheric_guardian.block, 40);
+        assert_eq!(
+            generate(3, Encounter::SentryAndSphericGuardian),
+            [
+                (
+                    Enemy::Sentry,
+                    40,
+                    EnemyAction::SentryBolt,
+                    vec![EnemyCondition::Artifact(1)]
+                ),
+                (
+                    Enemy::SphericGuardian,
+                    20,
+                    EnemyAction::SphericGuardianActivate,
+                    vec![EnemyCondition::Barricade, EnemyCondition::Artifact(3)]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shelled_parasite_and_fungi_beast() {
+        assert_eq!(
+            generate(7, Encounter::ShelledParasiteAndFungiBeast),
+            [
+                (
+                    Enemy::ShelledParasite,
+                    68,
+                    EnemyAction::ShelledParasiteDoubleStrike,
+                    vec![EnemyCondition::PlatedArmor(14)]
+                ),
+                (
+                    Enemy::FungiBeast,
+                    25,
+                    EnemyAction::FungiBeastGrow,
+                    vec![EnemyCondition::SporeCloud(2)]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shelled_parasite_stunned_without_plated_armor() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::ShelledParasite,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let parasite = party.0[0].as_mut().unwrap();
+        assert_eq!(parasite.block, 14);
+        parasite.conditions.clear();
+        parasite.on_hp_lost(1);
+        assert_eq!(parasite.next_action, EnemyAction::ShelledParasiteStunned);
+        parasite.advance_action(&mut enemy_rng, &BATTLEFIELD);
+        assert_eq!(parasite.next_action, EnemyAction::ShelledParasiteFell);
+    }
+
+    #[test]
+    fn test_snake_plant() {
+        assert_eq!(
+            generate(3, Encounter::SnakePlant),
+            [(
+                Enemy::SnakePlant,
+                77,
+                EnemyAction::SnakePlantEnfeeblingSpores,
+                vec![EnemyCondition::Malleable(3, 3)]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_snecko() {
+        assert_eq!(
+            generate(3, Encounter::Snecko),
+            [(
+                Enemy::Snecko,
+                117,
+                EnemyAction::SneckoPerplexingGlare,
+                vec![]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_three_byrds() {
+        assert_eq!(
+            generate(7, Encounter::ThreeByrds),
+            [
+                (
+                    Enemy::Byrd,
+                    28,
+                    EnemyAction::ByrdPeck,
+                    vec![EnemyCondition::Flight(3, 3)]
+                ),
+                (
+                    Enemy::Byrd,
+                    28,
+                    EnemyAction::ByrdPeck,
+                    vec![EnemyCondition::Flight(3, 3)]
+                ),
+                (
+                    Enemy::Byrd,
+                    29,
+                    EnemyAction::ByrdCaw,
+                    vec![EnemyCondition::Flight(3, 3)]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_two_thieves() {
+        assert_eq!(
+            generate(3, Encounter::TwoThieves),
+            [
+                (
+                    Enemy::Looter,
+                    46,
+                    EnemyAction::LooterMug,
+                    vec![EnemyCondition::Thievery(15, 0)]
+                ),
+                (
+                    Enemy::Mugger,
+                    52,
+                    EnemyAction::MuggerMug,
+                    vec![EnemyCondition::Thievery(15, 0)]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_masked_bandits() {
+        assert_eq!(
+            generate(3, Encounter::MaskedBandits),
+            [
+                (Enemy::Pointy, 30, EnemyAction::PointyAttack, vec![]),
+                (Enemy::Romeo, 37, EnemyAction::RomeoMock, vec![]),
+                (Enemy::Bear, 42, EnemyAction::BearBearHug, vec![])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_book_of_stabbing() {
+        assert_eq!(
+            generate(3, Encounter::BookOfStabbing),
+            [(
+                Enemy::BookOfStabbing,
+                162,
+                EnemyAction::BookOfStabbingMultiStab(2),
+                vec![EnemyCondition::PainfulStabs]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_book_of_stabbing_stabs_more_each_time() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::BookOfStabbing,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let book = party.0[0].as_mut().unwrap();
+        let mut stab_count = 2;
+        let mut multi_stabs_in_a_row = 1;
+        for _ in 0..30 {
+            book.advance_action(&mut enemy_rng, &BATTLEFIELD);
+            if let EnemyAction::BookOfStabbingMultiStab(count) = book.next_action {
+                stab_count += 1;
+                multi_stabs_in_a_row += 1;
+                assert_eq!(count, stab_count);
+                assert!(multi_stabs_in_a_row <= 2);
+            } else {
+                multi_stabs_in_a_row = 0;
+            }
+        }
+    }
+
+    #[test]
+    fn test_bronze_automaton_slots() {
+        assert_eq!(
+            slots(3, Encounter::BronzeAutomaton),
+            [None, Some(Enemy::BronzeAutomaton), None, None, None]
+        );
+    }
+
+    #[test]
+    fn test_bronze_automaton_cycle() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::BronzeAutomaton,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let automaton = party.0[1].as_mut().unwrap();
+        assert_eq!(automaton.next_action, EnemyAction::BronzeAutomatonSpawnOrbs);
+        let mut actions = vec![];
+        for _ in 0..11 {
+            automaton.advance_action(&mut enemy_rng, &BATTLEFIELD);
+            actions.push(automaton.next_action);
+        }
+        assert_eq!(
+            actions,
+            [
+                EnemyAction::BronzeAutomatonFlail,
+                EnemyAction::BronzeAutomatonBoost,
+                EnemyAction::BronzeAutomatonFlail,
+                EnemyAction::BronzeAutomatonBoost,
+                EnemyAction::BronzeAutomatonHyperBeam,
+                EnemyAction::BronzeAutomatonStunned,
+                EnemyAction::BronzeAutomatonFlail,
+                EnemyAction::BronzeAutomatonBoost,
+                EnemyAction::BronzeAutomatonFlail,
+                EnemyAction::BronzeAutomatonBoost,
+                EnemyAction::BronzeAutomatonHyperBeam,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mad_gremlin_grows_angry_when_hurt_by_attacks() {
+        let mut enemy_rng = StsRandom::from(Seed::from(7));
+        let mut misc_rng = StsRandom::from(Seed::from(8));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(7)),
+            Encounter::GremlinGang,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let mad_gremlin = party.0[1].as_mut().unwrap();
+        assert_eq!(mad_gremlin.enemy, Enemy::MadGremlin);
+        let mut effect_queue = EffectQueue::new();
+        for (hp_lost, provokes_thorns) in [(5, true), (0, true), (5, false), (3, true)] {
+            let damage_taken = DamageTaken {
+                blocked: 0,
+                hp_lost,
+                provokes_thorns,
+            };
+            mad_gremlin.conditions.retain_mut(|c| {
+                c.on_damage_taken(
+                    &mut mad_gremlin.block,
+                    &mut mad_gremlin.strength,
+                    &damage_taken,
+                    &mut effect_queue,
+                )
+            });
+        }
+        assert_eq!(mad_gremlin.strength, 2);
+    }
+
+    #[test]
+    fn test_gremlin_leader() {
+        assert_eq!(
+            generate(3, Encounter::GremlinLeader),
+            [
+                (
+                    Enemy::SneakyGremlin,
+                    12,
+                    EnemyAction::SneakyGremlinPuncture,
+                    vec![EnemyCondition::Minion]
+                ),
+                (
+                    Enemy::MadGremlin,
+                    24,
+                    EnemyAction::MadGremlinScratch,
+                    vec![EnemyCondition::Angry(1), EnemyCondition::Minion]
+                ),
+                (
+                    Enemy::GremlinLeader,
+                    144,
+                    EnemyAction::GremlinLeaderEncourage,
+                    vec![]
+                )
+            ]
+        );
+        assert_eq!(
+            generate(7, Encounter::GremlinLeader),
+            [
+                (
+                    Enemy::FatGremlin,
+                    13,
+                    EnemyAction::FatGremlinSmash,
+                    vec![EnemyCondition::Minion]
+                ),
+                (
+                    Enemy::FatGremlin,
+                    16,
+                    EnemyAction::FatGremlinSmash,
+                    vec![EnemyCondition::Minion]
+                ),
+                (
+                    Enemy::GremlinLeader,
+                    145,
+                    EnemyAction::GremlinLeaderStab,
+                    vec![]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gremlin_leader_slots() {
+        assert_eq!(
+            slots(3, Encounter::GremlinLeader),
+            [
+                None,
+                Some(Enemy::SneakyGremlin),
+                Some(Enemy::MadGremlin),
+                Some(Enemy::GremlinLeader),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gremlin_leader_rallies_when_alone() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::GremlinLeader,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let leader = party.0[3].as_mut().unwrap();
+        let mut actions = vec![];
+        for _ in 0..10 {
+            leader.advance_action(&mut enemy_rng, &BATTLEFIELD);
+            actions.push(leader.next_action);
+        }
+        assert!(actions.contains(&EnemyAction::GremlinLeaderRally));
+        assert!(!actions.contains(&EnemyAction::GremlinLeaderEncourage));
+        assert!(actions.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn test_summon() {
+        let mut hp_rng = StsRandom::from(Seed::from(3));
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut hp_rng,
+            Encounter::TheCollector,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        assert_eq!(
+            party.summon(Enemy::TorchHead, &mut hp_rng, &mut enemy_rng),
+            Some(0)
+        );
+        assert_eq!(
+            party.summon(Enemy::TorchHead, &mut hp_rng, &mut enemy_rng),
+            Some(1)
+        );
+        for slot in 0..=1 {
+            let torch_head = party.0[slot].as_ref().unwrap();
+            assert_eq!(torch_head.enemy, Enemy::TorchHead);
+            assert_eq!(torch_head.next_action, EnemyAction::TorchHeadTackle);
+            assert_eq!(torch_head.conditions, [EnemyCondition::Minion]);
+            assert!(torch_head.spawned_this_turn);
+        }
+        assert!(party.0[3].is_none());
+    }
+
+    #[test]
+    fn test_taskmaster() {
+        assert_eq!(
+            generate(3, Encounter::Taskmaster),
+            [
+                (Enemy::BlueSlaver, 48, EnemyAction::BlueSlaverStab, vec![]),
+                (
+                    Enemy::Taskmaster,
+                    60,
+                    EnemyAction::TaskmasterScouringWhip,
+                    vec![]
+                ),
+                (Enemy::RedSlaver, 49, EnemyAction::RedSlaverStab, vec![])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_the_champ() {
+        assert_eq!(
+            generate(3, Encounter::TheChamp),
+            [(
+                Enemy::TheChamp,
+                420,
+                EnemyAction::TheChampHeavySlash,
+                vec![]
+            )]
+        );
+        assert_eq!(
+            generate(7, Encounter::TheChamp),
+            [(Enemy::TheChamp, 420, EnemyAction::TheChampFaceSlap, vec![])]
+        );
+    }
+
+    #[test]
+    fn test_the_champ_taunts_then_executes() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::TheChamp,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let champ = party.0[0].as_mut().unwrap();
+        for _ in 0..3 {
+            champ.advance_action(&mut enemy_rng, &BATTLEFIELD);
+        }
+        assert_eq!(champ.next_action, EnemyAction::TheChampTaunt);
+        champ.hp = 209;
+        champ.on_hp_lost(211);
+        let mut actions = vec![];
+        for _ in 0..5 {
+            champ.advance_action(&mut enemy_rng, &BATTLEFIELD);
+            actions.push(champ.next_action);
+        }
+        assert_eq!(actions[0], EnemyAction::TheChampAnger);
+        assert_eq!(actions[1], EnemyAction::TheChampExecute);
+        assert_ne!(actions[2], EnemyAction::TheChampExecute);
+        assert_ne!(actions[3], EnemyAction::TheChampExecute);
+        assert_eq!(actions[4], EnemyAction::TheChampExecute);
+    }
+
+    #[test]
+    fn test_the_collector_slots() {
+        assert_eq!(
+            slots(3, Encounter::TheCollector),
+            [None, None, Some(Enemy::TheCollector), None, None]
+        );
+    }
+
+    #[test]
+    fn test_the_collector() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::TheCollector,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let collector = party.0[2].as_mut().unwrap();
+        assert_eq!(collector.hp_max, 282);
+        assert_eq!(collector.next_action, EnemyAction::TheCollectorSpawn);
+        let battlefield = Battlefield {
+            enemy_count: 3,
+            ..BATTLEFIELD
+        };
+        for _ in 0..3 {
+            collector.advance_action(&mut enemy_rng, &battlefield);
+            assert_ne!(collector.next_action, EnemyAction::TheCollectorRevive);
+        }
+        assert_eq!(collector.next_action, EnemyAction::TheCollectorMegaDebuff);
+    }
+
+    #[test]
+    fn test_three_shapes() {
+        assert_eq!(
+            generate(3, Encounter::ThreeShapes),
+            [
+                (
+                    Enemy::Exploder,
+                    30,
+                    EnemyAction::ExploderSlam,
+                    vec![EnemyCondition::Explosive(3)]
+                ),
+                (
+                    Enemy::Spiker,
+                    56,
+                    EnemyAction::SpikerSpike,
+                    vec![EnemyCondition::Thorns(3)]
+                ),
+                (
+                    Enemy::Spiker,
+                    55,
+                    EnemyAction::SpikerCut,
+                    vec![EnemyCondition::Thorns(3)]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jaw_worm_horde() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::JawWormHorde,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let worms = party.0.iter().flatten().collect::<Vec<_>>();
+        assert_eq!(worms.len(), 3);
+        for worm in worms.iter() {
+            assert_eq!((worm.strength, worm.block), (3, 6));
+        }
+        assert_eq!(
+            worms.iter().map(|w| w.next_action).collect::<Vec<_>>(),
+            [
+                EnemyAction::JawWormBellow,
+                EnemyAction::JawWormBellow,
+                EnemyAction::JawWormThrash
+            ]
+        );
+    }
+
+    #[test]
+    fn test_darklings_regrow_while_kin_stand() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::ThreeDarklings,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let darkling = party.0[0].as_mut().unwrap();
+        darkling.hp = 0;
+        assert!(darkling.lie_half_dead(true));
+        assert!(darkling.half_dead);
+        assert!(!darkling.is_standing());
+        assert_eq!(darkling.conditions, []);
+        assert_eq!(darkling.next_action, EnemyAction::DarklingRegrow);
+        darkling.advance_action(&mut enemy_rng, &BATTLEFIELD);
+        assert_eq!(darkling.next_action, EnemyAction::DarklingReincarnate);
+
+        let last_darkling = party.0[2].as_mut().unwrap();
+        last_darkling.hp = 0;
+        assert!(!last_darkling.lie_half_dead(false));
+    }
+
+    #[test]
+    fn test_awakened_one_rebirth() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::AwakenedOne,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let awakened_one = party.0[2].as_mut().unwrap();
+        awakened_one.conditions.push(EnemyCondition::Weak(2));
+        awakened_one.hp = 0;
+        assert!(awakened_one.lie_half_dead(false));
+        assert_eq!(awakened_one.next_action, EnemyAction::AwakenedOneRebirth);
+        assert_eq!(awakened_one.conditions, [EnemyCondition::Regenerate(10)]);
+        awakened_one.advance_action(&mut enemy_rng, &BATTLEFIELD);
+        assert_eq!(awakened_one.next_action, EnemyAction::AwakenedOneDarkEcho);
+        awakened_one.half_dead = false;
+        awakened_one.hp = 0;
+        assert!(!awakened_one.lie_half_dead(false));
+    }
+
+    #[test]
+    fn test_writhing_mass_rerolls_its_intent() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::WrithingMass,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let writhing_mass = party.0[0].as_mut().unwrap();
+        assert_eq!(writhing_mass.next_action, EnemyAction::WrithingMassWither);
+        for _ in 0..10 {
+            let previous_action = writhing_mass.next_action;
+            writhing_mass.reroll_action(&mut enemy_rng, &BATTLEFIELD);
+            assert_ne!(writhing_mass.next_action, previous_action);
+        }
+    }
+
+    #[test]
+    fn test_nemesis_turns_intangible_every_other_turn() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::Nemesis,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let nemesis = party.0[0].as_mut().unwrap();
+        let mut intangible = vec![];
+        for _ in 0..4 {
+            nemesis.advance_action(&mut enemy_rng, &BATTLEFIELD);
+            nemesis.conditions.retain_mut(|c| {
+                c.on_turn_finished(
+                    &mut nemesis.hp,
+                    nemesis.hp_max,
+                    &mut nemesis.strength,
+                    &mut nemesis.block,
+                )
+            });
+            intangible.push(!nemesis.conditions.is_empty());
+        }
+        assert_eq!(intangible, [true, false, true, false]);
+    }
+
+    #[test]
+    fn test_spire_shield_and_spire_spear() {
+        assert_eq!(
+            generate(3, Encounter::SpireShieldAndSpireSpear),
+            [
+                (
+                    Enemy::SpireShield,
+                    110,
+                    EnemyAction::SpireShieldBash,
+                    vec![EnemyCondition::Artifact(1), EnemyCondition::BackAttack]
+                ),
+                (
+                    Enemy::SpireSpear,
+                    160,
+                    EnemyAction::SpireSpearBurnStrike,
+                    vec![EnemyCondition::Artifact(1)]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_corrupt_heart_buffs_every_third_turn() {
+        let mut enemy_rng = StsRandom::from(Seed::from(3));
+        let mut misc_rng = StsRandom::from(Seed::from(4));
+        let mut party = EnemyParty::generate(
+            &mut StsRandom::from(Seed::from(3)),
+            Encounter::CorruptHeart,
+            &mut enemy_rng,
+            &mut misc_rng,
+        );
+        let heart = party.0[0].as_mut().unwrap();
+        let mut actions = vec![heart.next_action];
+        for _ in 0..9 {
+            heart.advance_action(&mut enemy_rng, &BATTLEFIELD);
+            actions.push(heart.next_action);
         }
+        assert_eq!(
+            actions,
+            [
+                EnemyAction::CorruptHeartDebilitate,
+                EnemyAction::CorruptHeartBloodShots,
+                EnemyAction::CorruptHeartEcho,
+                EnemyAction::CorruptHeartBuff(0),
+                EnemyAction::CorruptHeartEcho,
+                EnemyAction::CorruptHeartBloodShots,
+                EnemyAction::CorruptHeartBuff(1),
+                EnemyAction::CorruptHeartBloodShots,
+                EnemyAction::CorruptHeartEcho,
+                EnemyAction::CorruptHeartBuff(2),
+            ]
+        );
     }
 }
@@ -1,9 +1,13 @@
-use crate::components::{AttackerStatus, DefenderStatus, EnemyStatus};
-use crate::data::{Enemy, EnemyAction, EnemyCondition};
+use crate::components::{
+    AttackerStatus, CardCombatState, DamageTaken, DefenderStatus, EnemyStatus, PlayerCombatState,
+};
+use crate::data::{Damage, Enemy, EnemyAction, EnemyCondition, EnemyEffect, Intent, Stance};
+use crate::systems::combat::DamageCalculator;
 use crate::systems::rng::StsRandom;
-use crate::types::{Block, Dexterity, Hp, HpMax, Strength};
+use crate::types::{Block, Dexterity, Hp, HpMax, Mantra, StackCount, Strength};
 
-use super::enemy_characteristics::EnemyCharacteristics;
+use super::enemy_characteristics::{Battlefield, EnemyCharacteristics};
+use super::relic_system::RelicSystem;
 
 /// The `EnemyState` is the basic unit representing enemy combatants in the game.
 /// As per the ECS model, this is just a collection of components. We do provide some
@@ -18,6 +22,9 @@ pub struct EnemyState {
     pub conditions: Vec<EnemyCondition>,
     pub next_action: EnemyAction,
     pub run_length: usize,
+    pub spawned_this_turn: bool, // Enemies split off mid-turn wait until next turn to act.
+    pub half_dead: bool,         // Dead for now, but due to rise again (e.g. Darklings).
+    pub stasis: Option<CardCombatState>, // The player's card held by a Bronze Orb.
     pub characteristics: Box<dyn EnemyCharacteristics>,
 }
 
@@ -33,35 +40,124 @@ impl EnemyState {
             enemy,
             hp: hp_max,
             hp_max,
-            block: 0,
-            strength: 0,
+            block: characteristics.starting_block(),
+            strength: characteristics.starting_strength(),
             conditions,
             run_length: 1,
             next_action: first_action,
+            spawned_this_turn: false,
+            half_dead: false,
+            stasis: None,
             characteristics,
         }
     }
 
     /// Computes the next action for the enemy, updating the run length if necessary.
-    pub fn advance_action(&mut self, enemy_rng: &mut StsRandom) {
-        let action = self.next_action;
-        self.next_action = self
+    pub fn advance_action(&mut self, enemy_rng: &mut StsRandom, battlefield: &Battlefield) {
+        self.characteristics.survey(battlefield);
+        let action = self
             .characteristics
-            .next_action(enemy_rng, action, self.run_length);
+            .next_action(enemy_rng, self.next_action, self.run_length);
+        self.replace_action(action);
+    }
+
+    /// Picks a new intent in place of the current one, as Writhing Mass does when hit. The
+    /// abandoned intent counts as its last action.
+    pub fn reroll_action(&mut self, enemy_rng: &mut StsRandom, battlefield: &Battlefield) {
+        self.characteristics.survey(battlefield);
+        let action =
+            self.characteristics
+                .reroll_action(enemy_rng, self.next_action, self.run_length);
+        self.replace_action(action);
+    }
+
+    /// Lays the enemy half-dead instead of letting it die, if its characteristics allow it.
+    /// Returns true iff the enemy will rise again.
+    pub fn lie_half_dead(&mut self, kin_standing: bool) -> bool {
+        match self
+            .characteristics
+            .on_death(&mut self.conditions, kin_standing)
+        {
+            Some(action) => {
+                self.half_dead = true;
+                self.block = 0;
+                self.next_action = action;
+                self.run_length = 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Makes the specified action the enemy's next, updating the run length if necessary.
+    fn replace_action(&mut self, action: EnemyAction) {
         if self.next_action == action {
             self.run_length = self.run_length.saturating_add(1);
         } else {
+            self.next_action = action;
             self.run_length = 1;
         }
+        self.characteristics
+            .update_status(&mut self.conditions, &mut self.block);
+    }
+
+    /// Lets the enemy react to losing HP, possibly replacing its next action.
+    pub fn on_hp_lost(&mut self, hp_lost: Hp) {
+        if let Some(action) =
+            self.characteristics
+                .on_hp_lost(hp_lost, self.hp, self.hp_max, &self.conditions)
+        {
+            if self.next_action != action {
+                self.next_action = action;
+                self.run_length = 1;
+            }
+        }
+        self.characteristics
+            .update_status(&mut self.conditions, &mut self.block);
     }
 
-    /// Returns true iff the enemy is dead.
+    /// Returns true iff the enemy is dead, or at least half-dead.
     pub fn is_dead(&self) -> bool {
         self.hp == 0
     }
+
+    /// Returns true iff the enemy is still in the fight, i.e. neither dead nor half-dead.
+    pub fn is_standing(&self) -> bool {
+        !self.is_dead() && !self.half_dead
+    }
+
+    /// Returns the intent of the enemy's next action, with any attack damage shown per hit as the
+    /// player would take it before Block, as in the game.
+    pub fn intent_against(&self, pcs: &PlayerCombatState) -> Intent {
+        let intent = self.next_action.intent();
+        let Some(damage) = self
+            .next_action
+            .effect_chain()
+            .iter()
+            .find_map(|effect| match effect {
+                EnemyEffect::Deal(damage @ (Damage::Blockable(_) | Damage::HpLoss(_)))
+                | EnemyEffect::DealAndBlock(damage @ Damage::Blockable(_))
+                | EnemyEffect::DealAndHeal(damage @ Damage::Blockable(_)) => Some(damage),
+                _ => None,
+            })
+        else {
+            return intent;
+        };
+        let mut damage_taken = DamageTaken {
+            blocked: 0,
+            hp_lost: DamageCalculator::calculate_damage_inflicted(self, Some(pcs), damage).amount(),
+            provokes_thorns: false,
+        };
+        RelicSystem::modify_damage_taken_by_player(pcs.pps, &mut damage_taken);
+        intent.with_damage(damage_taken.hp_lost)
+    }
 }
 
 impl AttackerStatus for EnemyState {
+    fn additional_damage(&self) -> Hp {
+        0
+    }
+
     fn block(&self) -> Block {
         self.block
     }
@@ -74,16 +170,28 @@ impl AttackerStatus for EnemyState {
         0
     }
 
+    fn is_attacking_from_behind(&self) -> bool {
+        self.conditions.contains(&EnemyCondition::BackAttack)
+    }
+
     fn is_weak(&self) -> bool {
         self.conditions
             .iter()
             .any(|c| matches!(c, EnemyCondition::Weak(_)))
     }
 
+    fn mantra_gained(&self) -> Mantra {
+        0
+    }
+
     fn number_of_strike_cards_owned(&self) -> usize {
         0
     }
 
+    fn stance(&self) -> Option<Stance> {
+        None
+    }
+
     fn strength(&self) -> Strength {
         self.strength
     }
@@ -94,19 +202,49 @@ impl DefenderStatus for EnemyState {
         0
     }
 
+    fn has_paper_krane(&self) -> bool {
+        false
+    }
+
+    fn is_flying(&self) -> bool {
+        self.conditions
+            .iter()
+            .any(|c| matches!(c, EnemyCondition::Flight(_, _)))
+    }
+
     fn is_frail(&self) -> bool {
         false
     }
 
+    fn is_in_wrath(&self) -> bool {
+        false
+    }
+
+    fn is_intangible(&self) -> bool {
+        self.conditions
+            .iter()
+            .any(|c| matches!(c, EnemyCondition::Intangible(..)))
+    }
+
     fn is_vulnerable(&self) -> bool {
         self.conditions
             .iter()
             .any(|c| matches!(c, EnemyCondition::Vulnerable(_)))
     }
+
+    fn slow_stacks(&self) -> StackCount {
+        self.conditions
+            .iter()
+            .find_map(|c| match c {
+                EnemyCondition::Slow(stacks) => Some(*stacks),
+                _ => None,
+            })
+            .unwrap_or(0)
+    }
 }
 
-impl From<&EnemyState> for EnemyStatus {
-    fn from(enemy: &EnemyState) -> Self {
+impl From<(&EnemyState, &PlayerCombatState<'_>)> for EnemyStatus {
+    fn from((enemy, pcs): (&EnemyState, &PlayerCombatState<'_>)) -> Self {
         Self {
             enemy_type: enemy.enemy,
             hp: enemy.hp,
@@ -114,7 +252,7 @@ impl From<&EnemyState> for EnemyStatus {
             strength: enemy.strength,
             block: enemy.block,
             conditions: enemy.conditions.clone(),
-            intent: enemy.next_action.intent(),
+            intent: Some(enemy.intent_against(pcs)),
         }
     }
 }
@@ -1,6 +1,7 @@
 use anyhow::Error;
 
 use crate::components::{Interaction, Notification, PlayerPersistentState};
+use crate::data::Relic;
 use crate::types::{Hp, HpMax};
 
 pub struct HealthSystem;
@@ -14,13 +15,16 @@ impl HealthSystem {
         comms.send_notification(Notification::Health((pps.hp, pps.hp_max)))
     }
 
-    /// Heals the player for the given amount and notifies them of the change.
+    /// Heals the player for the given amount and notifies them of the change. The Mark of the
+    /// Bloom prevents all healing.
     pub fn heal<I: Interaction>(
         comms: &I,
         pps: &mut PlayerPersistentState,
         amount: Hp,
     ) -> Result<(), Error> {
-        pps.hp = pps.hp.saturating_add(amount).min(pps.hp_max);
+        if !pps.relics.contains(&Relic::MarkOfTheBloom) {
+            pps.hp = pps.hp.saturating_add(amount).min(pps.hp_max);
+        }
         Self::notify_player(comms, pps)
     }
 
@@ -3,7 +3,8 @@ use anyhow::Error;
 use crate::components::{
     Choice, Interaction, Notification, PlayerPersistentState, PotionAction, Prompt,
 };
-use crate::data::Potion;
+use crate::data::{PlayerCondition, Potion};
+use crate::systems::combat::{CardQueueSystem, PlayerConditionSystem};
 
 use super::combat_context::CombatContext;
 use super::health_system::HealthSystem;
@@ -151,8 +152,17 @@ impl PotionSystem {
                         ctx.comms
                             .send_notification(Notification::Dexterity(ctx.pcs.dexterity))?;
                     }
-                    Potion::DistilledChaos => todo!(),
-                    Potion::DuplicationPotion => todo!(),
+                    Potion::DistilledChaos => {
+                        for _ in 0..3 {
+                            CardQueueSystem::queue_top_card_of_draw_pile(ctx, false)?;
+                        }
+                    }
+                    Potion::DuplicationPotion => {
+                        PlayerConditionSystem::apply_to_player(
+                            ctx,
+                            &PlayerCondition::Duplication(1),
+                        )?;
+                    }
                     Potion::Elixir => todo!(),
                     Potion::EnergyPotion => todo!(),
                     Potion::EntropicBrew => Self::entropic_brew(ctx.comms, ctx.pcs.pps)?,
@@ -175,7 +185,7 @@ impl PotionSystem {
                     Potion::PowerPotion => todo!(),
                     Potion::RegenPotion => todo!(),
                     Potion::SkillPotion => todo!(),
-                    Potion::SmokeBomb => todo!(),
+                    Potion::SmokeBomb => ctx.pcs.escaped = true,
                     Potion::SneckoOil => todo!(),
                     Potion::SpeedPotion => todo!(),
                     Potion::StancePotion => todo!(),
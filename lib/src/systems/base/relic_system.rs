@@ -1,11 +1,12 @@
 use anyhow::Error;
 
 use crate::components::{DamageTaken, Effect, Interaction, Notification, PlayerPersistentState};
-use crate::data::{Damage, PlayerCondition, PlayerEffect, Relic, TargetEffect};
+use crate::data::{CardType, Damage, PlayerCondition, PlayerEffect, Relic, TargetEffect};
 use crate::systems::combat::PlayerConditionSystem;
 use crate::types::DrawCount;
 
 use super::combat_context::CombatContext;
+use super::deck_system::DeckSystem;
 use super::health_system::HealthSystem;
 
 pub struct RelicSystem;
@@ -26,6 +27,29 @@ impl RelicSystem {
         relic: Relic,
     ) -> Result<(), Error> {
         pps.relics.push(relic);
+        Self::notify_player(comms, pps)?;
+        match relic {
+            Relic::BottledFlame => DeckSystem::choose_card_to_bottle(comms, pps, CardType::Attack),
+            Relic::BottledLightning => {
+                DeckSystem::choose_card_to_bottle(comms, pps, CardType::Skill)
+            }
+            Relic::BottledTornado => DeckSystem::choose_card_to_bottle(comms, pps, CardType::Power),
+            _ => Ok(()),
+        }
+    }
+
+    /// Removes the indicated relic from the player's relics and notifies them of the change.
+    pub fn lose_relic<I: Interaction>(
+        comms: &I,
+        pps: &mut PlayerPersistentState,
+        relic: Relic,
+    ) -> Result<(), Error> {
+        let index = pps
+            .relics
+            .iter()
+            .position(|r| *r == relic)
+            .expect("Relic to lose not found");
+        pps.relics.remove(index);
         Self::notify_player(comms, pps)
     }
 
@@ -114,10 +138,11 @@ impl RelicSystem {
         for relic in ctx.pcs.pps.relics.iter() {
             match relic {
                 Relic::CharonsAshes => {
-                    ctx.effect_queue
-                        .push_back(Effect::PlayerState(PlayerEffect::ToAllEnemies(
-                            TargetEffect::Deal(Damage::BlockableNonAttack(3)),
-                        )));
+                    ctx.effect_queue.add_to_bottom(Effect::PlayerState(
+                        PlayerEffect::ToAllEnemies(TargetEffect::Deal(Damage::BlockableNonAttack(
+                            3,
+                        ))),
+                    ));
                 }
                 Relic::DeadBranch => todo!(),
                 _ => {}
@@ -1,8 +1,9 @@
 use anyhow::Error;
 
 use crate::components::{DamageTaken, EffectQueue, Interaction, Notification};
+use crate::data::{EnemyCondition, PlayerCondition};
 use crate::systems::base::{CombatContext, EnemyState, HealthSystem, RelicSystem};
-use crate::types::Block;
+use crate::types::{Block, Hp};
 
 use super::damage_calculator::{CalculatedBlock, CalculatedDamage};
 use super::enemy_condition_system::EnemyConditionSystem;
@@ -29,7 +30,9 @@ impl BlockSystem {
     /// Resets the enemies' block to 0 at the start of their turn.
     pub fn on_enemies_turn_started<I: Interaction>(ctx: &mut CombatContext<I>) {
         for enemy in ctx.enemy_party.0.iter_mut().filter_map(|e| e.as_mut()) {
-            enemy.block = 0;
+            if !enemy.conditions.contains(&EnemyCondition::Barricade) {
+                enemy.block = 0;
+            }
         }
     }
 
@@ -41,14 +44,28 @@ impl BlockSystem {
         ctx.pcs.block = ctx.pcs.block.saturating_add(calculated_block.amount);
         ctx.comms
             .send_notification(Notification::BlockGained(calculated_block.amount))?;
+        if calculated_block.amount > 0 {
+            PlayerConditionSystem::on_block_gained(ctx);
+        }
         Self::notify_player(ctx)
     }
 
     /// Damages the player and notifies them of the change.
+    /// Returns the amount of unblocked damage the player took.
     pub fn damage_player<I: Interaction>(
         ctx: &mut CombatContext<I>,
         damage: CalculatedDamage,
-    ) -> Result<(), Error> {
+    ) -> Result<Hp, Error> {
+        let damage = if ctx
+            .pcs
+            .conditions
+            .iter()
+            .any(|c| matches!(c, PlayerCondition::Intangible(_)))
+        {
+            damage.capped_at(1)
+        } else {
+            damage
+        };
         let mut damage_taken = Self::damage_taken(ctx.pcs.block, damage);
         if damage_taken.blocked > 0 {
             ctx.pcs.block = ctx.pcs.block.saturating_sub(damage_taken.blocked);
@@ -57,31 +74,53 @@ impl BlockSystem {
             Self::notify_player(ctx)?;
         }
         if damage_taken.hp_lost == 0 {
-            Ok(())
+            Ok(0)
         } else {
             RelicSystem::modify_damage_taken_by_player(ctx.pcs.pps, &mut damage_taken);
             PlayerConditionSystem::on_damage_taken(ctx, &damage_taken)?;
             ctx.comms
                 .send_notification(Notification::DamageTaken(damage_taken.hp_lost))?;
             HealthSystem::decrease_hp(ctx.comms, ctx.pcs.pps, damage_taken.hp_lost)?;
-            Self::notify_player(ctx)
+            Self::notify_player(ctx)?;
+            Ok(damage_taken.hp_lost)
         }
     }
 
     /// Inflicts the specified amount of damage on an enemy, provoking thorns if applicable.
+    /// Returns the amount of HP the enemy actually lost.
     pub fn damage_enemy(
         enemy_state: &mut EnemyState,
         damage: CalculatedDamage,
         effect_queue: &mut EffectQueue,
-    ) {
-        let damage_taken = Self::damage_taken(enemy_state.block, damage);
+    ) -> Hp {
+        let damage = if enemy_state
+            .conditions
+            .iter()
+            .any(|c| matches!(c, EnemyCondition::Intangible(..)))
+        {
+            damage.capped_at(1)
+        } else {
+            damage
+        };
+        let mut damage_taken = Self::damage_taken(enemy_state.block, damage);
+        if let Some(EnemyCondition::Invincible(hp, _)) = enemy_state
+            .conditions
+            .iter_mut()
+            .find(|c| matches!(c, EnemyCondition::Invincible(..)))
+        {
+            damage_taken.hp_lost = damage_taken.hp_lost.min(*hp);
+            *hp -= damage_taken.hp_lost;
+        }
         EnemyConditionSystem::on_damage_taken(enemy_state, &damage_taken, effect_queue);
         if damage_taken.blocked > 0 {
             enemy_state.block = enemy_state.block.saturating_sub(damage_taken.blocked);
         }
-        if damage_taken.hp_lost > 0 {
-            enemy_state.hp = enemy_state.hp.saturating_sub(damage_taken.hp_lost);
+        let hp_lost = damage_taken.hp_lost.min(enemy_state.hp);
+        enemy_state.hp -= hp_lost;
+        if hp_lost > 0 && !enemy_state.is_dead() {
+            enemy_state.on_hp_lost(hp_lost);
         }
+        hp_lost
     }
 
     /// Helper method that calculates block and HP lost for a given damage amount.
@@ -1,8 +1,17 @@
 use anyhow::Error;
 
-use crate::components::{CardCombatState, Interaction, Notification};
-use crate::data::{Card, CardDestination, CardPool, CardSelection, Character, CostModifier};
+use crate::components::{
+    CardCombatState, Choice, Interaction, Notification, PlayerCombatState, PlayerPersistentState,
+    Prompt,
+};
+use crate::data::{
+    Card, CardDestination, CardDetails, CardPool, CardSelection, CostModifier, PlayerCondition,
+    COMBAT_COLORLESS_CARD_POOL, DEFECT, IRONCLAD, SILENT, WATCHER,
+};
 use crate::systems::base::CombatContext;
+use crate::types::{AttackCount, DrawIndex};
+
+use super::energy_system::EnergySystem;
 
 pub struct CardCreationSystem;
 
@@ -15,83 +24,220 @@ impl CardCreationSystem {
         card_destination: &CardDestination,
         cost_modifier: &CostModifier,
     ) -> Result<(), Error> {
-        let card_pool = Self::get_card_pool(card_pool, ctx.pcs.pps.character);
-        let card_selection = Self::get_card_selection(card_selection, card_pool);
-        let modified_cards = Self::modify_costs(cost_modifier, card_selection);
+        let card_pool = Self::get_card_pool(card_pool, &ctx.pcs);
+        let card_selection = Self::get_card_selection(ctx, card_selection, &card_pool)?;
+        let modified_cards = Self::modify_costs(ctx.pcs.pps, cost_modifier, card_selection);
         Self::add_cards_to_destination(ctx, modified_cards, card_destination)
     }
 
-    /// Gets the card pool for the given character.
-    fn get_card_pool(card_pool: &CardPool, character: &Character) -> &'static [Card] {
+    /// Creates an Expunger in the player's hand that hits the given number of times.
+    pub fn create_expunger<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        hit_count: AttackCount,
+    ) -> Result<(), Error> {
+        let mut expunger =
+            CardCombatState::new(ctx.pcs.pps.allocate_card_id(), Card::Expunger(false));
+        expunger.x = hit_count;
+        Self::add_cards_to_destination(ctx, vec![expunger], &CardDestination::Hand)
+    }
+
+    /// Gets the card pool for the player's character. Cards that aren't defined yet (e.g. the
+    /// Silent's and Defect's) can't be created, so they are left out.
+    fn get_card_pool(card_pool: &CardPool, pcs: &PlayerCombatState) -> Vec<Card> {
+        let character = pcs.pps.character;
         match card_pool {
+            CardPool::AnyColorAttackPool => [IRONCLAD, SILENT, DEFECT, WATCHER]
+                .iter()
+                .flat_map(|character| Self::defined_cards(character.attack_card_pool))
+                .collect(),
             CardPool::AttacksAndPowersInHand => todo!(),
-            CardPool::CardInPlay => todo!(),
-            CardPool::CharacterAttackPool => character.attack_card_pool,
-            CardPool::CharacterCardPool => todo!(),
-            CardPool::CharacterPowerPool => todo!(),
-            CardPool::CharacterSkillPool => todo!(),
-            CardPool::ColorlessCardPool => todo!(),
-            CardPool::Fixed(cards) => cards,
-            CardPool::UpgradedColorlessCardPool => todo!(),
+            CardPool::CardInPlay => {
+                let Some(combat_card) = pcs.card_being_played else {
+                    panic!("No card being played");
+                };
+                vec![combat_card.card]
+            }
+            CardPool::CharacterAttackPool => {
+                Self::defined_cards(character.attack_card_pool).collect()
+            }
+            CardPool::CharacterCardPool => Self::defined_cards(character.attack_card_pool)
+                .chain(Self::defined_cards(character.skill_card_pool))
+                .chain(Self::defined_cards(character.power_card_pool))
+                .collect(),
+            CardPool::CharacterPowerPool => {
+                Self::defined_cards(character.power_card_pool).collect()
+            }
+            CardPool::CharacterSkillPool => {
+                Self::defined_cards(character.skill_card_pool).collect()
+            }
+            CardPool::ColorlessCardPool => COMBAT_COLORLESS_CARD_POOL.to_vec(),
+            CardPool::Fixed(cards) => cards.to_vec(),
+            CardPool::UpgradedColorlessCardPool => COMBAT_COLORLESS_CARD_POOL
+                .iter()
+                .map(|card| CardDetails::for_card(*card).upgrade.unwrap_or(*card))
+                .collect(),
         }
     }
 
+    /// Filters out the cards that don't have details yet.
+    fn defined_cards(cards: &'static [Card]) -> impl Iterator<Item = Card> {
+        cards
+            .iter()
+            .copied()
+            .filter(|card| CardDetails::try_for_card(*card).is_some())
+    }
+
     /// Selects the cards to be created from the card pool.
-    fn get_card_selection(card_selection: &CardSelection, card_pool: &'static [Card]) -> Vec<Card> {
+    fn get_card_selection<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_selection: &CardSelection,
+        card_pool: &[Card],
+    ) -> Result<Vec<Card>, Error> {
         match card_selection {
-            CardSelection::All => card_pool.to_vec(),
+            CardSelection::All => Ok(card_pool.to_vec()),
             CardSelection::PlayerChoice(_) => todo!(),
             CardSelection::PlayerChoiceUnlimited => todo!(),
             CardSelection::PlayerChoiceUpTo(_) => todo!(),
-            CardSelection::Random(num_cards) => Self::get_random_cards(card_pool, *num_cards),
-            CardSelection::RandomThenPlayerChoice(_, _) => todo!(),
-            CardSelection::RandomX => todo!(),
+            CardSelection::Random(num_cards) => {
+                Ok(Self::get_random_cards(ctx, card_pool, *num_cards))
+            }
+            CardSelection::RandomThenPlayerChoice(num_offered, num_chosen) => {
+                Self::choose_from_random_cards(ctx, card_pool, *num_offered, *num_chosen)
+            }
+            CardSelection::RandomX => {
+                let x = EnergySystem::resolve_x(ctx)? as usize;
+                Ok(Self::get_random_cards(ctx, card_pool, x))
+            }
         }
     }
 
-    /// Gets a random number of cards from the card pool.
-    fn get_random_cards(_card_pool_vec: &'static [Card], _num_cards: usize) -> Vec<Card> {
-        //card_pool_vec.to_vec()
-        todo!()
+    /// Picks the given number of cards from the card pool at random, allowing duplicates. An empty
+    /// pool yields no cards.
+    fn get_random_cards<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_pool: &[Card],
+        num_cards: usize,
+    ) -> Vec<Card> {
+        if card_pool.is_empty() {
+            return Vec::new();
+        }
+        (0..num_cards)
+            .map(|_| card_pool[ctx.card_randomizer_rng.gen_range(0..card_pool.len())])
+            .collect()
     }
 
-    /// Modifies the costs of the cards to be created.
+    /// Offers the player distinct cards drawn at random from the card pool, as the game does by
+    /// redrawing duplicates, and returns the ones they choose.
+    fn choose_from_random_cards<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_pool: &[Card],
+        num_offered: usize,
+        num_chosen: usize,
+    ) -> Result<Vec<Card>, Error> {
+        let mut offered = Vec::with_capacity(num_offered);
+        while offered.len() < num_offered.min(card_pool.len()) {
+            let card = card_pool[ctx.card_randomizer_rng.gen_range(0..card_pool.len())];
+            if !offered.contains(&card) {
+                offered.push(card);
+            }
+        }
+        let mut chosen = Vec::with_capacity(num_chosen);
+        while chosen.len() < num_chosen && !offered.is_empty() {
+            let choices = offered
+                .iter()
+                .copied()
+                .map(Choice::CreateCard)
+                .collect::<Vec<_>>();
+            match ctx
+                .comms
+                .prompt_for_choice(Prompt::ChooseCardToCreate, &choices)?
+            {
+                Choice::CreateCard(card) => {
+                    offered.retain(|c| c != card);
+                    chosen.push(*card);
+                }
+                invalid => unreachable!("{:?}", invalid),
+            }
+        }
+        Ok(chosen)
+    }
+
+    /// Gives each card to be created a fresh identity and modifies its cost.
     fn modify_costs(
+        pps: &mut PlayerPersistentState,
         cost_modifier: &CostModifier,
         card_selection_vec: Vec<Card>,
     ) -> Vec<CardCombatState> {
         card_selection_vec
             .iter()
-            .map(|card| match cost_modifier {
-                CostModifier::None => CardCombatState::new(*card, None),
-                CostModifier::ZeroThisCombat => todo!(),
-                CostModifier::ZeroThisTurn => todo!(),
-                CostModifier::ZeroUntilPlayed => todo!(),
+            .map(|card| {
+                let mut combat_card = CardCombatState::new(pps.allocate_card_id(), *card);
+                combat_card.modify_cost(cost_modifier);
+                combat_card
             })
             .collect()
     }
 
-    /// Adds the cards to the destination.
+    /// Adds the cards to the destination, upgrading them first if the player has Master Reality.
+    /// Cards bound for a full hand go to the discard pile instead. Each card is announced along
+    /// with where it actually landed.
     fn add_cards_to_destination<I: Interaction>(
         ctx: &mut CombatContext<I>,
         created_cards: Vec<CardCombatState>,
         card_destination: &CardDestination,
     ) -> Result<(), Error> {
-        for combat_card in created_cards {
-            match card_destination {
-                CardDestination::BottomOfDrawPile => todo!(),
+        let upgrade = ctx.pcs.conditions.contains(&PlayerCondition::MasterReality);
+        for mut combat_card in created_cards {
+            if upgrade {
+                combat_card.upgrade();
+            }
+            let notification = match card_destination {
+                CardDestination::BottomOfDrawPile
+                | CardDestination::ShuffledIntoDrawPile
+                | CardDestination::TopOfDrawPile => {
+                    let draw_index = Self::draw_pile_index(ctx, card_destination);
+                    ctx.pcs.cards.draw_pile.insert(draw_index, combat_card);
+                    Notification::CardCreatedInDrawPile(draw_index, combat_card)
+                }
                 CardDestination::DiscardPile => {
                     ctx.pcs.cards.discard_pile.push(combat_card);
+                    Notification::CardCreated(combat_card, *card_destination)
+                }
+                CardDestination::ExhaustPile => {
+                    ctx.pcs.cards.exhaust_pile.push(combat_card);
+                    Notification::CardCreated(combat_card, *card_destination)
+                }
+                CardDestination::Hand | CardDestination::HandAndRetain => {
+                    combat_card.retain_this_turn =
+                        *card_destination == CardDestination::HandAndRetain;
+                    if ctx.pcs.cards.hand.len() < 10 {
+                        ctx.pcs.cards.hand.push(combat_card);
+                        Notification::CardCreated(combat_card, *card_destination)
+                    } else {
+                        ctx.pcs.cards.discard_pile.push(combat_card);
+                        Notification::CardCreated(combat_card, CardDestination::DiscardPile)
+                    }
                 }
-                CardDestination::ExhaustPile => todo!(),
-                CardDestination::Hand => todo!(),
-                CardDestination::ShuffledIntoDrawPile => todo!(),
-                CardDestination::TopOfDrawPile => todo!(),
                 CardDestination::TwoCopiesInHand => todo!(),
-            }
-            ctx.comms
-                .send_notification(Notification::CardCreated(combat_card, *card_destination))?;
+            };
+            ctx.comms.send_notification(notification)?;
         }
         Ok(())
     }
+
+    /// Picks where in the draw pile a created card goes, with index 0 being the bottom. As in the
+    /// game, a card shuffled into a non-empty draw pile never lands on top.
+    fn draw_pile_index<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_destination: &CardDestination,
+    ) -> DrawIndex {
+        let draw_pile_len = ctx.pcs.cards.draw_pile.len();
+        match card_destination {
+            CardDestination::BottomOfDrawPile => 0,
+            CardDestination::ShuffledIntoDrawPile if draw_pile_len > 0 => {
+                ctx.card_randomizer_rng.gen_range(0..draw_pile_len)
+            }
+            _ => draw_pile_len,
+        }
+    }
 }
@@ -0,0 +1,246 @@
+use anyhow::Error;
+
+use crate::components::{
+    CardPlay, CardPlaySource, Choice, Effect, Interaction, PlayerCombatState, Prompt,
+};
+use crate::data::{CardSource, CardType, EnergyCost, PlayerCondition, PlayerEffect, Relic};
+use crate::systems::base::CombatContext;
+use crate::types::EnemyIndex;
+
+use super::discard_system::DiscardSystem;
+use super::draw_system::DrawSystem;
+use super::effect_system::EffectSystem;
+use super::energy_system::EnergySystem;
+use super::exhaust_system::ExhaustSystem;
+use super::player_combat_system::PlayerCombatSystem;
+use super::player_condition_system::PlayerConditionSystem;
+
+pub struct CardQueueSystem;
+
+impl CardQueueSystem {
+    /// Resets the per-turn duplication counters and queues Mayhem's plays, which must follow the
+    /// start-of-turn draw.
+    pub fn on_player_turn_started<I: Interaction>(ctx: &mut CombatContext<I>) {
+        ctx.pcs.copies_played_this_turn = 0;
+        ctx.pcs.necronomicon_used_this_turn = false;
+        for condition in ctx.pcs.conditions.iter() {
+            if let PlayerCondition::Mayhem(stack_count) = condition {
+                for _ in 0..*stack_count {
+                    ctx.effect_queue
+                        .add_to_bottom(Effect::PlayerState(PlayerEffect::PlayTopCardOfDrawPile));
+                }
+            }
+        }
+    }
+
+    /// Plays every queued card in order, including any plays queued along the way.
+    pub fn play_queued_cards<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        while let Some(card_play) = ctx.card_queue.pop_front() {
+            if ctx.combat_should_end() {
+                ctx.card_queue.clear();
+                break;
+            }
+            Self::play(ctx, card_play)?;
+        }
+        Ok(())
+    }
+
+    /// Plays a single queued card, triggering the same hooks as a card played from the hand.
+    fn play<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        mut card_play: CardPlay,
+    ) -> Result<(), Error> {
+        if card_play.source == CardPlaySource::Autoplay {
+            if card_play.combat_card.details.unplayable {
+                // Unplayable cards are set aside without being played.
+                let hand_index = ctx.pcs.cards.hand.len();
+                return if card_play.exhaust {
+                    ExhaustSystem::push(ctx, hand_index, card_play.combat_card)
+                } else {
+                    DiscardSystem::push(ctx, hand_index, card_play.combat_card)
+                };
+            }
+            if card_play.combat_card.details.cost == EnergyCost::X {
+                card_play.combat_card.x = EnergySystem::x_value(&ctx.pcs);
+            }
+        }
+        if card_play.combat_card.details.requires_target
+            && card_play.maybe_enemy_index.is_none_or(|enemy_index| {
+                ctx.enemy_party.0[enemy_index]
+                    .as_ref()
+                    .is_none_or(|enemy| enemy.half_dead)
+            })
+        {
+            card_play.maybe_enemy_index = Self::pick_random_target(ctx);
+        }
+        ctx.maybe_enemy_index = card_play.maybe_enemy_index;
+        if let Some(enemy_index) = card_play
+            .maybe_enemy_index
+            .filter(|_| card_play.combat_card.details.requires_target)
+        {
+            PlayerConditionSystem::face_enemy(ctx, enemy_index);
+        }
+        ctx.pcs.card_being_played = Some(card_play.combat_card);
+        PlayerCombatSystem::dispose_of_card_just_played(ctx, &card_play)?;
+        EffectSystem::process_effect_queue(ctx)?;
+        for effect in card_play.combat_card.details.on_play.iter() {
+            ctx.effect_queue.add_to_bottom(Effect::Card(effect));
+        }
+        EffectSystem::process_effect_queue(ctx)?;
+        PlayerCombatSystem::on_card_play_finished(ctx)
+    }
+
+    /// Picks a living enemy for an autoplayed or duplicated card, using the card random rng as
+    /// the game does.
+    fn pick_random_target<I: Interaction>(ctx: &mut CombatContext<I>) -> Option<EnemyIndex> {
+        let living_enemies = ctx
+            .enemy_party
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(_, maybe_enemy)| maybe_enemy.as_ref().is_some_and(|e| !e.half_dead))
+            .map(|(enemy_index, _)| enemy_index)
+            .collect::<Vec<_>>();
+        if living_enemies.is_empty() {
+            None
+        } else {
+            Some(living_enemies[ctx.card_randomizer_rng.gen_range(0..living_enemies.len())])
+        }
+    }
+
+    /// Queues duplicate plays of the card just played, consuming Amplify, Burst, Double Tap and
+    /// Duplication stacks and checking Echo Form and Necronomicon.
+    pub fn on_some_card_played<I: Interaction>(ctx: &mut CombatContext<I>, card_play: &CardPlay) {
+        if card_play.source == CardPlaySource::Copy {
+            ctx.pcs.copies_played_this_turn += 1;
+            return;
+        }
+        let card_type = card_play.combat_card.details.type_;
+        let original_cards_played =
+            ctx.pcs.cards_played_this_turn - ctx.pcs.copies_played_this_turn;
+        let mut copies = 0;
+        ctx.pcs.conditions.retain_mut(|condition| match condition {
+            PlayerCondition::Amplify(stack_count) if card_type == CardType::Power => {
+                copies += 1;
+                *stack_count -= 1;
+                *stack_count > 0
+            }
+            PlayerCondition::Burst(stack_count) if card_type == CardType::Skill => {
+                copies += 1;
+                *stack_count -= 1;
+                *stack_count > 0
+            }
+            PlayerCondition::DoubleTap(attack_count) if card_type == CardType::Attack => {
+                copies += 1;
+                *attack_count -= 1;
+                *attack_count > 0
+            }
+            PlayerCondition::Duplication(stack_count) => {
+                copies += 1;
+                *stack_count -= 1;
+                *stack_count > 0
+            }
+            PlayerCondition::EchoForm(stack_count) => {
+                if original_cards_played <= *stack_count as usize {
+                    copies += 1;
+                }
+                true
+            }
+            _ => true,
+        });
+        if card_type == CardType::Attack
+            && !ctx.pcs.necronomicon_used_this_turn
+            && ctx.pcs.pps.relics.contains(&Relic::Necronomicon)
+            && Self::costs_two_or_more(&ctx.pcs, card_play)
+        {
+            ctx.pcs.necronomicon_used_this_turn = true;
+            copies += 1;
+        }
+        for _ in 0..copies {
+            ctx.card_queue.push_front(CardPlay::copy_of(card_play));
+        }
+    }
+
+    /// Returns true iff the card was played for two or more energy, as Necronomicon requires.
+    /// Free plays (Havoc, Mayhem, Distilled Chaos, Swivel) don't count.
+    fn costs_two_or_more(pcs: &PlayerCombatState, card_play: &CardPlay) -> bool {
+        if card_play.source == CardPlaySource::Autoplay
+            || pcs
+                .conditions
+                .iter()
+                .any(|c| matches!(c, PlayerCondition::FreeAttack(_)))
+        {
+            return false;
+        }
+        match card_play.combat_card.cost_this_turn {
+            EnergyCost::Zero | EnergyCost::One => false,
+            EnergyCost::X => card_play.combat_card.x >= 2,
+            _ => true,
+        }
+    }
+
+    /// Takes the top card of the draw pile and queues it to be played for free against a random
+    /// target (Havoc, Mayhem, Distilled Chaos).
+    pub fn queue_top_card_of_draw_pile<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        exhaust: bool,
+    ) -> Result<(), Error> {
+        if let Some(combat_card) = DrawSystem::take_top_card_of_draw_pile(ctx)? {
+            ctx.card_queue
+                .push_back(CardPlay::autoplay(combat_card, exhaust));
+        }
+        Ok(())
+    }
+
+    /// Prompts the player to choose a card from their draw pile (Omniscience) or hand, which is
+    /// then played twice for free and Exhausted.
+    pub fn choose_card_to_play_twice<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_source: &CardSource,
+    ) -> Result<(), Error> {
+        let choices = match card_source {
+            CardSource::DrawPile => ctx
+                .pcs
+                .cards
+                .draw_pile
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(draw_index, combat_card)| {
+                    Choice::PlayCardFromDrawPile(draw_index, combat_card.card)
+                })
+                .collect::<Vec<_>>(),
+            CardSource::Hand => ctx
+                .pcs
+                .cards
+                .hand
+                .iter()
+                .enumerate()
+                .map(|(hand_index, combat_card)| {
+                    Choice::PlayCardFromHand(hand_index, combat_card.card, EnergyCost::Zero)
+                })
+                .collect::<Vec<_>>(),
+            invalid => unreachable!("{:?}", invalid),
+        };
+        if choices.is_empty() {
+            return Ok(());
+        }
+        let mut combat_card = match ctx
+            .comms
+            .prompt_for_choice(Prompt::ChooseCardToPlay, &choices)?
+        {
+            Choice::PlayCardFromDrawPile(draw_index, _) => {
+                ctx.pcs.cards.draw_pile.remove(*draw_index)
+            }
+            Choice::PlayCardFromHand(hand_index, _, _) => ctx.pcs.cards.hand.remove(*hand_index),
+            invalid => unreachable!("{:?}", invalid),
+        };
+        if combat_card.details.cost == EnergyCost::X {
+            combat_card.x = EnergySystem::x_value(&ctx.pcs);
+        }
+        let card_play = CardPlay::autoplay(combat_card, true);
+        ctx.card_queue.push_back(card_play);
+        ctx.card_queue.push_back(CardPlay::copy_of(&card_play));
+        Ok(())
+    }
+}
@@ -1,11 +1,11 @@
 use crate::components::{AttackerStatus, DefenderStatus};
-use crate::data::Damage;
+use crate::data::{Damage, Stance};
 use crate::types::{Block, Hp};
 
 pub struct DamageCalculator;
 
 /// Damage calculations after attacker and defender conditions are applied (i.e. strength, weak,
-/// and vulnerable).
+/// stance, and vulnerable).
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 pub enum CalculatedDamage {
     Blockable(Hp),
@@ -13,12 +13,35 @@ pub enum CalculatedDamage {
     HpLoss(Hp),
 }
 
+impl CalculatedDamage {
+    /// Returns the amount of damage, whatever its kind.
+    pub fn amount(self) -> Hp {
+        match self {
+            CalculatedDamage::Blockable(amount)
+            | CalculatedDamage::BlockableNonAttack(amount)
+            | CalculatedDamage::HpLoss(amount) => amount,
+        }
+    }
+
+    /// Returns the same kind of damage, lowered to at most the specified amount.
+    pub fn capped_at(self, cap: Hp) -> Self {
+        match self {
+            CalculatedDamage::Blockable(amount) => CalculatedDamage::Blockable(amount.min(cap)),
+            CalculatedDamage::BlockableNonAttack(amount) => {
+                CalculatedDamage::BlockableNonAttack(amount.min(cap))
+            }
+            CalculatedDamage::HpLoss(amount) => CalculatedDamage::HpLoss(amount.min(cap)),
+        }
+    }
+}
+
 /// The amount of block gained after defender conditions are applied (i.e. dexterity, frail).
 pub struct CalculatedBlock {
     pub amount: Block,
 }
 
-/// The initial damage calculation after only strength is applied. Useful as an intermediate result.
+/// The initial damage calculation after only strength and other flat bonuses are applied. Useful as
+/// an intermediate result.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 enum InitialCalculatedDamage {
     Blockable(Hp),
@@ -27,15 +50,20 @@ enum InitialCalculatedDamage {
 }
 
 impl DamageCalculator {
-    /// Calculates the damage inflicted, taking into account strength and weakness of the attacker
-    /// and vulnerability of the defender.
+    /// Calculates the damage inflicted, taking into account strength, weakness and stance of the
+    /// attacker and vulnerability, stance and intangibility of the defender.
     pub fn calculate_damage_inflicted<A: AttackerStatus, D: DefenderStatus>(
         attacker: &A,
         maybe_defender: Option<&D>,
         damage: &Damage,
     ) -> CalculatedDamage {
         let initial_damage = Self::calculate_initial_damage(attacker, damage);
-        Self::calculate_final_damage(attacker, maybe_defender, initial_damage)
+        let final_damage = Self::calculate_final_damage(attacker, maybe_defender, initial_damage);
+        if maybe_defender.is_some_and(|d| d.is_intangible()) {
+            final_damage.capped_at(1)
+        } else {
+            final_damage
+        }
     }
 
     /// Calculates the block gained by a defender, taking into account dexterity and frailty.
@@ -55,31 +83,40 @@ impl DamageCalculator {
         }
     }
 
-    /// Partial calculation of damage inflicted taking only strength into account.
+    /// Partial calculation of damage inflicted taking only strength and flat bonuses (e.g. Vigor)
+    /// into account.
     fn calculate_initial_damage<A: AttackerStatus>(
         attacker: &A,
         damage: &Damage,
     ) -> InitialCalculatedDamage {
+        let bonus = attacker.additional_damage();
         match damage {
             Damage::Blockable(amount) => InitialCalculatedDamage::Blockable(
-                amount.saturating_add_signed(attacker.strength()),
+                (amount + bonus).saturating_add_signed(attacker.strength()),
             ),
             Damage::BlockableCountingStrikeCards(base_amount, per_strike_bonus) => {
                 InitialCalculatedDamage::Blockable(
                     (base_amount
-                        + attacker.number_of_strike_cards_owned() as Hp * per_strike_bonus)
+                        + attacker.number_of_strike_cards_owned() as Hp * per_strike_bonus
+                        + bonus)
                         .saturating_add_signed(attacker.strength()),
                 )
             }
             Damage::BlockableEqualToDrawPileSize => InitialCalculatedDamage::Blockable(
-                (attacker.draw_pile_size() as Hp).saturating_add_signed(attacker.strength()),
+                (attacker.draw_pile_size() as Hp + bonus)
+                    .saturating_add_signed(attacker.strength()),
             ),
             Damage::BlockableEqualToPlayerBlock => InitialCalculatedDamage::Blockable(
-                (attacker.block() as Hp).saturating_add_signed(attacker.strength()),
+                (attacker.block() as Hp + bonus).saturating_add_signed(attacker.strength()),
+            ),
+            Damage::BlockablePlusMantraGained(amount) => InitialCalculatedDamage::Blockable(
+                (amount + attacker.mantra_gained() + bonus)
+                    .saturating_add_signed(attacker.strength()),
             ),
             Damage::BlockableWithStrengthMultiplier(base_amount, strength_multiplier) => {
                 InitialCalculatedDamage::Blockable(
-                    base_amount.saturating_add_signed(attacker.strength() * strength_multiplier),
+                    (base_amount + bonus)
+                        .saturating_add_signed(attacker.strength() * strength_multiplier),
                 )
             }
             Damage::BlockableNonAttack(amount) => {
@@ -93,8 +130,8 @@ impl DamageCalculator {
         }
     }
 
-    /// Final calculation of damage inflicted taking into account weakness and vulnerability of the
-    /// attacker and defender.
+    /// Final calculation of damage inflicted taking into account weakness, back attacks, stance,
+    /// vulnerability and slow of the attacker and defender, and whether the defender is in flight.
     fn calculate_final_damage<A: AttackerStatus, D: DefenderStatus>(
         attacker: &A,
         maybe_defender: Option<&D>,
@@ -102,17 +139,47 @@ impl DamageCalculator {
     ) -> CalculatedDamage {
         match damage {
             InitialCalculatedDamage::Blockable(amount) => {
-                let attacker_modified_amount = if attacker.is_weak() {
-                    (amount as f32 * 0.75).floor() as Hp
+                let weakened_amount = if attacker.is_weak() {
+                    // Paper Krane makes Weak enemies deal 40% less damage instead of 25%.
+                    let multiplier = if maybe_defender.is_some_and(|d| d.has_paper_krane()) {
+                        0.6
+                    } else {
+                        0.75
+                    };
+                    (amount as f32 * multiplier).floor() as Hp
                 } else {
                     amount
                 };
-                if maybe_defender.map_or(false, |d| d.is_vulnerable()) {
-                    CalculatedDamage::Blockable(
-                        (attacker_modified_amount as f32 * 1.5).floor() as Hp
-                    )
+                let back_attack_amount = if attacker.is_attacking_from_behind() {
+                    (weakened_amount as f32 * 1.5).floor() as Hp
+                } else {
+                    weakened_amount
+                };
+                let attacker_modified_amount = match attacker.stance() {
+                    Some(Stance::Wrath) => back_attack_amount * 2,
+                    Some(Stance::Divinity) => back_attack_amount * 3,
+                    _ => back_attack_amount,
+                };
+                let vulnerable_amount = if maybe_defender.is_some_and(|d| d.is_vulnerable()) {
+                    (attacker_modified_amount as f32 * 1.5).floor() as Hp
+                } else {
+                    attacker_modified_amount
+                };
+                let slow_stacks = maybe_defender.map_or(0, |d| d.slow_stacks());
+                let slowed_amount = if slow_stacks > 0 {
+                    (vulnerable_amount as f32 * (1. + 0.1 * slow_stacks as f32)).floor() as Hp
+                } else {
+                    vulnerable_amount
+                };
+                let wrath_amount = if maybe_defender.is_some_and(|d| d.is_in_wrath()) {
+                    slowed_amount * 2
+                } else {
+                    slowed_amount
+                };
+                if maybe_defender.is_some_and(|d| d.is_flying()) {
+                    CalculatedDamage::Blockable((wrath_amount as f32 * 0.5).floor() as Hp)
                 } else {
-                    CalculatedDamage::Blockable(attacker_modified_amount)
+                    CalculatedDamage::Blockable(wrath_amount)
                 }
             }
             InitialCalculatedDamage::BlockableNonAttack(amount) => {
@@ -1,44 +1,171 @@
 use std::collections::VecDeque;
+use std::iter::once;
 
 use anyhow::Error;
 
-use crate::components::{CardCombatState, Effect, Interaction, Notification};
+use crate::components::{CardCombatState, Choice, Effect, Interaction, Notification, Prompt};
+use crate::data::{Card, Damage, EnergyCost, PlayerCondition, PlayerEffect, Relic};
 use crate::systems::base::CombatContext;
-use crate::types::HandIndex;
+use crate::types::{HandIndex, Hp, StackCount};
 
 use super::exhaust_system::ExhaustSystem;
 
 pub struct DiscardSystem;
 
 impl DiscardSystem {
-    /// Discards the player's hand at the end of their turn.
+    /// Processes the player's hand at the end of their turn, in the game's order: lingering
+    /// card effects are queued, Ethereal cards are Exhausted, the player picks any cards to
+    /// Retain, and the rest of the hand is discarded unless something keeps it.
     pub fn on_player_turn_finished<I: Interaction>(
         ctx: &mut CombatContext<I>,
     ) -> Result<(), Error> {
+        Self::queue_linger_effects(ctx);
+        Self::exhaust_ethereal_cards(ctx)?;
+        let retain_hand = ctx
+            .pcs
+            .conditions
+            .iter()
+            .any(|c| matches!(c, PlayerCondition::Equilibrium(_)));
+        let keep_hand = ctx.pcs.pps.relics.contains(&Relic::RunicPyramid);
+        if !retain_hand && !keep_hand {
+            Self::choose_cards_to_retain(ctx)?;
+        }
+        let cost_reduction = ctx
+            .pcs
+            .conditions
+            .iter()
+            .map(|c| match c {
+                PlayerCondition::Establishment(stack_count) => *stack_count,
+                _ => 0,
+            })
+            .sum();
+
         // Emulating the game's behavior
-        let mut retained_cards = VecDeque::with_capacity(ctx.pcs.cards.hand.len());
-        while let Some(combat_card) = ctx.pcs.cards.hand.pop() {
+        let mut kept_cards = VecDeque::with_capacity(ctx.pcs.cards.hand.len());
+        while let Some(mut combat_card) = ctx.pcs.cards.hand.pop() {
             let hand_index = ctx.pcs.cards.hand.len();
-            if let Some(effect) = combat_card.details.on_linger.as_ref() {
-                ctx.effect_queue.push_back(Effect::Card(effect));
-            }
-            if combat_card.details.ethereal {
-                ExhaustSystem::push(ctx, hand_index, combat_card)?;
-            } else if combat_card.details.retain {
-                // TODO: Cost reduction on retain
-                retained_cards.push_front(combat_card);
+            if retain_hand || combat_card.details.retain || combat_card.retain_this_turn {
+                combat_card.retain_this_turn = false;
+                Self::on_card_retained(&mut combat_card, cost_reduction);
+                kept_cards.push_front(combat_card);
+            } else if keep_hand {
+                kept_cards.push_front(combat_card);
             } else {
                 Self::push(ctx, hand_index, combat_card)?;
             }
         }
-        ctx.pcs.cards.hand.extend(retained_cards.iter());
-        drop(retained_cards);
+        ctx.pcs.cards.hand.extend(kept_cards);
         for combat_card in ctx.pcs.cards.iter_mut() {
-            combat_card.cost_this_turn = combat_card.cost_this_combat;
+            combat_card.cost_this_turn = combat_card.cost_until_played;
+        }
+        Ok(())
+    }
+
+    /// Queues the end-of-turn effects of cards left in the player's hand (Burn, Regret, etc.)
+    fn queue_linger_effects<I: Interaction>(ctx: &mut CombatContext<I>) {
+        let hand_size = ctx.pcs.cards.hand.len();
+        for combat_card in ctx.pcs.cards.hand.iter().rev() {
+            match combat_card.details.on_linger.as_ref() {
+                // Regret counts the hand as it was before any cards left it.
+                Some(PlayerEffect::TakeDamage(Damage::HpLossEqualToHandSize)) => {
+                    ctx.effect_queue
+                        .add_to_bottom(Effect::PlayerState(PlayerEffect::TakeDamage(
+                            Damage::HpLoss(hand_size as Hp),
+                        )));
+                }
+                Some(effect) => ctx.effect_queue.add_to_bottom(Effect::Card(effect)),
+                None => {}
+            }
+        }
+    }
+
+    /// Exhausts every Ethereal card in the player's hand, regardless of Retain.
+    fn exhaust_ethereal_cards<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        for hand_index in (0..ctx.pcs.cards.hand.len()).rev() {
+            if ctx.pcs.cards.hand[hand_index].details.ethereal {
+                let combat_card = ctx.pcs.cards.hand.remove(hand_index);
+                ExhaustSystem::push(ctx, hand_index, combat_card)?;
+            }
         }
         Ok(())
     }
 
+    /// Lets the player pick cards to Retain this turn, one at a time, if Well-Laid Plans is
+    /// active.
+    fn choose_cards_to_retain<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        let retain_count: StackCount = ctx
+            .pcs
+            .conditions
+            .iter()
+            .map(|c| match c {
+                PlayerCondition::WellLaidPlans(stack_count) => *stack_count,
+                _ => 0,
+            })
+            .sum();
+        for _ in 0..retain_count {
+            let choices = ctx
+                .pcs
+                .cards
+                .hand
+                .iter()
+                .enumerate()
+                .filter(|(_, combat_card)| {
+                    !combat_card.details.retain && !combat_card.retain_this_turn
+                })
+                .map(|(hand_index, combat_card)| Choice::RetainCard(hand_index, combat_card.card))
+                .chain(once(Choice::Skip))
+                .collect::<Vec<_>>();
+            if choices.len() == 1 {
+                break;
+            }
+            match ctx
+                .comms
+                .prompt_for_choice(Prompt::ChooseCardsToRetain, &choices)?
+            {
+                Choice::RetainCard(hand_index, _) => {
+                    ctx.pcs.cards.hand[*hand_index].retain_this_turn = true;
+                }
+                Choice::Skip => break,
+                invalid => unreachable!("{:?}", invalid),
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies any card-specific bonuses for being retained, along with Establishment's cost
+    /// reduction.
+    fn on_card_retained(combat_card: &mut CardCombatState, cost_reduction: StackCount) {
+        match combat_card.card {
+            Card::Perserverance(upgraded) => {
+                combat_card.additional_block += if upgraded { 3 } else { 2 };
+            }
+            Card::SandsOfTime(_) => {
+                combat_card
+                    .set_cost_this_combat(Self::reduce_cost(combat_card.cost_this_combat, 1));
+            }
+            Card::WindmillStrike(upgraded) => {
+                combat_card.additional_damage += if upgraded { 5 } else { 4 };
+            }
+            _ => {}
+        }
+        combat_card.set_cost_this_combat(Self::reduce_cost(
+            combat_card.cost_this_combat,
+            cost_reduction,
+        ));
+    }
+
+    /// Lowers a fixed energy cost by the given amount, stopping at zero.
+    fn reduce_cost(energy_cost: EnergyCost, amount: StackCount) -> EnergyCost {
+        (0..amount).fold(energy_cost, |cost, _| match cost {
+            EnergyCost::One => EnergyCost::Zero,
+            EnergyCost::Two => EnergyCost::One,
+            EnergyCost::Three => EnergyCost::Two,
+            EnergyCost::Four => EnergyCost::Three,
+            EnergyCost::Five => EnergyCost::Four,
+            other => other,
+        })
+    }
+
     /// Discards the indicated card and notifies the player of the change.
     pub fn push<I: Interaction>(
         ctx: &mut CombatContext<I>,
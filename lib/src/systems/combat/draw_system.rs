@@ -7,6 +7,8 @@ use crate::data::{
 use crate::systems::base::{CombatContext, RelicSystem};
 use crate::types::DrawCount;
 
+use super::exhaust_system::ExhaustSystem;
+
 pub struct DrawSystem;
 
 impl DrawSystem {
@@ -17,20 +19,20 @@ impl DrawSystem {
         ctx.pcs
             .cards
             .draw_pile
-            .sort_by_key(|card| card.details.innate);
-        // Count the innate cards
+            .sort_by_key(|card| card.details.innate || card.bottled);
+        // Count the innate (and bottled) cards
         let innate_count = ctx
             .pcs
             .cards
             .draw_pile
             .iter()
-            .filter(|card| card.details.innate)
+            .filter(|card| card.details.innate || card.bottled)
             .count() as DrawCount;
         let cards_to_draw =
             5 + RelicSystem::extra_cards_to_draw_at_start_of_player_turn(ctx.pcs.pps);
         if innate_count > cards_to_draw {
             ctx.effect_queue
-                .push_back(Effect::PlayerState(PlayerEffect::Draw(
+                .add_to_bottom(Effect::PlayerState(PlayerEffect::Draw(
                     innate_count - cards_to_draw,
                 )));
         }
@@ -38,9 +40,15 @@ impl DrawSystem {
 
     /// Draws the appropriate number of cards at the start of the player's turn.
     pub fn on_player_turn_started<I: Interaction>(ctx: &mut CombatContext<I>) {
+        let draw_reduction =
+            ctx.pcs
+                .conditions
+                .iter()
+                .any(|c| matches!(c, PlayerCondition::DrawReduction(_))) as DrawCount;
         ctx.effect_queue
-            .push_back(Effect::PlayerState(PlayerEffect::Draw(
-                5 + RelicSystem::extra_cards_to_draw_at_start_of_player_turn(ctx.pcs.pps),
+            .add_to_bottom(Effect::PlayerState(PlayerEffect::Draw(
+                5 + RelicSystem::extra_cards_to_draw_at_start_of_player_turn(ctx.pcs.pps)
+                    - draw_reduction,
             )));
     }
 
@@ -48,10 +56,19 @@ impl DrawSystem {
     pub fn draw_one_card<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
         if ctx.pcs.conditions.contains(&PlayerCondition::NoDraw) || ctx.pcs.cards.hand.len() >= 10 {
             Ok(())
-        } else if let Some(card) = ctx.pcs.cards.draw_pile.pop() {
+        } else if let Some(card) = Self::take_top_card_of_draw_pile(ctx)? {
             Self::put_drawn_card_into_hand(ctx, card)
         } else {
-            // Shuffle discard pile into draw pile
+            Ok(())
+        }
+    }
+
+    /// Removes the top card of the draw pile, first shuffling the discard pile into the draw pile
+    /// if the draw pile is empty.
+    pub fn take_top_card_of_draw_pile<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+    ) -> Result<Option<CardCombatState>, Error> {
+        if ctx.pcs.cards.draw_pile.is_empty() {
             ctx.comms
                 .send_notification(Notification::ShufflingDiscardPileIntoDrawPile)?;
             ctx.shuffle_rng
@@ -60,12 +77,29 @@ impl DrawSystem {
                 .cards
                 .draw_pile
                 .append(&mut ctx.pcs.cards.discard_pile);
-            if let Some(card) = ctx.pcs.cards.draw_pile.pop() {
-                Self::put_drawn_card_into_hand(ctx, card)
-            } else {
-                Ok(())
-            }
         }
+        Ok(ctx.pcs.cards.draw_pile.pop())
+    }
+
+    /// Shuffles the discard pile into the draw pile, then shuffles the whole draw pile (Deep
+    /// Breath).
+    pub fn shuffle_discard_pile_into_draw_pile<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+    ) -> Result<(), Error> {
+        if ctx.pcs.cards.discard_pile.is_empty() {
+            return Ok(());
+        }
+        ctx.comms
+            .send_notification(Notification::ShufflingDiscardPileIntoDrawPile)?;
+        ctx.shuffle_rng
+            .java_compat_shuffle(&mut ctx.pcs.cards.discard_pile);
+        ctx.pcs
+            .cards
+            .draw_pile
+            .append(&mut ctx.pcs.cards.discard_pile);
+        ctx.shuffle_rng
+            .java_compat_shuffle(&mut ctx.pcs.cards.draw_pile);
+        Ok(())
     }
 
     /// Puts a drawn card into the player's hand.
@@ -77,13 +111,13 @@ impl DrawSystem {
         for condition in ctx.pcs.conditions.iter() {
             match condition {
                 PlayerCondition::Confused => {
-                    combat_card.cost_this_combat = *ctx.card_randomizer_rng.choose(&[
+                    combat_card.set_cost_this_combat(*ctx.card_randomizer_rng.choose(&[
                         EnergyCost::Zero,
                         EnergyCost::One,
                         EnergyCost::Two,
                         EnergyCost::Three,
-                    ]);
-                    combat_card.cost_this_turn = combat_card.cost_this_combat;
+                    ]));
+                    combat_card.cost_this_turn = combat_card.cost_until_played;
                 }
                 PlayerCondition::Corruption => {
                     if matches!(combat_card.details.type_, CardType::Skill) {
@@ -93,7 +127,7 @@ impl DrawSystem {
                 PlayerCondition::Evolve(draw_count) => {
                     if matches!(combat_card.details.type_, CardType::Status) {
                         ctx.effect_queue
-                            .push_back(Effect::PlayerState(PlayerEffect::Draw(*draw_count)));
+                            .add_to_bottom(Effect::PlayerState(PlayerEffect::Draw(*draw_count)));
                     }
                 }
                 PlayerCondition::FireBreathing(hp) => {
@@ -101,7 +135,7 @@ impl DrawSystem {
                         combat_card.details.type_,
                         CardType::Status | CardType::Curse
                     ) {
-                        ctx.effect_queue.push_back(Effect::PlayerState(
+                        ctx.effect_queue.add_to_bottom(Effect::PlayerState(
                             PlayerEffect::ToAllEnemies(TargetEffect::Deal(
                                 Damage::BlockableNonAttack(*hp),
                             )),
@@ -113,10 +147,10 @@ impl DrawSystem {
             }
         }
         if let Some(effect) = combat_card.details.on_draw.as_ref() {
-            ctx.effect_queue.push_back(Effect::Card(effect));
+            ctx.effect_queue.add_to_bottom(Effect::Card(effect));
         }
-        if combat_card.card == Card::Normality {
-            // TODO: Implement normality counter
+        if matches!(combat_card.card, Card::DeusExMachina(_)) {
+            return ExhaustSystem::push(ctx, ctx.pcs.cards.hand.len(), combat_card);
         }
         ctx.pcs.cards.hand.push(combat_card);
         ctx.comms.send_notification(Notification::CardDrawn(
@@ -1,26 +1,89 @@
 use anyhow::Error;
 
 use crate::components::{Effect, Interaction, Notification};
-use crate::systems::base::CombatContext;
+use crate::data::{EnemyCondition, PlayerEffect, TargetCondition, TargetEffect};
+use crate::systems::base::{CombatContext, GoldSystem};
+use crate::types::EnemyIndex;
 
 use super::enemy_condition_system::EnemyConditionSystem;
 use super::enemy_effect_system::EnemyEffectSystem;
+use super::player_condition_system::PlayerConditionSystem;
 use super::player_effect_system::PlayerEffectSystem;
 
 pub struct EffectSystem;
 
 impl EffectSystem {
-    /// Processes all effects in the queue.
+    /// Processes all effects in the queue, including any follow-up effects they queue. Once the
+    /// combat is over, the rest of the card being played is cancelled, as in the game.
     pub fn process_effect_queue<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        while let Some(effect) = ctx.effect_queue.pop_front() {
+            Self::process_effect(ctx, effect)?;
+            if ctx.combat_should_end() && !Self::rewards_fatal_blow(ctx.effect_queue.front()) {
+                Self::cancel_unfinished_actions(ctx);
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Queues and resolves the acting enemy's next action. If the enemy dies partway through
+    /// (e.g. to Thorns), the rest of its action is abandoned, but effects triggered along the way
+    /// (such as its death) still resolve.
+    pub fn process_enemy_action<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        enemy_index: EnemyIndex,
+    ) -> Result<(), Error> {
+        let Some(enemy_action) = ctx.enemy_party.0[enemy_index]
+            .as_ref()
+            .filter(|e| !e.spawned_this_turn)
+            .map(|e| e.next_action)
+        else {
+            return Ok(());
+        };
+        for effect in enemy_action.effect_chain().iter() {
+            ctx.effect_queue
+                .add_to_bottom(Effect::EnemyPlaybook(effect));
+        }
         while let Some(effect) = ctx.effect_queue.pop_front() {
             Self::process_effect(ctx, effect)?;
             if ctx.combat_should_end() {
+                Self::cancel_unfinished_actions(ctx);
                 break;
             }
+            if !ctx.enemy_party.0[enemy_index]
+                .as_ref()
+                .is_some_and(|enemy_state| enemy_state.is_standing())
+            {
+                ctx.effect_queue
+                    .retain(|effect| !matches!(effect, Effect::EnemyPlaybook(_)));
+            }
         }
         Ok(())
     }
 
+    /// Drops what is left of the card being played and of the acting enemy's action once the
+    /// combat is over, or everything if the player died, as the game does. Anything else still
+    /// pending is left for `EffectQueue::ensure_empty` to report.
+    fn cancel_unfinished_actions<I: Interaction>(ctx: &mut CombatContext<I>) {
+        if ctx.pcs.pps.hp == 0 {
+            ctx.effect_queue.clear();
+        } else {
+            ctx.effect_queue
+                .retain(|effect| !matches!(effect, Effect::Card(_) | Effect::EnemyPlaybook(_)));
+        }
+    }
+
+    /// Returns true iff the effect pays out for a killing blow (e.g. Feed, Lesson Learned), which
+    /// must still resolve when that blow ends the combat.
+    fn rewards_fatal_blow(maybe_effect: Option<&Effect>) -> bool {
+        matches!(
+            maybe_effect,
+            Some(Effect::Card(PlayerEffect::ToSingleTarget(
+                TargetEffect::Conditional(TargetCondition::AttackWasFatal, _)
+            )))
+        )
+    }
+
     /// Handles the incoming effect (PlayerEffect or EnemyEffect).
     pub fn process_effect<I: Interaction>(
         ctx: &mut CombatContext<I>,
@@ -40,24 +103,96 @@ impl EffectSystem {
                 PlayerEffectSystem::process_player_effect(ctx, &player_effect)?;
             }
         }
-        if let Some(enemy_index) = ctx.maybe_enemy_index {
-            let should_remove_enemy =
-                if let Some(enemy_state) = ctx.enemy_party.0[enemy_index].as_mut() {
-                    if enemy_state.is_dead() {
-                        EnemyConditionSystem::on_enemy_death(enemy_state, &mut ctx.effect_queue);
-                        ctx.comms.send_notification(Notification::EnemyDied(
-                            enemy_index,
-                            enemy_state.enemy,
-                        ))?;
-                        true
-                    } else {
-                        false
+        Self::resolve_enemy_deaths(ctx)?;
+        Ok(())
+    }
+
+    /// Clears away every enemy whose HP has run out, firing its death triggers and recovering any
+    /// Gold it stole. An enemy that rises again (e.g. a Darkling while its kin stand) lies
+    /// half-dead in its slot instead, until the last of its kin falls.
+    pub fn resolve_enemy_deaths<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        let mut any_removed = false;
+        for enemy_index in 0..ctx.enemy_party.0.len() {
+            let Some(enemy) = ctx.enemy_party.0[enemy_index]
+                .as_ref()
+                .filter(|enemy_state| enemy_state.is_dead() && !enemy_state.half_dead)
+                .map(|enemy_state| enemy_state.enemy)
+            else {
+                continue;
+            };
+            let kin_standing = ctx
+                .enemy_party
+                .0
+                .iter()
+                .enumerate()
+                .any(|(i, maybe_enemy)| {
+                    i != enemy_index
+                        && maybe_enemy
+                            .as_ref()
+                            .is_some_and(|e| e.enemy == enemy && e.is_standing())
+                });
+            let Some(enemy_state) = ctx.enemy_party.0[enemy_index].as_mut() else {
+                continue;
+            };
+            EnemyConditionSystem::on_enemy_death(enemy_state, &mut ctx.effect_queue);
+            if enemy_state.lie_half_dead(kin_standing) {
+                continue;
+            }
+            // A thief that is killed drops the Gold it stole.
+            for enemy_condition in &enemy_state.conditions {
+                if let EnemyCondition::Thievery(_, gold_stolen) = enemy_condition {
+                    GoldSystem::increase_gold(ctx.comms, ctx.pcs.pps, *gold_stolen)?;
+                }
+            }
+            ctx.comms
+                .send_notification(Notification::EnemyDied(enemy_index, enemy))?;
+            if let Some(combat_card) = ctx
+                .enemy_party
+                .despawn(enemy_index)
+                .and_then(|enemy_state| enemy_state.stasis)
+            {
+                EnemyConditionSystem::return_card_in_stasis(ctx, combat_card)?;
+            }
+            any_removed = true;
+            if !kin_standing {
+                for kin_index in 0..ctx.enemy_party.0.len() {
+                    if ctx.enemy_party.0[kin_index]
+                        .as_ref()
+                        .is_some_and(|e| e.enemy == enemy && e.half_dead)
+                    {
+                        ctx.enemy_party.despawn(kin_index);
+                        ctx.comms
+                            .send_notification(Notification::EnemyDied(kin_index, enemy))?;
                     }
-                } else {
-                    false
-                };
-            if should_remove_enemy {
-                ctx.enemy_party.0[enemy_index] = None;
+                }
+            }
+        }
+        if any_removed {
+            Self::dismiss_leaderless_minions(ctx)?;
+            PlayerConditionSystem::on_enemy_removed(ctx)?;
+        }
+        Ok(())
+    }
+
+    /// Minions don't outlast their leader: once only minions remain, they all leave the fight.
+    fn dismiss_leaderless_minions<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        if ctx
+            .enemy_party
+            .0
+            .iter()
+            .flatten()
+            .any(|enemy_state| !enemy_state.conditions.contains(&EnemyCondition::Minion))
+        {
+            return Ok(());
+        }
+        for enemy_index in 0..ctx.enemy_party.0.len() {
+            if let Some(mut enemy_state) = ctx.enemy_party.despawn(enemy_index) {
+                EnemyConditionSystem::on_enemy_death(&mut enemy_state, &mut ctx.effect_queue);
+                ctx.comms
+                    .send_notification(Notification::EnemyDied(enemy_index, enemy_state.enemy))?;
+                if let Some(combat_card) = enemy_state.stasis {
+                    EnemyConditionSystem::return_card_in_stasis(ctx, combat_card)?;
+                }
             }
         }
         Ok(())
@@ -1,21 +1,95 @@
-use crate::components::Interaction;
+use anyhow::Error;
+
+use crate::components::{EnemyStatus, Interaction, Notification};
+use crate::data::Relic;
 use crate::systems::base::CombatContext;
+use crate::types::EnemyIndex;
 
 use super::block_system::BlockSystem;
+use super::effect_system::EffectSystem;
 use super::enemy_condition_system::EnemyConditionSystem;
+use super::player_condition_system::PlayerConditionSystem;
 
 pub struct EnemyCombatSystem;
 
 impl EnemyCombatSystem {
-    pub fn on_enemies_turn_started<I: Interaction>(ctx: &mut CombatContext<I>) {
+    /// Sends the player a snapshot of every slot in the enemy party, with intents showing the
+    /// damage the player would currently take. Runic Dome keeps the enemies' intents out of it.
+    pub fn notify_player<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        let intents_hidden = ctx.pcs.pps.relics.contains(&Relic::RunicDome);
+        ctx.comms.send_notification(Notification::EnemyParty(
+            ctx.enemy_party
+                .0
+                .iter()
+                .map(|enemy| {
+                    enemy.as_ref().map(|enemy| {
+                        let mut enemy_status = EnemyStatus::from((enemy, &ctx.pcs));
+                        if intents_hidden {
+                            enemy_status.intent = None;
+                        }
+                        enemy_status
+                    })
+                })
+                .collect(),
+        ))
+    }
+
+    /// Announces the enemies that just joined the fight, then refreshes the player's view of the
+    /// party.
+    pub fn on_enemies_spawned<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        enemy_indices: &[EnemyIndex],
+    ) -> Result<(), Error> {
+        if enemy_indices.is_empty() {
+            return Ok(());
+        }
+        for &enemy_index in enemy_indices {
+            if let Some(enemy_state) = ctx.enemy_party.0[enemy_index].as_ref() {
+                ctx.comms.send_notification(Notification::EnemySpawned(
+                    enemy_index,
+                    enemy_state.enemy,
+                ))?;
+            }
+        }
+        Self::notify_player(ctx)
+    }
+
+    /// Lets the enemy flee the fight, taking any Gold it stole with it, then refreshes the
+    /// player's view of the party.
+    pub fn on_enemy_escaped<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        enemy_index: EnemyIndex,
+    ) -> Result<(), Error> {
+        let Some(enemy_state) = ctx.enemy_party.despawn(enemy_index) else {
+            return Ok(());
+        };
+        ctx.comms
+            .send_notification(Notification::EnemyEscaped(enemy_index, enemy_state.enemy))?;
+        PlayerConditionSystem::on_enemy_removed(ctx)?;
+        Self::notify_player(ctx)
+    }
+
+    pub fn on_enemies_turn_started<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+    ) -> Result<(), Error> {
         EnemyConditionSystem::on_enemies_turn_started(ctx);
         BlockSystem::on_enemies_turn_started(ctx);
+        EffectSystem::resolve_enemy_deaths(ctx)
     }
 
-    pub fn on_enemies_turn_finished<I: Interaction>(ctx: &mut CombatContext<I>) {
-        EnemyConditionSystem::on_enemies_turn_finished(ctx);
+    pub fn on_enemies_turn_finished<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+    ) -> Result<(), Error> {
+        let battlefield = ctx.battlefield();
+        // As in the game, enemies pick their next moves before their end-of-turn powers trigger.
         for enemy_state in ctx.enemy_party.0.iter_mut().filter_map(|e| e.as_mut()) {
-            enemy_state.advance_action(&mut ctx.enemy_rng);
+            if enemy_state.spawned_this_turn {
+                enemy_state.spawned_this_turn = false;
+            } else {
+                enemy_state.advance_action(&mut ctx.enemy_rng, &battlefield);
+            }
         }
+        EnemyConditionSystem::on_enemies_turn_finished(ctx);
+        EffectSystem::resolve_enemy_deaths(ctx)
     }
 }
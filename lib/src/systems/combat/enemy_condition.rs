@@ -1,24 +1,167 @@
-use crate::components::{DamageTaken, Effect, EffectQueue};
-use crate::data::{Damage, EnemyCondition, EnemyEffect, PlayerCondition};
-use crate::types::{Block, Strength};
+use crate::components::{CardCombatState, DamageTaken, Effect, EffectQueue};
+use crate::data::{
+    Card, CardDestination, CardPool, CardSelection, CardType, CostModifier, Damage, EnemyCondition,
+    EnemyEffect, PlayerCondition, PlayerEffect, Resource,
+};
+use crate::types::{Block, Hp, HpMax, Strength};
 
 impl EnemyCondition {
     /// Attempts to merge the supplied condition into self, returning true iff the conditions
     /// were merged.
     pub fn merge(&mut self, other: &Self) -> bool {
         match other {
+            EnemyCondition::Angry(incoming_strength) => {
+                if let EnemyCondition::Angry(strength) = self {
+                    *strength += incoming_strength;
+                    return true;
+                }
+            }
+            EnemyCondition::Artifact(incoming_stacks) => {
+                if let EnemyCondition::Artifact(stacks) = self {
+                    *stacks += incoming_stacks;
+                    return true;
+                }
+            }
+            EnemyCondition::BackAttack => {
+                if let EnemyCondition::BackAttack = self {
+                    return true;
+                }
+            }
+            EnemyCondition::Barricade => {
+                if let EnemyCondition::Barricade = self {
+                    return true;
+                }
+            }
+            EnemyCondition::BeatOfDeath(incoming_hp) => {
+                if let EnemyCondition::BeatOfDeath(hp) = self {
+                    *hp += incoming_hp;
+                    return true;
+                }
+            }
+            EnemyCondition::BlockReturn(incoming_block) => {
+                if let EnemyCondition::BlockReturn(block) = self {
+                    *block += incoming_block;
+                    return true;
+                }
+            }
             EnemyCondition::CurlUp(incoming_block) => {
                 if let EnemyCondition::CurlUp(block) = self {
                     *block += incoming_block;
                     return true;
                 }
             }
+            EnemyCondition::Curiosity(incoming_strength) => {
+                if let EnemyCondition::Curiosity(strength) = self {
+                    *strength += incoming_strength;
+                    return true;
+                }
+            }
             EnemyCondition::Enrage(incoming_strength) => {
                 if let EnemyCondition::Enrage(strength) = self {
                     *strength += incoming_strength;
                     return true;
                 }
             }
+            EnemyCondition::Explosive(_) => {
+                if let EnemyCondition::Explosive(_) = self {
+                    return true;
+                }
+            }
+            EnemyCondition::Fading(incoming_turns) => {
+                if let EnemyCondition::Fading(turns) = self {
+                    *turns += incoming_turns;
+                    return true;
+                }
+            }
+            EnemyCondition::Flight(incoming_stacks, incoming_base) => {
+                if let EnemyCondition::Flight(stacks, base) = self {
+                    *stacks += incoming_stacks;
+                    *base += incoming_base;
+                    return true;
+                }
+            }
+            EnemyCondition::Intangible(_, _) => {
+                // Nemesis only ever renews its Intangible once the last one has worn off.
+                if let EnemyCondition::Intangible(_, _) = self {
+                    return true;
+                }
+            }
+            EnemyCondition::Invincible(incoming_hp, incoming_base) => {
+                if let EnemyCondition::Invincible(hp, base) = self {
+                    *hp = *incoming_hp;
+                    *base = *incoming_base;
+                    return true;
+                }
+            }
+            EnemyCondition::LockOn(incoming_turns) => {
+                if let EnemyCondition::LockOn(turns) = self {
+                    *turns += incoming_turns;
+                    return true;
+                }
+            }
+            EnemyCondition::Malleable(incoming_block, incoming_base) => {
+                if let EnemyCondition::Malleable(block, base) = self {
+                    *block += incoming_block;
+                    *base += incoming_base;
+                    return true;
+                }
+            }
+            EnemyCondition::Mark(incoming_stacks) => {
+                if let EnemyCondition::Mark(stacks) = self {
+                    *stacks += incoming_stacks;
+                    return true;
+                }
+            }
+            EnemyCondition::Metallicize(incoming_block) => {
+                if let EnemyCondition::Metallicize(block) = self {
+                    *block += incoming_block;
+                    return true;
+                }
+            }
+            EnemyCondition::Minion => {
+                if let EnemyCondition::Minion = self {
+                    return true;
+                }
+            }
+            EnemyCondition::ModeShift(incoming_hp) => {
+                if let EnemyCondition::ModeShift(hp) = self {
+                    *hp = *incoming_hp;
+                    return true;
+                }
+            }
+            EnemyCondition::PainfulStabs => {
+                if let EnemyCondition::PainfulStabs = self {
+                    return true;
+                }
+            }
+            EnemyCondition::PlatedArmor(incoming_block) => {
+                if let EnemyCondition::PlatedArmor(block) = self {
+                    *block += incoming_block;
+                    return true;
+                }
+            }
+            EnemyCondition::Poison(incoming_stacks) => {
+                if let EnemyCondition::Poison(stacks) = self {
+                    *stacks += incoming_stacks;
+                    return true;
+                }
+            }
+            EnemyCondition::Reactive => {
+                if let EnemyCondition::Reactive = self {
+                    return true;
+                }
+            }
+            EnemyCondition::Regenerate(incoming_hp) => {
+                if let EnemyCondition::Regenerate(hp) = self {
+                    *hp += incoming_hp;
+                    return true;
+                }
+            }
+            EnemyCondition::Regrow => {
+                if let EnemyCondition::Regrow = self {
+                    return true;
+                }
+            }
             EnemyCondition::Ritual(incoming_strength, incoming_just_applied) => {
                 if let EnemyCondition::Ritual(strength, just_applied) = self {
                     *strength += incoming_strength;
@@ -26,24 +169,69 @@ impl EnemyCondition {
                     return true;
                 }
             }
+            EnemyCondition::SharpHide(incoming_hp) => {
+                if let EnemyCondition::SharpHide(hp) = self {
+                    *hp += incoming_hp;
+                    return true;
+                }
+            }
+            EnemyCondition::Shifting => {
+                if let EnemyCondition::Shifting = self {
+                    return true;
+                }
+            }
+            EnemyCondition::Slow(incoming_stacks) => {
+                if let EnemyCondition::Slow(stacks) = self {
+                    *stacks += incoming_stacks;
+                    return true;
+                }
+            }
+            EnemyCondition::Split => {
+                if let EnemyCondition::Split = self {
+                    return true;
+                }
+            }
             EnemyCondition::SporeCloud(incoming_stacks) => {
                 if let EnemyCondition::SporeCloud(stacks) = self {
                     *stacks += incoming_stacks;
                     return true;
                 }
             }
+            EnemyCondition::Stasis(_) => {} // Each enemy holds on to its own card.
             EnemyCondition::StrengthLossThisTurn(incoming_strength) => {
                 if let EnemyCondition::StrengthLossThisTurn(strength) = self {
                     *strength += incoming_strength;
                     return true;
                 }
             }
+            EnemyCondition::StrengthUp(incoming_strength) => {
+                if let EnemyCondition::StrengthUp(strength) = self {
+                    *strength += incoming_strength;
+                    return true;
+                }
+            }
+            EnemyCondition::Thievery(_, incoming_gold_stolen) => {
+                if let EnemyCondition::Thievery(_, gold_stolen) = self {
+                    *gold_stolen += incoming_gold_stolen;
+                    return true;
+                }
+            }
             EnemyCondition::Thorns(incoming_hp) => {
                 if let EnemyCondition::Thorns(hp) = self {
                     *hp += incoming_hp;
                     return true;
                 }
             }
+            EnemyCondition::TimeWarp(_) => {
+                if let EnemyCondition::TimeWarp(_) = self {
+                    return true;
+                }
+            }
+            EnemyCondition::Unawakened => {
+                if let EnemyCondition::Unawakened = self {
+                    return true;
+                }
+            }
             EnemyCondition::Vulnerable(incoming_turns) => {
                 if let EnemyCondition::Vulnerable(turns) = self {
                     *turns += incoming_turns;
@@ -61,16 +249,93 @@ impl EnemyCondition {
         false
     }
 
+    /// Returns true iff the condition is a debuff, and so can be negated by Artifact.
+    pub fn is_debuff(&self) -> bool {
+        matches!(
+            self,
+            EnemyCondition::LockOn(_)
+                | EnemyCondition::Mark(_)
+                | EnemyCondition::Poison(_)
+                | EnemyCondition::Slow(_)
+                | EnemyCondition::StrengthLossThisTurn(_)
+                | EnemyCondition::Vulnerable(_)
+                | EnemyCondition::Weak(_)
+        )
+    }
+
     /// Ticks down a condition's turn counter at the start of the enemies' turn.
     /// Returns true iff the condition is still active.
     pub fn on_turn_started(&mut self) -> bool {
-        true
+        match self {
+            EnemyCondition::Flight(stacks, base) => {
+                *stacks = *base;
+                true
+            }
+            EnemyCondition::Invincible(hp, base) => {
+                *hp = *base;
+                true
+            }
+            EnemyCondition::Poison(stacks) => {
+                // The HP loss itself is dealt by the EnemyConditionSystem, before ticking down.
+                *stacks = stacks.saturating_sub(1);
+                *stacks > 0
+            }
+            _ => true,
+        }
     }
 
     /// Ticks down the conditions at the end of the enemies' turn.
     /// Returns true iff the condition is still active.
-    pub fn on_turn_finished(&mut self, enemy_strength: &mut Strength) -> bool {
+    pub fn on_turn_finished(
+        &mut self,
+        enemy_hp: &mut Hp,
+        enemy_hp_max: HpMax,
+        enemy_strength: &mut Strength,
+        enemy_block: &mut Block,
+    ) -> bool {
         match self {
+            EnemyCondition::Explosive(turns) => {
+                // The explosion itself is the Exploder's last move.
+                *turns = turns.saturating_sub(1).max(1);
+                true
+            }
+            EnemyCondition::Fading(turns) => {
+                if *turns <= 1 {
+                    *enemy_hp = 0;
+                    false
+                } else {
+                    *turns -= 1;
+                    true
+                }
+            }
+            EnemyCondition::Intangible(turns, just_applied) => {
+                if *just_applied {
+                    *just_applied = false;
+                    true
+                } else {
+                    *turns = turns.saturating_sub(1);
+                    *turns > 0
+                }
+            }
+            EnemyCondition::LockOn(turns) => {
+                *turns = turns.saturating_sub(1);
+                *turns > 0
+            }
+            EnemyCondition::Malleable(block, base) => {
+                *block = *base;
+                true
+            }
+            EnemyCondition::Metallicize(block) | EnemyCondition::PlatedArmor(block) => {
+                *enemy_block = enemy_block.saturating_add(*block);
+                true
+            }
+            EnemyCondition::Regenerate(hp) => {
+                // A half-dead enemy has to rise again before it can heal.
+                if *enemy_hp > 0 {
+                    *enemy_hp = enemy_hp.saturating_add(*hp).min(enemy_hp_max);
+                }
+                true
+            }
             EnemyCondition::Ritual(strength, just_applied) => {
                 if *just_applied {
                     *just_applied = false;
@@ -79,6 +344,18 @@ impl EnemyCondition {
                 }
                 true
             }
+            EnemyCondition::Slow(stacks) => {
+                *stacks = 0;
+                true
+            }
+            EnemyCondition::StrengthLossThisTurn(strength) => {
+                *enemy_strength += *strength;
+                false
+            }
+            EnemyCondition::StrengthUp(strength) => {
+                *enemy_strength += *strength;
+                true
+            }
             EnemyCondition::Vulnerable(turns) => {
                 *turns = turns.saturating_sub(1);
                 *turns > 0
@@ -95,16 +372,56 @@ impl EnemyCondition {
     pub fn on_damage_taken(
         &mut self,
         enemy_block: &mut Block,
+        enemy_strength: &mut Strength,
         damage_taken: &DamageTaken,
         effect_queue: &mut EffectQueue,
     ) -> bool {
         match self {
-            EnemyCondition::CurlUp(block) => {
+            EnemyCondition::Angry(strength)
+                if damage_taken.provokes_thorns && damage_taken.hp_lost > 0 =>
+            {
+                *enemy_strength += *strength;
+                true
+            }
+            EnemyCondition::BlockReturn(block) if damage_taken.provokes_thorns => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Gain(
+                    Resource::Block(*block),
+                )));
+                true
+            }
+            EnemyCondition::CurlUp(block)
+                if damage_taken.provokes_thorns && damage_taken.hp_lost > 0 =>
+            {
                 *enemy_block = enemy_block.saturating_add(*block);
                 false
             }
+            EnemyCondition::Flight(stacks, _)
+                if damage_taken.provokes_thorns && damage_taken.hp_lost > 0 =>
+            {
+                *stacks = stacks.saturating_sub(1);
+                *stacks > 0
+            }
+            EnemyCondition::Malleable(block, _)
+                if damage_taken.provokes_thorns && damage_taken.hp_lost > 0 =>
+            {
+                *enemy_block = enemy_block.saturating_add(*block);
+                *block += 1;
+                true
+            }
+            EnemyCondition::PlatedArmor(block)
+                if damage_taken.provokes_thorns && damage_taken.hp_lost > 0 =>
+            {
+                *block = block.saturating_sub(1);
+                *block > 0
+            }
+            EnemyCondition::Reactive
+                if damage_taken.provokes_thorns && damage_taken.hp_lost > 0 =>
+            {
+                effect_queue.add_to_bottom(Effect::EnemyState(EnemyEffect::RerollIntent));
+                true
+            }
             EnemyCondition::Thorns(hp) if damage_taken.provokes_thorns => {
-                effect_queue.push_front(Effect::EnemyState(EnemyEffect::Deal(
+                effect_queue.add_to_top(Effect::EnemyState(EnemyEffect::Deal(
                     Damage::BlockableNonAttack(*hp),
                 )));
                 true
@@ -113,11 +430,72 @@ impl EnemyCondition {
         }
     }
 
+    /// Queues any effects triggered by the enemy's attack costing the player HP.
+    pub fn on_attack_damage_dealt(&mut self, effect_queue: &mut EffectQueue) -> bool {
+        match self {
+            EnemyCondition::PainfulStabs => {
+                effect_queue.add_to_bottom(Effect::EnemyState(EnemyEffect::CreateCards(
+                    CardPool::Fixed(&[Card::Wound]),
+                    CardSelection::All,
+                    CardDestination::DiscardPile,
+                    CostModifier::None,
+                )));
+                true
+            }
+            _ => true,
+        }
+    }
+
+    /// Reacts to the player playing a card, queueing any resulting effects.
+    pub fn on_some_card_played(
+        &mut self,
+        enemy_strength: &mut Strength,
+        combat_card: &CardCombatState,
+        effect_queue: &mut EffectQueue,
+    ) -> bool {
+        match self {
+            EnemyCondition::BeatOfDeath(hp) => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::TakeDamage(
+                    Damage::BlockableNonAttack(*hp),
+                )));
+                true
+            }
+            EnemyCondition::Curiosity(strength) if combat_card.details.type_ == CardType::Power => {
+                *enemy_strength += *strength;
+                true
+            }
+            EnemyCondition::Enrage(strength) if combat_card.details.type_ == CardType::Skill => {
+                *enemy_strength += *strength;
+                true
+            }
+            EnemyCondition::SharpHide(hp) if combat_card.details.type_ == CardType::Attack => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::TakeDamage(
+                    Damage::BlockableNonAttack(*hp),
+                )));
+                true
+            }
+            EnemyCondition::Slow(stacks) => {
+                *stacks += 1;
+                true
+            }
+            EnemyCondition::TimeWarp(card_count) => {
+                *card_count += 1;
+                if *card_count >= 12 {
+                    *card_count = 0;
+                    *enemy_strength += 2;
+                    effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::EndTurn));
+                }
+                true
+            }
+            _ => true,
+        }
+    }
+
     /// Queues any effects triggered by the enemy dying.
     pub fn on_death(&mut self, effect_queue: &mut EffectQueue) -> bool {
         match self {
             EnemyCondition::SporeCloud(stacks) => {
-                effect_queue.push_front(Effect::EnemyState(EnemyEffect::Inflict(
+                effect_queue.add_to_top(Effect::EnemyState(EnemyEffect::Inflict(
                     PlayerCondition::Vulnerable(*stacks),
                 )));
                 true
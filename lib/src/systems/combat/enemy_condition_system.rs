@@ -1,44 +1,57 @@
-use crate::components::{DamageTaken, EffectQueue, Interaction};
-use crate::data::EnemyCondition;
+use anyhow::Error;
+
+use crate::components::{CardCombatState, DamageTaken, EffectQueue, Interaction, Notification};
+use crate::data::{CardDestination, EnemyCondition};
 use crate::systems::base::{CombatContext, EnemyState};
+use crate::types::Strength;
+
+use super::block_system::BlockSystem;
+use super::damage_calculator::CalculatedDamage;
 
 pub struct EnemyConditionSystem;
 
 impl EnemyConditionSystem {
-    /// Ticks down the conditions at the start of the enemies' turn.
+    /// Ticks down the conditions at the start of the enemies' turn, after Poison has taken its
+    /// toll. Any enemy poisoned to death is left for the caller to clear away.
     pub fn on_enemies_turn_started<I: Interaction>(ctx: &mut CombatContext<I>) {
-        for maybe_enemy in ctx.enemy_party.0.iter_mut() {
-            let enemy_died = if let Some(enemy_state) = maybe_enemy {
-                enemy_state.conditions.retain_mut(|c| c.on_turn_started());
-                enemy_state.is_dead()
-            } else {
-                false
-            };
-            if enemy_died {
-                *maybe_enemy = None;
+        for enemy_state in ctx.enemy_party.0.iter_mut().flatten() {
+            if let Some(stacks) = enemy_state.conditions.iter().find_map(|c| match c {
+                EnemyCondition::Poison(stacks) => Some(*stacks),
+                _ => None,
+            }) {
+                BlockSystem::damage_enemy(
+                    enemy_state,
+                    CalculatedDamage::HpLoss(stacks),
+                    &mut ctx.effect_queue,
+                );
             }
+            enemy_state.conditions.retain_mut(|c| c.on_turn_started());
         }
     }
 
-    /// Ticks down the conditions at the end of the enemies' turn.
+    /// Ticks down the conditions at the end of the enemies' turn. Any enemy that dies as a
+    /// result (e.g. the Transient fading away) is left for the caller to clear away.
     pub fn on_enemies_turn_finished<I: Interaction>(ctx: &mut CombatContext<I>) {
-        for maybe_enemy in ctx.enemy_party.0.iter_mut() {
-            let enemy_died = if let Some(enemy) = maybe_enemy {
-                enemy
-                    .conditions
-                    .retain_mut(|c| c.on_turn_finished(&mut enemy.strength));
-                enemy.is_dead()
-            } else {
-                false
-            };
-            if enemy_died {
-                *maybe_enemy = None;
-            }
+        for enemy in ctx.enemy_party.0.iter_mut().flatten() {
+            enemy.conditions.retain_mut(|c| {
+                c.on_turn_finished(
+                    &mut enemy.hp,
+                    enemy.hp_max,
+                    &mut enemy.strength,
+                    &mut enemy.block,
+                )
+            });
         }
     }
 
-    /// Applies a condition to an enemy.
+    /// Applies a condition to an enemy, unless it is a debuff negated by Artifact.
     pub fn apply_to_enemy(enemy: &mut EnemyState, condition: &EnemyCondition) {
+        if condition.is_debuff() && Self::negate_debuff(enemy) {
+            return;
+        }
+        if let EnemyCondition::StrengthLossThisTurn(strength) = condition {
+            enemy.strength -= strength;
+        }
         for preexisting_condition in enemy.conditions.iter_mut() {
             if preexisting_condition.merge(condition) {
                 return;
@@ -47,15 +60,80 @@ impl EnemyConditionSystem {
         enemy.conditions.push(condition.clone());
     }
 
+    /// Consumes a stack of the enemy's Artifact, if it has any, returning true iff a debuff was
+    /// negated.
+    pub fn negate_debuff(enemy: &mut EnemyState) -> bool {
+        let mut negated = false;
+        enemy.conditions.retain_mut(|c| match c {
+            EnemyCondition::Artifact(stacks) if !negated => {
+                negated = true;
+                *stacks -= 1;
+                *stacks > 0
+            }
+            _ => true,
+        });
+        negated
+    }
+
     /// Queues any effects triggered by the enemy taking damage.
     pub fn on_damage_taken(
         enemy: &mut EnemyState,
         damage_taken: &DamageTaken,
         effect_queue: &mut EffectQueue,
     ) {
+        enemy.conditions.retain_mut(|c| {
+            c.on_damage_taken(
+                &mut enemy.block,
+                &mut enemy.strength,
+                damage_taken,
+                effect_queue,
+            )
+        });
+        if damage_taken.hp_lost > 0 && enemy.conditions.contains(&EnemyCondition::Shifting) {
+            Self::apply_to_enemy(
+                enemy,
+                &EnemyCondition::StrengthLossThisTurn(damage_taken.hp_lost as Strength),
+            );
+        }
+    }
+
+    /// Queues any effects triggered by the enemy's attack costing the player HP.
+    pub fn on_attack_damage_dealt(enemy: &mut EnemyState, effect_queue: &mut EffectQueue) {
         enemy
             .conditions
-            .retain_mut(|c| c.on_damage_taken(&mut enemy.block, damage_taken, effect_queue));
+            .retain_mut(|c| c.on_attack_damage_dealt(effect_queue));
+    }
+
+    /// Lets every enemy's conditions react to the player playing a card.
+    pub fn on_some_card_played<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        combat_card: &CardCombatState,
+    ) {
+        for enemy in ctx.enemy_party.0.iter_mut().flatten() {
+            enemy.conditions.retain_mut(|c| {
+                c.on_some_card_played(&mut enemy.strength, combat_card, &mut ctx.effect_queue)
+            });
+        }
+    }
+
+    /// Returns the card an enemy held in Stasis to the player's hand, or to the discard pile if
+    /// the hand is full. It is the same card that was taken, with any changes made to it intact.
+    pub fn return_card_in_stasis<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        combat_card: CardCombatState,
+    ) -> Result<(), Error> {
+        let card_destination = if ctx.pcs.cards.hand.len() < 10 {
+            ctx.pcs.cards.hand.push(combat_card);
+            CardDestination::Hand
+        } else {
+            ctx.pcs.cards.discard_pile.push(combat_card);
+            CardDestination::DiscardPile
+        };
+        ctx.comms
+            .send_notification(Notification::CardReturnedFromStasis(
+                combat_card,
+                card_destination,
+            ))
     }
 
     /// Triggers effects from the enemy's conditions when it dies.
@@ -1,12 +1,14 @@
 use anyhow::Error;
 
-use crate::components::Interaction;
-use crate::data::{EnemyEffect, Resource};
-use crate::systems::base::CombatContext;
+use crate::components::{Interaction, Notification};
+use crate::data::{Card, CardRarity, Enemy, EnemyCondition, EnemyEffect, Resource};
+use crate::systems::base::{CombatContext, DeckSystem, EnemyState, GoldSystem};
+use crate::types::{Block, Hp};
 
 use super::block_system::BlockSystem;
 use super::card_creation_system::CardCreationSystem;
-use super::damage_calculator::DamageCalculator;
+use super::damage_calculator::{CalculatedDamage, DamageCalculator};
+use super::enemy_combat_system::EnemyCombatSystem;
 use super::enemy_condition_system::EnemyConditionSystem;
 use super::player_condition_system::PlayerConditionSystem;
 
@@ -23,9 +25,15 @@ impl EnemyEffectSystem {
             .and_then(|maybe_enemy| maybe_enemy.as_mut())
         {
             match effect {
+                EnemyEffect::AddToDeck(card) => {
+                    DeckSystem::obtain_card(ctx.comms, ctx.pcs.pps, *card)?;
+                }
                 EnemyEffect::Apply(enemy_condition) => {
                     EnemyConditionSystem::apply_to_enemy(enemy_state, enemy_condition);
                 }
+                EnemyEffect::ClearNegativeStrength => {
+                    enemy_state.strength = enemy_state.strength.max(0);
+                }
                 EnemyEffect::CreateCards(
                     card_pool,
                     card_selection,
@@ -46,7 +54,41 @@ impl EnemyEffectSystem {
                         Some(&ctx.pcs),
                         damage,
                     );
-                    BlockSystem::damage_player(ctx, damage)?;
+                    Self::deal_damage(ctx, damage)?;
+                }
+                EnemyEffect::DealAndBlock(damage) => {
+                    let damage = DamageCalculator::calculate_damage_inflicted(
+                        enemy_state,
+                        Some(&ctx.pcs),
+                        damage,
+                    );
+                    Self::deal_damage(ctx, damage)?;
+                    if let (Some(enemy_state), CalculatedDamage::Blockable(amount)) = (
+                        ctx.maybe_enemy_index
+                            .and_then(|i| ctx.enemy_party.0[i].as_mut()),
+                        damage,
+                    ) {
+                        enemy_state.block += amount;
+                    }
+                }
+                EnemyEffect::DealAndHeal(damage) => {
+                    let damage = DamageCalculator::calculate_damage_inflicted(
+                        enemy_state,
+                        Some(&ctx.pcs),
+                        damage,
+                    );
+                    let hp_lost = Self::deal_damage(ctx, damage)?;
+                    if let Some(enemy_state) = ctx
+                        .maybe_enemy_index
+                        .and_then(|i| ctx.enemy_party.0[i].as_mut())
+                    {
+                        Self::heal(enemy_state, hp_lost);
+                    }
+                }
+                EnemyEffect::Escape => {
+                    if let Some(enemy_index) = ctx.maybe_enemy_index {
+                        EnemyCombatSystem::on_enemy_escaped(ctx, enemy_index)?;
+                    }
                 }
                 EnemyEffect::Gain(Resource::Block(block)) => {
                     let calculated_block =
@@ -57,11 +99,251 @@ impl EnemyEffectSystem {
                     enemy_state.strength += strength;
                 }
                 EnemyEffect::Gain(invalid) => unreachable!("{:?}", invalid),
+                EnemyEffect::HealToHalfHp => {
+                    enemy_state.hp = enemy_state.hp.max(enemy_state.hp_max / 2);
+                }
+                EnemyEffect::HealParty(hp) => {
+                    for enemy_state in ctx.enemy_party.0.iter_mut().flatten() {
+                        Self::heal(enemy_state, *hp);
+                    }
+                }
                 EnemyEffect::Inflict(player_condition) => {
                     PlayerConditionSystem::apply_to_player(ctx, player_condition)?;
                 }
+                EnemyEffect::RemoveDebuffs => {
+                    enemy_state.conditions.retain(|c| !c.is_debuff());
+                    enemy_state.strength = enemy_state.strength.max(0);
+                }
+                EnemyEffect::RerollIntent => {
+                    let battlefield = ctx.battlefield();
+                    if let Some(enemy_state) = ctx
+                        .maybe_enemy_index
+                        .and_then(|i| ctx.enemy_party.0[i].as_mut())
+                    {
+                        enemy_state.reroll_action(&mut ctx.enemy_rng, &battlefield);
+                    }
+                }
+                EnemyEffect::ReviveAtFullHp => {
+                    enemy_state.half_dead = false;
+                    enemy_state.hp = enemy_state.hp_max;
+                }
+                EnemyEffect::ReviveAtHalfHp => {
+                    enemy_state.half_dead = false;
+                    enemy_state.hp = enemy_state.hp_max / 2;
+                }
+                EnemyEffect::SapDexterity(dexterity) => {
+                    ctx.pcs.dexterity -= dexterity;
+                    ctx.comms
+                        .send_notification(Notification::Dexterity(ctx.pcs.dexterity))?;
+                }
+                EnemyEffect::SapStrength(strength) => {
+                    ctx.pcs.strength -= strength;
+                    ctx.comms
+                        .send_notification(Notification::Strength(ctx.pcs.strength))?;
+                }
+                EnemyEffect::SelfDestruct => {
+                    enemy_state.hp = 0;
+                }
+                EnemyEffect::ShieldAllies(block) => {
+                    for (i, maybe_ally) in ctx.enemy_party.0.iter_mut().enumerate() {
+                        if let Some(ally) = maybe_ally
+                            .as_mut()
+                            .filter(|_| Some(i) != ctx.maybe_enemy_index)
+                        {
+                            ally.block += block;
+                        }
+                    }
+                }
+                EnemyEffect::ShieldAlly(block) => {
+                    Self::shield_random_ally(ctx, *block);
+                }
+                EnemyEffect::ShieldLeader(block) => {
+                    if let Some(leader) =
+                        ctx.enemy_party.0.iter_mut().flatten().find(|enemy_state| {
+                            !enemy_state.conditions.contains(&EnemyCondition::Minion)
+                        })
+                    {
+                        leader.block += block;
+                    }
+                }
+                EnemyEffect::Split(first, second) => {
+                    if let Some(enemy_index) = ctx.maybe_enemy_index {
+                        let spawned =
+                            ctx.enemy_party
+                                .split(enemy_index, *first, *second, &mut ctx.enemy_rng);
+                        EnemyCombatSystem::on_enemies_spawned(ctx, &spawned)?;
+                    }
+                }
+                EnemyEffect::Stasis => {
+                    Self::put_card_in_stasis(ctx);
+                }
+                EnemyEffect::StrengthenParty(strength) => {
+                    for enemy_state in ctx.enemy_party.0.iter_mut().flatten() {
+                        enemy_state.strength += strength;
+                    }
+                }
+                EnemyEffect::Summon(enemy, count) => {
+                    let present = ctx
+                        .enemy_party
+                        .0
+                        .iter()
+                        .flatten()
+                        .filter(|enemy_state| enemy_state.enemy == *enemy)
+                        .count();
+                    let spawned = (present..*count)
+                        .filter_map(|_| {
+                            ctx.enemy_party
+                                .summon(*enemy, &mut ctx.hp_rng, &mut ctx.enemy_rng)
+                        })
+                        .collect::<Vec<_>>();
+                    EnemyCombatSystem::on_enemies_spawned(ctx, &spawned)?;
+                }
+                EnemyEffect::SummonDaggers(count) => {
+                    let spawned = (0..*count)
+                        .filter_map(|_| {
+                            ctx.enemy_party.summon(
+                                Enemy::Dagger,
+                                &mut ctx.hp_rng,
+                                &mut ctx.enemy_rng,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    EnemyCombatSystem::on_enemies_spawned(ctx, &spawned)?;
+                }
+                EnemyEffect::SummonGremlins(count) => {
+                    let spawned = (0..*count)
+                        .filter_map(|_| {
+                            ctx.enemy_party
+                                .summon_gremlin(&mut ctx.hp_rng, &mut ctx.enemy_rng)
+                        })
+                        .collect::<Vec<_>>();
+                    EnemyCombatSystem::on_enemies_spawned(ctx, &spawned)?;
+                }
+                EnemyEffect::UpgradeBurns => {
+                    Self::upgrade_burns(ctx);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Deals the acting enemy's damage to the player, letting the enemy's conditions react if an
+    /// attack got through. Returns the amount of HP the player lost.
+    fn deal_damage<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        damage: CalculatedDamage,
+    ) -> Result<Hp, Error> {
+        let is_attack = matches!(damage, CalculatedDamage::Blockable(_));
+        if is_attack {
+            Self::steal_gold(ctx)?;
+        }
+        let hp_lost = BlockSystem::damage_player(ctx, damage)?;
+        if is_attack && hp_lost > 0 {
+            if let Some(enemy_state) = ctx
+                .maybe_enemy_index
+                .and_then(|i| ctx.enemy_party.0[i].as_mut())
+            {
+                EnemyConditionSystem::on_attack_damage_dealt(enemy_state, &mut ctx.effect_queue);
+            }
+        }
+        Ok(hp_lost)
+    }
+
+    /// Lets a thieving enemy pocket some of the player's Gold as its attack lands, whether or not
+    /// the attack is blocked.
+    fn steal_gold<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        let Some(enemy_state) = ctx
+            .maybe_enemy_index
+            .and_then(|i| ctx.enemy_party.0[i].as_mut())
+        else {
+            return Ok(());
+        };
+        for enemy_condition in enemy_state.conditions.iter_mut() {
+            if let EnemyCondition::Thievery(gold_per_attack, gold_stolen) = enemy_condition {
+                let gold = (*gold_per_attack).min(ctx.pcs.pps.gold);
+                if gold > 0 {
+                    *gold_stolen += gold;
+                    GoldSystem::decrease_gold(ctx.comms, ctx.pcs.pps, gold)?;
+                }
             }
         }
         Ok(())
     }
+
+    /// Heals the enemy without exceeding its max HP.
+    fn heal(enemy_state: &mut EnemyState, hp: Hp) {
+        enemy_state.hp = enemy_state.hp.saturating_add(hp).min(enemy_state.hp_max);
+    }
+
+    /// Gives Block to a random living ally of the acting enemy, or to the enemy itself if it has
+    /// no allies left.
+    fn shield_random_ally<I: Interaction>(ctx: &mut CombatContext<I>, block: Block) {
+        let Some(enemy_index) = ctx.maybe_enemy_index else {
+            return;
+        };
+        let allies = ctx
+            .enemy_party
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(i, maybe_enemy)| *i != enemy_index && maybe_enemy.is_some())
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let target_index = if allies.is_empty() {
+            enemy_index
+        } else {
+            allies[ctx.enemy_rng.gen_range(0..allies.len())]
+        };
+        if let Some(enemy_state) = ctx.enemy_party.0[target_index].as_mut() {
+            enemy_state.block += block;
+        }
+    }
+
+    /// Takes a card from the player's draw pile (or discard pile, if the draw pile is empty) and
+    /// holds it in Stasis, preferring rare cards, then uncommon, then common.
+    fn put_card_in_stasis<I: Interaction>(ctx: &mut CombatContext<I>) {
+        let pile = if ctx.pcs.cards.draw_pile.is_empty() {
+            &mut ctx.pcs.cards.discard_pile
+        } else {
+            &mut ctx.pcs.cards.draw_pile
+        };
+        if pile.is_empty() {
+            return;
+        }
+        let candidates = [CardRarity::Rare, CardRarity::Uncommon, CardRarity::Common]
+            .iter()
+            .map(|rarity| {
+                (0..pile.len())
+                    .filter(|i| pile[*i].details.rarity == *rarity)
+                    .collect::<Vec<_>>()
+            })
+            .find(|candidates| !candidates.is_empty())
+            .unwrap_or_else(|| (0..pile.len()).collect());
+        let index = candidates[ctx.card_randomizer_rng.gen_range(0..candidates.len())];
+        let combat_card = pile.remove(index);
+        if let Some(enemy_state) = ctx
+            .maybe_enemy_index
+            .and_then(|i| ctx.enemy_party.0[i].as_mut())
+        {
+            EnemyConditionSystem::apply_to_enemy(
+                enemy_state,
+                &EnemyCondition::Stasis(combat_card.card),
+            );
+            enemy_state.stasis = Some(combat_card);
+        }
+    }
+
+    /// Upgrades every Burn in the player's draw and discard piles.
+    fn upgrade_burns<I: Interaction>(ctx: &mut CombatContext<I>) {
+        for combat_card in ctx
+            .pcs
+            .cards
+            .draw_pile
+            .iter_mut()
+            .chain(ctx.pcs.cards.discard_pile.iter_mut())
+            .filter(|combat_card| combat_card.card == Card::Burn(false))
+        {
+            combat_card.upgrade();
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use anyhow::Error;
 
 use crate::components::{Interaction, Notification, PlayerCombatState};
-use crate::data::EnergyCost;
+use crate::data::{EnergyCost, Relic};
 use crate::systems::base::CombatContext;
 use crate::types::Energy;
 
@@ -20,6 +20,18 @@ impl EnergySystem {
         Self::notify_player(ctx)
     }
 
+    /// Gives the player the specified amount of energy.
+    pub fn gain<I: Interaction>(ctx: &mut CombatContext<I>, amount: Energy) -> Result<(), Error> {
+        ctx.pcs.energy = ctx.pcs.energy.saturating_add(amount);
+        Self::notify_player(ctx)
+    }
+
+    /// Takes the specified amount of energy from the player.
+    pub fn lose<I: Interaction>(ctx: &mut CombatContext<I>, amount: Energy) -> Result<(), Error> {
+        ctx.pcs.energy = ctx.pcs.energy.saturating_sub(amount);
+        Self::notify_player(ctx)
+    }
+
     /// Checks if the player can afford the specified energy cost.
     pub fn can_afford(pcs: &PlayerCombatState, energy_cost: EnergyCost) -> bool {
         match energy_cost {
@@ -34,11 +46,38 @@ impl EnergySystem {
         }
     }
 
-    /// Spends the specified amount of energy from the player.
+    /// Returns X for an X-cost card played now, i.e. the player's energy plus 2 for Chemical X.
+    pub fn x_value(pcs: &PlayerCombatState) -> Energy {
+        if pcs.pps.relics.contains(&Relic::ChemicalX) {
+            pcs.energy + 2
+        } else {
+            pcs.energy
+        }
+    }
+
+    /// Returns X for the card being played. The first time one of its X effects resolves, the
+    /// player's remaining energy is spent, as in the game.
+    pub fn resolve_x<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<Energy, Error> {
+        if std::mem::take(&mut ctx.pcs.x_cost_unpaid) {
+            ctx.pcs.energy = 0;
+            Self::notify_player(ctx)?;
+        }
+        Ok(ctx
+            .pcs
+            .card_being_played
+            .map_or(0, |combat_card| combat_card.x))
+    }
+
+    /// Spends the specified amount of energy from the player. X costs are deferred until the
+    /// card's X effect resolves; see `resolve_x`.
     pub fn spend<I: Interaction>(
         ctx: &mut CombatContext<I>,
         energy_cost: EnergyCost,
     ) -> Result<(), Error> {
+        if energy_cost == EnergyCost::X {
+            ctx.pcs.x_cost_unpaid = true;
+            return Ok(());
+        }
         ctx.pcs.energy = match energy_cost {
             EnergyCost::Zero => ctx.pcs.energy,
             EnergyCost::One => ctx.pcs.energy.saturating_sub(1),
@@ -54,7 +93,7 @@ impl EnergySystem {
                 .energy
                 .saturating_sub(4u32.saturating_sub(ctx.pcs.hp_loss_count as Energy)),
             EnergyCost::Five => ctx.pcs.energy.saturating_sub(5),
-            EnergyCost::X => 0,
+            EnergyCost::X => unreachable!(),
         };
         Self::notify_player(ctx)
     }
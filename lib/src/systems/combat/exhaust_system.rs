@@ -19,7 +19,7 @@ impl ExhaustSystem {
         RelicSystem::on_card_exhausted(ctx);
         PlayerConditionSystem::on_card_exhausted(ctx)?;
         if let Some(effect) = combat_card.details.on_exhaust.as_ref() {
-            ctx.effect_queue.push_back(Effect::Card(effect));
+            ctx.effect_queue.add_to_bottom(Effect::Card(effect));
         }
         ctx.pcs.cards.exhaust_pile.push(combat_card);
         ctx.comms
@@ -1,5 +1,6 @@
 mod block_system;
 mod card_creation_system;
+mod card_queue_system;
 mod damage_calculator;
 mod discard_system;
 mod draw_system;
@@ -10,13 +11,17 @@ mod enemy_condition_system;
 mod enemy_effect_system;
 mod energy_system;
 mod exhaust_system;
+mod play_restriction_system;
 mod player_combat_action;
 mod player_combat_system;
 mod player_condition;
 mod player_condition_system;
 mod player_effect_system;
+mod scry_system;
+mod stance_system;
 
 pub use block_system::BlockSystem;
+pub use card_queue_system::CardQueueSystem;
 pub use damage_calculator::DamageCalculator;
 pub use discard_system::DiscardSystem;
 pub use draw_system::DrawSystem;
@@ -24,6 +29,8 @@ pub use effect_system::EffectSystem;
 pub use enemy_combat_system::EnemyCombatSystem;
 pub use energy_system::EnergySystem;
 pub use exhaust_system::ExhaustSystem;
+pub use play_restriction_system::PlayRestrictionSystem;
 pub use player_combat_action::PlayerCombatAction;
 pub use player_combat_system::PlayerCombatSystem;
 pub use player_condition_system::PlayerConditionSystem;
+pub use stance_system::StanceSystem;
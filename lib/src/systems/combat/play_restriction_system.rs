@@ -0,0 +1,163 @@
+use crate::components::{CardCombatState, PlayerCombatState};
+use crate::data::{Card, CardType, EnergyCost, PlayerCondition, Relic};
+
+use super::energy_system::EnergySystem;
+
+pub struct PlayRestrictionSystem;
+
+impl PlayRestrictionSystem {
+    /// Returns true iff the player can legally play the given card at the given cost right now.
+    pub fn can_play_card(
+        pcs: &PlayerCombatState,
+        combat_card: &CardCombatState,
+        energy_cost: EnergyCost,
+    ) -> bool {
+        Self::is_playable(pcs, combat_card)
+            && !Self::card_limit_reached(pcs)
+            && !Self::is_forbidden_by_conditions(pcs, combat_card)
+            && Self::card_requirements_met(pcs, combat_card)
+            && EnergySystem::can_afford(pcs, energy_cost)
+    }
+
+    /// Unplayable curses and statuses can only be played with Blue Candle or Medical Kit.
+    fn is_playable(pcs: &PlayerCombatState, combat_card: &CardCombatState) -> bool {
+        !combat_card.details.unplayable
+            || match combat_card.details.type_ {
+                CardType::Curse => pcs.pps.relics.contains(&Relic::BlueCandle),
+                CardType::Status => pcs.pps.relics.contains(&Relic::MedicalKit),
+                _ => false,
+            }
+    }
+
+    /// Normality (while in hand) and Velvet Choker cap the number of cards played per turn.
+    fn card_limit_reached(pcs: &PlayerCombatState) -> bool {
+        let normality_in_hand = pcs.cards.hand.iter().any(|c| c.card == Card::Normality);
+        (normality_in_hand && pcs.cards_played_this_turn >= 3)
+            || (pcs.pps.relics.contains(&Relic::VelvetChoker) && pcs.cards_played_this_turn >= 6)
+    }
+
+    /// Entangled prevents the player from playing Attacks.
+    fn is_forbidden_by_conditions(pcs: &PlayerCombatState, combat_card: &CardCombatState) -> bool {
+        combat_card.details.type_ == CardType::Attack
+            && pcs.conditions.contains(&PlayerCondition::Entangled)
+    }
+
+    /// Some cards place requirements on the rest of the hand, e.g. Clash and Signature Move.
+    fn card_requirements_met(pcs: &PlayerCombatState, combat_card: &CardCombatState) -> bool {
+        let details = combat_card.details;
+        if details.playable_only_if_all_cards_in_hand_are_attacks
+            && !pcs
+                .cards
+                .hand
+                .iter()
+                .all(|c| c.details.type_ == CardType::Attack)
+        {
+            return false;
+        }
+        if details.playable_only_if_only_attack_in_hand
+            && pcs
+                .cards
+                .hand
+                .iter()
+                .filter(|c| c.details.type_ == CardType::Attack)
+                .count()
+                > 1
+        {
+            return false;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::components::PlayerPersistentState;
+    use crate::data::IRONCLAD;
+
+    use super::*;
+
+    /// Returns whether each card in the hand can be played, with three energy to spend.
+    fn playable(pcs: &PlayerCombatState) -> Vec<bool> {
+        pcs.cards
+            .hand
+            .iter()
+            .map(|combat_card| {
+                PlayRestrictionSystem::can_play_card(pcs, combat_card, combat_card.cost_this_turn)
+            })
+            .collect()
+    }
+
+    /// Creates a hand holding the given cards.
+    fn hand_of(cards: &[Card]) -> Vec<CardCombatState> {
+        cards
+            .iter()
+            .enumerate()
+            .map(|(id, card)| CardCombatState::new(id, *card))
+            .collect()
+    }
+
+    #[test]
+    fn test_unplayable_cards() {
+        let mut pps = PlayerPersistentState::new(IRONCLAD);
+        let mut pcs = PlayerCombatState::new(&mut pps);
+        pcs.cards.hand = hand_of(&[Card::Regret, Card::Wound, Card::Strike(false)]);
+        assert_eq!(playable(&pcs), [false, false, true]);
+
+        pcs.pps.relics = vec![Relic::BlueCandle];
+        assert_eq!(playable(&pcs), [true, false, true]);
+
+        pcs.pps.relics = vec![Relic::MedicalKit];
+        assert_eq!(playable(&pcs), [false, true, true]);
+    }
+
+    #[test]
+    fn test_clash_and_signature_move_need_the_right_hand() {
+        let mut pps = PlayerPersistentState::new(IRONCLAD);
+        let mut pcs = PlayerCombatState::new(&mut pps);
+        pcs.cards.hand = hand_of(&[Card::Clash(false), Card::Strike(false), Card::Defend(false)]);
+        assert_eq!(playable(&pcs), [false, true, true]);
+
+        pcs.cards.hand.pop();
+        assert_eq!(playable(&pcs), [true, true]);
+
+        pcs.cards.hand = hand_of(&[Card::SignatureMove(false), Card::Defend(false)]);
+        assert_eq!(playable(&pcs), [true, true]);
+
+        pcs.cards
+            .hand
+            .push(CardCombatState::new(2, Card::Strike(false)));
+        assert_eq!(playable(&pcs), [false, true, true]);
+    }
+
+    #[test]
+    fn test_normality_and_velvet_choker_limit_cards_per_turn() {
+        let mut pps = PlayerPersistentState::new(IRONCLAD);
+        let mut pcs = PlayerCombatState::new(&mut pps);
+        pcs.cards.hand = hand_of(&[Card::Normality, Card::Strike(false)]);
+        pcs.cards_played_this_turn = 2;
+        assert_eq!(playable(&pcs), [false, true]);
+
+        pcs.cards_played_this_turn = 3;
+        assert_eq!(playable(&pcs), [false, false]);
+
+        // Normality only counts while it's in the hand.
+        pcs.cards.hand.remove(0);
+        assert_eq!(playable(&pcs), [true]);
+
+        pcs.pps.relics = vec![Relic::VelvetChoker];
+        pcs.cards_played_this_turn = 5;
+        assert_eq!(playable(&pcs), [true]);
+
+        pcs.cards_played_this_turn = 6;
+        assert_eq!(playable(&pcs), [false]);
+    }
+
+    #[test]
+    fn test_entangled_forbids_attacks() {
+        let mut pps = PlayerPersistentState::new(IRONCLAD);
+        let mut pcs = PlayerCombatState::new(&mut pps);
+        pcs.cards.hand = hand_of(&[Card::Strike(false), Card::Defend(false)]);
+        pcs.conditions.push(PlayerCondition::Entangled);
+        assert_eq!(playable(&pcs), [false, true]);
+    }
+}
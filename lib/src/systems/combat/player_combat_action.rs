@@ -1,7 +1,6 @@
-use crate::components::CardCombatState;
-use crate::types::EnemyIndex;
+use crate::components::CardPlay;
 
 pub enum PlayerCombatAction {
     EndTurn,
-    PlayCard(CardCombatState, Option<EnemyIndex>),
+    PlayCard(CardPlay),
 }
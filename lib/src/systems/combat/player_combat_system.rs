@@ -1,29 +1,28 @@
 use anyhow::Error;
 
 use crate::components::{
-    CardCombatState, Choice, EnemyStatus, Interaction, Notification, PlayerCombatState,
-    PlayerPersistentState, Prompt,
+    CardCombatState, CardPlay, CardPlaySource, Choice, Effect, Interaction, Notification,
+    PlayerCombatState, PlayerPersistentState, PotionAction, Prompt,
+};
+use crate::data::{
+    CardType, Damage, EnemyCondition, EnergyCost, PlayerCondition, PlayerEffect, Potion,
 };
-use crate::data::CardType;
 use crate::systems::base::{CombatContext, HealthSystem, PotionSystem, RelicSystem};
 use crate::systems::combat::{
-    BlockSystem, DiscardSystem, DrawSystem, EnergySystem, ExhaustSystem, PlayerConditionSystem,
+    BlockSystem, DiscardSystem, DrawSystem, EnemyCombatSystem, EnergySystem, ExhaustSystem,
+    PlayRestrictionSystem, PlayerConditionSystem, StanceSystem,
 };
 use crate::types::EnemyIndex;
 
+use super::card_queue_system::CardQueueSystem;
+use super::enemy_condition_system::EnemyConditionSystem;
 use super::player_combat_action::PlayerCombatAction;
 
 pub struct PlayerCombatSystem;
 
 impl PlayerCombatSystem {
     pub fn notify_player<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
-        ctx.comms.send_notification(Notification::EnemyParty(
-            ctx.enemy_party
-                .0
-                .iter()
-                .map(|enemy| enemy.as_ref().map(EnemyStatus::from))
-                .collect(),
-        ))?;
+        EnemyCombatSystem::notify_player(ctx)?;
         HealthSystem::notify_player(ctx.comms, ctx.pcs.pps)?;
         EnergySystem::notify_player(ctx)?;
         ctx.comms
@@ -37,6 +36,7 @@ impl PlayerCombatSystem {
     /// combat state as well as the enemy party.
     pub fn on_combat_started<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
         RelicSystem::on_combat_started(ctx)?;
+        PlayerConditionSystem::on_combat_started(ctx)?;
         DrawSystem::on_combat_started(ctx);
         PlayerCombatSystem::notify_player(ctx)?;
         ctx.comms.send_notification(Notification::StartingCombat)
@@ -51,10 +51,32 @@ impl PlayerCombatSystem {
         comms.send_notification(Notification::EndingCombat)
     }
 
+    /// Writes permanent changes made to cards during combat (e.g. Ritual Dagger's growth) back
+    /// to the deck. `misc` is the only such change: upgrades made in combat (e.g. Armaments,
+    /// Apotheosis) wear off when it ends, Lesson Learned upgrades the deck card directly, and
+    /// nothing in combat changes which card is bottled.
+    pub fn persist_card_changes<I: Interaction>(ctx: &mut CombatContext<I>) {
+        for combat_card in ctx.pcs.cards.iter() {
+            if let Some(deck_card) = ctx
+                .pcs
+                .pps
+                .deck
+                .iter_mut()
+                .find(|deck_card| deck_card.id == combat_card.id)
+            {
+                deck_card.misc = combat_card.misc;
+            }
+        }
+    }
+
     /// Triggers start-of-turn effects.
     pub fn on_player_turn_started<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        ctx.pcs.end_turn_requested = false;
+        ctx.pcs.cards_played_this_turn = 0;
+        StanceSystem::on_player_turn_started(ctx)?;
         PlayerConditionSystem::on_player_turn_started(ctx)?;
         DrawSystem::on_player_turn_started(ctx);
+        CardQueueSystem::on_player_turn_started(ctx);
         BlockSystem::on_player_turn_started(ctx)?;
         EnergySystem::on_player_turn_started(ctx)
     }
@@ -67,46 +89,77 @@ impl PlayerCombatSystem {
         PlayerConditionSystem::on_player_turn_finished(ctx)
     }
 
+    /// Returns true iff the player has earned an extra turn (e.g. from Vault), consuming it.
+    pub fn take_extra_turn<I: Interaction>(ctx: &mut CombatContext<I>) -> bool {
+        std::mem::take(&mut ctx.pcs.extra_turn_pending)
+    }
+
     /// Prompts the player for their next action.
     pub fn choose_next_action<I: Interaction>(
         ctx: &mut CombatContext<I>,
     ) -> Result<PlayerCombatAction, Error> {
-        PlayerCombatSystem::notify_player(ctx)?;
         loop {
+            // Refreshed before every prompt, so that intents reflect whatever just changed.
+            PlayerCombatSystem::notify_player(ctx)?;
             let mut choices = ctx
                 .pcs
                 .cards
                 .hand
                 .iter()
                 .enumerate()
-                .filter(|(_, combat_card)| Self::can_play_card(&ctx.pcs, combat_card))
+                .filter(|(_, combat_card)| {
+                    PlayRestrictionSystem::can_play_card(
+                        &ctx.pcs,
+                        combat_card,
+                        Self::effective_cost(&ctx.pcs, combat_card),
+                    )
+                })
                 .map(|(hand_index, combat_card)| {
                     Choice::PlayCardFromHand(
                         hand_index,
                         combat_card.card,
-                        combat_card.cost_this_turn,
+                        Self::effective_cost(&ctx.pcs, combat_card),
                     )
                 })
                 .collect::<Vec<_>>();
             PotionSystem::extend_with_potion_actions(ctx.pcs.pps, true, &mut choices);
+            if !Self::can_escape(ctx) {
+                choices.retain(|choice| {
+                    !matches!(
+                        choice,
+                        Choice::ExpendPotion(PotionAction::Drink(_, Potion::SmokeBomb))
+                    )
+                });
+            }
             choices.push(Choice::EndTurn);
             match ctx
                 .comms
                 .prompt_for_choice(Prompt::CombatAction, &choices)?
             {
-                Choice::PlayCardFromHand(hand_index, _, _) => {
-                    ctx.pcs.cards.card_in_play = Some(*hand_index);
-                    let combat_card = ctx.pcs.cards.hand[*hand_index];
-                    EnergySystem::spend(ctx, combat_card.cost_this_turn)?;
-                    if combat_card.details.requires_target {
-                        let enemy_index = Self::choose_enemy_to_target(ctx)?;
-                        return Ok(PlayerCombatAction::PlayCard(combat_card, Some(enemy_index)));
-                    } else {
-                        return Ok(PlayerCombatAction::PlayCard(combat_card, None));
+                Choice::PlayCardFromHand(hand_index, _, energy_cost) => {
+                    let mut combat_card = ctx.pcs.cards.hand[*hand_index];
+                    if combat_card.details.cost == EnergyCost::X {
+                        combat_card.x = EnergySystem::x_value(&ctx.pcs);
                     }
+                    EnergySystem::spend(ctx, *energy_cost)?;
+                    let maybe_enemy_index = if combat_card.details.requires_target {
+                        Some(Self::choose_enemy_to_target(ctx)?)
+                    } else {
+                        None
+                    };
+                    return Ok(PlayerCombatAction::PlayCard(CardPlay::from_hand(
+                        *hand_index,
+                        combat_card,
+                        maybe_enemy_index,
+                    )));
                 }
                 Choice::ExpendPotion(potion_action) => {
-                    PotionSystem::expend_potion_in_combat(ctx, potion_action)?
+                    PotionSystem::expend_potion_in_combat(ctx, potion_action)?;
+                    // Potions such as Distilled Chaos play cards immediately.
+                    CardQueueSystem::play_queued_cards(ctx)?;
+                    if ctx.combat_should_end() {
+                        return Ok(PlayerCombatAction::EndTurn);
+                    }
                 }
                 Choice::EndTurn => return Ok(PlayerCombatAction::EndTurn),
                 invalid => unreachable!("{:?}", invalid),
@@ -114,22 +167,85 @@ impl PlayerCombatSystem {
         }
     }
 
-    /// Disposes of the card just played.
+    /// Disposes of the card just played, triggering any "on card played" hooks. Duplicate plays
+    /// vanish, while cards from the hand or played from elsewhere are Exhausted or discarded.
     pub fn dispose_of_card_just_played<I: Interaction>(
         ctx: &mut CombatContext<I>,
+        card_play: &CardPlay,
     ) -> Result<(), Error> {
-        let Some(hand_index) = ctx.pcs.cards.card_in_play else {
-            panic!("No card in play");
+        let mut combat_card = card_play.combat_card;
+        // Playing the card uses up any discount that lasts until it is played (e.g. Forethought).
+        combat_card.cost_until_played = combat_card.cost_this_combat;
+        let hand_index = match card_play.source {
+            CardPlaySource::Hand(hand_index) => {
+                ctx.pcs.cards.hand.remove(hand_index);
+                hand_index
+            }
+            CardPlaySource::Copy | CardPlaySource::Autoplay => ctx.pcs.cards.hand.len(),
         };
-        let combat_card = ctx.pcs.cards.hand.remove(hand_index);
+        ctx.pcs.cards_played_this_turn += 1;
+        CardQueueSystem::on_some_card_played(ctx, card_play);
         PlayerConditionSystem::on_some_card_played(ctx, &combat_card)?;
-        if combat_card.details.exhaust {
+        EnemyConditionSystem::on_some_card_played(ctx, &combat_card);
+        for _ in ctx.pcs.cards.hand.iter().filter(|c| c.details.pain) {
+            ctx.effect_queue
+                .add_to_bottom(Effect::PlayerState(PlayerEffect::TakeDamage(
+                    Damage::HpLoss(1),
+                )));
+        }
+        if card_play.source == CardPlaySource::Copy {
+            Ok(())
+        } else if combat_card.details.unplayable {
+            // Only reachable via Blue Candle or Medical Kit.
+            if combat_card.details.type_ == CardType::Curse {
+                ctx.effect_queue
+                    .add_to_bottom(Effect::PlayerState(PlayerEffect::TakeDamage(
+                        Damage::HpLoss(1),
+                    )));
+            }
+            ExhaustSystem::push(ctx, hand_index, combat_card)
+        } else if combat_card.details.type_ == CardType::Power {
+            // Powers leave play for the rest of combat, even when autoplayed by Havoc.
+            ctx.comms
+                .send_notification(Notification::PowerPlayed(hand_index, combat_card))
+        } else if combat_card.details.exhaust || card_play.exhaust {
             ExhaustSystem::push(ctx, hand_index, combat_card)
+        } else if combat_card.details.shuffle_back_into_draw_pile {
+            let draw_index = ctx
+                .card_randomizer_rng
+                .gen_range(0..=ctx.pcs.cards.draw_pile.len());
+            ctx.pcs.cards.draw_pile.insert(draw_index, combat_card);
+            ctx.comms.send_notification(Notification::CardPutOnDrawPile(
+                hand_index,
+                draw_index,
+                combat_card,
+            ))
         } else {
             DiscardSystem::push(ctx, hand_index, combat_card)
         }
     }
 
+    /// Cleans up after all of the effects of the card just played have resolved.
+    pub fn on_card_play_finished<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        // Settle the X cost even if the card had no X effect to resolve.
+        EnergySystem::resolve_x(ctx)?;
+        let Some(combat_card) = ctx.pcs.card_being_played.take() else {
+            panic!("No card being played");
+        };
+        ctx.pcs.last_card_played = Some(combat_card.details.type_);
+        ctx.pcs.unblocked_damage_dealt = 0;
+        if combat_card.details.type_ == CardType::Attack {
+            let vigor_count = ctx.pcs.conditions.len();
+            ctx.pcs
+                .conditions
+                .retain(|c| !matches!(c, PlayerCondition::Vigor(_)));
+            if ctx.pcs.conditions.len() != vigor_count {
+                PlayerConditionSystem::notify_player(ctx)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Prompts the player to choose an enemy to target.
     fn choose_enemy_to_target<I: Interaction>(
         ctx: &mut CombatContext<I>,
@@ -142,6 +258,7 @@ impl PlayerCombatSystem {
             .filter_map(|(index, maybe_enemy)| {
                 maybe_enemy
                     .as_ref()
+                    .filter(|enemy| !enemy.half_dead)
                     .map(|enemy| Choice::TargetEnemy(index, enemy.enemy))
             })
             .collect::<Vec<_>>();
@@ -151,16 +268,29 @@ impl PlayerCombatSystem {
         }
     }
 
-    /// Returns true iff the player can play the given card.
-    fn can_play_card(pcs: &PlayerCombatState, combat_card: &CardCombatState) -> bool {
-        EnergySystem::can_afford(pcs, combat_card.cost_this_turn)
-            && (!combat_card
-                .details
-                .playable_only_if_all_cards_in_hand_are_attacks
-                || pcs
-                    .cards
-                    .hand
-                    .iter()
-                    .all(|c| matches!(c.details.type_, CardType::Attack)))
+    /// Returns true iff the player may flee the fight, which isn't possible against a boss or
+    /// while an enemy is attacking from behind.
+    fn can_escape<I: Interaction>(ctx: &CombatContext<I>) -> bool {
+        !ctx.encounter.is_boss()
+            && !ctx
+                .enemy_party
+                .0
+                .iter()
+                .flatten()
+                .any(|enemy_state| enemy_state.conditions.contains(&EnemyCondition::BackAttack))
+    }
+
+    /// Returns the energy the player would actually spend to play the given card.
+    fn effective_cost(pcs: &PlayerCombatState, combat_card: &CardCombatState) -> EnergyCost {
+        if combat_card.details.type_ == CardType::Attack
+            && pcs
+                .conditions
+                .iter()
+                .any(|c| matches!(c, PlayerCondition::FreeAttack(_)))
+        {
+            EnergyCost::Zero
+        } else {
+            combat_card.cost_this_turn
+        }
     }
 }
@@ -1,11 +1,20 @@
 use crate::components::{CardCombatState, DamageTaken, Effect, EffectQueue};
-use crate::data::{CardType, Damage, PlayerCondition, PlayerEffect, Resource, TargetEffect};
+use crate::data::{
+    Card, CardDestination, CardPool, CardSelection, CardType, CostModifier, Damage, EnemyCondition,
+    PlayerCondition, PlayerEffect, Resource, Stance, TargetEffect,
+};
 
 impl PlayerCondition {
     /// Attempts to merge the supplied condition into self, returning true iff the conditions
     /// were merged.
     pub fn merge(&mut self, other: &Self) -> bool {
         match other {
+            PlayerCondition::Amplify(incoming_stack_count) => {
+                if let PlayerCondition::Amplify(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
             PlayerCondition::Artifact(incoming_counter) => {
                 if let PlayerCondition::Artifact(counter) = self {
                     *counter += incoming_counter;
@@ -17,18 +26,41 @@ impl PlayerCondition {
                     return true;
                 }
             }
+            PlayerCondition::BattleHymn(incoming_stack_count) => {
+                if let PlayerCondition::BattleHymn(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
             PlayerCondition::Berserk(incoming_energy) => {
                 if let PlayerCondition::Berserk(energy) = self {
                     *energy += incoming_energy;
                     return true;
                 }
             }
+            PlayerCondition::Blasphemer => {
+                if let PlayerCondition::Blasphemer = self {
+                    return true;
+                }
+            }
             PlayerCondition::Brutality(incoming_draw_count) => {
                 if let PlayerCondition::Brutality(draw_count) = self {
                     *draw_count += incoming_draw_count;
                     return true;
                 }
             }
+            PlayerCondition::Burst(incoming_stack_count) => {
+                if let PlayerCondition::Burst(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
+            PlayerCondition::Collect(incoming_stack_count) => {
+                if let PlayerCondition::Collect(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
             PlayerCondition::Combust(incoming_damage_to_self, incoming_damage_to_enemies) => {
                 if let PlayerCondition::Combust(damage_to_self, damage_to_enemies) = self {
                     *damage_to_self += incoming_damage_to_self;
@@ -41,6 +73,12 @@ impl PlayerCondition {
                     return true;
                 }
             }
+            PlayerCondition::Constricted(incoming_hp) => {
+                if let PlayerCondition::Constricted(hp) = self {
+                    *hp += incoming_hp;
+                    return true;
+                }
+            }
             PlayerCondition::Corruption => {
                 if let PlayerCondition::Corruption = self {
                     return true;
@@ -58,18 +96,71 @@ impl PlayerCondition {
                     return true;
                 }
             }
+            PlayerCondition::DevaForm(_, incoming_stack_count) => {
+                if let PlayerCondition::DevaForm(_, stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
+            PlayerCondition::Devotion(incoming_mantra) => {
+                if let PlayerCondition::Devotion(mantra) = self {
+                    *mantra += incoming_mantra;
+                    return true;
+                }
+            }
             PlayerCondition::DoubleTap(incoming_attack_count) => {
                 if let PlayerCondition::DoubleTap(attack_count) = self {
                     *attack_count += incoming_attack_count;
                     return true;
                 }
             }
+            PlayerCondition::DrawReduction(incoming_turns) => {
+                if let PlayerCondition::DrawReduction(turns) = self {
+                    *turns += incoming_turns;
+                    return true;
+                }
+            }
+            PlayerCondition::Duplication(incoming_stack_count) => {
+                if let PlayerCondition::Duplication(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
+            PlayerCondition::EchoForm(incoming_stack_count) => {
+                if let PlayerCondition::EchoForm(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
+            PlayerCondition::Entangled => {
+                if let PlayerCondition::Entangled = self {
+                    return true;
+                }
+            }
+            PlayerCondition::Equilibrium(incoming_turns) => {
+                if let PlayerCondition::Equilibrium(turns) = self {
+                    *turns += incoming_turns;
+                    return true;
+                }
+            }
+            PlayerCondition::Establishment(incoming_stack_count) => {
+                if let PlayerCondition::Establishment(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
             PlayerCondition::Evolve(incoming_draw_count) => {
                 if let PlayerCondition::Evolve(draw_count) = self {
                     *draw_count += incoming_draw_count;
                     return true;
                 }
             }
+            PlayerCondition::Fasting(incoming_energy) => {
+                if let PlayerCondition::Fasting(energy) = self {
+                    *energy += incoming_energy;
+                    return true;
+                }
+            }
             PlayerCondition::FeelNoPain(incoming_block) => {
                 if let PlayerCondition::FeelNoPain(block) = self {
                     *block += incoming_block;
@@ -88,12 +179,30 @@ impl PlayerCondition {
                     return true;
                 }
             }
+            PlayerCondition::Foresight(incoming_scry_count) => {
+                if let PlayerCondition::Foresight(scry_count) = self {
+                    *scry_count += incoming_scry_count;
+                    return true;
+                }
+            }
             PlayerCondition::Frail(incoming_turns) => {
                 if let PlayerCondition::Frail(turns) = self {
                     *turns += incoming_turns;
                     return true;
                 }
             }
+            PlayerCondition::FreeAttack(incoming_stack_count) => {
+                if let PlayerCondition::FreeAttack(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
+            PlayerCondition::Hex(incoming_stack_count) => {
+                if let PlayerCondition::Hex(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
             PlayerCondition::Intangible(incoming_turns) => {
                 if let PlayerCondition::Intangible(turns) = self {
                     *turns += incoming_turns;
@@ -106,24 +215,47 @@ impl PlayerCondition {
                     return true;
                 }
             }
+            PlayerCondition::LikeWater(incoming_block) => {
+                if let PlayerCondition::LikeWater(block) = self {
+                    *block += incoming_block;
+                    return true;
+                }
+            }
             PlayerCondition::Magnetism(incoming_stack_count) => {
                 if let PlayerCondition::Magnetism(stack_count) = self {
                     *stack_count += incoming_stack_count;
                     return true;
                 }
             }
+            PlayerCondition::MasterReality => {
+                if let PlayerCondition::MasterReality = self {
+                    return true;
+                }
+            }
             PlayerCondition::Mayhem(incoming_stack_count) => {
                 if let PlayerCondition::Mayhem(stack_count) = self {
                     *stack_count += incoming_stack_count;
                     return true;
                 }
             }
+            PlayerCondition::MentalFortress(incoming_block) => {
+                if let PlayerCondition::MentalFortress(block) = self {
+                    *block += incoming_block;
+                    return true;
+                }
+            }
             PlayerCondition::Metallicize(incoming_block) => {
                 if let PlayerCondition::Metallicize(block) = self {
                     *block += incoming_block;
                     return true;
                 }
             }
+            PlayerCondition::Nirvana(incoming_block) => {
+                if let PlayerCondition::Nirvana(block) = self {
+                    *block += incoming_block;
+                    return true;
+                }
+            }
             PlayerCondition::NoBlock(incoming_turns) => {
                 if let PlayerCondition::NoBlock(turns) = self {
                     *turns += incoming_turns;
@@ -135,6 +267,12 @@ impl PlayerCondition {
                     return true;
                 }
             }
+            PlayerCondition::Omega(incoming_damage) => {
+                if let PlayerCondition::Omega(damage) = self {
+                    *damage += incoming_damage;
+                    return true;
+                }
+            }
             PlayerCondition::Panache(incoming_stack_count, incoming_damage) => {
                 if let PlayerCondition::Panache(stack_count, damage) = self {
                     *stack_count = (*stack_count).max(*incoming_stack_count);
@@ -142,6 +280,12 @@ impl PlayerCondition {
                     return true;
                 }
             }
+            PlayerCondition::PlatedArmor(incoming_block) => {
+                if let PlayerCondition::PlatedArmor(block) = self {
+                    *block += incoming_block;
+                    return true;
+                }
+            }
             PlayerCondition::Rage(incoming_block) => {
                 if let PlayerCondition::Rage(block) = self {
                     *block += incoming_block;
@@ -154,18 +298,41 @@ impl PlayerCondition {
                     return true;
                 }
             }
+            PlayerCondition::Rushdown(incoming_draw_count) => {
+                if let PlayerCondition::Rushdown(draw_count) = self {
+                    *draw_count += incoming_draw_count;
+                    return true;
+                }
+            }
             PlayerCondition::Sadistic(incoming_damage) => {
                 if let PlayerCondition::Sadistic(damage) = self {
                     *damage += incoming_damage;
                     return true;
                 }
             }
+            PlayerCondition::SimmeringFury(incoming_draw_count) => {
+                if let PlayerCondition::SimmeringFury(draw_count) = self {
+                    *draw_count += incoming_draw_count;
+                    return true;
+                }
+            }
             PlayerCondition::StrengthDown(incoming_strength) => {
                 if let PlayerCondition::StrengthDown(strength) = self {
                     *strength += incoming_strength;
                     return true;
                 }
             }
+            PlayerCondition::Study(incoming_stack_count) => {
+                if let PlayerCondition::Study(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
+            PlayerCondition::Surrounded => {
+                if let PlayerCondition::Surrounded = self {
+                    return true;
+                }
+            }
             PlayerCondition::TheBomb(_, _) => {
                 return false; // Doesn't stack
             }
@@ -175,99 +342,262 @@ impl PlayerCondition {
                     return true;
                 }
             }
+            PlayerCondition::Vigor(incoming_damage) => {
+                if let PlayerCondition::Vigor(damage) = self {
+                    *damage += incoming_damage;
+                    return true;
+                }
+            }
             PlayerCondition::Vulnerable(incoming_turns) => {
                 if let PlayerCondition::Vulnerable(turns) = self {
                     *turns += incoming_turns;
                     return true;
                 }
             }
+            PlayerCondition::WaveOfTheHand(incoming_stack_count) => {
+                if let PlayerCondition::WaveOfTheHand(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
             PlayerCondition::Weak(incoming_turns) => {
                 if let PlayerCondition::Weak(turns) = self {
                     *turns += incoming_turns;
                     return true;
                 }
             }
+            PlayerCondition::WellLaidPlans(incoming_stack_count) => {
+                if let PlayerCondition::WellLaidPlans(stack_count) = self {
+                    *stack_count += incoming_stack_count;
+                    return true;
+                }
+            }
         }
         false
     }
 
+    /// Queues any effects triggered by the start of the player's turn.
     /// Returns true iff the condition is still active.
-    pub fn on_turn_started(&mut self) -> bool {
-        !matches!(self, PlayerCondition::FlameBarrier(_))
+    pub fn on_turn_started(&mut self, effect_queue: &mut EffectQueue) -> bool {
+        match self {
+            PlayerCondition::BattleHymn(stack_count) => {
+                for _ in 0..*stack_count {
+                    effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::CreateCards(
+                        CardPool::Fixed(&[Card::Smite(false)]),
+                        CardSelection::All,
+                        CardDestination::Hand,
+                        CostModifier::None,
+                    )));
+                }
+                true
+            }
+            PlayerCondition::Blasphemer => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::TakeDamage(
+                    Damage::HpLoss(99999),
+                )));
+                false
+            }
+            PlayerCondition::Collect(turns) => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::CreateCards(
+                    CardPool::Fixed(&[Card::Miracle(true)]),
+                    CardSelection::All,
+                    CardDestination::Hand,
+                    CostModifier::None,
+                )));
+                *turns = turns.saturating_sub(1);
+                *turns > 0
+            }
+            PlayerCondition::DevaForm(energy, stack_count) => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Gain(
+                    Resource::Energy(*energy),
+                )));
+                *energy += *stack_count;
+                true
+            }
+            PlayerCondition::Devotion(mantra) => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Gain(
+                    Resource::Mantra(*mantra),
+                )));
+                true
+            }
+            PlayerCondition::Fasting(energy) => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Lose(
+                    Resource::Energy(*energy),
+                )));
+                true
+            }
+            PlayerCondition::FlameBarrier(_) => false,
+            PlayerCondition::Foresight(scry_count) => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Scry(*scry_count)));
+                true
+            }
+            PlayerCondition::SimmeringFury(draw_count) => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::EnterStance(
+                    Stance::Wrath,
+                )));
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Draw(*draw_count)));
+                false
+            }
+            _ => true,
+        }
     }
 
-    /// Ticks down a condition's turn counter at the end of the player's turn.
-    /// Returns true iff the condition is still active.
-    pub fn on_turn_finished(&mut self) -> bool {
+    /// Ticks down a condition's turn counter at the end of the player's turn, queueing any
+    /// effects it triggers. Returns true iff the condition is still active.
+    pub fn on_turn_finished(
+        &mut self,
+        stance: Option<Stance>,
+        effect_queue: &mut EffectQueue,
+    ) -> bool {
         match self {
+            PlayerCondition::Amplify(_) => false, // This turn only
             PlayerCondition::Artifact(_) => true,
             PlayerCondition::Barricade => true,
+            PlayerCondition::BattleHymn(_) => true,
             PlayerCondition::Berserk(_) => true,
+            PlayerCondition::Blasphemer => true,
             PlayerCondition::Brutality(_) => true,
+            PlayerCondition::Burst(_) => false, // This turn only
+            PlayerCondition::Collect(_) => true,
             PlayerCondition::Combust(_, _) => true,
             PlayerCondition::Confused => true,
+            PlayerCondition::Constricted(hp) => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::TakeDamage(
+                    Damage::BlockableNonAttack(*hp),
+                )));
+                true
+            }
             PlayerCondition::Corruption => true,
             PlayerCondition::DarkEmbrace(_) => true,
             PlayerCondition::DemonForm(_) => true,
+            PlayerCondition::DevaForm(_, _) => true,
+            PlayerCondition::Devotion(_) => true,
             PlayerCondition::DoubleTap(_) => false, // This turn only
+            PlayerCondition::DrawReduction(turns) => {
+                *turns = turns.saturating_sub(1);
+                *turns > 0
+            }
+            PlayerCondition::Duplication(_) => false, // This turn only
+            PlayerCondition::EchoForm(_) => true,
+            PlayerCondition::Entangled => false, // This turn only
+            PlayerCondition::Equilibrium(turns) => {
+                *turns = turns.saturating_sub(1);
+                *turns > 0
+            }
+            PlayerCondition::Establishment(_) => true,
             PlayerCondition::Evolve(_) => true,
+            PlayerCondition::Fasting(_) => true,
             PlayerCondition::FeelNoPain(_) => true,
             PlayerCondition::FireBreathing(_) => true,
             PlayerCondition::FlameBarrier(_) => true,
+            PlayerCondition::Foresight(_) => true,
             PlayerCondition::Frail(turns) => {
                 *turns = turns.saturating_sub(1);
                 *turns > 0
             }
+            PlayerCondition::FreeAttack(_) => true,
+            PlayerCondition::Hex(_) => true,
             PlayerCondition::Intangible(turns) => {
                 *turns = turns.saturating_sub(1);
                 *turns > 0
             }
             PlayerCondition::Juggernaut(_) => true,
+            PlayerCondition::LikeWater(block) => {
+                if stance == Some(Stance::Calm) {
+                    effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Gain(
+                        Resource::Block(*block),
+                    )));
+                }
+                true
+            }
             PlayerCondition::Magnetism(_) => true,
+            PlayerCondition::MasterReality => true,
             PlayerCondition::Mayhem(_) => true,
-            PlayerCondition::Metallicize(_) => true,
+            PlayerCondition::MentalFortress(_) => true,
+            PlayerCondition::Metallicize(block) | PlayerCondition::PlatedArmor(block) => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Gain(
+                    Resource::Block(*block),
+                )));
+                true
+            }
+            PlayerCondition::Nirvana(_) => true,
             PlayerCondition::NoBlock(turns) => {
                 *turns = turns.saturating_sub(1);
                 *turns > 0
             }
             PlayerCondition::NoDraw => false, // This turn only
+            PlayerCondition::Omega(hp) => {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::ToAllEnemies(
+                    TargetEffect::Deal(Damage::BlockableNonAttack(*hp)),
+                )));
+                true
+            }
             PlayerCondition::Panache(stack_count, _) => {
                 *stack_count = 5; // Always rests to 5 at end of turn
                 true
             }
             PlayerCondition::Rage(_) => false, // This turn only
             PlayerCondition::Rupture(_) => true,
+            PlayerCondition::Rushdown(_) => true,
             PlayerCondition::Sadistic(_) => true,
+            PlayerCondition::SimmeringFury(_) => true,
             PlayerCondition::StrengthDown(_) => false, // This turn only
+            PlayerCondition::Study(stack_count) => {
+                for _ in 0..*stack_count {
+                    effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::CreateCards(
+                        CardPool::Fixed(&[Card::Insight(false)]),
+                        CardSelection::All,
+                        CardDestination::ShuffledIntoDrawPile,
+                        CostModifier::None,
+                    )));
+                }
+                true
+            }
+            PlayerCondition::Surrounded => true,
             PlayerCondition::TheBomb(turns, damage) => {
                 *turns = turns.saturating_sub(1);
                 if *turns == 0 {
-                    todo!("implement bomb damage {}", damage)
+                    effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::ToAllEnemies(
+                        TargetEffect::Deal(Damage::BlockableNonAttack(*damage)),
+                    )));
                 }
                 *turns > 0
             }
             PlayerCondition::Thorns(_) => true,
+            PlayerCondition::Vigor(_) => true,
             PlayerCondition::Vulnerable(turns) => {
                 *turns = turns.saturating_sub(1);
                 *turns > 0
             }
+            PlayerCondition::WaveOfTheHand(_) => false, // This turn only
             PlayerCondition::Weak(turns) => {
                 *turns = turns.saturating_sub(1);
                 *turns > 0
             }
+            PlayerCondition::WellLaidPlans(_) => true,
         }
     }
 
+    /// Queues any effects triggered by the player gaining block.
+    pub fn on_block_gained(&self, effect_queue: &mut EffectQueue) -> bool {
+        if let PlayerCondition::WaveOfTheHand(stack_count) = self {
+            effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::ToAllEnemies(
+                TargetEffect::Inflict(EnemyCondition::Weak(*stack_count)),
+            )));
+        }
+        true
+    }
+
     /// Queues any effects triggered by the player exhausting a card.
     pub fn on_card_exhausted(&self, effect_queue: &mut EffectQueue) -> bool {
         match self {
             PlayerCondition::DarkEmbrace(card_count) => {
-                effect_queue.push_back(Effect::PlayerState(PlayerEffect::Draw(*card_count)));
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Draw(*card_count)));
             }
             PlayerCondition::FeelNoPain(block) => {
-                effect_queue.push_back(Effect::PlayerState(PlayerEffect::Gain(Resource::Block(
-                    *block,
-                ))));
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::Gain(
+                    Resource::Block(*block),
+                )));
             }
             _ => {}
         }
@@ -276,17 +606,21 @@ impl PlayerCondition {
 
     /// Queues any effects triggered by the player taking damage.
     pub fn on_damage_taken(
-        &self,
+        &mut self,
         damage_taken: &DamageTaken,
         effect_queue: &mut EffectQueue,
     ) -> bool {
         if damage_taken.provokes_thorns {
             match self {
                 PlayerCondition::FlameBarrier(hp) | PlayerCondition::Thorns(hp) => {
-                    effect_queue.push_front(Effect::PlayerState(PlayerEffect::ToSingleTarget(
+                    effect_queue.add_to_top(Effect::PlayerState(PlayerEffect::ToSingleTarget(
                         TargetEffect::Deal(Damage::BlockableNonAttack(*hp)),
                     )));
                 }
+                PlayerCondition::PlatedArmor(block) if damage_taken.hp_lost > 0 => {
+                    *block = block.saturating_sub(1);
+                    return *block > 0;
+                }
                 _ => {}
             }
         }
@@ -295,15 +629,31 @@ impl PlayerCondition {
 
     /// Queues any effects triggered by the player playing a card.
     pub fn on_some_card_played(
-        &self,
+        &mut self,
         combat_card: &CardCombatState,
         effect_queue: &mut EffectQueue,
     ) -> bool {
-        if let PlayerCondition::Rage(stacks) = self {
-            if combat_card.details.type_ == CardType::Attack {
-                effect_queue.push_front(Effect::PlayerState(PlayerEffect::Gain(Resource::Block(
-                    *stacks,
-                ))));
+        if combat_card.details.type_ == CardType::Attack {
+            match self {
+                PlayerCondition::FreeAttack(stack_count) => {
+                    *stack_count = stack_count.saturating_sub(1);
+                    return *stack_count > 0;
+                }
+                PlayerCondition::Rage(stacks) => {
+                    effect_queue.add_to_top(Effect::PlayerState(PlayerEffect::Gain(
+                        Resource::Block(*stacks),
+                    )));
+                }
+                _ => {}
+            }
+        } else if let PlayerCondition::Hex(stack_count) = self {
+            for _ in 0..*stack_count {
+                effect_queue.add_to_bottom(Effect::PlayerState(PlayerEffect::CreateCards(
+                    CardPool::Fixed(&[Card::Dazed]),
+                    CardSelection::All,
+                    CardDestination::ShuffledIntoDrawPile,
+                    CostModifier::None,
+                )));
             }
         }
         true
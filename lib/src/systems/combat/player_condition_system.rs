@@ -1,8 +1,9 @@
 use anyhow::Error;
 
 use crate::components::{CardCombatState, DamageTaken, Interaction, Notification};
-use crate::data::PlayerCondition;
+use crate::data::{EnemyCondition, PlayerCondition};
 use crate::systems::base::CombatContext;
+use crate::types::EnemyIndex;
 
 pub struct PlayerConditionSystem;
 
@@ -13,9 +14,73 @@ impl PlayerConditionSystem {
             .send_notification(Notification::Conditions(ctx.pcs.conditions.to_vec()))
     }
 
+    /// Surrounds the player when an enemy starts the fight behind them.
+    pub fn on_combat_started<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        if ctx
+            .enemy_party
+            .0
+            .iter()
+            .flatten()
+            .any(|enemy_state| enemy_state.conditions.contains(&EnemyCondition::BackAttack))
+        {
+            ctx.pcs.conditions.push(PlayerCondition::Surrounded);
+        }
+        Ok(())
+    }
+
+    /// Turns a surrounded player to face the targeted enemy, leaving the others at their back.
+    pub fn face_enemy<I: Interaction>(ctx: &mut CombatContext<I>, enemy_index: EnemyIndex) {
+        if !ctx.pcs.conditions.contains(&PlayerCondition::Surrounded) {
+            return;
+        }
+        for (i, enemy_state) in ctx
+            .enemy_party
+            .0
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(i, maybe_enemy)| maybe_enemy.as_mut().map(|e| (i, e)))
+        {
+            let behind = enemy_state.conditions.contains(&EnemyCondition::BackAttack);
+            if i == enemy_index {
+                enemy_state
+                    .conditions
+                    .retain(|c| *c != EnemyCondition::BackAttack);
+            } else if !behind && enemy_state.is_standing() {
+                enemy_state.conditions.push(EnemyCondition::BackAttack);
+            }
+        }
+    }
+
+    /// Lifts Surrounded once fewer than two enemies are left standing to flank the player.
+    pub fn on_enemy_removed<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        if !ctx.pcs.conditions.contains(&PlayerCondition::Surrounded)
+            || ctx
+                .enemy_party
+                .0
+                .iter()
+                .flatten()
+                .filter(|enemy_state| enemy_state.is_standing())
+                .count()
+                >= 2
+        {
+            return Ok(());
+        }
+        ctx.pcs
+            .conditions
+            .retain(|c| *c != PlayerCondition::Surrounded);
+        for enemy_state in ctx.enemy_party.0.iter_mut().flatten() {
+            enemy_state
+                .conditions
+                .retain(|c| *c != EnemyCondition::BackAttack);
+        }
+        Self::notify_player(ctx)
+    }
+
     /// Ticks down the conditions at the start of the player's turn.
     pub fn on_player_turn_started<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
-        ctx.pcs.conditions.retain_mut(|c| c.on_turn_started());
+        ctx.pcs
+            .conditions
+            .retain_mut(|c| c.on_turn_started(&mut ctx.effect_queue));
         Self::notify_player(ctx)
     }
 
@@ -23,7 +88,10 @@ impl PlayerConditionSystem {
     pub fn on_player_turn_finished<I: Interaction>(
         ctx: &mut CombatContext<I>,
     ) -> Result<(), Error> {
-        ctx.pcs.conditions.retain_mut(|c| c.on_turn_finished());
+        let stance = ctx.pcs.stance;
+        ctx.pcs
+            .conditions
+            .retain_mut(|c| c.on_turn_finished(stance, &mut ctx.effect_queue));
         Self::notify_player(ctx)
     }
 
@@ -48,6 +116,13 @@ impl PlayerConditionSystem {
         Self::notify_player(ctx)
     }
 
+    /// Queues any effects triggered by the player gaining block.
+    pub fn on_block_gained<I: Interaction>(ctx: &mut CombatContext<I>) {
+        ctx.pcs
+            .conditions
+            .retain_mut(|c| c.on_block_gained(&mut ctx.effect_queue));
+    }
+
     /// Queues any effects triggered by the player taking damage.
     pub fn on_damage_taken<I: Interaction>(
         ctx: &mut CombatContext<I>,
@@ -1,17 +1,26 @@
 use anyhow::Error;
 
-use crate::components::Interaction;
-use crate::data::{PlayerEffect, Resource, TargetEffect};
-use crate::systems::base::CombatContext;
-use crate::types::EnemyIndex;
+use crate::components::{CardCombatState, Choice, Effect, Interaction, Notification, Prompt};
+use crate::data::{
+    CardDestination, CardDetails, CardSelection, CardSource, CardType, CostModifier, Damage,
+    EnemyCondition, EnergyCost, Intent, PlayerEffect, PlayerEffectCondition, Resource,
+    TargetCondition, TargetEffect,
+};
+use crate::systems::base::{CombatContext, DeckSystem, GoldSystem, HealthSystem};
+use crate::types::{EnemyIndex, Hp};
 
 use super::block_system::BlockSystem;
 use super::card_creation_system::CardCreationSystem;
-use super::damage_calculator::DamageCalculator;
+use super::card_queue_system::CardQueueSystem;
+use super::damage_calculator::{CalculatedBlock, CalculatedDamage, DamageCalculator};
 use super::draw_system::DrawSystem;
 use super::effect_system::EffectSystem;
 use super::enemy_condition_system::EnemyConditionSystem;
+use super::energy_system::EnergySystem;
+use super::exhaust_system::ExhaustSystem;
 use super::player_condition_system::PlayerConditionSystem;
+use super::scry_system::ScrySystem;
+use super::stance_system::StanceSystem;
 
 pub struct PlayerEffectSystem;
 
@@ -24,7 +33,28 @@ impl PlayerEffectSystem {
             PlayerEffect::Apply(player_condition) => {
                 PlayerConditionSystem::apply_to_player(ctx, player_condition)
             }
-            PlayerEffect::Conditional(_player_effect_condition, _player_effects) => todo!(),
+            PlayerEffect::ChooseOne(player_effects) => {
+                let choices = player_effects
+                    .iter()
+                    .cloned()
+                    .map(Choice::ChooseEffect)
+                    .collect::<Vec<_>>();
+                match ctx.comms.prompt_for_choice(Prompt::ChooseOne, &choices)? {
+                    Choice::ChooseEffect(player_effect) => {
+                        ctx.effect_queue
+                            .add_to_top(Effect::PlayerState(player_effect.clone()));
+                        Ok(())
+                    }
+                    invalid => unreachable!("{:?}", invalid),
+                }
+            }
+            PlayerEffect::Conditional(player_effect_condition, player_effects) => {
+                if Self::evaluate_condition(ctx, player_effect_condition) {
+                    ctx.effect_queue
+                        .add_all_to_top(player_effects.iter().map(Effect::Card));
+                }
+                Ok(())
+            }
             PlayerEffect::CreateCards(
                 card_pool,
                 card_selection,
@@ -37,27 +67,155 @@ impl PlayerEffectSystem {
                 card_destination,
                 cost_modifier,
             ),
+            PlayerEffect::CreateExpungerHittingXPlus(extra_hits) => {
+                let x = EnergySystem::resolve_x(ctx)?;
+                CardCreationSystem::create_expunger(ctx, x + extra_hits)
+            }
             PlayerEffect::Draw(draw_count) => {
                 for _ in 0..*draw_count {
                     DrawSystem::draw_one_card(ctx)?;
                 }
                 Ok(())
             }
+            PlayerEffect::EndTurn => {
+                ctx.pcs.end_turn_requested = true;
+                Ok(())
+            }
+            PlayerEffect::EnterStance(stance) => StanceSystem::change_stance(ctx, Some(*stance)),
+            PlayerEffect::ExitStance => StanceSystem::change_stance(ctx, None),
             PlayerEffect::ForEachExhausted(_player_effects) => todo!(),
             PlayerEffect::Gain(resource) => Self::gain_resource(ctx, resource),
+            PlayerEffect::Lose(Resource::Energy(energy)) => EnergySystem::lose(ctx, *energy),
             PlayerEffect::Lose(_resource) => todo!(),
+            PlayerEffect::ManipulateCards(
+                CardSource::DiscardPile,
+                CardSelection::PlayerChoice(card_count),
+                card_destination @ (CardDestination::Hand | CardDestination::HandAndRetain),
+                _cost_modifier,
+            ) => Self::return_cards_from_discard_pile(
+                ctx,
+                *card_count,
+                *card_destination == CardDestination::HandAndRetain,
+            ),
+            PlayerEffect::ManipulateCards(
+                CardSource::DiscardPile,
+                CardSelection::All,
+                CardDestination::ShuffledIntoDrawPile,
+                _cost_modifier,
+            ) => DrawSystem::shuffle_discard_pile_into_draw_pile(ctx),
+            PlayerEffect::ManipulateCards(
+                CardSource::Hand,
+                CardSelection::All,
+                CardDestination::Hand,
+                cost_modifier,
+            ) => {
+                for combat_card in ctx.pcs.cards.hand.iter_mut() {
+                    combat_card.modify_cost(cost_modifier);
+                }
+                Ok(())
+            }
+            PlayerEffect::ManipulateCards(
+                CardSource::Hand,
+                CardSelection::Random(1),
+                CardDestination::Hand,
+                cost_modifier,
+            ) => {
+                Self::modify_cost_of_random_card_in_hand(ctx, cost_modifier);
+                Ok(())
+            }
+            PlayerEffect::ManipulateCards(
+                CardSource::Hand,
+                card_selection @ (CardSelection::PlayerChoice(_)
+                | CardSelection::PlayerChoiceUnlimited
+                | CardSelection::PlayerChoiceUpTo(_)),
+                card_destination @ (CardDestination::BottomOfDrawPile
+                | CardDestination::ExhaustPile
+                | CardDestination::TopOfDrawPile),
+                cost_modifier,
+            ) => Self::move_cards_from_hand(ctx, card_selection, card_destination, cost_modifier),
+            PlayerEffect::ManipulateCards(
+                card_source @ (CardSource::AttacksInDrawPile | CardSource::SkillsInDrawPile),
+                CardSelection::PlayerChoice(1),
+                CardDestination::Hand,
+                _cost_modifier,
+            ) => Self::take_card_from_draw_pile(
+                ctx,
+                if *card_source == CardSource::AttacksInDrawPile {
+                    CardType::Attack
+                } else {
+                    CardType::Skill
+                },
+            ),
+            PlayerEffect::ManipulateCards(
+                CardSource::AttacksInDrawPile,
+                CardSelection::Random(card_count),
+                CardDestination::Hand,
+                _cost_modifier,
+            ) => Self::take_random_attacks_from_draw_pile(ctx, *card_count),
             PlayerEffect::ManipulateCards(
                 _card_source,
                 _card_selection,
                 _card_destination,
                 _cost_modifier,
             ) => todo!(),
-            PlayerEffect::PlayThenExhaustTopCardOfDrawPile => todo!(),
-            PlayerEffect::RampUpCardDamage(_) => todo!(),
-            PlayerEffect::TakeDamage(_damage) => todo!(),
+            PlayerEffect::PlayThenExhaustTopCardOfDrawPile => {
+                CardQueueSystem::queue_top_card_of_draw_pile(ctx, true)
+            }
+            PlayerEffect::PlayTopCardOfDrawPile => {
+                CardQueueSystem::queue_top_card_of_draw_pile(ctx, false)
+            }
+            PlayerEffect::PlayTwiceThenExhaust(card_source) => {
+                CardQueueSystem::choose_card_to_play_twice(ctx, card_source)
+            }
+            PlayerEffect::RampUpCardDamage(amount) => {
+                Self::ramp_up_card_damage(ctx, *amount, false);
+                Ok(())
+            }
+            PlayerEffect::RampUpCardDamagePermanently(amount) => {
+                Self::ramp_up_card_damage(ctx, *amount, true);
+                Ok(())
+            }
+            PlayerEffect::RepeatX(player_effects) => {
+                let x = EnergySystem::resolve_x(ctx)?;
+                for _ in 0..x {
+                    ctx.effect_queue
+                        .add_all_to_top(player_effects.iter().map(Effect::Card));
+                }
+                Ok(())
+            }
+            PlayerEffect::Scry(scry_count) => ScrySystem::scry(ctx, *scry_count),
+            PlayerEffect::TakeDamage(damage) => {
+                let calculated_damage = match damage {
+                    Damage::Blockable(amount) | Damage::BlockableNonAttack(amount) => {
+                        CalculatedDamage::BlockableNonAttack(*amount)
+                    }
+                    Damage::HpLoss(amount) => CalculatedDamage::HpLoss(*amount),
+                    invalid => unreachable!("{:?}", invalid),
+                };
+                BlockSystem::damage_player(ctx, calculated_damage)?;
+                Ok(())
+            }
+            PlayerEffect::TakeExtraTurn => {
+                ctx.pcs.extra_turn_pending = true;
+                Ok(())
+            }
+            PlayerEffect::ToAllEnemies(TargetEffect::DealXTimes(damage)) => {
+                // Each round hits every enemy before the next begins (e.g. Whirlwind).
+                let x = EnergySystem::resolve_x(ctx)?;
+                for _ in 0..x {
+                    Self::process_player_effect(
+                        ctx,
+                        &PlayerEffect::ToAllEnemies(TargetEffect::Deal(damage.clone())),
+                    )?;
+                }
+                Ok(())
+            }
             PlayerEffect::ToAllEnemies(target_effect) => {
                 for enemy_index in 0..ctx.enemy_party.0.len() {
-                    if ctx.enemy_party.0[enemy_index].is_some() {
+                    if ctx.enemy_party.0[enemy_index]
+                        .as_ref()
+                        .is_some_and(|enemy| !enemy.half_dead)
+                    {
                         ctx.maybe_enemy_index = Some(enemy_index);
                         Self::to_target_effect(ctx, target_effect)?;
                     }
@@ -66,14 +224,77 @@ impl PlayerEffectSystem {
                 Ok(())
             }
             PlayerEffect::ToRandomEnemy(target_effect) => {
+                // The hit is skipped if every enemy died partway through (e.g. Ragnarok).
                 ctx.maybe_enemy_index = Self::pick_random_enemy(ctx);
-                Self::to_target_effect(ctx, target_effect)
+                if ctx.maybe_enemy_index.is_some() {
+                    Self::to_target_effect(ctx, target_effect)
+                } else {
+                    Ok(())
+                }
             }
             PlayerEffect::ToSingleTarget(target_effect) => {
                 assert!(ctx.maybe_enemy_index.is_some());
                 Self::to_target_effect(ctx, target_effect)
             }
-            PlayerEffect::Upgrade(_card_source, _card_selection) => todo!(),
+            PlayerEffect::Upgrade(CardSource::Deck, CardSelection::Random(card_count)) => {
+                Self::upgrade_random_cards_in_deck(ctx, *card_count)
+            }
+            PlayerEffect::Upgrade(CardSource::AllCardsInCombat, CardSelection::All) => {
+                for combat_card in ctx.pcs.cards.iter_mut() {
+                    combat_card.upgrade();
+                }
+                Ok(())
+            }
+            PlayerEffect::Upgrade(CardSource::Hand, card_selection) => {
+                Self::upgrade_cards_in_hand(ctx, card_selection)
+            }
+            invalid @ PlayerEffect::Upgrade(..) => unreachable!("{:?}", invalid),
+        }
+    }
+
+    fn evaluate_condition<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        condition: &PlayerEffectCondition,
+    ) -> bool {
+        match condition {
+            PlayerEffectCondition::IfHandContainsNoAttackCards => ctx
+                .pcs
+                .cards
+                .hand
+                .iter()
+                .all(|combat_card| combat_card.details.type_ != CardType::Attack),
+            PlayerEffectCondition::IfInStance(stance) => ctx.pcs.stance == Some(*stance),
+            PlayerEffectCondition::IfLastCardPlayedWas(card_type) => {
+                ctx.pcs.last_card_played == Some(*card_type)
+            }
+            PlayerEffectCondition::IfNotInStance(stance) => ctx.pcs.stance != Some(*stance),
+        }
+    }
+
+    fn evaluate_target_condition<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        condition: &TargetCondition,
+    ) -> bool {
+        let maybe_enemy = ctx
+            .maybe_enemy_index
+            .and_then(|i| ctx.enemy_party.0.get(i))
+            .and_then(|maybe_enemy| maybe_enemy.as_ref());
+        match condition {
+            TargetCondition::AttackWasFatal => maybe_enemy.is_none_or(|e| e.is_dead()),
+            TargetCondition::IntendsToAttack => maybe_enemy.is_some_and(|e| {
+                matches!(
+                    e.next_action.intent(),
+                    Intent::Aggressive(_, _)
+                        | Intent::AggressiveBuff(_, _)
+                        | Intent::AggressiveDebuff(_, _)
+                        | Intent::AggressiveDefensive(_, _)
+                )
+            }),
+            TargetCondition::IsVulnerable => maybe_enemy.is_some_and(|e| {
+                e.conditions
+                    .iter()
+                    .any(|c| matches!(c, EnemyCondition::Vulnerable(_)))
+            }),
         }
     }
 
@@ -83,18 +304,152 @@ impl PlayerEffectSystem {
     ) -> Result<(), Error> {
         match resource {
             Resource::Block(block) => {
-                let calculated_block = DamageCalculator::calculate_block_gained(&ctx.pcs, *block);
+                let additional_block = ctx
+                    .pcs
+                    .card_being_played
+                    .map_or(0, |combat_card| combat_card.additional_block);
+                let calculated_block =
+                    DamageCalculator::calculate_block_gained(&ctx.pcs, *block + additional_block);
                 BlockSystem::gain_block(ctx, calculated_block)
             }
+            Resource::BlockEqualToUnblockedDamage => {
+                let amount = ctx.pcs.unblocked_damage_dealt;
+                BlockSystem::gain_block(ctx, CalculatedBlock { amount })
+            }
+            Resource::BlockPerCardInHand(block) => {
+                let per_card = DamageCalculator::calculate_block_gained(&ctx.pcs, *block).amount;
+                let amount = per_card * ctx.pcs.cards.hand.len() as u32;
+                BlockSystem::gain_block(ctx, CalculatedBlock { amount })
+            }
             Resource::CurrentBlockIsDoubled => todo!(),
             Resource::CurrentStrengthIsDoubled => todo!(),
-            Resource::Energy(_) => todo!(),
-            Resource::Gold(_) => todo!(),
-            Resource::Hp(_) => todo!(),
-            Resource::HpEqualToUnblockedDamage => todo!(),
-            Resource::HpMax(_) => todo!(),
-            Resource::Strength(_) => todo!(),
+            Resource::Dexterity(dexterity) => {
+                ctx.pcs.dexterity += dexterity;
+                ctx.comms
+                    .send_notification(Notification::Dexterity(ctx.pcs.dexterity))
+            }
+            Resource::Energy(energy) => EnergySystem::gain(ctx, *energy),
+            Resource::Gold(gold) => GoldSystem::increase_gold(ctx.comms, ctx.pcs.pps, *gold),
+            Resource::Hp(hp) => HealthSystem::heal(ctx.comms, ctx.pcs.pps, *hp),
+            Resource::HpEqualToUnblockedDamage => {
+                HealthSystem::heal(ctx.comms, ctx.pcs.pps, ctx.pcs.unblocked_damage_dealt)
+            }
+            Resource::HpMax(hp_max) => {
+                HealthSystem::increase_hp_max(ctx.comms, ctx.pcs.pps, *hp_max)
+            }
+            Resource::Mantra(mantra) => StanceSystem::gain_mantra(ctx, *mantra),
+            Resource::Strength(strength) => {
+                ctx.pcs.strength += strength;
+                ctx.comms
+                    .send_notification(Notification::Strength(ctx.pcs.strength))
+            }
+        }
+    }
+
+    /// Lowers the cost of a random card in the player's hand (Madness). As in the game, cards
+    /// that cost energy this turn are preferred over those that only cost energy normally, and
+    /// the pick is redrawn until it lands on one of them.
+    fn modify_cost_of_random_card_in_hand<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        cost_modifier: &CostModifier,
+    ) {
+        let costs_energy = |energy_cost| !matches!(energy_cost, EnergyCost::Zero | EnergyCost::X);
+        let hand = &mut ctx.pcs.cards.hand;
+        let this_turn = hand
+            .iter()
+            .any(|combat_card| costs_energy(combat_card.cost_this_turn));
+        if !this_turn
+            && !hand
+                .iter()
+                .any(|combat_card| costs_energy(combat_card.cost_this_combat))
+        {
+            return;
+        }
+        loop {
+            let hand_index = ctx.card_randomizer_rng.gen_range(0..hand.len());
+            let combat_card = &mut hand[hand_index];
+            if costs_energy(if this_turn {
+                combat_card.cost_this_turn
+            } else {
+                combat_card.cost_this_combat
+            }) {
+                combat_card.modify_cost(cost_modifier);
+                break;
+            }
+        }
+    }
+
+    /// Prompts the player to move cards from their hand to the draw pile or the exhaust pile,
+    /// lowering their cost on the way. The player may stop early unless a fixed number of cards
+    /// must be chosen, and the choice is made for them if there is nothing to choose between.
+    fn move_cards_from_hand<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_selection: &CardSelection,
+        card_destination: &CardDestination,
+        cost_modifier: &CostModifier,
+    ) -> Result<(), Error> {
+        let (card_count, optional) = match card_selection {
+            CardSelection::PlayerChoice(card_count) => (*card_count, false),
+            CardSelection::PlayerChoiceUnlimited => (usize::MAX, true),
+            CardSelection::PlayerChoiceUpTo(card_count) => (*card_count, true),
+            invalid => unreachable!("{:?}", invalid),
+        };
+        let (prompt, to_choice): (_, fn(_, _) -> _) = match card_destination {
+            CardDestination::BottomOfDrawPile => (
+                Prompt::ChooseCardToPutOnBottomOfDrawPile,
+                Choice::PutOnBottomOfDrawPile,
+            ),
+            CardDestination::ExhaustPile => (Prompt::ChooseCardToExhaust, Choice::ExhaustCard),
+            CardDestination::TopOfDrawPile => (
+                Prompt::ChooseCardToPutOnTopOfDrawPile,
+                Choice::PutBackOnDrawPile,
+            ),
+            invalid => unreachable!("{:?}", invalid),
+        };
+        for _ in 0..card_count {
+            let hand_index = if ctx.pcs.cards.hand.is_empty() {
+                break;
+            } else if !optional && ctx.pcs.cards.hand.len() == 1 {
+                0
+            } else {
+                let mut choices = ctx
+                    .pcs
+                    .cards
+                    .hand
+                    .iter()
+                    .enumerate()
+                    .map(|(hand_index, combat_card)| to_choice(hand_index, combat_card.card))
+                    .collect::<Vec<_>>();
+                if optional {
+                    choices.push(Choice::Skip);
+                }
+                match ctx.comms.prompt_for_choice(prompt.clone(), &choices)? {
+                    Choice::ExhaustCard(hand_index, _)
+                    | Choice::PutBackOnDrawPile(hand_index, _)
+                    | Choice::PutOnBottomOfDrawPile(hand_index, _) => *hand_index,
+                    Choice::Skip => break,
+                    invalid => unreachable!("{:?}", invalid),
+                }
+            };
+            let mut combat_card = ctx.pcs.cards.hand.remove(hand_index);
+            combat_card.modify_cost(cost_modifier);
+            let draw_index = match card_destination {
+                CardDestination::BottomOfDrawPile => 0,
+                CardDestination::ExhaustPile => {
+                    ExhaustSystem::push(ctx, hand_index, combat_card)?;
+                    continue;
+                }
+                _ => ctx.pcs.cards.draw_pile.len(),
+            };
+            ctx.pcs.cards.draw_pile.insert(draw_index, combat_card);
+            ctx.comms
+                .send_notification(Notification::CardPutOnDrawPile(
+                    hand_index,
+                    draw_index,
+                    combat_card,
+                ))?;
         }
+        Ok(())
     }
 
     fn pick_random_enemy<I: Interaction>(ctx: &mut CombatContext<I>) -> Option<EnemyIndex> {
@@ -103,42 +458,346 @@ impl PlayerEffectSystem {
             .0
             .iter()
             .enumerate()
-            .filter(|(_, e)| e.is_some())
+            .filter(|(_, maybe_enemy)| maybe_enemy.as_ref().is_some_and(|e| !e.half_dead))
+            .map(|(enemy_index, _)| enemy_index)
+            .collect::<Vec<_>>();
+        if living_enemies.is_empty() {
+            None
+        } else {
+            Some(living_enemies[ctx.card_randomizer_rng.gen_range(0..living_enemies.len())])
+        }
+    }
+
+    /// Prompts the player to move cards from their discard pile into their hand, optionally
+    /// retaining them this turn.
+    fn return_cards_from_discard_pile<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_count: usize,
+        retain: bool,
+    ) -> Result<(), Error> {
+        for _ in 0..card_count {
+            if ctx.pcs.cards.discard_pile.is_empty() || ctx.pcs.cards.hand.len() >= 10 {
+                break;
+            }
+            let choices = ctx
+                .pcs
+                .cards
+                .discard_pile
+                .iter()
+                .enumerate()
+                .map(|(discard_index, combat_card)| {
+                    Choice::ReturnToHand(discard_index, combat_card.card)
+                })
+                .collect::<Vec<_>>();
+            match ctx
+                .comms
+                .prompt_for_choice(Prompt::ChooseCardToReturnToHand, &choices)?
+            {
+                Choice::ReturnToHand(discard_index, _) => {
+                    let mut combat_card = ctx.pcs.cards.discard_pile.remove(*discard_index);
+                    combat_card.retain_this_turn = retain;
+                    ctx.pcs.cards.hand.push(combat_card);
+                    ctx.comms.send_notification(Notification::CardDrawn(
+                        ctx.pcs.cards.hand.len() - 1,
+                        combat_card,
+                    ))?;
+                }
+                invalid => unreachable!("{:?}", invalid),
+            }
+        }
+        Ok(())
+    }
+
+    /// Puts a card taken from the draw pile into the player's hand, or into the discard pile if
+    /// their hand is full.
+    fn put_into_hand<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        combat_card: CardCombatState,
+    ) -> Result<(), Error> {
+        if ctx.pcs.cards.hand.len() < 10 {
+            ctx.pcs.cards.hand.push(combat_card);
+            ctx.comms.send_notification(Notification::CardDrawn(
+                ctx.pcs.cards.hand.len() - 1,
+                combat_card,
+            ))
+        } else {
+            ctx.pcs.cards.discard_pile.push(combat_card);
+            ctx.comms
+                .send_notification(Notification::AddToDiscardPile(vec![combat_card]))
+        }
+    }
+
+    /// Increases the damage of the card being played, either for the rest of combat or (by way of
+    /// its misc value, which is written back to the deck) for the rest of the run.
+    fn ramp_up_card_damage<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        amount: Hp,
+        permanently: bool,
+    ) {
+        let Some(id) = ctx.pcs.card_being_played.map(|combat_card| combat_card.id) else {
+            panic!("No card being played");
+        };
+        for combat_card in ctx
+            .pcs
+            .cards
+            .iter_mut()
+            .filter(|combat_card| combat_card.id == id)
+        {
+            if permanently {
+                combat_card.misc += amount;
+            } else {
+                combat_card.additional_damage += amount;
+            }
+        }
+    }
+
+    /// Permanently upgrades random upgradable cards in the player's deck, along with their
+    /// counterparts in this combat.
+    fn upgrade_random_cards_in_deck<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_count: usize,
+    ) -> Result<(), Error> {
+        let mut upgradable_deck_indices = ctx
+            .pcs
+            .pps
+            .deck
+            .iter()
+            .enumerate()
+            .filter(|(_, deck_card)| CardDetails::for_card(deck_card.card).upgrade.is_some())
+            .map(|(deck_index, _)| deck_index)
+            .collect::<Vec<_>>();
+        for _ in 0..card_count {
+            if upgradable_deck_indices.is_empty() {
+                break;
+            }
+            let deck_index = upgradable_deck_indices
+                .remove(ctx.misc_rng.gen_range(0..upgradable_deck_indices.len()));
+            let deck_card = &mut ctx.pcs.pps.deck[deck_index];
+            let card = deck_card.card;
+            let upgraded = deck_card
+                .upgrade()
+                .expect("Only upgradable cards are selected");
+            let id = deck_card.id;
+            for combat_card in ctx
+                .pcs
+                .cards
+                .iter_mut()
+                .filter(|combat_card| combat_card.id == id)
+            {
+                combat_card.upgrade();
+            }
+            ctx.comms
+                .send_notification(Notification::CardUpgraded(card, upgraded))?;
+        }
+        DeckSystem::notify_player(ctx.comms, ctx.pcs.pps)
+    }
+
+    /// Upgrades every upgradable card in the player's hand (Armaments+), or the one they choose
+    /// (Armaments). The choice is made for them if only one card can be upgraded.
+    fn upgrade_cards_in_hand<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_selection: &CardSelection,
+    ) -> Result<(), Error> {
+        let upgradable_hand_indices = ctx
+            .pcs
+            .cards
+            .hand
+            .iter()
+            .enumerate()
+            .filter(|(_, combat_card)| combat_card.details.upgrade.is_some())
+            .map(|(hand_index, _)| hand_index)
             .collect::<Vec<_>>();
-        let living_index = ctx.misc_rng.gen_range(0..living_enemies.len());
-        Some(living_enemies[living_index].0)
+        let hand_indices = match card_selection {
+            CardSelection::All => upgradable_hand_indices,
+            CardSelection::PlayerChoice(1) if upgradable_hand_indices.len() > 1 => {
+                let choices = upgradable_hand_indices
+                    .iter()
+                    .map(|hand_index| {
+                        let combat_card = &ctx.pcs.cards.hand[*hand_index];
+                        Choice::UpgradeCardInHand(
+                            *hand_index,
+                            combat_card.card,
+                            combat_card.details.upgrade.expect("Only upgradable cards"),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                match ctx.comms.prompt_for_choice(Prompt::UpgradeCard, &choices)? {
+                    Choice::UpgradeCardInHand(hand_index, _, _) => vec![*hand_index],
+                    invalid => unreachable!("{:?}", invalid),
+                }
+            }
+            CardSelection::PlayerChoice(1) => upgradable_hand_indices,
+            invalid => unreachable!("{:?}", invalid),
+        };
+        for hand_index in hand_indices {
+            let combat_card = &mut ctx.pcs.cards.hand[hand_index];
+            let card = combat_card.card;
+            combat_card.upgrade();
+            let upgraded = combat_card.card;
+            ctx.comms
+                .send_notification(Notification::CardUpgraded(card, upgraded))?;
+        }
+        Ok(())
+    }
+
+    /// Prompts the player to put a card of the given type from their draw pile into their hand
+    /// (Secret Technique, Secret Weapon).
+    fn take_card_from_draw_pile<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_type: CardType,
+    ) -> Result<(), Error> {
+        let choices = ctx
+            .pcs
+            .cards
+            .draw_pile
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, combat_card)| combat_card.details.type_ == card_type)
+            .map(|(draw_index, combat_card)| Choice::TakeFromDrawPile(draw_index, combat_card.card))
+            .collect::<Vec<_>>();
+        if choices.is_empty() {
+            return Ok(());
+        }
+        match ctx
+            .comms
+            .prompt_for_choice(Prompt::ChooseCardToTakeFromDrawPile, &choices)?
+        {
+            Choice::TakeFromDrawPile(draw_index, _) => {
+                let combat_card = ctx.pcs.cards.draw_pile.remove(*draw_index);
+                Self::put_into_hand(ctx, combat_card)
+            }
+            invalid => unreachable!("{:?}", invalid),
+        }
+    }
+
+    /// Puts random attacks from the draw pile into the player's hand (Violence).
+    fn take_random_attacks_from_draw_pile<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        card_count: usize,
+    ) -> Result<(), Error> {
+        for _ in 0..card_count {
+            let attack_draw_indices = ctx
+                .pcs
+                .cards
+                .draw_pile
+                .iter()
+                .enumerate()
+                .filter(|(_, combat_card)| combat_card.details.type_ == CardType::Attack)
+                .map(|(draw_index, _)| draw_index)
+                .collect::<Vec<_>>();
+            if attack_draw_indices.is_empty() {
+                break;
+            }
+            let draw_index = attack_draw_indices[ctx
+                .card_randomizer_rng
+                .gen_range(0..attack_draw_indices.len())];
+            let combat_card = ctx.pcs.cards.draw_pile.remove(draw_index);
+            Self::put_into_hand(ctx, combat_card)?;
+        }
+        Ok(())
     }
 
     fn to_target_effect<I: Interaction>(
         ctx: &mut CombatContext<I>,
         effect: &TargetEffect,
     ) -> Result<(), Error> {
+        // Conditions are checked even if the target has just been removed from the party
+        // (e.g. Feed), so their effects resolve immediately rather than being queued.
+        if let TargetEffect::Conditional(target_condition, player_effects) = effect {
+            if Self::evaluate_target_condition(ctx, target_condition) {
+                for player_effect in player_effects.iter() {
+                    Self::process_player_effect(ctx, player_effect)?;
+                }
+            }
+            return Ok(());
+        }
+        if let TargetEffect::DealXTimes(damage) = effect {
+            let x = EnergySystem::resolve_x(ctx)?;
+            for _ in 0..x {
+                Self::to_target_effect(ctx, &TargetEffect::Deal(damage.clone()))?;
+            }
+            return Ok(());
+        }
         if let Some(enemy_state) = ctx
             .maybe_enemy_index
             .and_then(|i| ctx.enemy_party.0.get_mut(i))
             .and_then(|maybe_enemy| maybe_enemy.as_mut())
         {
             match effect {
-                TargetEffect::Conditional(_target_condition, _player_effects) => todo!(),
+                TargetEffect::Conditional(_, _) | TargetEffect::DealXTimes(_) => unreachable!(),
                 TargetEffect::Deal(damage) => {
                     let calculated_damage = DamageCalculator::calculate_damage_inflicted(
                         &ctx.pcs,
                         Some(enemy_state),
                         damage,
                     );
-                    BlockSystem::damage_enemy(
+                    ctx.pcs.unblocked_damage_dealt += BlockSystem::damage_enemy(
                         enemy_state,
                         calculated_damage,
                         &mut ctx.effect_queue,
                     );
                     Ok(())
                 }
-                TargetEffect::DealXTimes(_damage) => todo!(),
+                TargetEffect::DealOncePerEnemy(damage) => {
+                    let living_enemy_count =
+                        ctx.enemy_party.0.iter().filter(|e| e.is_some()).count();
+                    for _ in 0..living_enemy_count {
+                        let Some(enemy_state) = ctx
+                            .maybe_enemy_index
+                            .and_then(|i| ctx.enemy_party.0.get_mut(i))
+                            .and_then(|maybe_enemy| maybe_enemy.as_mut())
+                            .filter(|enemy_state| !enemy_state.is_dead())
+                        else {
+                            break;
+                        };
+                        let calculated_damage = DamageCalculator::calculate_damage_inflicted(
+                            &ctx.pcs,
+                            Some(enemy_state),
+                            damage,
+                        );
+                        ctx.pcs.unblocked_damage_dealt += BlockSystem::damage_enemy(
+                            enemy_state,
+                            calculated_damage,
+                            &mut ctx.effect_queue,
+                        );
+                    }
+                    Ok(())
+                }
+                TargetEffect::ExecuteIfHpAtMost(hp) => {
+                    if enemy_state.hp <= *hp {
+                        enemy_state.hp = 0;
+                    }
+                    Ok(())
+                }
                 TargetEffect::Inflict(enemy_condition) => {
                     EnemyConditionSystem::apply_to_enemy(enemy_state, enemy_condition);
                     Ok(())
                 }
-                TargetEffect::SapStrength(_) => todo!(),
+                TargetEffect::LoseHpEqualToMark => {
+                    let mark = enemy_state
+                        .conditions
+                        .iter()
+                        .find_map(|c| match c {
+                            EnemyCondition::Mark(hp) => Some(*hp),
+                            _ => None,
+                        })
+                        .unwrap_or(0);
+                    if mark > 0 {
+                        BlockSystem::damage_enemy(
+                            enemy_state,
+                            CalculatedDamage::HpLoss(mark),
+                            &mut ctx.effect_queue,
+                        );
+                    }
+                    Ok(())
+                }
+                TargetEffect::SapStrength(strength) => {
+                    if !EnemyConditionSystem::negate_debuff(enemy_state) {
+                        enemy_state.strength -= strength;
+                    }
+                    Ok(())
+                }
             }
         } else {
             Ok(())
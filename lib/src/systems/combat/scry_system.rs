@@ -0,0 +1,90 @@
+use std::iter::once;
+
+use anyhow::Error;
+
+use crate::components::{Choice, Effect, Interaction, Notification, Prompt};
+use crate::data::{Card, PlayerCondition, PlayerEffect, Relic, Resource};
+use crate::systems::base::CombatContext;
+use crate::types::ScryCount;
+
+pub struct ScrySystem;
+
+impl ScrySystem {
+    /// Looks at the top cards of the draw pile, letting the player discard any number of them,
+    /// then triggers any conditions and cards that care about scrying.
+    pub fn scry<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        scry_count: ScryCount,
+    ) -> Result<(), Error> {
+        let scry_count = if ctx.pcs.pps.relics.contains(&Relic::GoldenEye) {
+            scry_count + 2
+        } else {
+            scry_count
+        };
+        // The top of the draw pile is the end of the vector.
+        let first_scried_index = ctx
+            .pcs
+            .cards
+            .draw_pile
+            .len()
+            .saturating_sub(scry_count as usize);
+        let mut discarded_cards = Vec::new();
+        loop {
+            let choices = ctx.pcs.cards.draw_pile[first_scried_index..]
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(offset, combat_card)| {
+                    Choice::ScryDiscard(first_scried_index + offset, combat_card.card)
+                })
+                .chain(once(Choice::Skip))
+                .collect::<Vec<_>>();
+            if choices.len() == 1 {
+                break;
+            }
+            match ctx.comms.prompt_for_choice(Prompt::Scry, &choices)? {
+                Choice::ScryDiscard(draw_index, _) => {
+                    let combat_card = ctx.pcs.cards.draw_pile.remove(*draw_index);
+                    ctx.pcs.cards.discard_pile.push(combat_card);
+                    discarded_cards.push(combat_card);
+                }
+                Choice::Skip => break,
+                invalid => unreachable!("{:?}", invalid),
+            }
+        }
+        if !discarded_cards.is_empty() {
+            ctx.comms
+                .send_notification(Notification::AddToDiscardPile(discarded_cards))?;
+        }
+        for condition in ctx.pcs.conditions.iter() {
+            if let PlayerCondition::Nirvana(block) = condition {
+                ctx.effect_queue
+                    .add_to_bottom(Effect::PlayerState(PlayerEffect::Gain(Resource::Block(
+                        *block,
+                    ))));
+            }
+        }
+        Self::return_weaves_to_hand(ctx)
+    }
+
+    /// Moves every Weave in the discard pile into the player's hand, space permitting.
+    fn return_weaves_to_hand<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        let mut discard_index = 0;
+        while discard_index < ctx.pcs.cards.discard_pile.len() && ctx.pcs.cards.hand.len() < 10 {
+            if matches!(
+                ctx.pcs.cards.discard_pile[discard_index].card,
+                Card::Weave(_)
+            ) {
+                let combat_card = ctx.pcs.cards.discard_pile.remove(discard_index);
+                ctx.pcs.cards.hand.push(combat_card);
+                ctx.comms.send_notification(Notification::CardDrawn(
+                    ctx.pcs.cards.hand.len() - 1,
+                    combat_card,
+                ))?;
+            } else {
+                discard_index += 1;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::Error;
+
+use crate::components::{Effect, Interaction, Notification};
+use crate::data::{Card, PlayerCondition, PlayerEffect, Relic, Resource, Stance};
+use crate::systems::base::CombatContext;
+use crate::types::Mantra;
+
+use super::energy_system::EnergySystem;
+
+pub struct StanceSystem;
+
+impl StanceSystem {
+    /// Notifies the player of their current stance.
+    pub fn notify_player<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        ctx.comms
+            .send_notification(Notification::Stance(ctx.pcs.stance))
+    }
+
+    /// Leaves Divinity at the start of the player's turn.
+    pub fn on_player_turn_started<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
+        if ctx.pcs.stance == Some(Stance::Divinity) {
+            Self::change_stance(ctx, None)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Switches the player into the supplied stance (or out of any stance if `None`), triggering
+    /// exit and entry effects along with any conditions and cards that care about stance changes.
+    /// Re-entering the current stance has no effect.
+    pub fn change_stance<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        new_stance: Option<Stance>,
+    ) -> Result<(), Error> {
+        if ctx.pcs.stance == new_stance {
+            return Ok(());
+        }
+        if ctx.pcs.stance == Some(Stance::Calm) {
+            let energy = if ctx.pcs.pps.relics.contains(&Relic::VioletLotus) {
+                3
+            } else {
+                2
+            };
+            EnergySystem::gain(ctx, energy)?;
+        }
+        ctx.pcs.stance = new_stance;
+        Self::notify_player(ctx)?;
+        if new_stance == Some(Stance::Divinity) {
+            EnergySystem::gain(ctx, 3)?;
+        }
+        for condition in ctx.pcs.conditions.iter() {
+            match condition {
+                PlayerCondition::MentalFortress(block) => {
+                    ctx.effect_queue
+                        .add_to_bottom(Effect::PlayerState(PlayerEffect::Gain(Resource::Block(
+                            *block,
+                        ))));
+                }
+                PlayerCondition::Rushdown(draw_count) if new_stance == Some(Stance::Wrath) => {
+                    ctx.effect_queue
+                        .add_to_bottom(Effect::PlayerState(PlayerEffect::Draw(*draw_count)));
+                }
+                _ => {}
+            }
+        }
+        Self::return_flurries_of_blows_to_hand(ctx)
+    }
+
+    /// Adds mantra for the player, entering Divinity for every 10 accumulated.
+    pub fn gain_mantra<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+        amount: Mantra,
+    ) -> Result<(), Error> {
+        ctx.pcs.mantra += amount;
+        ctx.pcs.mantra_gained += amount;
+        if ctx.pcs.mantra >= 10 {
+            ctx.pcs.mantra -= 10;
+            ctx.comms
+                .send_notification(Notification::Mantra(ctx.pcs.mantra))?;
+            Self::change_stance(ctx, Some(Stance::Divinity))
+        } else {
+            ctx.comms
+                .send_notification(Notification::Mantra(ctx.pcs.mantra))
+        }
+    }
+
+    /// Moves every Flurry of Blows in the discard pile into the player's hand, space permitting.
+    fn return_flurries_of_blows_to_hand<I: Interaction>(
+        ctx: &mut CombatContext<I>,
+    ) -> Result<(), Error> {
+        let mut discard_index = 0;
+        while discard_index < ctx.pcs.cards.discard_pile.len() && ctx.pcs.cards.hand.len() < 10 {
+            if matches!(
+                ctx.pcs.cards.discard_pile[discard_index].card,
+                Card::FlurryOfBlows(_)
+            ) {
+                let combat_card = ctx.pcs.cards.discard_pile.remove(discard_index);
+                ctx.pcs.cards.hand.push(combat_card);
+                ctx.comms.send_notification(Notification::CardDrawn(
+                    ctx.pcs.cards.hand.len() - 1,
+                    combat_card,
+                ))?;
+            } else {
+                discard_index += 1;
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
-use crate::data::{Act, Card, Character, CURSE_CARD_POOL, UNCOMMON_COLORLESS_CARD_POOL};
+use crate::data::{
+    Act, Card, CardRarity, Character, CURSE_CARD_POOL, RARE_COLORLESS_CARD_POOL,
+    UNCOMMON_COLORLESS_CARD_POOL,
+};
 
 use super::seed::Seed;
 use super::sts_random::StsRandom;
@@ -34,6 +37,42 @@ impl CardGenerator {
             .sample_without_replacement(UNCOMMON_COLORLESS_CARD_POOL, 3)
     }
 
+    pub fn three_rare_colorless_card_choices(&mut self) -> Vec<Card> {
+        self.card_rng
+            .sample_without_replacement(RARE_COLORLESS_CARD_POOL, 3)
+    }
+
+    /// Draws three distinct colorless cards for a card reward, each of which is rare 30% of the
+    /// time.
+    pub fn colorless_card_rewards(&mut self) -> Vec<Card> {
+        let mut result: Vec<Card> = Vec::with_capacity(3);
+        for _ in 0..3 {
+            let pool = if *self.card_rng.weighted_choose(&[(true, 0.3), (false, 0.7)]) {
+                self.rarity_bias = 5;
+                RARE_COLORLESS_CARD_POOL
+            } else {
+                UNCOMMON_COLORLESS_CARD_POOL
+            };
+            let mut card = self.card_rng.choose(pool);
+            while result.contains(card) {
+                card = self.card_rng.choose(pool);
+            }
+            result.push(*card);
+        }
+        result
+    }
+
+    /// Draws a card of the given rarity for the character.
+    pub fn class_card_of_rarity(&mut self, rarity: CardRarity) -> Card {
+        let pool = match rarity {
+            CardRarity::Common => self.character.common_card_pool,
+            CardRarity::Uncommon => self.character.uncommon_card_pool,
+            CardRarity::Rare => self.character.rare_card_pool,
+            invalid => unreachable!("{:?}", invalid),
+        };
+        *self.card_rng.choose(pool)
+    }
+
     pub fn one_curse(&mut self) -> Card {
         *self.card_rng.choose(CURSE_CARD_POOL)
     }
@@ -63,6 +102,27 @@ impl CardGenerator {
         result
     }
 
+    /// Draws the specified number of distinct cards for the character, rolling each card's
+    /// rarity the way events do, i.e. without nudging the odds of future combat rewards.
+    pub fn distinct_class_cards(&mut self, count: usize) -> Vec<Card> {
+        let mut result: Vec<Card> = Vec::with_capacity(count);
+        while result.len() < count {
+            let d100 = self.card_rng.gen_range(0..100) + self.rarity_bias;
+            let pool = if d100 < 3 {
+                self.character.rare_card_pool
+            } else if d100 < 40 {
+                self.character.uncommon_card_pool
+            } else {
+                self.character.common_card_pool
+            };
+            let card = *self.card_rng.choose(pool);
+            if !result.contains(&card) {
+                result.push(card);
+            }
+        }
+        result
+    }
+
     fn pool_for_class(&mut self) -> (bool, &'static [Card]) {
         let d100 = self.card_rng.gen_range(0..100) + self.rarity_bias;
         println!(
@@ -76,6 +76,11 @@ impl<'a> NeowGenerator<'a> {
         self.card_generator.three_colorless_card_choices()
     }
 
+    pub fn three_rare_colorless_card_choices(&mut self) -> Vec<Card> {
+        // Intentionally using card_generator here for fidelity to the original game
+        self.card_generator.three_rare_colorless_card_choices()
+    }
+
     pub fn one_random_rare_card(&mut self) -> Card {
         *self.neow_rng.choose(self.character.rare_card_pool)
     }
@@ -11,6 +11,7 @@ pub struct RelicGenerator {
     rare_relic_pool: VecDeque<Relic>,
     shop_relic_pool: VecDeque<Relic>,
     boss_relic_pool: VecDeque<Relic>,
+    relic_rng: StsRandom,
 }
 
 impl RelicGenerator {
@@ -32,6 +33,7 @@ impl RelicGenerator {
             rare_relic_pool: rare_relic_pool.into_iter().collect(),
             shop_relic_pool: shop_relic_pool.into_iter().collect(),
             boss_relic_pool: boss_relic_pool.into_iter().collect(),
+            relic_rng,
         }
     }
 
@@ -61,6 +63,38 @@ impl RelicGenerator {
     pub fn boss_relic(&mut self) -> Relic {
         self.boss_relic_pool.pop_front().unwrap_or(Relic::Circlet)
     }
+
+    /// Rolls a tier (50% common, 33% uncommon, 17% rare) and returns a relic of that tier.
+    pub fn random_relic(&mut self) -> Relic {
+        let roll = self.relic_rng.gen_range(0..100);
+        self.relic_for_roll(roll)
+    }
+
+    /// Like `random_relic`, but skips relics that would open a card selection screen when
+    /// obtained, as events that hand out relics directly do.
+    pub fn random_screenless_relic(&mut self) -> Relic {
+        let roll = self.relic_rng.gen_range(0..100);
+        loop {
+            let relic = self.relic_for_roll(roll);
+            if !matches!(
+                relic,
+                Relic::BottledFlame
+                    | Relic::BottledLightning
+                    | Relic::BottledTornado
+                    | Relic::Whetstone
+            ) {
+                return relic;
+            }
+        }
+    }
+
+    fn relic_for_roll(&mut self, roll: u32) -> Relic {
+        match roll {
+            0..=49 => self.common_relic(),
+            50..=82 => self.uncommon_relic(),
+            _ => self.rare_relic(),
+        }
+    }
 }
 
 #[cfg(test)]
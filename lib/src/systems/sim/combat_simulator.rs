@@ -1,13 +1,24 @@
 use anyhow::Error;
 
-use crate::components::{Effect, Interaction, PlayerPersistentState};
+use crate::components::{Interaction, PlayerPersistentState};
 use crate::data::Encounter;
 use crate::systems::base::CombatContext;
 use crate::systems::combat::{
-    EffectSystem, EnemyCombatSystem, PlayerCombatAction, PlayerCombatSystem,
+    CardQueueSystem, EffectSystem, EnemyCombatSystem, PlayerCombatAction, PlayerCombatSystem,
 };
 use crate::systems::rng::{Seed, StsRandom};
 
+/// How a combat encounter came to an end.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CombatOutcome {
+    /// Every enemy died or fled.
+    Victory,
+    /// The player died.
+    Defeat,
+    /// The player fled (e.g. with a Smoke Bomb), forfeiting the rewards.
+    Escaped,
+}
+
 pub struct CombatSimulator<'a> {
     seed_for_floor: Seed,
     misc_rng: &'a mut StsRandom,
@@ -22,13 +33,13 @@ impl<'a> CombatSimulator<'a> {
         }
     }
 
-    /// Runs a combat encounter, returning true if the player wins.
+    /// Runs a combat encounter, returning how it ended.
     pub fn run_encounter<I: Interaction>(
         self,
         comms: &I,
         encounter: Encounter,
         pps: &mut PlayerPersistentState,
-    ) -> Result<bool, Error> {
+    ) -> Result<CombatOutcome, Error> {
         println!("[CombatSimulator] Running encounter: {:?}", encounter);
         let mut ctx = CombatContext::new(comms, self.seed_for_floor, encounter, pps, self.misc_rng);
         PlayerCombatSystem::on_combat_started(&mut ctx)?;
@@ -38,32 +49,40 @@ impl<'a> CombatSimulator<'a> {
             if ctx.combat_should_end() {
                 break;
             }
+            if PlayerCombatSystem::take_extra_turn(&mut ctx) {
+                continue;
+            }
             Self::conduct_enemies_turn(&mut ctx)?;
             if ctx.combat_should_end() {
                 break;
             }
         }
+        ctx.effect_queue.ensure_empty()?;
+        PlayerCombatSystem::persist_card_changes(&mut ctx);
+        let escaped = ctx.pcs.escaped;
         PlayerCombatSystem::on_combat_finished(comms, pps)?;
-        let victorious = pps.hp > 0;
-        Ok(victorious)
+        Ok(if pps.hp == 0 {
+            CombatOutcome::Defeat
+        } else if escaped {
+            CombatOutcome::Escaped
+        } else {
+            CombatOutcome::Victory
+        })
     }
 
     /// Conducts the player's turn.
     fn conduct_player_turn<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
         PlayerCombatSystem::on_player_turn_started(ctx)?;
         EffectSystem::process_effect_queue(ctx)?;
+        CardQueueSystem::play_queued_cards(ctx)?;
         while !ctx.combat_should_end() {
             match PlayerCombatSystem::choose_next_action(ctx)? {
-                PlayerCombatAction::PlayCard(combat_card, maybe_enemy_index) => {
-                    ctx.maybe_enemy_index = maybe_enemy_index;
-                    println!("Disposing of card just played: {:?}", combat_card);
-                    PlayerCombatSystem::dispose_of_card_just_played(ctx)?;
-                    EffectSystem::process_effect_queue(ctx)?;
-                    for effect in combat_card.details.on_play.iter() {
-                        ctx.effect_queue.push_back(Effect::Card(effect));
+                PlayerCombatAction::PlayCard(card_play) => {
+                    ctx.card_queue.push_back(card_play);
+                    CardQueueSystem::play_queued_cards(ctx)?;
+                    if ctx.pcs.end_turn_requested {
+                        break;
                     }
-                    println!("Hand is now {:?}", ctx.pcs.cards.hand);
-                    EffectSystem::process_effect_queue(ctx)?;
                 }
                 PlayerCombatAction::EndTurn => break,
             };
@@ -77,34 +96,1916 @@ impl<'a> CombatSimulator<'a> {
 
     /// Conducts the enemies' turn.
     fn conduct_enemies_turn<I: Interaction>(ctx: &mut CombatContext<I>) -> Result<(), Error> {
-        EnemyCombatSystem::on_enemies_turn_started(ctx);
+        EnemyCombatSystem::on_enemies_turn_started(ctx)?;
         for enemy_index in 0..ctx.enemy_party.0.len() {
             ctx.maybe_enemy_index = Some(enemy_index);
-            if let Some(enemy_action) = ctx.enemy_party.0[enemy_index]
-                .as_mut()
-                .map(|e| e.next_action)
-            {
+            if let Some(enemy) = ctx.enemy_party.0[enemy_index].as_ref() {
                 println!(
                     "[CombatSimulator] Enemy {} action: {:?}",
-                    enemy_index, enemy_action
+                    enemy_index, enemy.next_action
                 );
-                for effect in enemy_action.effect_chain().iter() {
-                    ctx.effect_queue.push_back(Effect::EnemyPlaybook(effect));
-                }
-                while let Some(effect) = ctx.effect_queue.pop_front() {
-                    EffectSystem::process_effect(ctx, effect)?;
-                    if ctx.combat_should_end() || ctx.enemy_party.0[enemy_index].is_none() {
-                        break;
-                    }
-                }
             }
+            EffectSystem::process_enemy_action(ctx, enemy_index)?;
             if ctx.combat_should_end() {
                 break;
             }
         }
         if !ctx.combat_should_end() {
-            EnemyCombatSystem::on_enemies_turn_finished(ctx);
+            EnemyCombatSystem::on_enemies_turn_finished(ctx)?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::mpsc::{channel, Receiver, Sender};
+    use std::thread::{self, JoinHandle};
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    use crate::components::{
+        CardCombatState, Choice, DeckCard, EnemyStatus, Notification, PotionAction, Prompt,
+        StsMessage,
+    };
+    use crate::data::{
+        Card, CardDestination, CardDetails, CardType, Character, Enemy, EnemyCondition, EnergyCost,
+        Intent, PlayerCondition, Potion, Relic, Stance, COMBAT_COLORLESS_CARD_POOL, DEFECT,
+        IRONCLAD, WATCHER,
+    };
+    use crate::systems::base::RelicSystem;
+    use crate::systems::sim::player_interaction::PlayerInteraction;
+    use crate::systems::sim::sts_simulator::test::{index_of, next_prompt};
+    use crate::types::Hp;
+
+    /// Creates the state of a player who has the given deck and relics.
+    fn pps_with(
+        character: &'static Character,
+        cards: &[Card],
+        relics: &[Relic],
+    ) -> PlayerPersistentState {
+        let mut pps = PlayerPersistentState::new(character);
+        pps.deck = cards
+            .iter()
+            .copied()
+            .enumerate()
+            .map(|(id, card)| DeckCard::new(id, card))
+            .collect();
+        pps.next_card_id = pps.deck.len();
+        pps.relics = relics.to_vec();
+        pps
+    }
+
+    /// Runs the encounter on its own thread, after giving the player any relics in `obtained`,
+    /// returning the channels used to talk to it and the handle to collect the outcome.
+    fn start_combat(
+        seed: u64,
+        encounter: Encounter,
+        mut pps: PlayerPersistentState,
+        obtained: &[Relic],
+    ) -> (
+        Sender<usize>,
+        Receiver<StsMessage>,
+        JoinHandle<Result<CombatOutcome, Error>>,
+    ) {
+        let (to_server, from_client) = channel();
+        let (to_client, from_server) = channel();
+        let obtained = obtained.to_vec();
+        let handle = thread::spawn(move || -> Result<CombatOutcome, Error> {
+            let comms = PlayerInteraction::new(from_client, to_client);
+            for relic in obtained {
+                RelicSystem::obtain_relic(&comms, &mut pps, relic)?;
+            }
+            let mut misc_rng = StsRandom::from(Seed::from(seed));
+            CombatSimulator::new(Seed::from(seed), &mut misc_rng)
+                .run_encounter(&comms, encounter, &mut pps)
+        });
+        (to_server, from_server, handle)
+    }
+
+    /// Returns the cards offered to be played from the player's hand.
+    fn playable_cards(message: &StsMessage) -> Vec<Card> {
+        match message {
+            StsMessage::Choices(Prompt::CombatAction, choices) => choices
+                .iter()
+                .filter_map(|choice| match choice {
+                    Choice::PlayCardFromHand(_, card, _) => Some(*card),
+                    _ => None,
+                })
+                .collect(),
+            invalid => panic!("Expected a combat action prompt, got {:?}", invalid),
+        }
+    }
+
+    /// Plays the first copy of the card in the player's hand.
+    #[track_caller]
+    fn play(to_server: &Sender<usize>, prompt: &StsMessage, card: Card) {
+        let StsMessage::Choices(Prompt::CombatAction, choices) = prompt else {
+            panic!("Expected a combat action prompt, got {:?}", prompt);
+        };
+        let choice_index = choices
+            .iter()
+            .position(|choice| matches!(choice, Choice::PlayCardFromHand(_, c, _) if *c == card))
+            .unwrap_or_else(|| panic!("{:?} not in hand: {:?}", card, choices));
+        to_server.send(choice_index).unwrap();
+    }
+
+    /// The party of the lone Jaw Worm that is fought with seed 3.
+    fn jaw_worm(hp: Hp, intent: Intent) -> Vec<Option<EnemyStatus>> {
+        vec![
+            Some(EnemyStatus::new(Enemy::JawWorm, (hp, 42), intent)),
+            None,
+            None,
+            None,
+            None,
+        ]
+    }
+
+    #[test]
+    fn test_bottled_card_starts_in_opening_hand() {
+        let mut cards = vec![Card::Strike(false); 5];
+        cards.extend([Card::Defend(false); 5]);
+        cards.push(Card::Bash(false));
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) =
+            start_combat(3, Encounter::JawWorm, pps, &[Relic::BottledFlame]);
+
+        assert_eq!(
+            next_prompt(&from_server, &[]),
+            StsMessage::Choices(
+                Prompt::BottleCard,
+                (0..5)
+                    .map(|deck_index| Choice::BottleCard(deck_index, Card::Strike(false)))
+                    .chain([Choice::BottleCard(10, Card::Bash(false))])
+                    .collect()
+            )
+        );
+        to_server.send(5).unwrap(); // Bottle "Bash"
+        let opening_hand = playable_cards(&next_prompt(&from_server, &[]));
+        assert_eq!(opening_hand.len(), 5);
+        assert!(opening_hand.contains(&Card::Bash(false)));
+    }
+
+    #[test]
+    fn test_foreign_influence_offers_attacks_of_any_color() {
+        let pps = pps_with(&WATCHER, &[Card::ForeignInfluence(true)], &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::ForeignInfluence(true),
+        );
+        let prompt = next_prompt(&from_server, &[]);
+        let StsMessage::Choices(Prompt::ChooseCardToCreate, choices) = &prompt else {
+            panic!("Expected to choose a card, got {:?}", prompt);
+        };
+        let offered = choices
+            .iter()
+            .map(|choice| match choice {
+                Choice::CreateCard(card) => *card,
+                invalid => panic!("{:?}", invalid),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(offered.len(), 3);
+        assert!(offered
+            .iter()
+            .all(|card| CardDetails::for_card(*card).type_ == CardType::Attack));
+        // Attacks from outside the Ironclad's pool are on offer too (here, Conclude).
+        assert!(offered
+            .iter()
+            .any(|card| !IRONCLAD.attack_card_pool.contains(card)));
+        assert!(offered[1..].iter().all(|card| *card != offered[0]) && offered[1] != offered[2]);
+        to_server
+            .send(index_of(&prompt, &Choice::CreateCard(offered[1])))
+            .unwrap();
+        // The upgraded card makes its pick free to play this turn.
+        assert_eq!(
+            next_prompt(&from_server, &[]),
+            StsMessage::Choices(
+                Prompt::CombatAction,
+                vec![
+                    Choice::PlayCardFromHand(0, offered[1], EnergyCost::Zero),
+                    Choice::EndTurn
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_stances_change_energy_and_damage() {
+        let pps = pps_with(
+            &WATCHER,
+            &[
+                Card::Vigilance(false),
+                Card::Crescendo(false),
+                Card::Strike(false),
+                Card::Defend(false),
+                Card::Defend(false),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Vigilance(false),
+        );
+        let prompt = next_prompt(
+            &from_server,
+            &[
+                Notification::Energy(1),
+                Notification::BlockGained(8),
+                Notification::Stance(Some(Stance::Calm)),
+            ],
+        );
+        play(&to_server, &prompt, Card::Crescendo(false));
+        // Leaving Calm refunds two energy, and Wrath doubles the damage the player takes...
+        let prompt = next_prompt(
+            &from_server,
+            &[
+                Notification::Energy(0),
+                Notification::Energy(2),
+                Notification::Stance(Some(Stance::Wrath)),
+                Notification::EnemyParty(jaw_worm(42, Intent::Aggressive(22, 1))),
+            ],
+        );
+        play(&to_server, &prompt, Card::Strike(false));
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+                                    // ...as well as the damage the player deals.
+        let prompt = next_prompt(
+            &from_server,
+            &[Notification::EnemyParty(jaw_worm(
+                30,
+                Intent::Aggressive(22, 1),
+            ))],
+        );
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        next_prompt(
+            &from_server,
+            &[
+                Notification::DamageBlocked(8),
+                Notification::DamageTaken(14),
+                Notification::Health((58, 72)),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_mantra_enters_divinity() {
+        let pps = pps_with(
+            &WATCHER,
+            &[
+                Card::Worship(false),
+                Card::Prostrate(false),
+                Card::Prostrate(false),
+                Card::Prostrate(false),
+                Card::Strike(false),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Worship(false),
+        );
+        let prompt = next_prompt(&from_server, &[Notification::Mantra(5)]);
+        play(&to_server, &prompt, Card::Prostrate(false));
+        let prompt = next_prompt(&from_server, &[Notification::Mantra(7)]);
+        play(&to_server, &prompt, Card::Prostrate(false));
+        let prompt = next_prompt(&from_server, &[Notification::Mantra(9)]);
+        play(&to_server, &prompt, Card::Prostrate(false));
+        // Reaching 10 mantra enters Divinity, keeping the excess and granting three energy.
+        let prompt = next_prompt(
+            &from_server,
+            &[
+                Notification::Mantra(1),
+                Notification::Stance(Some(Stance::Divinity)),
+                Notification::Energy(4),
+            ],
+        );
+        play(&to_server, &prompt, Card::Strike(false));
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+        let prompt = next_prompt(
+            &from_server,
+            &[Notification::EnemyParty(jaw_worm(
+                24,
+                Intent::Aggressive(11, 1),
+            ))],
+        );
+        // Divinity leaves the damage taken alone, and ends when the next turn starts.
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        next_prompt(
+            &from_server,
+            &[Notification::DamageBlocked(11), Notification::Stance(None)],
+        );
+    }
+
+    #[test]
+    fn test_scry_discards_chosen_cards() {
+        let mut cards = vec![Card::ThirdEye(false)];
+        cards.extend([Card::Strike(false); 4]);
+        cards.extend([
+            Card::Defend(false),
+            Card::Eruption(false),
+            Card::Vigilance(false),
+        ]);
+        let pps = pps_with(&WATCHER, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::ThirdEye(false),
+        );
+        let prompt = next_prompt(&from_server, &[Notification::BlockGained(7)]);
+        let StsMessage::Choices(Prompt::Scry, choices) = &prompt else {
+            panic!("Expected to scry, got {:?}", prompt);
+        };
+        // All three cards left in the draw pile are offered, top first.
+        assert_eq!(
+            choices
+                .iter()
+                .map(|choice| match choice {
+                    Choice::ScryDiscard(draw_index, _) => Some(*draw_index),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            [Some(2), Some(1), Some(0), None]
+        );
+        let (Choice::ScryDiscard(_, top), _, Choice::ScryDiscard(_, bottom)) =
+            (&choices[0], &choices[1], &choices[2])
+        else {
+            unreachable!();
+        };
+        to_server.send(1).unwrap(); // Discard the second card
+                                    // The cards left to scry are renumbered to match the draw pile.
+        assert_eq!(
+            next_prompt(&from_server, &[]),
+            StsMessage::Choices(
+                Prompt::Scry,
+                vec![
+                    Choice::ScryDiscard(1, *top),
+                    Choice::ScryDiscard(0, *bottom),
+                    Choice::Skip
+                ]
+            )
+        );
+        to_server.send(2).unwrap(); // Skip
+        playable_cards(&next_prompt(&from_server, &[]));
+    }
+
+    #[test]
+    fn test_vault_skips_the_enemy_turn() {
+        let mut cards = vec![Card::Vault(false)];
+        cards.extend([Card::Strike(false); 4]);
+        let pps = pps_with(&WATCHER, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Vault(false),
+        );
+        // The Jaw Worm is still winding up its Chomp when the player's next turn begins.
+        let prompt = next_prompt(
+            &from_server,
+            &[
+                Notification::Energy(0),
+                Notification::Energy(3),
+                Notification::EnemyParty(jaw_worm(42, Intent::Aggressive(11, 1))),
+            ],
+        );
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        next_prompt(
+            &from_server,
+            &[
+                Notification::DamageTaken(11),
+                Notification::Health((61, 72)),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_blasphemy_kills_the_player_next_turn() {
+        let mut cards = vec![Card::Blasphemy(false)];
+        cards.extend([Card::Defend(false); 4]);
+        let pps = pps_with(&WATCHER, &cards, &[]);
+        let (to_server, from_server, handle) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Blasphemy(false),
+        );
+        let prompt = next_prompt(
+            &from_server,
+            &[
+                Notification::Energy(2),
+                Notification::Stance(Some(Stance::Divinity)),
+                Notification::Energy(5),
+            ],
+        );
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        assert_eq!(handle.join().unwrap().unwrap(), CombatOutcome::Defeat);
+    }
+
+    #[test]
+    fn test_ragnarok_stops_once_every_enemy_is_dead() {
+        let mut cards = vec![Card::Blasphemy(false), Card::Ragnarok(false)];
+        cards.extend([Card::Defend(false); 3]);
+        let pps = pps_with(&WATCHER, &cards, &[]);
+        let (to_server, from_server, handle) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Blasphemy(false),
+        );
+        // In Divinity each of Ragnarok's five hits deals 15, so the third one is fatal.
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Ragnarok(false),
+        );
+        assert_eq!(handle.join().unwrap().unwrap(), CombatOutcome::Victory);
+    }
+
+    #[test]
+    fn test_the_rest_of_a_fatal_card_is_cancelled() {
+        let pps = pps_with(&IRONCLAD, &[Card::Anger(true); 5], &[]);
+        let (to_server, from_server, handle) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        // Five Angers deal 40 of the Jaw Worm's 42 HP on the first turn.
+        for _ in 0..5 {
+            play(
+                &to_server,
+                &next_prompt(&from_server, &[]),
+                Card::Anger(true),
+            );
+            next_prompt(&from_server, &[]);
+            to_server.send(0).unwrap(); // Target the Jaw Worm
+        }
+        let prompt = next_prompt(&from_server, &[]);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Anger(true),
+        );
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+                                    // The fatal Anger never gets to copy itself, and nothing is left pending.
+        while let Ok(message) = from_server.recv_timeout(Duration::from_secs(5)) {
+            assert!(
+                !matches!(
+                    message,
+                    StsMessage::Notification(Notification::CardCreated(..))
+                ),
+                "{:?}",
+                message
+            );
+        }
+        assert_eq!(handle.join().unwrap().unwrap(), CombatOutcome::Victory);
+    }
+
+    #[test]
+    fn test_stasis_returns_the_very_card_it_took() {
+        let mut cards = vec![Card::Apotheosis(false)];
+        cards.extend([Card::Anger(true); 9]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::BronzeAutomaton, pps, &[]);
+
+        // Waiting out two turns leaves every card in the discard pile, so an orb takes the rare
+        // Apotheosis. The Angers then go after whichever orb holds it.
+        let mut stasis_index = None;
+        let mut turns_waited = 0;
+        loop {
+            let message = from_server.recv_timeout(Duration::from_secs(5)).unwrap();
+            match &message {
+                StsMessage::Notification(Notification::EnemyParty(enemy_party)) => {
+                    stasis_index = enemy_party.iter().position(|maybe_enemy| {
+                        maybe_enemy.as_ref().is_some_and(|enemy| {
+                            enemy
+                                .conditions
+                                .contains(&EnemyCondition::Stasis(Card::Apotheosis(false)))
+                        })
+                    });
+                }
+                StsMessage::Notification(Notification::CardReturnedFromStasis(
+                    combat_card,
+                    card_destination,
+                )) => {
+                    assert_eq!(combat_card.id, 0);
+                    assert_eq!(combat_card.card, Card::Apotheosis(false));
+                    assert_eq!(*card_destination, CardDestination::Hand);
+                    break;
+                }
+                StsMessage::Choices(Prompt::CombatAction, _)
+                    if turns_waited >= 2
+                        && stasis_index.is_some()
+                        && playable_cards(&message).contains(&Card::Anger(true)) =>
+                {
+                    play(&to_server, &message, Card::Anger(true));
+                }
+                StsMessage::Choices(Prompt::CombatAction, _) => {
+                    turns_waited += 1;
+                    to_server
+                        .send(index_of(&message, &Choice::EndTurn))
+                        .unwrap();
+                }
+                StsMessage::Choices(Prompt::TargetEnemy, _) => {
+                    let target = Choice::TargetEnemy(stasis_index.unwrap(), Enemy::BronzeOrb);
+                    to_server.send(index_of(&message, &target)).unwrap();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Collects the notifications sent ahead of the next prompt.
+    fn notifications_before_prompt(
+        from_server: &Receiver<StsMessage>,
+    ) -> (Vec<Notification>, StsMessage) {
+        let mut notifications = Vec::new();
+        loop {
+            match from_server.recv_timeout(Duration::from_secs(5)).unwrap() {
+                StsMessage::Notification(notification) => notifications.push(notification),
+                message => return (notifications, message),
+            }
+        }
+    }
+
+    /// Returns the cards offered to be played from the player's hand, along with their costs.
+    fn hand_costs(message: &StsMessage) -> Vec<(Card, EnergyCost)> {
+        match message {
+            StsMessage::Choices(Prompt::CombatAction, choices) => choices
+                .iter()
+                .filter_map(|choice| match choice {
+                    Choice::PlayCardFromHand(_, card, energy_cost) => Some((*card, *energy_cost)),
+                    _ => None,
+                })
+                .collect(),
+            invalid => panic!("Expected a combat action prompt, got {:?}", invalid),
+        }
+    }
+
+    /// Starts the fight with the given skill bottled, so that it's in the opening hand.
+    fn start_combat_with_bottled_skill(
+        cards: &[Card],
+    ) -> (
+        Sender<usize>,
+        Receiver<StsMessage>,
+        JoinHandle<Result<CombatOutcome, Error>>,
+    ) {
+        let pps = pps_with(&IRONCLAD, cards, &[]);
+        let (to_server, from_server, handle) =
+            start_combat(3, Encounter::JawWorm, pps, &[Relic::BottledLightning]);
+        let prompt = next_prompt(&from_server, &[]);
+        to_server
+            .send(index_of(&prompt, &Choice::BottleCard(0, cards[0])))
+            .unwrap();
+        (to_server, from_server, handle)
+    }
+
+    #[test]
+    fn test_apotheosis_upgrades_every_card() {
+        let pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::Apotheosis(true),
+                Card::Bash(false),
+                Card::Strike(false),
+                Card::Defend(false),
+                Card::Defend(true),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Apotheosis(true),
+        );
+        let mut hand = playable_cards(&next_prompt(&from_server, &[]));
+        hand.sort_by_key(|card| format!("{:?}", card));
+        assert_eq!(
+            hand,
+            [
+                Card::Bash(true),
+                Card::Defend(true),
+                Card::Defend(true),
+                Card::Strike(true)
+            ]
+        );
+    }
+
+    /// Plays a card that shuffles random cards into the draw pile as the given character,
+    /// returning the cards created.
+    fn play_card_creating_cards_in_draw_pile(
+        character: &'static Character,
+        card: Card,
+    ) -> Vec<CardCombatState> {
+        let mut cards = vec![card];
+        cards.extend([Card::Defend(false); 4]);
+        let pps = pps_with(character, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(&to_server, &next_prompt(&from_server, &[]), card);
+        notifications_before_prompt(&from_server)
+            .0
+            .into_iter()
+            .filter_map(|notification| match notification {
+                Notification::CardCreatedInDrawPile(_, combat_card) => Some(combat_card),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_chrysalis_and_metamorphosis_shuffle_in_free_cards() {
+        for (card, card_type, card_count) in [
+            (Card::Chrysalis(false), CardType::Skill, 3),
+            (Card::Chrysalis(true), CardType::Skill, 5),
+            (Card::Metamorphosis(false), CardType::Attack, 3),
+            (Card::Metamorphosis(true), CardType::Attack, 5),
+        ] {
+            let created = play_card_creating_cards_in_draw_pile(&IRONCLAD, card);
+            assert_eq!(created.len(), card_count, "{:?}", card);
+            for combat_card in created {
+                assert_eq!(combat_card.details.type_, card_type);
+                // X costs are left alone (e.g. Whirlwind).
+                assert!(matches!(
+                    combat_card.cost_this_combat,
+                    EnergyCost::Zero | EnergyCost::X
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_chrysalis_and_metamorphosis_draw_from_the_players_character() {
+        for (card, pool) in [
+            (Card::Chrysalis(false), WATCHER.skill_card_pool),
+            (Card::Metamorphosis(false), WATCHER.attack_card_pool),
+        ] {
+            let created = play_card_creating_cards_in_draw_pile(&WATCHER, card);
+            assert_eq!(created.len(), 3, "{:?}", card);
+            assert!(created
+                .iter()
+                .all(|combat_card| pool.contains(&combat_card.card)));
+        }
+        // None of the Defect's attacks are defined yet, so there is nothing to create.
+        assert_eq!(
+            play_card_creating_cards_in_draw_pile(&DEFECT, Card::Metamorphosis(false)),
+            vec![]
+        );
+    }
+
+    /// Plays Secret Technique or Secret Weapon, checking that every card of the given type in
+    /// the draw pile is offered, and that the chosen one ends up in the player's hand.
+    fn take_card_from_draw_pile(card: Card, card_type: CardType) {
+        let cards = [
+            card,
+            Card::Strike(false),
+            Card::Strike(false),
+            Card::Bash(false),
+            Card::Defend(false),
+            Card::Defend(false),
+            Card::ShrugItOff(false),
+            Card::TrueGrit(false),
+        ];
+        let (to_server, from_server, _) = start_combat_with_bottled_skill(&cards);
+        let prompt = next_prompt(&from_server, &[]);
+        let mut hand = playable_cards(&prompt);
+        play(&to_server, &prompt, card);
+        let prompt = next_prompt(&from_server, &[]);
+        let StsMessage::Choices(Prompt::ChooseCardToTakeFromDrawPile, choices) = &prompt else {
+            panic!("Expected to take a card, got {:?}", prompt);
+        };
+        let mut offered = choices
+            .iter()
+            .map(|choice| match choice {
+                Choice::TakeFromDrawPile(_, card) => *card,
+                invalid => panic!("{:?}", invalid),
+            })
+            .collect::<Vec<_>>();
+        let mut draw_pile = cards[1..].to_vec();
+        for card in hand.iter() {
+            if let Some(i) = draw_pile.iter().position(|c| c == card) {
+                draw_pile.remove(i);
+            }
+        }
+        draw_pile.retain(|card| CardDetails::for_card(*card).type_ == card_type);
+        draw_pile.sort_by_key(|card| format!("{:?}", card));
+        offered.sort_by_key(|card| format!("{:?}", card));
+        assert_eq!(offered, draw_pile);
+
+        to_server.send(0).unwrap();
+        let Choice::TakeFromDrawPile(_, taken) = choices[0] else {
+            unreachable!();
+        };
+        hand.retain(|c| *c != card);
+        hand.push(taken);
+        hand.sort_by_key(|card| format!("{:?}", card));
+        let mut new_hand = playable_cards(&next_prompt(&from_server, &[]));
+        new_hand.sort_by_key(|card| format!("{:?}", card));
+        assert_eq!(new_hand, hand);
+    }
+
+    #[test]
+    fn test_secret_technique_takes_a_skill_from_the_draw_pile() {
+        take_card_from_draw_pile(Card::SecretTechnique(false), CardType::Skill);
+    }
+
+    #[test]
+    fn test_secret_weapon_takes_an_attack_from_the_draw_pile() {
+        take_card_from_draw_pile(Card::SecretWeapon(true), CardType::Attack);
+    }
+
+    #[test]
+    fn test_thinking_ahead_puts_a_card_back_on_the_draw_pile() {
+        let mut cards = vec![Card::ThinkingAhead(false)];
+        cards.extend([Card::Strike(false); 4]);
+        cards.extend([Card::Defend(false); 2]);
+        let (to_server, from_server, _) = start_combat_with_bottled_skill(&cards);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::ThinkingAhead(false),
+        );
+        // Both cards left in the draw pile are drawn, then one card is put back.
+        let prompt = next_prompt(&from_server, &[]);
+        let StsMessage::Choices(Prompt::ChooseCardToPutOnTopOfDrawPile, choices) = &prompt else {
+            panic!("Expected to put a card back, got {:?}", prompt);
+        };
+        assert_eq!(choices.len(), 6);
+        let hand_index = choices
+            .iter()
+            .position(|choice| matches!(choice, Choice::PutBackOnDrawPile(_, Card::Defend(_))))
+            .unwrap();
+        to_server.send(hand_index).unwrap();
+        let (notifications, prompt) = notifications_before_prompt(&from_server);
+        assert!(notifications.iter().any(|notification| matches!(
+            notification,
+            Notification::CardPutOnDrawPile(_, 0, combat_card)
+                if combat_card.card == Card::Defend(false)
+        )));
+        assert_eq!(playable_cards(&prompt).len(), 5);
+    }
+
+    #[test]
+    fn test_violence_takes_attacks_from_the_draw_pile() {
+        let mut cards = vec![Card::Violence(false)];
+        cards.extend([Card::Strike(false); 3]);
+        cards.extend([Card::Defend(false); 6]);
+        let (to_server, from_server, _) = start_combat_with_bottled_skill(&cards);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Violence(false),
+        );
+        let hand = playable_cards(&next_prompt(&from_server, &[]));
+        assert_eq!(
+            hand.iter()
+                .filter(|card| **card == Card::Strike(false))
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_deep_breath_shuffles_the_discard_pile_into_the_draw_pile() {
+        let mut cards = vec![Card::DeepBreath(true), Card::Strike(false)];
+        cards.extend([Card::Defend(false); 3]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Strike(false),
+        );
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::DeepBreath(true),
+        );
+        // Deep Breath+ draws two cards, so Strike is drawn however the pile is shuffled.
+        let (notifications, prompt) = notifications_before_prompt(&from_server);
+        assert!(notifications.contains(&Notification::ShufflingDiscardPileIntoDrawPile));
+        assert!(playable_cards(&prompt).contains(&Card::Strike(false)));
+    }
+
+    #[test]
+    fn test_discovery_offers_three_free_cards() {
+        let mut cards = vec![Card::Discovery(false)];
+        cards.extend([Card::Defend(false); 4]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Discovery(false),
+        );
+        let prompt = next_prompt(&from_server, &[]);
+        let StsMessage::Choices(Prompt::ChooseCardToCreate, choices) = &prompt else {
+            panic!("Expected to choose a card, got {:?}", prompt);
+        };
+        assert_eq!(choices.len(), 3);
+        assert!(
+            choices[1..].iter().all(|choice| *choice != choices[0]) && choices[1] != choices[2]
+        );
+        let Choice::CreateCard(discovered) = choices[2] else {
+            panic!("{:?}", choices[2]);
+        };
+        assert!(IRONCLAD
+            .attack_card_pool
+            .iter()
+            .chain(IRONCLAD.skill_card_pool)
+            .chain(IRONCLAD.power_card_pool)
+            .any(|card| *card == discovered));
+        to_server.send(2).unwrap();
+        let hand = hand_costs(&next_prompt(&from_server, &[]));
+        assert!(hand.iter().any(|(card, energy_cost)| *card == discovered
+            && matches!(energy_cost, EnergyCost::Zero | EnergyCost::X)));
+    }
+
+    #[test]
+    fn test_anger_puts_a_copy_of_itself_into_the_discard_pile() {
+        let mut cards = vec![Card::Anger(true)];
+        cards.extend([Card::Defend(false); 4]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Anger(true),
+        );
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+        let created = notifications_before_prompt(&from_server)
+            .0
+            .into_iter()
+            .filter_map(|notification| match notification {
+                Notification::CardCreated(combat_card, CardDestination::DiscardPile) => {
+                    Some(combat_card.card)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(created, vec![Card::Anger(true)]);
+    }
+
+    #[test]
+    fn test_armaments_upgrades_a_chosen_card_in_hand() {
+        let cards = [
+            Card::Armaments(false),
+            Card::Bash(false),
+            Card::Strike(false),
+            Card::Defend(true),
+            Card::Wound,
+        ];
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Armaments(false),
+        );
+        // Only cards that can still be upgraded are offered.
+        let prompt = next_prompt(&from_server, &[]);
+        let StsMessage::Choices(Prompt::UpgradeCard, choices) = &prompt else {
+            panic!("Expected to choose a card to upgrade, got {:?}", prompt);
+        };
+        let mut offered = choices
+            .iter()
+            .map(|choice| match choice {
+                Choice::UpgradeCardInHand(_, card, _) => *card,
+                invalid => panic!("{:?}", invalid),
+            })
+            .collect::<Vec<_>>();
+        offered.sort_by_key(|card| format!("{:?}", card));
+        assert_eq!(offered, vec![Card::Bash(false), Card::Strike(false)]);
+        to_server
+            .send(index_of(
+                &prompt,
+                choices
+                    .iter()
+                    .find(|choice| {
+                        matches!(choice, Choice::UpgradeCardInHand(_, Card::Bash(false), _))
+                    })
+                    .unwrap(),
+            ))
+            .unwrap();
+        assert!(playable_cards(&next_prompt(&from_server, &[])).contains(&Card::Bash(true)));
+    }
+
+    #[test]
+    fn test_upgraded_armaments_upgrades_the_whole_hand() {
+        let cards = [
+            Card::Armaments(true),
+            Card::Bash(false),
+            Card::Strike(false),
+            Card::Defend(false),
+            Card::Defend(true),
+        ];
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Armaments(true),
+        );
+        assert_eq!(
+            sorted_hand(&next_prompt(&from_server, &[])),
+            vec![
+                Card::Bash(true),
+                Card::Defend(true),
+                Card::Defend(true),
+                Card::Strike(true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cards_created_for_a_full_hand_are_announced_in_the_discard_pile() {
+        let mut cards = vec![Card::PowerThrough(false)];
+        cards.extend([Card::Offering(true); 15]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        // Offerings fill the hand to its limit of ten with Power Through in it.
+        let mut prompt = next_prompt(&from_server, &[]);
+        while playable_cards(&prompt).len() < 10
+            || !playable_cards(&prompt).contains(&Card::PowerThrough(false))
+        {
+            play(&to_server, &prompt, Card::Offering(true));
+            prompt = next_prompt(&from_server, &[]);
+        }
+        play(&to_server, &prompt, Card::PowerThrough(false));
+        let created = notifications_before_prompt(&from_server)
+            .0
+            .into_iter()
+            .filter_map(|notification| match notification {
+                Notification::CardCreated(combat_card, card_destination) => {
+                    Some((combat_card.card, card_destination))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // The first Wound fills the slot Power Through left, so the second is discarded.
+        assert_eq!(
+            created,
+            vec![
+                (Card::Wound, CardDestination::Hand),
+                (Card::Wound, CardDestination::DiscardPile)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enlightenment_reduces_costs_to_one() {
+        let pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::Enlightenment(false),
+                Card::Enlightenment(true),
+                Card::Bludgeon(false),
+                Card::Bash(false),
+                Card::Strike(false),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+        let reduced = [
+            (Card::Bludgeon(false), EnergyCost::One),
+            (Card::Bash(false), EnergyCost::One),
+            (Card::Strike(false), EnergyCost::One),
+        ];
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Enlightenment(false),
+        );
+        let prompt = next_prompt(&from_server, &[]);
+        let hand = hand_costs(&prompt);
+        assert!(reduced.iter().all(|card_cost| hand.contains(card_cost)));
+        // The reduction only lasts for the turn...
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        let prompt = next_prompt(&from_server, &[]);
+        let hand = hand_costs(&prompt);
+        assert!(hand.contains(&(Card::Bash(false), EnergyCost::Two)));
+        assert!(hand.contains(&(Card::Bludgeon(false), EnergyCost::Three)));
+        // ...unless Enlightenment is upgraded.
+        play(&to_server, &prompt, Card::Enlightenment(true));
+        let prompt = next_prompt(&from_server, &[]);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        let hand = hand_costs(&next_prompt(&from_server, &[]));
+        assert!(reduced.iter().all(|card_cost| hand.contains(card_cost)));
+    }
+
+    #[test]
+    fn test_forethought_makes_a_card_free_until_played() {
+        let mut cards = vec![Card::Forethought(false), Card::Bash(false)];
+        cards.extend([Card::Defend(false); 3]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Forethought(false),
+        );
+        let prompt = next_prompt(&from_server, &[]);
+        let StsMessage::Choices(Prompt::ChooseCardToPutOnBottomOfDrawPile, choices) = &prompt
+        else {
+            panic!("Expected to put a card on the bottom, got {:?}", prompt);
+        };
+        let bash_index = choices
+            .iter()
+            .position(|choice| matches!(choice, Choice::PutOnBottomOfDrawPile(_, Card::Bash(_))))
+            .unwrap();
+        to_server.send(bash_index).unwrap();
+        let prompt = next_prompt(&from_server, &[]);
+        assert!(!playable_cards(&prompt).contains(&Card::Bash(false)));
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        // Bash is drawn from the bottom of the draw pile, still free.
+        let prompt = next_prompt(&from_server, &[]);
+        assert!(hand_costs(&prompt).contains(&(Card::Bash(false), EnergyCost::Zero)));
+        play(&to_server, &prompt, Card::Bash(false));
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+        let prompt = next_prompt(&from_server, &[]);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        // Once played, it's back to full price.
+        let prompt = next_prompt(&from_server, &[]);
+        assert!(hand_costs(&prompt).contains(&(Card::Bash(false), EnergyCost::Two)));
+    }
+
+    #[test]
+    fn test_forethought_plus_puts_any_number_of_cards_on_the_bottom() {
+        let mut cards = vec![Card::Forethought(true), Card::Bash(false)];
+        cards.extend([Card::Defend(false); 3]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Forethought(true),
+        );
+        for _ in 0..2 {
+            let prompt = next_prompt(&from_server, &[]);
+            assert!(matches!(
+                prompt,
+                StsMessage::Choices(Prompt::ChooseCardToPutOnBottomOfDrawPile, _)
+            ));
+            to_server.send(0).unwrap();
+        }
+        let prompt = next_prompt(&from_server, &[]);
+        to_server.send(index_of(&prompt, &Choice::Skip)).unwrap();
+        assert_eq!(playable_cards(&next_prompt(&from_server, &[])).len(), 2);
+    }
+
+    #[test]
+    fn test_jack_of_all_trades_creates_colorless_cards() {
+        let mut cards = vec![Card::JackOfAllTrades(true)];
+        cards.extend([Card::Defend(false); 4]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::JackOfAllTrades(true),
+        );
+        let created = notifications_before_prompt(&from_server)
+            .0
+            .into_iter()
+            .filter_map(|notification| match notification {
+                Notification::CardCreated(combat_card, CardDestination::Hand) => {
+                    Some(combat_card.card)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(created.len(), 2);
+        assert!(created
+            .iter()
+            .all(|card| COMBAT_COLORLESS_CARD_POOL.contains(card)));
+    }
+
+    #[test]
+    fn test_madness_makes_a_card_free_for_the_rest_of_combat() {
+        let pps = pps_with(&IRONCLAD, &[Card::Madness(true), Card::Bash(false)], &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Madness(true),
+        );
+        let prompt = next_prompt(&from_server, &[]);
+        assert_eq!(hand_costs(&prompt), [(Card::Bash(false), EnergyCost::Zero)]);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        assert_eq!(
+            hand_costs(&next_prompt(&from_server, &[])),
+            [(Card::Bash(false), EnergyCost::Zero)]
+        );
+    }
+
+    #[test]
+    fn test_purity_exhausts_up_to_three_cards() {
+        let mut cards = vec![Card::Purity(false)];
+        cards.extend([Card::Strike(false); 4]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Purity(false),
+        );
+        let prompt = next_prompt(&from_server, &[]);
+        assert_eq!(
+            prompt,
+            StsMessage::Choices(
+                Prompt::ChooseCardToExhaust,
+                (0..4)
+                    .map(|hand_index| Choice::ExhaustCard(hand_index, Card::Strike(false)))
+                    .chain([Choice::Skip])
+                    .collect()
+            )
+        );
+        to_server.send(0).unwrap();
+        let prompt = next_prompt(&from_server, &[]);
+        to_server.send(index_of(&prompt, &Choice::Skip)).unwrap();
+        assert_eq!(
+            playable_cards(&next_prompt(&from_server, &[])),
+            [Card::Strike(false); 3]
+        );
+    }
+
+    #[test]
+    fn test_transmutation_creates_x_free_upgraded_colorless_cards() {
+        let mut cards = vec![Card::Transmutation(true)];
+        cards.extend([Card::Defend(false); 4]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Transmutation(true),
+        );
+        let (notifications, prompt) = notifications_before_prompt(&from_server);
+        let created = notifications
+            .into_iter()
+            .filter_map(|notification| match notification {
+                Notification::CardCreated(combat_card, CardDestination::Hand) => Some(combat_card),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(created.len(), 3);
+        for combat_card in created.iter() {
+            assert!(combat_card.details.upgrade.is_none());
+            assert!(matches!(
+                combat_card.cost_this_turn,
+                EnergyCost::Zero | EnergyCost::X
+            ));
+        }
+        assert!(hand_costs(&prompt)
+            .iter()
+            .all(|(card, energy_cost)| *card == Card::Defend(false)
+                || *energy_cost == EnergyCost::Zero
+                || CardDetails::for_card(*card).cost == EnergyCost::X));
+    }
+
+    #[test]
+    fn test_the_bomb_explodes_at_the_end_of_the_third_turn() {
+        let mut cards = vec![Card::TheBomb(true)];
+        cards.extend([Card::Defend(false); 4]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, handle) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::TheBomb(true),
+        );
+        for _ in 0..3 {
+            let prompt = next_prompt(&from_server, &[]);
+            to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        }
+        // The Jaw Worm dies without the player being prompted again.
+        while let Ok(message) = from_server.recv_timeout(Duration::from_secs(5)) {
+            assert!(
+                matches!(message, StsMessage::Notification(_)),
+                "{:?}",
+                message
+            );
+        }
+        assert_eq!(handle.join().unwrap().unwrap(), CombatOutcome::Victory);
+    }
+
+    #[test]
+    fn test_time_warp_ends_the_turn_after_twelve_cards() {
+        let pps = pps_with(&IRONCLAD, &[Card::FlashOfSteel(false); 13], &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::TimeEater, pps, &[]);
+
+        let mut prompt = next_prompt(&from_server, &[]);
+        for _ in 0..11 {
+            play(&to_server, &prompt, Card::FlashOfSteel(false));
+            next_prompt(&from_server, &[]);
+            to_server.send(0).unwrap(); // Target the Time Eater
+            prompt = next_prompt(&from_server, &[]);
+        }
+        play(&to_server, &prompt, Card::FlashOfSteel(false));
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Time Eater
+                                    // The twelfth card ends the turn, and the Time Eater gains 2 Strength before taking its
+                                    // own turn.
+        let mut time_eater =
+            EnemyStatus::new(Enemy::TimeEater, (420, 456), Intent::Aggressive(13, 3));
+        time_eater.block = 20;
+        time_eater.strength = 2;
+        time_eater.conditions = vec![EnemyCondition::TimeWarp(0)];
+        next_prompt(
+            &from_server,
+            &[Notification::EnemyParty(vec![
+                Some(time_eater),
+                None,
+                None,
+                None,
+                None,
+            ])],
+        );
+    }
+
+    /// Returns the cards offered to be played from the player's hand, sorted by name.
+    fn sorted_hand(message: &StsMessage) -> Vec<Card> {
+        let mut hand = playable_cards(message);
+        hand.sort_by_key(|card| format!("{:?}", card));
+        hand
+    }
+
+    /// Returns the cards that were Exhausted and discarded, in that order, each sorted by name.
+    fn exhausted_and_discarded(notifications: &[Notification]) -> (Vec<Card>, Vec<Card>) {
+        let mut exhausted = Vec::new();
+        let mut discarded = Vec::new();
+        for notification in notifications {
+            match notification {
+                Notification::CardExhausted(_, combat_card) => exhausted.push(combat_card.card),
+                Notification::CardDiscarded(_, combat_card) => discarded.push(combat_card.card),
+                _ => {}
+            }
+        }
+        exhausted.sort_by_key(|card| format!("{:?}", card));
+        discarded.sort_by_key(|card| format!("{:?}", card));
+        (exhausted, discarded)
+    }
+
+    #[test]
+    fn test_ethereal_cards_are_exhausted_and_retained_cards_kept() {
+        let pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::Carnage(false),
+                Card::Dazed,
+                Card::WindmillStrike(false),
+                Card::Defend(false),
+                Card::Defend(false),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        let prompt = next_prompt(&from_server, &[]);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        let (notifications, prompt) = notifications_before_prompt(&from_server);
+        assert_eq!(
+            exhausted_and_discarded(&notifications),
+            (
+                vec![Card::Carnage(false), Card::Dazed],
+                vec![Card::Defend(false), Card::Defend(false)]
+            )
+        );
+        assert_eq!(
+            sorted_hand(&prompt),
+            [
+                Card::Defend(false),
+                Card::Defend(false),
+                Card::WindmillStrike(false)
+            ]
+        );
+        play(&to_server, &prompt, Card::WindmillStrike(false));
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+                                    // Being retained raised Windmill Strike's damage from 7 to 11.
+        next_prompt(
+            &from_server,
+            &[Notification::EnemyParty(jaw_worm(
+                31,
+                Intent::DefensiveBuff,
+            ))],
+        );
+    }
+
+    #[test]
+    fn test_well_laid_plans_prompts_for_a_card_to_retain() {
+        let pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::WellLaidPlans(false),
+                Card::Strike(false),
+                Card::Strike(false),
+                Card::Defend(false),
+                Card::Bash(false),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::WellLaidPlans(false),
+        );
+        let prompt = next_prompt(&from_server, &[]);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        let prompt = next_prompt(&from_server, &[]);
+        let StsMessage::Choices(Prompt::ChooseCardsToRetain, choices) = &prompt else {
+            panic!("Expected to retain cards, got {:?}", prompt);
+        };
+        let mut offered = choices
+            .iter()
+            .filter_map(|choice| match choice {
+                Choice::RetainCard(_, card) => Some(*card),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        offered.sort_by_key(|card| format!("{:?}", card));
+        assert_eq!(
+            offered,
+            [
+                Card::Bash(false),
+                Card::Defend(false),
+                Card::Strike(false),
+                Card::Strike(false)
+            ]
+        );
+        assert_eq!(choices.last(), Some(&Choice::Skip));
+        let retain_bash = choices
+            .iter()
+            .find(|choice| matches!(choice, Choice::RetainCard(_, Card::Bash(false))))
+            .unwrap();
+        to_server.send(index_of(&prompt, retain_bash)).unwrap();
+        // Only one card can be retained, and the other three are discarded.
+        let (notifications, prompt) = notifications_before_prompt(&from_server);
+        assert_eq!(
+            exhausted_and_discarded(&notifications),
+            (
+                vec![],
+                vec![
+                    Card::Defend(false),
+                    Card::Strike(false),
+                    Card::Strike(false)
+                ]
+            )
+        );
+        assert!(playable_cards(&prompt).contains(&Card::Bash(false)));
+    }
+
+    #[test]
+    fn test_equilibrium_retains_the_whole_hand() {
+        let pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::Equilibrium(false),
+                Card::Strike(false),
+                Card::Defend(false),
+                Card::Bash(false),
+                Card::Dazed,
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Equilibrium(false),
+        );
+        let prompt = next_prompt(&from_server, &[]);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        // Ethereal cards are still Exhausted, and Equilibrium itself is drawn again.
+        let (notifications, prompt) = notifications_before_prompt(&from_server);
+        assert_eq!(
+            exhausted_and_discarded(&notifications),
+            (vec![Card::Dazed], vec![])
+        );
+        assert_eq!(
+            sorted_hand(&prompt),
+            [
+                Card::Bash(false),
+                Card::Defend(false),
+                Card::Equilibrium(false),
+                Card::Strike(false)
+            ]
+        );
+        // Equilibrium only lasts for one turn.
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        let (notifications, _) = notifications_before_prompt(&from_server);
+        assert_eq!(
+            exhausted_and_discarded(&notifications),
+            (
+                vec![],
+                vec![
+                    Card::Bash(false),
+                    Card::Defend(false),
+                    Card::Equilibrium(false),
+                    Card::Strike(false)
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_runic_pyramid_keeps_the_whole_hand() {
+        let mut cards = vec![Card::Strike(false); 5];
+        cards.extend([Card::Defend(false); 5]);
+        let pps = pps_with(&IRONCLAD, &cards, &[Relic::RunicPyramid]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        let prompt = next_prompt(&from_server, &[]);
+        let hand = playable_cards(&prompt);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        // Nothing is discarded, and five more cards are drawn on top of the kept ones.
+        let (notifications, prompt) = notifications_before_prompt(&from_server);
+        assert_eq!(exhausted_and_discarded(&notifications), (vec![], vec![]));
+        let new_hand = playable_cards(&prompt);
+        assert_eq!(new_hand.len(), 10);
+        assert_eq!(new_hand[..5], hand);
+    }
+
+    #[test]
+    fn test_establishment_reduces_the_cost_of_retained_cards() {
+        let mut cards = vec![Card::Establishment(false), Card::Protect(false)];
+        cards.extend([Card::Defend(false); 3]);
+        let pps = pps_with(&WATCHER, &cards, &[]);
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Establishment(false),
+        );
+        let prompt = next_prompt(&from_server, &[]);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        let prompt = next_prompt(&from_server, &[]);
+        assert!(hand_costs(&prompt).contains(&(Card::Protect(false), EnergyCost::One)));
+        // The reduction lasts for the rest of combat, and stacks each time the card is retained.
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        let prompt = next_prompt(&from_server, &[]);
+        assert!(hand_costs(&prompt).contains(&(Card::Protect(false), EnergyCost::Zero)));
+    }
+
+    #[test]
+    fn test_double_tap_plays_the_next_attack_twice() {
+        let pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::DoubleTap(false),
+                Card::Strike(false),
+                Card::Strike(false),
+                Card::Defend(false),
+                Card::Defend(false),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::DoubleTap(false),
+        );
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Strike(false),
+        );
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+        let prompt = next_prompt(
+            &from_server,
+            &[Notification::EnemyParty(jaw_worm(
+                30,
+                Intent::Aggressive(11, 1),
+            ))],
+        );
+        // Double Tap is used up by the first Attack.
+        play(&to_server, &prompt, Card::Strike(false));
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+        next_prompt(
+            &from_server,
+            &[Notification::EnemyParty(jaw_worm(
+                24,
+                Intent::Aggressive(11, 1),
+            ))],
+        );
+    }
+
+    #[test]
+    fn test_burst_plays_the_next_skill_twice() {
+        let pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::Burst(false),
+                Card::Strike(false),
+                Card::Defend(false),
+                Card::Defend(false),
+                Card::Defend(false),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Burst(false),
+        );
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Defend(false),
+        );
+        let prompt = next_prompt(
+            &from_server,
+            &[
+                Notification::BlockGained(5),
+                Notification::BlockGained(5),
+                Notification::Block(10),
+            ],
+        );
+        // Burst is used up by the first Skill.
+        play(&to_server, &prompt, Card::Defend(false));
+        next_prompt(&from_server, &[Notification::Block(15)]);
+    }
+
+    #[test]
+    fn test_amplify_plays_the_next_power_twice() {
+        let pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::Amplify(false),
+                Card::Inflame(false),
+                Card::Defend(false),
+                Card::Defend(false),
+                Card::Defend(false),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Amplify(false),
+        );
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Inflame(false),
+        );
+        let prompt = next_prompt(
+            &from_server,
+            &[Notification::Strength(2), Notification::Strength(4)],
+        );
+        // Played Powers leave the fight, so only the other four cards are drawn next turn.
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        assert_eq!(
+            sorted_hand(&next_prompt(&from_server, &[])),
+            [
+                Card::Amplify(false),
+                Card::Defend(false),
+                Card::Defend(false),
+                Card::Defend(false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_powers_and_tantrum_announce_where_they_go() {
+        let pps = pps_with(
+            &WATCHER,
+            &[
+                Card::Inflame(false),
+                Card::Tantrum(false),
+                Card::Defend(false),
+                Card::Defend(false),
+                Card::Defend(false),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Inflame(false),
+        );
+        let (notifications, prompt) = notifications_before_prompt(&from_server);
+        assert!(notifications.iter().any(|notification| matches!(
+            notification,
+            Notification::PowerPlayed(_, combat_card) if combat_card.card == Card::Inflame(false)
+        )));
+        play(&to_server, &prompt, Card::Tantrum(false));
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+        let (notifications, _) = notifications_before_prompt(&from_server);
+        assert!(notifications.iter().any(|notification| matches!(
+            notification,
+            Notification::CardPutOnDrawPile(_, _, combat_card)
+                if combat_card.card == Card::Tantrum(false)
+        )));
+    }
+
+    #[test]
+    fn test_duplication_potion_plays_the_next_card_twice() {
+        let mut pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::Strike(false),
+                Card::Strike(false),
+                Card::Defend(false),
+                Card::Defend(false),
+                Card::Defend(false),
+            ],
+            &[],
+        );
+        pps.potions = vec![Some(Potion::DuplicationPotion), None, None];
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        let prompt = next_prompt(&from_server, &[]);
+        to_server
+            .send(index_of(
+                &prompt,
+                &Choice::ExpendPotion(PotionAction::Drink(0, Potion::DuplicationPotion)),
+            ))
+            .unwrap();
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Strike(false),
+        );
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+        let prompt = next_prompt(
+            &from_server,
+            &[Notification::EnemyParty(jaw_worm(
+                30,
+                Intent::Aggressive(11, 1),
+            ))],
+        );
+        play(&to_server, &prompt, Card::Defend(false));
+        next_prompt(&from_server, &[Notification::Block(5)]);
+    }
+
+    #[test]
+    fn test_echo_form_plays_the_first_card_each_turn_twice() {
+        let pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::EchoForm(false),
+                Card::Strike(false),
+                Card::Strike(false),
+                Card::Defend(false),
+                Card::Defend(false),
+            ],
+            &[],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        // Echo Form isn't in play yet when it's played, so it isn't played twice itself.
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::EchoForm(false),
+        );
+        let prompt = next_prompt(&from_server, &[Notification::Energy(0)]);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        let mut prompt = next_prompt(&from_server, &[]);
+        for hp in [30, 24] {
+            play(&to_server, &prompt, Card::Strike(false));
+            next_prompt(&from_server, &[]);
+            to_server.send(0).unwrap(); // Target the Jaw Worm
+            prompt = next_prompt(
+                &from_server,
+                &[Notification::EnemyParty(jaw_worm(
+                    hp,
+                    Intent::DefensiveBuff,
+                ))],
+            );
+        }
+    }
+
+    #[test]
+    fn test_necronomicon_plays_one_costly_attack_twice_per_turn() {
+        let pps = pps_with(
+            &IRONCLAD,
+            &[
+                Card::SeeingRed(false),
+                Card::Bash(false),
+                Card::Bash(false),
+                Card::Defend(false),
+                Card::Defend(false),
+            ],
+            &[Relic::Necronomicon],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::SeeingRed(false),
+        );
+        // Bash costs two, so the first is played twice, with the copy dealing 12 to the now
+        // Vulnerable Jaw Worm. Necronomicon only triggers once per turn.
+        let mut prompt = next_prompt(&from_server, &[]);
+        for (hp, turns) in [(22, 4), (10, 6)] {
+            play(&to_server, &prompt, Card::Bash(false));
+            next_prompt(&from_server, &[]);
+            to_server.send(0).unwrap(); // Target the Jaw Worm
+            let mut jaw_worm = jaw_worm(hp, Intent::Aggressive(11, 1));
+            jaw_worm[0].as_mut().unwrap().conditions = vec![EnemyCondition::Vulnerable(turns)];
+            prompt = next_prompt(&from_server, &[Notification::EnemyParty(jaw_worm)]);
+        }
+    }
+
+    #[test]
+    fn test_necronomicon_ignores_free_attacks() {
+        let pps = pps_with(
+            &WATCHER,
+            &[
+                Card::Swivel(false),
+                Card::Bash(false),
+                Card::Defend(false),
+                Card::Defend(false),
+                Card::Defend(false),
+            ],
+            &[Relic::Necronomicon],
+        );
+        let (to_server, from_server, _) = start_combat(3, Encounter::JawWorm, pps, &[]);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Swivel(false),
+        );
+        // Swivel makes Bash free, so it is only played once.
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Bash(false),
+        );
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Target the Jaw Worm
+        let jaw_worm_hps = notifications_before_prompt(&from_server)
+            .0
+            .into_iter()
+            .filter_map(|notification| match notification {
+                Notification::EnemyParty(enemy_party) => Some(enemy_party[0].as_ref()?.hp),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(jaw_worm_hps.last(), Some(&34));
+    }
+
+    /// Plays Havoc with the given card on top of the draw pile, returning the notifications
+    /// sent before the next prompt.
+    fn play_havoc(top_card: Card) -> (Vec<Notification>, StsMessage) {
+        let mut cards = vec![Card::Havoc(false)];
+        cards.extend([top_card; 5]);
+        let (to_server, from_server, _) = start_combat_with_bottled_skill(&cards);
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Havoc(false),
+        );
+        notifications_before_prompt(&from_server)
+    }
+
+    #[test]
+    fn test_havoc_exhausts_an_unplayable_card() {
+        let (notifications, _) = play_havoc(Card::Dazed);
+        assert_eq!(
+            exhausted_and_discarded(&notifications),
+            (vec![Card::Dazed], vec![Card::Havoc(false)])
+        );
+    }
+
+    #[test]
+    fn test_havoc_plays_an_x_cost_card_with_the_energy_left() {
+        let (notifications, prompt) = play_havoc(Card::Whirlwind(false));
+        // Whirlwind hits twice for the two energy left after Havoc, without spending it.
+        assert!(notifications.contains(&Notification::EnemyParty(jaw_worm(
+            32,
+            Intent::Aggressive(11, 1)
+        ))));
+        assert_eq!(
+            exhausted_and_discarded(&notifications),
+            (vec![Card::Whirlwind(false)], vec![Card::Havoc(false)])
+        );
+        assert_eq!(
+            hand_costs(&prompt),
+            [(Card::Whirlwind(false), EnergyCost::X); 4]
+        );
+        assert!(!notifications.contains(&Notification::Energy(0)));
+    }
+
+    /// Plays Mayhem with the rest of the deck made up of the given card, and ends the turn,
+    /// returning the notifications sent before the next prompt.
+    fn play_mayhem(card: Card) -> (Vec<Notification>, StsMessage) {
+        let mut cards = vec![Card::Mayhem(false)];
+        cards.extend([card; 10]);
+        let pps = pps_with(&IRONCLAD, &cards, &[]);
+        let (to_server, from_server, _) =
+            start_combat(3, Encounter::JawWorm, pps, &[Relic::BottledTornado]);
+        let prompt = next_prompt(&from_server, &[]);
+        to_server
+            .send(index_of(
+                &prompt,
+                &Choice::BottleCard(0, Card::Mayhem(false)),
+            ))
+            .unwrap();
+
+        play(
+            &to_server,
+            &next_prompt(&from_server, &[]),
+            Card::Mayhem(false),
+        );
+        let prompt = next_prompt(&from_server, &[]);
+        to_server.send(index_of(&prompt, &Choice::EndTurn)).unwrap();
+        notifications_before_prompt(&from_server)
+    }
+
+    #[test]
+    fn test_mayhem_discards_an_unplayable_card() {
+        let (notifications, _) = play_mayhem(Card::Dazed);
+        // The four Dazed left in hand are Exhausted at the end of the turn, while the one
+        // autoplayed after the next draw is discarded.
+        assert_eq!(
+            exhausted_and_discarded(&notifications),
+            (vec![Card::Dazed; 4], vec![Card::Dazed])
+        );
+    }
+
+    #[test]
+    fn test_mayhem_plays_an_x_cost_card_with_a_full_turn_of_energy() {
+        let (notifications, prompt) = play_mayhem(Card::Whirlwind(false));
+        // Mayhem plays the card after the draw, when the player has three energy to spend, and
+        // the energy is still there afterwards.
+        assert!(notifications.contains(&Notification::EnemyParty(jaw_worm(
+            27,
+            Intent::DefensiveBuff
+        ))));
+        assert_eq!(
+            exhausted_and_discarded(&notifications),
+            (vec![], vec![Card::Whirlwind(false); 5])
+        );
+        assert_eq!(
+            hand_costs(&prompt),
+            [(Card::Whirlwind(false), EnergyCost::X); 5]
+        );
+        assert!(notifications.ends_with(&[
+            Notification::Health((69, 80)),
+            Notification::Energy(3),
+            Notification::Strength(0),
+            Notification::Dexterity(0),
+            Notification::Conditions(vec![PlayerCondition::Mayhem(1)]),
+        ]));
+    }
+}
@@ -1,78 +1,1118 @@
 use anyhow::Error;
 
-use crate::components::{Choice, Interaction, PlayerPersistentState, Prompt};
-use crate::data::Event;
-use crate::systems::base::{DeckSystem, GoldSystem, HealthSystem, PotionSystem};
-use crate::systems::rng::PotionGenerator;
+use crate::components::{Choice, Interaction, Notification, PlayerPersistentState, Prompt};
+use crate::data::{
+    Card, CardDetails, CardRarity, CardType, Damage, Encounter, Event, PlayerEffect, Relic,
+    TargetEffect, RARE_COLORLESS_CARD_POOL, UNCOMMON_COLORLESS_CARD_POOL,
+};
+use crate::systems::base::{DeckSystem, GoldSystem, HealthSystem, PotionSystem, RelicSystem};
+use crate::systems::rng::{CardGenerator, PotionGenerator, RelicGenerator, Seed, StsRandom};
+use crate::types::{Floor, Gold, GridIndex, Hp, HpMax};
+
+use super::combat_simulator::{CombatOutcome, CombatSimulator};
+use super::main_screen_system::MainScreenSystem;
+
+/// How an event came to an end.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventOutcome {
+    /// The player carries on to the next room on the map.
+    Continue,
+    /// The player stepped through the Secret Portal, so the boss is the next room.
+    SecretPortal,
+}
+
+/// What the Dead Adventurer's body turns up on each search, in the order they're shuffled.
+#[derive(Clone, Copy, Debug)]
+enum DeadAdventurerReward {
+    Gold,
+    Nothing,
+    Relic,
+}
 
 pub struct EventSimulator<'a, I: Interaction> {
     comms: &'a I,
+    floor: Floor,
+    seed_for_floor: Seed,
+    misc_rng: &'a mut StsRandom,
+    card_generator: &'a mut CardGenerator,
     potion_generator: &'a mut PotionGenerator,
+    relic_generator: &'a mut RelicGenerator,
 }
 
-impl<'a, I: Interaction> EventSimulator<'a, I> {
-    pub fn new(comms: &'a I, potion_generator: &'a mut PotionGenerator) -> Self {
-        Self {
-            comms,
-            potion_generator,
+impl<'a, I: Interaction> EventSimulator<'a, I> {
+    /// Creates a new event simulator. Events draw their randomness from `misc_rng`, except for
+    /// cards, potions and relics, which come from their respective generators.
+    pub fn new(
+        comms: &'a I,
+        floor: Floor,
+        seed_for_floor: Seed,
+        misc_rng: &'a mut StsRandom,
+        card_generator: &'a mut CardGenerator,
+        potion_generator: &'a mut PotionGenerator,
+        relic_generator: &'a mut RelicGenerator,
+    ) -> Self {
+        Self {
+            comms,
+            floor,
+            seed_for_floor,
+            misc_rng,
+            card_generator,
+            potion_generator,
+            relic_generator,
+        }
+    }
+
+    pub fn run_event(
+        self,
+        event: Event,
+        pps: &mut PlayerPersistentState,
+    ) -> Result<EventOutcome, Error> {
+        match event {
+            Event::AncientWriting => self.ancient_writing(pps),
+            Event::ANoteForYourself => self.note_for_yourself(pps),
+            Event::Augmenter => self.augmenter(pps),
+            Event::BigFish => self.big_fish(pps),
+            Event::BonfireSpirits => self.bonfire_spirits(pps),
+            Event::CouncilOfGhosts => self.council_of_ghosts(pps),
+            Event::CursedTome => self.cursed_tome(pps),
+            Event::DeadAdventurer => self.dead_adventurer(pps),
+            Event::DesignerInSpire => self.designer_in_spire(pps),
+            Event::Duplicator => self.duplicator(pps),
+            Event::FaceTrader => self.face_trader(pps),
+            Event::Falling => self.falling(pps),
+            Event::ForgottenAltar => self.forgotten_altar(pps),
+            Event::GoldenIdol => self.golden_idol(pps),
+            Event::GoldenShrine => self.golden_shrine(pps),
+            Event::HypnotizingColoredMushrooms => self.hypnotizing_colored_mushrooms(pps),
+            Event::KnowingSkull => self.knowing_skull(pps),
+            Event::Lab => self.lab(pps),
+            Event::LivingWall => self.living_wall(pps),
+            Event::MaskedBandits => self.masked_bandits(pps),
+            Event::MatchAndKeep => self.match_and_keep(pps),
+            Event::MindBloom => self.mind_bloom(pps),
+            Event::MysteriousSphere => self.mysterious_sphere(pps),
+            Event::Neow => todo!("{:?}", event),
+            Event::Nloth => self.nloth(pps),
+            Event::NoteForYourself => self.note_for_yourself(pps),
+            Event::OldBeggar => self.old_beggar(pps),
+            Event::OminousForge => self.ominous_forge(pps),
+            Event::PleadingVagrant => self.pleading_vagrant(pps),
+            Event::Purifier => self.purifier(pps),
+            Event::ScrapOoze => self.scrap_ooze(pps),
+            Event::SecretPortal => return self.secret_portal(pps),
+            Event::SensoryStone => self.sensory_stone(pps),
+            Event::ShiningLight => self.shining_light(pps),
+            Event::TheCleric => self.the_cleric(pps),
+            Event::TheColosseum => self.the_colosseum(pps),
+            Event::TheDivineFountain => self.the_divine_fountain(pps),
+            Event::TheJoust => self.the_joust(pps),
+            Event::TheLibrary => self.the_library(pps),
+            Event::TheMausoleum => self.the_mausoleum(pps),
+            Event::TheMoaiHead => self.the_moai_head(pps),
+            Event::TheNest => self.the_nest(pps),
+            Event::TheSsssserpent => self.the_ssssserpent(pps),
+            Event::TheWomanInBlue => self.the_woman_in_blue(pps),
+            Event::TombOfLordRedMask => self.tomb_of_lord_red_mask(pps),
+            Event::Transmogrifier => self.transmogrifier(pps),
+            Event::UpgradeShrine => self.upgrade_shrine(pps),
+            Event::Vampires => self.vampires(pps),
+            Event::WeMeetAgain => self.we_meet_again(pps),
+            Event::WheelOfChange => self.wheel_of_change(pps),
+            Event::WindingHalls => self.winding_halls(pps),
+            Event::WingStatue => self.wing_statue(pps),
+            Event::WorldOfGoop => self.world_of_goop(pps),
+        }?;
+        Ok(EventOutcome::Continue)
+    }
+
+    fn ancient_writing(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Elegance: Remove a card from your deck.".into()),
+            Choice::Event(1, "Simplicity: Upgrade all Strikes and Defends.".into()),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => DeckSystem::choose_card_to_remove(self.comms, pps),
+            Choice::Event(1, _) => {
+                for deck_card in pps.deck.iter_mut() {
+                    let card = deck_card.card;
+                    if matches!(card, Card::Strike(_) | Card::Defend(_)) {
+                        if let Some(upgraded) = deck_card.upgrade() {
+                            self.comms
+                                .send_notification(Notification::CardUpgraded(card, upgraded))?;
+                        }
+                    }
+                }
+                DeckSystem::notify_player(self.comms, pps)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn augmenter(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Test J.A.X.: Obtain J.A.X.".into()),
+            Choice::Event(1, "Become Test Subject: Transform 2 cards.".into()),
+            Choice::Event(2, "Ingest Mutagens: Obtain Mutagenic Strength.".into()),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => DeckSystem::obtain_card(self.comms, pps, Card::Jax(false)),
+            Choice::Event(1, _) => {
+                DeckSystem::choose_cards_to_transform(self.comms, pps, 2, self.misc_rng)
+            }
+            Choice::Event(2, _) => {
+                RelicSystem::obtain_relic(self.comms, pps, Relic::MutagenicStrength)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn big_fish(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let heal_amt = pps.hp_max / 3;
+        let choices = vec![
+            Choice::Event(0, format!("Banana: Heal {} HP.", heal_amt)),
+            Choice::Event(1, "Donut: Max HP +5.".into()),
+            Choice::Event(2, "Box: Receive a Relic. Become Cursed - Regret.".into()),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => HealthSystem::heal(self.comms, pps, heal_amt),
+            Choice::Event(1, _) => HealthSystem::increase_hp_max(self.comms, pps, 5),
+            Choice::Event(2, _) => {
+                let relic = self.relic_generator.random_screenless_relic();
+                RelicSystem::obtain_relic(self.comms, pps, relic)?;
+                DeckSystem::obtain_card(self.comms, pps, Card::Regret)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn bonfire_spirits(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = pps
+            .deck
+            .iter()
+            .map(|deck_card| deck_card.card)
+            .enumerate()
+            .filter(|(_, card)| !CardDetails::for_card(*card).irremovable)
+            .map(|(deck_index, card)| Choice::OfferCard(deck_index, card))
+            .collect::<Vec<_>>();
+        let choice = self.comms.prompt_for_choice(Prompt::OfferCard, &choices)?;
+        match choice {
+            Choice::OfferCard(deck_index, card) => {
+                // The spirits reward the offering according to its rarity.
+                let details = CardDetails::for_card(*card);
+                if details.type_ == CardType::Curse {
+                    RelicSystem::obtain_relic(self.comms, pps, Relic::SpiritPoop)?;
+                } else {
+                    match details.rarity {
+                        CardRarity::Starter => {}
+                        CardRarity::Common | CardRarity::Special => {
+                            HealthSystem::heal(self.comms, pps, 5)?
+                        }
+                        CardRarity::Uncommon => HealthSystem::heal(self.comms, pps, 10)?,
+                        CardRarity::Rare => {
+                            HealthSystem::increase_hp_max(self.comms, pps, 10)?;
+                            HealthSystem::heal(self.comms, pps, pps.hp_max)?;
+                        }
+                    }
+                }
+                DeckSystem::remove_card(self.comms, pps, *deck_index)?;
+                DeckSystem::notify_player(self.comms, pps)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn council_of_ghosts(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let hp_max_loss = ((pps.hp_max as f32 * 0.5).ceil() as HpMax).min(pps.hp_max - 1);
+        let choices = vec![
+            Choice::Event(
+                0,
+                format!(
+                    "Accept: Receive 5 Apparitions. Lose {} Max HP.",
+                    hp_max_loss
+                ),
+            ),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                HealthSystem::decrease_hp_max(self.comms, pps, hp_max_loss)?;
+                for _ in 0..5 {
+                    DeckSystem::obtain_card(self.comms, pps, Card::Apparition(false))?;
+                }
+                Ok(())
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn cursed_tome(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![Choice::Event(0, "Read.".into()), Choice::Skip];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {}
+            Choice::Skip => return Ok(()),
+            _ => unreachable!(),
+        }
+        // Each page turned hurts a little more than the last.
+        for hp_loss in 1..=3 {
+            let choices = vec![Choice::Event(0, format!("Continue: Lose {} HP.", hp_loss))];
+            self.comms
+                .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+            HealthSystem::decrease_hp(self.comms, pps, hp_loss)?;
+            if pps.hp == 0 {
+                return Ok(());
+            }
+        }
+        let choices = vec![
+            Choice::Event(0, "Take: Obtain the Book. Lose 10 HP.".into()),
+            Choice::Event(1, "Stop: Lose 3 HP.".into()),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                HealthSystem::decrease_hp(self.comms, pps, 10)?;
+                if pps.hp == 0 {
+                    return Ok(());
+                }
+                let mut books = [Relic::Necronomicon, Relic::Enchiridion, Relic::NilrysCodex]
+                    .into_iter()
+                    .filter(|book| !pps.relics.contains(book))
+                    .collect::<Vec<_>>();
+                if books.is_empty() {
+                    books.push(Relic::Circlet);
+                }
+                let book = *self.misc_rng.choose(&books);
+                RelicSystem::obtain_relic(self.comms, pps, book)
+            }
+            Choice::Event(1, _) => HealthSystem::decrease_hp(self.comms, pps, 3),
+            _ => unreachable!(),
+        }
+    }
+
+    fn dead_adventurer(mut self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut rewards = [
+            DeadAdventurerReward::Gold,
+            DeadAdventurerReward::Nothing,
+            DeadAdventurerReward::Relic,
+        ];
+        self.misc_rng.java_compat_shuffle(&mut rewards);
+        let encounter = [
+            Encounter::ThreeSentries,
+            Encounter::GremlinNob,
+            Encounter::LagavulinEvent,
+        ][self.misc_rng.gen_range(0..=2)];
+        for searches in 0..rewards.len() {
+            let fight_chance = 25 + 25 * searches;
+            let choices = vec![
+                Choice::Event(
+                    0,
+                    format!("Search: Find Loot. {}% chance of a fight.", fight_chance),
+                ),
+                Choice::Skip,
+            ];
+            let choice = self
+                .comms
+                .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+            match choice {
+                Choice::Event(0, _) => {}
+                Choice::Skip => return Ok(()),
+                _ => unreachable!(),
+            }
+            if self.misc_rng.gen_range(0..100) < fight_chance {
+                // Whatever the body still holds is added to the rewards for the fight.
+                let mut gold_reward = self.misc_rng.gen_range(25..=35);
+                let mut relics = vec![];
+                for reward in &rewards[searches..] {
+                    match reward {
+                        DeadAdventurerReward::Gold => gold_reward += 30,
+                        DeadAdventurerReward::Nothing => {}
+                        DeadAdventurerReward::Relic => {
+                            relics.push(self.relic_generator.random_relic())
+                        }
+                    }
+                }
+                return self.fight_for_rewards(pps, encounter, gold_reward, &relics);
+            }
+            match rewards[searches] {
+                DeadAdventurerReward::Gold => GoldSystem::increase_gold(self.comms, pps, 30)?,
+                DeadAdventurerReward::Nothing => {}
+                DeadAdventurerReward::Relic => {
+                    let relic = self.relic_generator.random_screenless_relic();
+                    RelicSystem::obtain_relic(self.comms, pps, relic)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn designer_in_spire(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        // Which version of the first two services is on offer is decided per visit.
+        let upgrade_one = self.misc_rng.next_bool();
+        let clean_up_is_remove = self.misc_rng.next_bool();
+        let mut choices = vec![];
+        if pps.gold >= 40 && Self::has_upgradable_card(pps) {
+            choices.push(if upgrade_one {
+                Choice::Event(0, "Adjustments: Lose 40 Gold. Upgrade a card.".into())
+            } else {
+                Choice::Event(
+                    1,
+                    "Adjustments: Lose 40 Gold. Upgrade 2 random cards.".into(),
+                )
+            });
+        }
+        if pps.gold >= 60 {
+            choices.push(if clean_up_is_remove {
+                Choice::Event(2, "Clean Up: Lose 60 Gold. Remove a card.".into())
+            } else {
+                Choice::Event(
+                    3,
+                    "Clean Up: Lose 60 Gold. Transform 2 random cards.".into(),
+                )
+            });
+        }
+        if pps.gold >= 90 {
+            choices.push(Choice::Event(
+                4,
+                "Full Service: Lose 90 Gold. Remove a card, then upgrade a random card.".into(),
+            ));
+        }
+        choices.push(Choice::Event(5, "Punch: Lose 3 HP.".into()));
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                GoldSystem::decrease_gold(self.comms, pps, 40)?;
+                DeckSystem::choose_card_to_upgrade(self.comms, pps)
+            }
+            Choice::Event(1, _) => {
+                GoldSystem::decrease_gold(self.comms, pps, 40)?;
+                DeckSystem::upgrade_random_cards(self.comms, pps, 2, self.misc_rng)
+            }
+            Choice::Event(2, _) => {
+                GoldSystem::decrease_gold(self.comms, pps, 60)?;
+                DeckSystem::choose_card_to_remove(self.comms, pps)
+            }
+            Choice::Event(3, _) => {
+                GoldSystem::decrease_gold(self.comms, pps, 60)?;
+                DeckSystem::transform_random_cards(self.comms, pps, 2, self.misc_rng)
+            }
+            Choice::Event(4, _) => {
+                GoldSystem::decrease_gold(self.comms, pps, 90)?;
+                DeckSystem::choose_card_to_remove(self.comms, pps)?;
+                DeckSystem::upgrade_random_cards(self.comms, pps, 1, self.misc_rng)
+            }
+            Choice::Event(5, _) => HealthSystem::decrease_hp(self.comms, pps, 3),
+            _ => unreachable!(),
+        }
+    }
+
+    fn duplicator(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Pray: Duplicate a card in your deck.".into()),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => DeckSystem::choose_card_to_duplicate(self.comms, pps),
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn face_trader(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let damage = ((pps.hp_max as f32 * 0.1) as Hp).max(1);
+        let choices = vec![
+            Choice::Event(0, format!("Touch: Gain 75 Gold. Take {} damage.", damage)),
+            Choice::Event(1, "Trade: Obtain a random face relic.".into()),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                GoldSystem::increase_gold(self.comms, pps, 75)?;
+                HealthSystem::decrease_hp(self.comms, pps, damage)
+            }
+            Choice::Event(1, _) => {
+                let mut faces = [
+                    Relic::CultistHeadpiece,
+                    Relic::FaceOfCleric,
+                    Relic::GremlinVisage,
+                    Relic::NlothsHungryFace,
+                    Relic::SsserpentHead,
+                ]
+                .into_iter()
+                .filter(|face| !pps.relics.contains(face))
+                .collect::<Vec<_>>();
+                if faces.is_empty() {
+                    faces.push(Relic::Circlet);
+                }
+                self.misc_rng.java_compat_shuffle(&mut faces);
+                RelicSystem::obtain_relic(self.comms, pps, faces[0])
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn falling(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        // The card of each type at risk is picked before the player chooses where to land.
+        let [attack, skill, power] =
+            [CardType::Attack, CardType::Skill, CardType::Power].map(|type_| {
+                let deck_indices = pps
+                    .deck
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, deck_card)| CardDetails::for_card(deck_card.card).type_ == type_)
+                    .map(|(deck_index, _)| deck_index)
+                    .collect::<Vec<_>>();
+                (!deck_indices.is_empty())
+                    .then(|| deck_indices[self.misc_rng.gen_range(0..deck_indices.len())])
+            });
+        let mut choices = vec![];
+        for (i, action, maybe_deck_index) in [
+            (0, "Land", skill),
+            (1, "Channel", power),
+            (2, "Strike", attack),
+        ] {
+            if let Some(deck_index) = maybe_deck_index {
+                choices.push(Choice::Event(
+                    i,
+                    format!("{}: Lose {:?}.", action, pps.deck[deck_index].card),
+                ));
+            }
+        }
+        if choices.is_empty() {
+            choices.push(Choice::Event(3, "Land: Nothing happens.".into()));
+        }
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        let deck_index = match choice {
+            Choice::Event(0, _) => skill,
+            Choice::Event(1, _) => power,
+            Choice::Event(2, _) => attack,
+            Choice::Event(3, _) => None,
+            _ => unreachable!(),
+        };
+        if let Some(deck_index) = deck_index {
+            DeckSystem::remove_card(self.comms, pps, deck_index)?;
+            DeckSystem::notify_player(self.comms, pps)?;
+        }
+        Ok(())
+    }
+
+    fn forgotten_altar(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let hp_loss = (pps.hp_max as f32 * 0.25).round() as Hp;
+        let mut choices = vec![];
+        if pps.relics.contains(&Relic::GoldenIdol) {
+            choices.push(Choice::Event(
+                0,
+                "Offer: Trade the Golden Idol for the Bloody Idol.".into(),
+            ));
+        }
+        choices.push(Choice::Event(
+            1,
+            format!("Sacrifice: Gain 5 Max HP. Lose {} HP.", hp_loss),
+        ));
+        choices.push(Choice::Event(2, "Desecrate: Become Cursed - Decay.".into()));
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                RelicSystem::replace_relic(self.comms, pps, Relic::GoldenIdol, Relic::BloodyIdol)
+            }
+            Choice::Event(1, _) => {
+                HealthSystem::increase_hp_max(self.comms, pps, 5)?;
+                HealthSystem::decrease_hp(self.comms, pps, hp_loss)
+            }
+            Choice::Event(2, _) => DeckSystem::obtain_card(self.comms, pps, Card::Decay),
+            _ => unreachable!(),
+        }
+    }
+
+    fn golden_idol(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Take: Obtain Golden Idol. Trigger a trap.".into()),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => RelicSystem::obtain_relic(self.comms, pps, Relic::GoldenIdol)?,
+            Choice::Skip => return Ok(()),
+            _ => unreachable!(),
+        }
+        let damage = (pps.hp_max as f32 * 0.25) as Hp;
+        let hp_max_loss = ((pps.hp_max as f32 * 0.08) as HpMax).max(1);
+        let choices = vec![
+            Choice::Event(0, "Outrun: Become Cursed - Injury.".into()),
+            Choice::Event(1, format!("Smash: Take {} damage.", damage)),
+            Choice::Event(2, format!("Hide: Lose {} Max HP.", hp_max_loss)),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => DeckSystem::obtain_card(self.comms, pps, Card::Injury),
+            Choice::Event(1, _) => HealthSystem::decrease_hp(self.comms, pps, damage),
+            Choice::Event(2, _) => HealthSystem::decrease_hp_max(self.comms, pps, hp_max_loss),
+            _ => unreachable!(),
+        }
+    }
+
+    fn golden_shrine(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Pray: Gain 100 Gold.".into()),
+            Choice::Event(
+                1,
+                "Desecrate: Gain 275 Gold. Become Cursed - Regret.".into(),
+            ),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => GoldSystem::increase_gold(self.comms, pps, 100),
+            Choice::Event(1, _) => {
+                GoldSystem::increase_gold(self.comms, pps, 275)?;
+                DeckSystem::obtain_card(self.comms, pps, Card::Regret)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn hypnotizing_colored_mushrooms(
+        mut self,
+        pps: &mut PlayerPersistentState,
+    ) -> Result<(), Error> {
+        let heal_amt = (pps.hp_max as f32 * 0.25) as Hp;
+        let choices = vec![
+            Choice::Event(0, "Stomp: Anger the Mushrooms.".into()),
+            Choice::Event(
+                1,
+                format!("Eat: Heal {} HP. Become Cursed - Parasite.", heal_amt),
+            ),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                let gold_reward = self.misc_rng.gen_range(20..=30);
+                self.fight_for_rewards(
+                    pps,
+                    Encounter::TheMushroomLair,
+                    gold_reward,
+                    &[Relic::OddMushroom],
+                )
+            }
+            Choice::Event(1, _) => {
+                HealthSystem::heal(self.comms, pps, heal_amt)?;
+                DeckSystem::obtain_card(self.comms, pps, Card::Parasite)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn knowing_skull(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut shuffle_rng = StsRandom::from(self.seed_for_floor);
+        // Each request costs 1 HP more than the last time it was made.
+        let mut hp_costs: [Hp; 3] = [6, 6, 6];
+        loop {
+            let choices = vec![
+                Choice::Event(
+                    0,
+                    format!("Riches?: Obtain 90 Gold. Lose {} HP.", hp_costs[0]),
+                ),
+                Choice::Event(
+                    1,
+                    format!(
+                        "Success?: Obtain an Uncommon colorless card. Lose {} HP.",
+                        hp_costs[1]
+                    ),
+                ),
+                Choice::Event(
+                    2,
+                    format!("A Pick Me Up?: Obtain a Potion. Lose {} HP.", hp_costs[2]),
+                ),
+                Choice::Event(3, "How do I leave?: Lose 6 HP.".into()),
+            ];
+            let choice = self
+                .comms
+                .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+            let request = match choice {
+                Choice::Event(3, _) => return HealthSystem::decrease_hp(self.comms, pps, 6),
+                Choice::Event(request, _) => *request,
+                _ => unreachable!(),
+            };
+            HealthSystem::decrease_hp(self.comms, pps, hp_costs[request])?;
+            hp_costs[request] += 1;
+            if pps.hp == 0 {
+                return Ok(());
+            }
+            match request {
+                0 => GoldSystem::increase_gold(self.comms, pps, 90)?,
+                1 => {
+                    let card = Self::uncommon_colorless_card(&mut shuffle_rng);
+                    DeckSystem::obtain_card(self.comms, pps, card)?;
+                }
+                2 => {
+                    let potions = self.potion_generator.gen_potions(1);
+                    PotionSystem::choose_potions_to_obtain(self.comms, pps, &potions, 1)?;
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    fn lab(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let potions = self.potion_generator.gen_potions(3);
+        PotionSystem::choose_potions_to_obtain(self.comms, pps, &potions, 3)
+    }
+
+    fn living_wall(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut choices = vec![
+            Choice::Event(0, "Forget: Remove a card from your deck.".into()),
+            Choice::Event(1, "Change: Transform a card in your deck.".into()),
+        ];
+        if Self::has_upgradable_card(pps) {
+            choices.push(Choice::Event(
+                2,
+                "Grow: Upgrade a card in your deck.".into(),
+            ));
+        }
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => DeckSystem::choose_card_to_remove(self.comms, pps),
+            Choice::Event(1, _) => {
+                DeckSystem::choose_cards_to_transform(self.comms, pps, 1, self.misc_rng)
+            }
+            Choice::Event(2, _) => DeckSystem::choose_card_to_upgrade(self.comms, pps),
+            _ => unreachable!(),
+        }
+    }
+
+    fn masked_bandits(mut self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Pay: Lose all of your Gold.".into()),
+            Choice::Event(1, "Fight!".into()),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => GoldSystem::decrease_gold(self.comms, pps, pps.gold),
+            Choice::Event(1, _) => {
+                let gold_reward = self.misc_rng.gen_range(25..=35);
+                self.fight_for_rewards(
+                    pps,
+                    Encounter::MaskedBandits,
+                    gold_reward,
+                    &[Relic::RedMask],
+                )
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn match_and_keep(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let cards = [
+            self.card_generator.class_card_of_rarity(CardRarity::Rare),
+            self.card_generator
+                .class_card_of_rarity(CardRarity::Uncommon),
+            self.card_generator.class_card_of_rarity(CardRarity::Common),
+            Self::uncommon_colorless_card(&mut StsRandom::from(self.seed_for_floor)),
+            self.card_generator.one_curse(),
+            pps.character.start_card_for_event,
+        ];
+        let mut card_indices = [0, 1, 2, 3, 4, 5, 0, 1, 2, 3, 4, 5];
+        self.misc_rng.java_compat_shuffle(&mut card_indices);
+        // The shuffled pairs are dealt face down onto a grid of 3 rows of 4, along diagonals.
+        let mut grid: [Option<Card>; 12] = [None; 12];
+        for (i, card_index) in card_indices.into_iter().enumerate() {
+            grid[(i % 3) * 4 + i % 4] = Some(cards[card_index]);
+        }
+        for _ in 0..5 {
+            let mut revealed: Vec<GridIndex> = Vec::with_capacity(2);
+            while revealed.len() < 2 {
+                let choices = grid
+                    .iter()
+                    .enumerate()
+                    .filter(|(grid_index, maybe_card)| {
+                        maybe_card.is_some() && !revealed.contains(grid_index)
+                    })
+                    .map(|(grid_index, _)| Choice::RevealCard(grid_index))
+                    .collect::<Vec<_>>();
+                let choice = self.comms.prompt_for_choice(Prompt::RevealCard, &choices)?;
+                match choice {
+                    Choice::RevealCard(grid_index) => {
+                        let card = grid[*grid_index].expect("Revealed an empty grid slot");
+                        self.comms
+                            .send_notification(Notification::CardRevealed(*grid_index, card))?;
+                        revealed.push(*grid_index);
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            // A matching pair is kept; otherwise both cards are turned face down again.
+            if grid[revealed[0]] == grid[revealed[1]] {
+                grid[revealed[1]] = None;
+                if let Some(card) = grid[revealed[0]].take() {
+                    DeckSystem::obtain_card(self.comms, pps, card)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn mind_bloom(mut self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut choices = vec![
+            Choice::Event(
+                0,
+                "I am War: Fight a Boss from Act 1. Obtain a Rare Relic.".into(),
+            ),
+            Choice::Event(
+                1,
+                "I am Awake: Upgrade all cards. You can no longer heal.".into(),
+            ),
+        ];
+        if self.floor <= 40 {
+            choices.push(Choice::Event(
+                2,
+                "I am Rich: Gain 999 Gold. Become Cursed - 2 Normality.".into(),
+            ));
+        } else {
+            choices.push(Choice::Event(
+                3,
+                "I am Healthy: Heal to full HP. Become Cursed - Doubt.".into(),
+            ));
+        }
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                let mut bosses = [
+                    Encounter::TheGuardian,
+                    Encounter::Hexaghost,
+                    Encounter::SlimeBoss,
+                ];
+                self.misc_rng.java_compat_shuffle(&mut bosses);
+                let relic = self.relic_generator.rare_relic();
+                self.fight_for_rewards(pps, bosses[0], 50, &[relic])
+            }
+            Choice::Event(1, _) => {
+                DeckSystem::upgrade_all_cards(self.comms, pps)?;
+                RelicSystem::obtain_relic(self.comms, pps, Relic::MarkOfTheBloom)
+            }
+            Choice::Event(2, _) => {
+                GoldSystem::increase_gold(self.comms, pps, 999)?;
+                DeckSystem::obtain_card(self.comms, pps, Card::Normality)?;
+                DeckSystem::obtain_card(self.comms, pps, Card::Normality)
+            }
+            Choice::Event(3, _) => {
+                HealthSystem::heal(self.comms, pps, pps.hp_max)?;
+                DeckSystem::obtain_card(self.comms, pps, Card::Doubt)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn mysterious_sphere(mut self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Open Sphere: Fight. Reward: Rare Relic.".into()),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                let gold_reward = self.misc_rng.gen_range(45..=55);
+                let relic = self.relic_generator.rare_relic();
+                self.fight_for_rewards(pps, Encounter::TwoOrbWalkers, gold_reward, &[relic])
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn nloth(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut relics = pps.relics.clone();
+        self.misc_rng.java_compat_shuffle(&mut relics);
+        let mut choices = relics
+            .iter()
+            .take(2)
+            .enumerate()
+            .map(|(i, relic)| Choice::Event(i, format!("Offer {:?}: Obtain N'loth's Gift.", relic)))
+            .collect::<Vec<_>>();
+        choices.push(Choice::Skip);
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(i, _) => {
+                RelicSystem::lose_relic(self.comms, pps, relics[*i])?;
+                RelicSystem::obtain_relic(self.comms, pps, Relic::NlothsGift)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn note_for_yourself(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        // The note would hold a card left behind by a previous run, but runs don't share state
+        // here, so it always holds the game's default Iron Wave.
+        let choices = vec![
+            Choice::Event(
+                0,
+                "Take and Give: Obtain Iron Wave. Remove a card from your deck.".into(),
+            ),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                DeckSystem::obtain_card(self.comms, pps, Card::IronWave(false))?;
+                DeckSystem::choose_card_to_remove(self.comms, pps)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn old_beggar(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut choices = vec![];
+        if pps.gold >= 75 {
+            choices.push(Choice::Event(
+                0,
+                "Offer Gold: Lose 75 Gold. Remove a card from your deck.".into(),
+            ));
+        }
+        choices.push(Choice::Skip);
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                GoldSystem::decrease_gold(self.comms, pps, 75)?;
+                DeckSystem::choose_card_to_remove(self.comms, pps)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn ominous_forge(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut choices = vec![];
+        if Self::has_upgradable_card(pps) {
+            choices.push(Choice::Event(0, "Forge: Upgrade a card.".into()));
+        }
+        choices.push(Choice::Event(
+            1,
+            "Rummage: Obtain Warped Tongs. Become Cursed - Pain.".into(),
+        ));
+        choices.push(Choice::Skip);
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => DeckSystem::choose_card_to_upgrade(self.comms, pps),
+            Choice::Event(1, _) => {
+                DeckSystem::obtain_card(self.comms, pps, Card::Pain)?;
+                RelicSystem::obtain_relic(self.comms, pps, Relic::WarpedTongs)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn pleading_vagrant(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut choices = vec![];
+        if pps.gold >= 85 {
+            choices.push(Choice::Event(
+                0,
+                "Offer Gold: Lose 85 Gold. Obtain a Relic.".into(),
+            ));
+        }
+        choices.push(Choice::Event(
+            1,
+            "Rob: Obtain a Relic. Become Cursed - Shame.".into(),
+        ));
+        choices.push(Choice::Skip);
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                GoldSystem::decrease_gold(self.comms, pps, 85)?;
+                let relic = self.relic_generator.random_screenless_relic();
+                RelicSystem::obtain_relic(self.comms, pps, relic)
+            }
+            Choice::Event(1, _) => {
+                let relic = self.relic_generator.random_screenless_relic();
+                RelicSystem::obtain_relic(self.comms, pps, relic)?;
+                DeckSystem::obtain_card(self.comms, pps, Card::Shame)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn purifier(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Pray: Remove a card from your deck.".into()),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => DeckSystem::choose_card_to_remove(self.comms, pps),
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn scrap_ooze(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut damage = 3;
+        let mut relic_chance = 25;
+        loop {
+            let choices = vec![
+                Choice::Event(
+                    0,
+                    format!(
+                        "Reach Inside: Lose {} HP. {}% chance to find a Relic.",
+                        damage, relic_chance
+                    ),
+                ),
+                Choice::Skip,
+            ];
+            let choice = self
+                .comms
+                .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+            match choice {
+                Choice::Event(0, _) => HealthSystem::decrease_hp(self.comms, pps, damage)?,
+                Choice::Skip => return Ok(()),
+                _ => unreachable!(),
+            }
+            if pps.hp == 0 {
+                return Ok(());
+            }
+            if self.misc_rng.gen_range(0..100) >= 99 - relic_chance {
+                let relic = self.relic_generator.random_screenless_relic();
+                return RelicSystem::obtain_relic(self.comms, pps, relic);
+            }
+            damage += 1;
+            relic_chance += 10;
+        }
+    }
+
+    fn secret_portal(self, _pps: &mut PlayerPersistentState) -> Result<EventOutcome, Error> {
+        let choices = vec![
+            Choice::Event(
+                0,
+                "Enter the Portal: Immediately travel to the Boss.".into(),
+            ),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => Ok(EventOutcome::SecretPortal),
+            Choice::Skip => Ok(EventOutcome::Continue),
+            _ => unreachable!(),
+        }
+    }
+
+    fn sensory_stone(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Recall: Add 1 colorless card to your deck.".into()),
+            Choice::Event(
+                1,
+                "Recall: Add 2 colorless cards to your deck. Lose 5 HP.".into(),
+            ),
+            Choice::Event(
+                2,
+                "Recall: Add 3 colorless cards to your deck. Lose 10 HP.".into(),
+            ),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        let reward_count = match choice {
+            Choice::Event(0, _) => 1,
+            Choice::Event(1, _) => {
+                HealthSystem::decrease_hp(self.comms, pps, 5)?;
+                2
+            }
+            Choice::Event(2, _) => {
+                HealthSystem::decrease_hp(self.comms, pps, 10)?;
+                3
+            }
+            _ => unreachable!(),
+        };
+        if pps.hp == 0 {
+            return Ok(());
         }
+        for _ in 0..reward_count {
+            let cards = self.card_generator.colorless_card_rewards();
+            DeckSystem::choose_card_to_obtain(self.comms, pps, &cards)?;
+        }
+        Ok(())
     }
 
-    pub fn run_event(self, event: Event, pps: &mut PlayerPersistentState) -> Result<(), Error> {
-        match event {
-            Event::AncientWriting => todo!("{:?}", event),
-            Event::ANoteForYourself => todo!("{:?}", event),
-            Event::Augmenter => todo!("{:?}", event),
-            Event::BigFish => todo!("{:?}", event),
-            Event::BonfireSpirits => todo!("{:?}", event),
-            Event::CouncilOfGhosts => todo!("{:?}", event),
-            Event::CursedTome => todo!("{:?}", event),
-            Event::DeadAdventurer => todo!("{:?}", event),
-            Event::DesignerInSpire => todo!("{:?}", event),
-            Event::Duplicator => todo!("{:?}", event),
-            Event::FaceTrader => todo!("{:?}", event),
-            Event::Falling => todo!("{:?}", event),
-            Event::ForgottenAltar => todo!("{:?}", event),
-            Event::GoldenIdol => todo!("{:?}", event),
-            Event::GoldenShrine => todo!("{:?}", event),
-            Event::HypnotizingColoredMushrooms => todo!("{:?}", event),
-            Event::KnowingSkull => todo!("{:?}", event),
-            Event::Lab => todo!("{:?}", event),
-            Event::LivingWall => todo!("{:?}", event),
-            Event::MaskedBandits => todo!("{:?}", event),
-            Event::MatchAndKeep => todo!("{:?}", event),
-            Event::MindBloom => todo!("{:?}", event),
-            Event::MysteriousSphere => todo!("{:?}", event),
-            Event::Neow => todo!("{:?}", event),
-            Event::Nloth => todo!("{:?}", event),
-            Event::NoteForYourself => todo!("{:?}", event),
-            Event::OldBeggar => todo!("{:?}", event),
-            Event::OminousForge => todo!("{:?}", event),
-            Event::PleadingVagrant => todo!("{:?}", event),
-            Event::Purifier => todo!("{:?}", event),
-            Event::ScrapOoze => todo!("{:?}", event),
-            Event::SecretPortal => todo!("{:?}", event),
-            Event::SensoryStone => todo!("{:?}", event),
-            Event::ShiningLight => todo!("{:?}", event),
-            Event::TheCleric => self.the_cleric(pps),
-            Event::TheColosseum => todo!("{:?}", event),
-            Event::TheDivineFountain => todo!("{:?}", event),
-            Event::TheJoust => todo!("{:?}", event),
-            Event::TheLibrary => todo!("{:?}", event),
-            Event::TheMausoleum => todo!("{:?}", event),
-            Event::TheMoaiHead => todo!("{:?}", event),
-            Event::TheNest => todo!("{:?}", event),
-            Event::TheSsssserpent => todo!("{:?}", event),
-            Event::TheWomanInBlue => self.the_woman_in_blue(pps),
-            Event::TombOfLordRedMask => todo!("{:?}", event),
-            Event::Transmogrifier => todo!("{:?}", event),
-            Event::UpgradeShrine => todo!("{:?}", event),
-            Event::Vampires => todo!("{:?}", event),
-            Event::WeMeetAgain => todo!("{:?}", event),
-            Event::WheelOfChange => todo!("{:?}", event),
-            Event::WindingHalls => todo!("{:?}", event),
-            Event::WingStatue => todo!("{:?}", event),
-            Event::WorldOfGoop => todo!("{:?}", event),
+    fn shining_light(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let damage = (pps.hp_max as f32 * 0.2).round() as Hp;
+        let mut choices = vec![];
+        if Self::has_upgradable_card(pps) {
+            choices.push(Choice::Event(
+                0,
+                format!("Enter: Upgrade 2 random cards. Take {} damage.", damage),
+            ));
+        }
+        choices.push(Choice::Skip);
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                HealthSystem::decrease_hp(self.comms, pps, damage)?;
+                DeckSystem::upgrade_random_cards(self.comms, pps, 2, self.misc_rng)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
         }
     }
 
@@ -103,6 +1143,193 @@ impl<'a, I: Interaction> EventSimulator<'a, I> {
         }
     }
 
+    fn the_colosseum(mut self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![Choice::Event(0, "Fight!".into())];
+        self.comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        // The first fight is only for the crowd's amusement, so it offers no rewards.
+        if self.fight(pps, Encounter::ColosseumSlavers)? != CombatOutcome::Victory {
+            return Ok(());
+        }
+        let choices = vec![
+            Choice::Event(0, "Cowardice: Escape.".into()),
+            Choice::Event(
+                1,
+                "Victory: A Rare Relic, an Uncommon Relic and 100 Gold await.".into(),
+            ),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => Ok(()),
+            Choice::Event(1, _) => {
+                let relics = [
+                    self.relic_generator.rare_relic(),
+                    self.relic_generator.uncommon_relic(),
+                ];
+                self.fight_for_rewards(pps, Encounter::ColosseumNobs, 100, &relics)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn the_divine_fountain(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Drink: Remove all Curses from your deck.".into()),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                for deck_index in (0..pps.deck.len()).rev() {
+                    let details = CardDetails::for_card(pps.deck[deck_index].card);
+                    if details.type_ == CardType::Curse && !details.irremovable {
+                        DeckSystem::remove_card(self.comms, pps, deck_index)?;
+                    }
+                }
+                DeckSystem::notify_player(self.comms, pps)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn the_joust(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(
+                0,
+                "Murderer: Bet 50 Gold - 70% chance to win 100 Gold.".into(),
+            ),
+            Choice::Event(1, "Owner: Bet 50 Gold - 30% chance to win 250 Gold.".into()),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        GoldSystem::decrease_gold(self.comms, pps, 50)?;
+        let owner_wins = *self.misc_rng.weighted_choose(&[(true, 0.3), (false, 0.7)]);
+        match (choice, owner_wins) {
+            (Choice::Event(0, _), false) => GoldSystem::increase_gold(self.comms, pps, 100),
+            (Choice::Event(1, _), true) => GoldSystem::increase_gold(self.comms, pps, 250),
+            (Choice::Event(0..=1, _), _) => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn the_library(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let heal_amt = (pps.hp_max as f32 * 0.33).round() as Hp;
+        let choices = vec![
+            Choice::Event(0, "Read: Choose 1 of 20 cards to add to your deck.".into()),
+            Choice::Event(1, format!("Sleep: Heal {} HP.", heal_amt)),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                let cards = self.card_generator.distinct_class_cards(20);
+                DeckSystem::choose_card_to_obtain(self.comms, pps, &cards)
+            }
+            Choice::Event(1, _) => HealthSystem::heal(self.comms, pps, heal_amt),
+            _ => unreachable!(),
+        }
+    }
+
+    fn the_mausoleum(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(
+                0,
+                "Open Coffin: Obtain a Relic. 50% chance to become Cursed - Writhe.".into(),
+            ),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                if self.misc_rng.next_bool() {
+                    DeckSystem::obtain_card(self.comms, pps, Card::Writhe)?;
+                }
+                let relic = self.relic_generator.random_screenless_relic();
+                RelicSystem::obtain_relic(self.comms, pps, relic)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn the_moai_head(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let hp_max_loss = ((pps.hp_max as f32 * 0.125).round() as HpMax).min(pps.hp_max - 1);
+        let mut choices = vec![Choice::Event(
+            0,
+            format!("Jump Inside: Heal to full HP. Lose {} Max HP.", hp_max_loss),
+        )];
+        if pps.relics.contains(&Relic::GoldenIdol) {
+            choices.push(Choice::Event(
+                1,
+                "Offer: Trade the Golden Idol for 333 Gold.".into(),
+            ));
+        }
+        choices.push(Choice::Skip);
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                HealthSystem::decrease_hp_max(self.comms, pps, hp_max_loss)?;
+                HealthSystem::heal(self.comms, pps, pps.hp_max)
+            }
+            Choice::Event(1, _) => {
+                GoldSystem::increase_gold(self.comms, pps, 333)?;
+                RelicSystem::lose_relic(self.comms, pps, Relic::GoldenIdol)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn the_nest(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Smash and Grab: Obtain 99 Gold.".into()),
+            Choice::Event(1, "Stay in Line: Obtain Ritual Dagger. Lose 6 HP.".into()),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => GoldSystem::increase_gold(self.comms, pps, 99),
+            Choice::Event(1, _) => {
+                HealthSystem::decrease_hp(self.comms, pps, 6)?;
+                if pps.hp == 0 {
+                    return Ok(());
+                }
+                DeckSystem::obtain_card(self.comms, pps, Card::RitualDagger(false))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn the_ssssserpent(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Agree: Receive 175 Gold. Become Cursed - Doubt.".into()),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                GoldSystem::increase_gold(self.comms, pps, 175)?;
+                DeckSystem::obtain_card(self.comms, pps, Card::Doubt)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
     fn the_woman_in_blue(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
         let mut choices = vec![Choice::Event(20, "Buy 1 Potion for 20 Gold".into())];
         if pps.gold >= 30 {
@@ -127,4 +1354,590 @@ impl<'a, I: Interaction> EventSimulator<'a, I> {
         let potions = self.potion_generator.gen_potions(count);
         PotionSystem::choose_potions_to_obtain(self.comms, pps, &potions, count)
     }
+
+    fn tomb_of_lord_red_mask(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            if pps.relics.contains(&Relic::RedMask) {
+                Choice::Event(0, "Don the Red Mask: Gain 222 Gold.".into())
+            } else {
+                Choice::Event(1, format!("Offer: {} Gold: Obtain the Red Mask.", pps.gold))
+            },
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => GoldSystem::increase_gold(self.comms, pps, 222),
+            Choice::Event(1, _) => {
+                GoldSystem::decrease_gold(self.comms, pps, pps.gold)?;
+                RelicSystem::obtain_relic(self.comms, pps, Relic::RedMask)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn transmogrifier(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![
+            Choice::Event(0, "Pray: Transform a card.".into()),
+            Choice::Skip,
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                DeckSystem::choose_cards_to_transform(self.comms, pps, 1, self.misc_rng)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn upgrade_shrine(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut choices = vec![];
+        if Self::has_upgradable_card(pps) {
+            choices.push(Choice::Event(0, "Pray: Upgrade a card.".into()));
+        }
+        choices.push(Choice::Skip);
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => DeckSystem::choose_card_to_upgrade(self.comms, pps),
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn vampires(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let hp_max_loss = ((pps.hp_max as f32 * 0.3).ceil() as HpMax).min(pps.hp_max - 1);
+        let mut choices = vec![];
+        if pps.relics.contains(&Relic::BloodVial) {
+            choices.push(Choice::Event(
+                0,
+                "Offer: Trade the Blood Vial. Remove all Strikes. Receive 5 Bites.".into(),
+            ));
+        }
+        choices.push(Choice::Event(
+            1,
+            format!(
+                "Accept: Remove all Strikes. Receive 5 Bites. Lose {} Max HP.",
+                hp_max_loss
+            ),
+        ));
+        choices.push(Choice::Skip);
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => RelicSystem::lose_relic(self.comms, pps, Relic::BloodVial)?,
+            Choice::Event(1, _) => HealthSystem::decrease_hp_max(self.comms, pps, hp_max_loss)?,
+            Choice::Skip => return Ok(()),
+            _ => unreachable!(),
+        }
+        for deck_index in (0..pps.deck.len()).rev() {
+            if matches!(pps.deck[deck_index].card, Card::Strike(_)) {
+                DeckSystem::remove_card(self.comms, pps, deck_index)?;
+            }
+        }
+        for _ in 0..5 {
+            DeckSystem::obtain_card(self.comms, pps, Card::Bite(false))?;
+        }
+        Ok(())
+    }
+
+    fn we_meet_again(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        // Ranwid asks for a random potion, some of the player's gold, and a random card that
+        // is neither a starter nor a curse.
+        let mut potion_indices = pps
+            .potions
+            .iter()
+            .enumerate()
+            .filter(|(_, maybe_potion)| maybe_potion.is_some())
+            .map(|(potion_index, _)| potion_index)
+            .collect::<Vec<_>>();
+        let maybe_potion_index = if potion_indices.is_empty() {
+            None
+        } else {
+            self.misc_rng.java_compat_shuffle(&mut potion_indices);
+            Some(potion_indices[0])
+        };
+        let maybe_gold = if pps.gold >= 50 {
+            Some(self.misc_rng.gen_range(50..=pps.gold.min(150)))
+        } else {
+            None
+        };
+        let mut deck_indices = pps
+            .deck
+            .iter()
+            .enumerate()
+            .filter(|(_, deck_card)| {
+                let details = CardDetails::for_card(deck_card.card);
+                details.rarity != CardRarity::Starter && details.type_ != CardType::Curse
+            })
+            .map(|(deck_index, _)| deck_index)
+            .collect::<Vec<_>>();
+        let maybe_deck_index = if deck_indices.is_empty() {
+            None
+        } else {
+            self.misc_rng.java_compat_shuffle(&mut deck_indices);
+            Some(deck_indices[0])
+        };
+        let mut choices = vec![];
+        if let Some(potion_index) = maybe_potion_index {
+            choices.push(Choice::Event(
+                0,
+                format!(
+                    "Give Potion: Lose {:?}. Obtain a Relic.",
+                    pps.potions[potion_index].expect("Potion slot is filled")
+                ),
+            ));
+        }
+        if let Some(gold) = maybe_gold {
+            choices.push(Choice::Event(
+                1,
+                format!("Give Gold: Lose {} Gold. Obtain a Relic.", gold),
+            ));
+        }
+        if let Some(deck_index) = maybe_deck_index {
+            choices.push(Choice::Event(
+                2,
+                format!(
+                    "Give Card: Lose {:?}. Obtain a Relic.",
+                    pps.deck[deck_index].card
+                ),
+            ));
+        }
+        choices.push(Choice::Event(3, "Attack: Nothing happens.".into()));
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match (choice, maybe_potion_index, maybe_gold, maybe_deck_index) {
+            (Choice::Event(0, _), Some(potion_index), _, _) => {
+                pps.potions[potion_index] = None;
+                PotionSystem::notify_player(self.comms, pps)?;
+            }
+            (Choice::Event(1, _), _, Some(gold), _) => {
+                GoldSystem::decrease_gold(self.comms, pps, gold)?;
+            }
+            (Choice::Event(2, _), _, _, Some(deck_index)) => {
+                DeckSystem::remove_card(self.comms, pps, deck_index)?;
+                DeckSystem::notify_player(self.comms, pps)?;
+            }
+            (Choice::Event(3, _), _, _, _) => return Ok(()),
+            _ => unreachable!(),
+        }
+        let relic = self.relic_generator.random_screenless_relic();
+        RelicSystem::obtain_relic(self.comms, pps, relic)
+    }
+
+    fn wheel_of_change(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let choices = vec![Choice::Event(0, "Play: Spin the Wheel.".into())];
+        self.comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match self.misc_rng.gen_range(0..=5) {
+            0 => {
+                // Each act spans 17 floors, boss included.
+                let act = (self.floor as Gold - 1) / 17 + 1;
+                GoldSystem::increase_gold(self.comms, pps, act * 100)
+            }
+            1 => {
+                let relic = self.relic_generator.random_screenless_relic();
+                RelicSystem::obtain_relic(self.comms, pps, relic)
+            }
+            2 => HealthSystem::heal(self.comms, pps, pps.hp_max),
+            3 => DeckSystem::obtain_card(self.comms, pps, Card::Decay),
+            4 => DeckSystem::choose_card_to_remove(self.comms, pps),
+            _ => {
+                let hp_loss = (pps.hp_max as f32 * 0.1) as Hp;
+                HealthSystem::decrease_hp(self.comms, pps, hp_loss)
+            }
+        }
+    }
+
+    fn winding_halls(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let hp_loss = (pps.hp_max as f32 * 0.125).round() as Hp;
+        let heal_amt = (pps.hp_max as f32 * 0.25).round() as Hp;
+        let hp_max_loss = (pps.hp_max as f32 * 0.05).round() as HpMax;
+        let choices = vec![
+            Choice::Event(
+                0,
+                format!("Embrace Madness: Receive 2 Madness. Lose {} HP.", hp_loss),
+            ),
+            Choice::Event(
+                1,
+                format!("Focus: Heal {} HP. Become Cursed - Writhe.", heal_amt),
+            ),
+            Choice::Event(
+                2,
+                format!("Retrace Your Steps: Lose {} Max HP.", hp_max_loss),
+            ),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                HealthSystem::decrease_hp(self.comms, pps, hp_loss)?;
+                if pps.hp == 0 {
+                    return Ok(());
+                }
+                DeckSystem::obtain_card(self.comms, pps, Card::Madness(false))?;
+                DeckSystem::obtain_card(self.comms, pps, Card::Madness(false))
+            }
+            Choice::Event(1, _) => {
+                HealthSystem::heal(self.comms, pps, heal_amt)?;
+                DeckSystem::obtain_card(self.comms, pps, Card::Writhe)
+            }
+            Choice::Event(2, _) => HealthSystem::decrease_hp_max(self.comms, pps, hp_max_loss),
+            _ => unreachable!(),
+        }
+    }
+
+    fn wing_statue(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let mut choices = vec![Choice::Event(
+            0,
+            "Pray: Remove a card from your deck. Lose 7 HP.".into(),
+        )];
+        if Self::has_attack_dealing_at_least(pps, 10) {
+            choices.push(Choice::Event(1, "Destroy: Gain 50-80 Gold.".into()));
+        }
+        choices.push(Choice::Skip);
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                HealthSystem::decrease_hp(self.comms, pps, 7)?;
+                DeckSystem::choose_card_to_remove(self.comms, pps)
+            }
+            Choice::Event(1, _) => {
+                let gold = self.misc_rng.gen_range(50..=80);
+                GoldSystem::increase_gold(self.comms, pps, gold)
+            }
+            Choice::Skip => Ok(()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn world_of_goop(self, pps: &mut PlayerPersistentState) -> Result<(), Error> {
+        let gold_loss = self.misc_rng.gen_range(20..=50).min(pps.gold);
+        let choices = vec![
+            Choice::Event(0, "Gather Gold: Gain 75 Gold. Lose 11 HP.".into()),
+            Choice::Event(1, format!("Leave It: Lose {} Gold.", gold_loss)),
+        ];
+        let choice = self
+            .comms
+            .prompt_for_choice(Prompt::ChooseForEvent, &choices)?;
+        match choice {
+            Choice::Event(0, _) => {
+                HealthSystem::decrease_hp(self.comms, pps, 11)?;
+                GoldSystem::increase_gold(self.comms, pps, 75)
+            }
+            Choice::Event(1, _) => GoldSystem::decrease_gold(self.comms, pps, gold_loss),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Runs a fight that the event leads into, without any rewards.
+    fn fight(
+        &mut self,
+        pps: &mut PlayerPersistentState,
+        encounter: Encounter,
+    ) -> Result<CombatOutcome, Error> {
+        CombatSimulator::new(self.seed_for_floor, self.misc_rng)
+            .run_encounter(self.comms, encounter, pps)
+    }
+
+    /// Runs a fight that the event leads into, handing out the rewards if the player wins.
+    fn fight_for_rewards(
+        &mut self,
+        pps: &mut PlayerPersistentState,
+        encounter: Encounter,
+        gold_reward: Gold,
+        relics: &[Relic],
+    ) -> Result<(), Error> {
+        if self.fight(pps, encounter)? != CombatOutcome::Victory {
+            return Ok(());
+        }
+        let maybe_potion = self.potion_generator.combat_reward();
+        let card_rewards = self.card_generator.combat_rewards();
+        MainScreenSystem::choose_combat_rewards(
+            self.comms,
+            pps,
+            gold_reward,
+            relics,
+            maybe_potion,
+            &card_rewards,
+        )
+    }
+
+    /// Draws an uncommon colorless card the way the game does, by shuffling the colorless pool
+    /// with the floor's shuffle rng and taking the first uncommon.
+    fn uncommon_colorless_card(shuffle_rng: &mut StsRandom) -> Card {
+        let mut pool = [UNCOMMON_COLORLESS_CARD_POOL, RARE_COLORLESS_CARD_POOL].concat();
+        shuffle_rng.java_compat_shuffle(&mut pool);
+        pool.into_iter()
+            .find(|card| CardDetails::for_card(*card).rarity == CardRarity::Uncommon)
+            .expect("No uncommon colorless cards")
+    }
+
+    fn has_upgradable_card(pps: &PlayerPersistentState) -> bool {
+        pps.deck
+            .iter()
+            .any(|deck_card| CardDetails::for_card(deck_card.card).upgrade.is_some())
+    }
+
+    /// Returns true iff the deck holds an attack whose base damage is at least `damage`.
+    fn has_attack_dealing_at_least(pps: &PlayerPersistentState, damage: Hp) -> bool {
+        pps.deck.iter().any(|deck_card| {
+            let details = CardDetails::for_card(deck_card.card);
+            details.type_ == CardType::Attack
+                && details.on_play.iter().any(|effect| match effect {
+                    PlayerEffect::ToAllEnemies(target_effect)
+                    | PlayerEffect::ToRandomEnemy(target_effect)
+                    | PlayerEffect::ToSingleTarget(target_effect) => matches!(
+                        target_effect,
+                        TargetEffect::Deal(Damage::Blockable(amount))
+                            | TargetEffect::DealOncePerEnemy(Damage::Blockable(amount))
+                            | TargetEffect::DealXTimes(Damage::Blockable(amount))
+                            if *amount >= damage
+                    ),
+                    _ => false,
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::mpsc::{channel, Receiver, Sender};
+    use std::thread::{self, JoinHandle};
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    use crate::components::{EnemyStatus, StsMessage};
+    use crate::data::{Act, Enemy, EnemyCondition, Intent, IRONCLAD};
+    use crate::systems::sim::player_interaction::PlayerInteraction;
+    use crate::systems::sim::sts_simulator::test::{index_of, next_prompt};
+
+    /// Runs the event on its own thread for a fresh Ironclad, returning the channels used to
+    /// talk to it and the handle to collect its outcome and the player's final state.
+    fn start_event(
+        seed: u64,
+        floor: Floor,
+        event: Event,
+    ) -> (
+        Sender<usize>,
+        Receiver<StsMessage>,
+        JoinHandle<Result<(EventOutcome, PlayerPersistentState), Error>>,
+    ) {
+        let (to_server, from_client) = channel();
+        let (to_client, from_server) = channel();
+        let handle = thread::spawn(move || {
+            let seed = Seed::from(seed);
+            let comms = PlayerInteraction::new(from_client, to_client);
+            let mut pps = PlayerPersistentState::new(&IRONCLAD);
+            let mut misc_rng = StsRandom::from(seed.with_offset(floor));
+            let mut card_generator = CardGenerator::new(seed, &IRONCLAD, Act::get(1));
+            let mut potion_generator = PotionGenerator::new(seed, &IRONCLAD);
+            let mut relic_generator = RelicGenerator::new(seed, &IRONCLAD);
+            let outcome = EventSimulator::new(
+                &comms,
+                floor,
+                seed.with_offset(floor),
+                &mut misc_rng,
+                &mut card_generator,
+                &mut potion_generator,
+                &mut relic_generator,
+            )
+            .run_event(event, &mut pps)?;
+            Ok((outcome, pps))
+        });
+        (to_server, from_server, handle)
+    }
+
+    #[test]
+    fn test_secret_portal() {
+        let (to_server, from_server, handle) = start_event(3, 40, Event::SecretPortal);
+        assert_eq!(
+            next_prompt(&from_server, &[]),
+            StsMessage::Choices(
+                Prompt::ChooseForEvent,
+                vec![
+                    Choice::Event(
+                        0,
+                        "Enter the Portal: Immediately travel to the Boss.".into()
+                    ),
+                    Choice::Skip,
+                ]
+            )
+        );
+        to_server.send(0).unwrap(); // Enter the Portal
+        assert_eq!(
+            handle.join().unwrap().unwrap().0,
+            EventOutcome::SecretPortal
+        );
+
+        let (to_server, from_server, handle) = start_event(3, 40, Event::SecretPortal);
+        next_prompt(&from_server, &[]);
+        to_server.send(1).unwrap(); // Skip
+        assert_eq!(handle.join().unwrap().unwrap().0, EventOutcome::Continue);
+    }
+
+    #[test]
+    fn test_mind_bloom() {
+        let (to_server, from_server, _handle) = start_event(3, 40, Event::MindBloom);
+        assert_eq!(
+            next_prompt(&from_server, &[]),
+            StsMessage::Choices(
+                Prompt::ChooseForEvent,
+                vec![
+                    Choice::Event(
+                        0,
+                        "I am War: Fight a Boss from Act 1. Obtain a Rare Relic.".into()
+                    ),
+                    Choice::Event(
+                        1,
+                        "I am Awake: Upgrade all cards. You can no longer heal.".into()
+                    ),
+                    Choice::Event(
+                        2,
+                        "I am Rich: Gain 999 Gold. Become Cursed - 2 Normality.".into()
+                    ),
+                ]
+            )
+        );
+        to_server.send(0).unwrap(); // I am War
+        assert!(matches!(
+            next_prompt(
+                &from_server,
+                &[
+                    Notification::StartingCombat,
+                    Notification::EnemyParty(vec![
+                        Some(EnemyStatus::new(
+                            Enemy::Hexaghost,
+                            (250, 250),
+                            Intent::Unknown
+                        )),
+                        None,
+                        None,
+                        None,
+                        None
+                    ])
+                ]
+            ),
+            StsMessage::Choices(Prompt::CombatAction, _)
+        ));
+
+        let (to_server, from_server, handle) = start_event(3, 40, Event::MindBloom);
+        next_prompt(&from_server, &[]);
+        to_server.send(1).unwrap(); // I am Awake
+        let (_, pps) = handle.join().unwrap().unwrap();
+        assert!(pps
+            .deck
+            .iter()
+            .all(|deck_card| CardDetails::for_card(deck_card.card).upgrade.is_none()));
+        assert!(pps.relics.contains(&Relic::MarkOfTheBloom));
+
+        let (to_server, from_server, handle) = start_event(3, 40, Event::MindBloom);
+        next_prompt(&from_server, &[]);
+        to_server.send(2).unwrap(); // I am Rich
+        let (_, pps) = handle.join().unwrap().unwrap();
+        assert_eq!(pps.gold, 99 + 999);
+        assert_eq!(
+            pps.deck_cards()[pps.deck.len() - 2..],
+            [Card::Normality, Card::Normality]
+        );
+
+        // Past floor 40, riches give way to health.
+        let (to_server, from_server, handle) = start_event(3, 41, Event::MindBloom);
+        let prompt = next_prompt(&from_server, &[]);
+        let choice = Choice::Event(
+            3,
+            "I am Healthy: Heal to full HP. Become Cursed - Doubt.".into(),
+        );
+        to_server.send(index_of(&prompt, &choice)).unwrap();
+        let (_, pps) = handle.join().unwrap().unwrap();
+        assert_eq!(pps.deck_cards().last(), Some(&Card::Doubt));
+    }
+
+    #[test]
+    fn test_mysterious_sphere() {
+        let (to_server, from_server, handle) = start_event(3, 40, Event::MysteriousSphere);
+        assert_eq!(
+            next_prompt(&from_server, &[]),
+            StsMessage::Choices(
+                Prompt::ChooseForEvent,
+                vec![
+                    Choice::Event(0, "Open Sphere: Fight. Reward: Rare Relic.".into()),
+                    Choice::Skip,
+                ]
+            )
+        );
+        to_server.send(1).unwrap(); // Skip
+        assert_eq!(handle.join().unwrap().unwrap().0, EventOutcome::Continue);
+
+        let (to_server, from_server, _handle) = start_event(3, 40, Event::MysteriousSphere);
+        next_prompt(&from_server, &[]);
+        to_server.send(0).unwrap(); // Open Sphere
+        assert!(matches!(
+            next_prompt(
+                &from_server,
+                &[
+                    Notification::StartingCombat,
+                    Notification::EnemyParty(vec![
+                        Some(
+                            EnemyStatus::new(Enemy::OrbWalker, (94, 94), Intent::Aggressive(15, 1))
+                                .with_condition(EnemyCondition::StrengthUp(3))
+                        ),
+                        Some(
+                            EnemyStatus::new(Enemy::OrbWalker, (90, 90), Intent::Aggressive(15, 1))
+                                .with_condition(EnemyCondition::StrengthUp(3))
+                        ),
+                        None,
+                        None,
+                        None
+                    ])
+                ]
+            ),
+            StsMessage::Choices(Prompt::CombatAction, _)
+        ));
+    }
+
+    #[test]
+    fn test_match_and_keep() {
+        // For this seed the grid, read row by row, is:
+        //   Feed  Pain  Flex  Bash
+        //   Blind Blind ?     ?
+        //   Pain  Bash  Reckless Charge ?
+        let (to_server, from_server, handle) = start_event(3, 10, Event::MatchAndKeep);
+        let reveal = |grid_index: GridIndex, expected: &[Notification]| {
+            let prompt = next_prompt(&from_server, expected);
+            assert!(matches!(prompt, StsMessage::Choices(Prompt::RevealCard, _)));
+            to_server
+                .send(index_of(&prompt, &Choice::RevealCard(grid_index)))
+                .unwrap();
+        };
+        reveal(0, &[]);
+        reveal(1, &[Notification::CardRevealed(0, Card::Feed(false))]);
+        // A mismatched pair is turned face down again.
+        reveal(1, &[Notification::CardRevealed(1, Card::Pain)]);
+        reveal(8, &[Notification::CardRevealed(1, Card::Pain)]);
+        reveal(3, &[Notification::CardObtained(Card::Pain)]);
+        reveal(9, &[Notification::CardRevealed(3, Card::Bash(false))]);
+        reveal(4, &[Notification::CardObtained(Card::Bash(false))]);
+        reveal(5, &[Notification::CardRevealed(4, Card::Blind(false))]);
+        reveal(2, &[Notification::CardObtained(Card::Blind(false))]);
+        reveal(10, &[Notification::CardRevealed(2, Card::Flex(false))]);
+        let (_, pps) = handle.join().unwrap().unwrap();
+        assert_eq!(
+            pps.deck_cards()[IRONCLAD.starting_deck.len()..],
+            [Card::Pain, Card::Bash(false), Card::Blind(false)]
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use anyhow::Error;
 
 use crate::components::{Choice, Interaction, PlayerPersistentState, Prompt};
-use crate::data::{Card, Potion};
+use crate::data::{Card, Potion, Relic};
 use crate::systems::base::{DeckSystem, GoldSystem, HealthSystem, PotionSystem, RelicSystem};
 use crate::types::Gold;
 
@@ -25,20 +25,25 @@ impl MainScreenSystem {
         comms: &I,
         pps: &mut PlayerPersistentState,
         available_gold: Gold,
+        available_relics: &[Relic],
         mut maybe_potion: Option<Potion>,
         available_cards: &[Card],
     ) -> Result<(), Error> {
         let mut maybe_gold: Option<Gold> = Some(available_gold);
+        let mut available_relic_vec = available_relics.to_vec();
         let mut available_card_vec = available_cards.to_vec();
         let mut cards_left_to_choose = 1;
         while maybe_gold.is_some()
+            || !available_relic_vec.is_empty()
             || (maybe_potion.is_some() && PotionSystem::has_potion_slot_available(pps))
             || (!available_card_vec.is_empty() && cards_left_to_choose > 0)
         {
-            let mut choices = Vec::with_capacity(available_card_vec.len() + 2);
+            let mut choices =
+                Vec::with_capacity(available_relic_vec.len() + available_card_vec.len() + 2);
             if let Some(gold_to_obtain) = maybe_gold {
                 choices.push(Choice::ObtainGold(gold_to_obtain));
             }
+            choices.extend(available_relic_vec.iter().copied().map(Choice::ObtainRelic));
             if let Some(potion_to_obtain) = maybe_potion {
                 choices.push(Choice::ObtainPotion(potion_to_obtain));
             }
@@ -68,6 +73,10 @@ impl MainScreenSystem {
                     PotionSystem::obtain_potion(comms, pps, *potion_to_obtain)?;
                     maybe_potion = None;
                 }
+                Choice::ObtainRelic(relic_to_obtain) => {
+                    RelicSystem::obtain_relic(comms, pps, *relic_to_obtain)?;
+                    available_relic_vec.retain(|relic| relic != relic_to_obtain);
+                }
                 Choice::Skip => break,
                 invalid => unreachable!("{:?}", invalid),
             }
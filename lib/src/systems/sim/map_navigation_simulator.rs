@@ -103,6 +103,13 @@ impl<'a, I: Interaction> MapNavigationSimulator<'a, I> {
         }
     }
 
+    /// Moves the player to the top row of the map, so that the next room they advance to is the
+    /// boss.
+    pub fn jump_to_boss(&mut self) {
+        let column_index = self.map.nonempty_columns_for_row(ROW_COUNT - 1)[0];
+        self.player_location = Some((ROW_COUNT - 1, column_index));
+    }
+
     fn climb_floor(
         &self,
         pps: &mut PlayerPersistentState,
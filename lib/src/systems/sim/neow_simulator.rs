@@ -110,7 +110,10 @@ impl<'a, I: Interaction> NeowSimulator<'a, I> {
                 }
                 match bonus {
                     NeowBonus::ChooseRareCard => todo!(),
-                    NeowBonus::ChooseRareColorlessCard => todo!(),
+                    NeowBonus::ChooseRareColorlessCard => {
+                        let cards = self.neow_generator.three_rare_colorless_card_choices();
+                        DeckSystem::choose_card_to_obtain(self.comms, pps, &cards)
+                    }
                     NeowBonus::GainTwoHundredFiftyGold => {
                         GoldSystem::increase_gold(self.comms, pps, 250)
                     }
@@ -11,8 +11,8 @@ use crate::systems::rng::{
 };
 use crate::types::{Floor, Hp};
 
-use super::combat_simulator::CombatSimulator;
-use super::event_simulator::EventSimulator;
+use super::combat_simulator::{CombatOutcome, CombatSimulator};
+use super::event_simulator::{EventOutcome, EventSimulator};
 use super::main_screen_system::MainScreenSystem;
 use super::map_navigation_simulator::MapNavigationSimulator;
 use super::neow_simulator::NeowSimulator;
@@ -126,8 +126,22 @@ impl StsSimulator {
                 }
                 Room::Event => match self.event_generator.next_event(floor, &pps) {
                     (Room::Event, Some(event)) => {
-                        EventSimulator::new(&comms, &mut self.potion_generator)
-                            .run_event(event, &mut pps)?
+                        let outcome = EventSimulator::new(
+                            &comms,
+                            floor,
+                            self.seed.with_offset(floor),
+                            &mut self.misc_rng,
+                            &mut self.card_generator,
+                            &mut self.potion_generator,
+                            &mut self.relic_generator,
+                        )
+                        .run_event(event, &mut pps)?;
+                        if pps.hp == 0 {
+                            break;
+                        }
+                        if outcome == EventOutcome::SecretPortal {
+                            map_simulator.jump_to_boss();
+                        }
                     }
                     (Room::Monster, None) => {
                         let encounter = self.encounter_generator.next_monster_encounter();
@@ -153,6 +167,8 @@ impl StsSimulator {
         comms.send_game_over(pps.hp > 0)
     }
 
+    /// Runs a combat encounter, handing out its rewards if the player wins. Returns false iff the
+    /// player died.
     pub fn run_encounter(
         &mut self,
         comms: &PlayerInteraction,
@@ -161,23 +177,26 @@ impl StsSimulator {
         pps: &mut PlayerPersistentState,
         _burning_elite_buff: Option<u8>,
     ) -> Result<bool, Error> {
-        if !CombatSimulator::new(self.seed.with_offset(floor), &mut self.misc_rng)
+        match CombatSimulator::new(self.seed.with_offset(floor), &mut self.misc_rng)
             .run_encounter(comms, encounter, pps)?
         {
-            Ok(false)
-        } else {
-            let gold_reward = self.treasure_rng.gen_range(10..=20);
-            // TODO: Relic::WhiteBeastStatue
-            let maybe_potion = self.potion_generator.combat_reward();
-            let card_rewards = self.card_generator.combat_rewards();
-            MainScreenSystem::choose_combat_rewards(
-                comms,
-                pps,
-                gold_reward,
-                maybe_potion,
-                &card_rewards,
-            )?;
-            Ok(true)
+            CombatOutcome::Defeat => Ok(false),
+            CombatOutcome::Escaped => Ok(true),
+            CombatOutcome::Victory => {
+                let gold_reward = self.treasure_rng.gen_range(10..=20);
+                // TODO: Relic::WhiteBeastStatue
+                let maybe_potion = self.potion_generator.combat_reward();
+                let card_rewards = self.card_generator.combat_rewards();
+                MainScreenSystem::choose_combat_rewards(
+                    comms,
+                    pps,
+                    gold_reward,
+                    &[],
+                    maybe_potion,
+                    &card_rewards,
+                )?;
+                Ok(true)
+            }
         }
     }
 
@@ -201,7 +220,7 @@ impl StsSimulator {
 }
 
 #[cfg(test)]
-mod test {
+pub(super) mod test {
     use pretty_assertions::assert_eq;
 
     use super::*;
@@ -258,6 +277,18 @@ mod test {
         maybe_message.take().expect("expected a prompt")
     }
 
+    /// Returns the index of the given choice within the prompt, so it can be sent back.
+    #[track_caller]
+    pub fn index_of(message: &StsMessage, choice: &Choice) -> usize {
+        match message {
+            StsMessage::Choices(_, choices) => choices
+                .iter()
+                .position(|c| c == choice)
+                .unwrap_or_else(|| panic!("{:?} not offered in {:?}", choice, choices)),
+            invalid => panic!("Expected a prompt, got {:?}", invalid),
+        }
+    }
+
     // TODO: This test depends on `CombatSimulator` behavior. Should test combat separately.
     #[test]
     pub fn test_game_3_ironclad() {
@@ -539,7 +570,7 @@ mod test {
                 &from_server,
                 &[Notification::CardExhausted(
                     1,
-                    CardCombatState::new(Card::Slimed, None)
+                    CardCombatState::new(10, Card::Slimed)
                 )]
             ),
             StsMessage::Choices(Prompt::CombatAction, vec![Choice::EndTurn])
@@ -940,7 +971,7 @@ mod test {
                     Notification::Energy(3),
                     Notification::EnemyParty(vec![
                         Some(
-                            EnemyStatus::new(Enemy::Cultist, (15, 50), Intent::Aggressive(6, 1))
+                            EnemyStatus::new(Enemy::Cultist, (15, 50), Intent::Aggressive(9, 1))
                                 .with_condition(EnemyCondition::Ritual(3, false))
                                 .with_condition(EnemyCondition::Vulnerable(1))
                                 .with_strength(3)
@@ -972,7 +1003,7 @@ mod test {
                     Notification::Energy(2),
                     Notification::EnemyParty(vec![
                         Some(
-                            EnemyStatus::new(Enemy::Cultist, (9, 50), Intent::Aggressive(6, 1))
+                            EnemyStatus::new(Enemy::Cultist, (9, 50), Intent::Aggressive(9, 1))
                                 .with_condition(EnemyCondition::Ritual(3, false))
                                 .with_condition(EnemyCondition::Vulnerable(2))
                                 .with_strength(3)
@@ -1037,7 +1068,7 @@ mod test {
                     Notification::Block(0),
                     Notification::EnemyParty(vec![
                         Some(
-                            EnemyStatus::new(Enemy::Cultist, (9, 50), Intent::Aggressive(6, 1))
+                            EnemyStatus::new(Enemy::Cultist, (9, 50), Intent::Aggressive(12, 1))
                                 .with_condition(EnemyCondition::Ritual(3, false))
                                 .with_condition(EnemyCondition::Vulnerable(1))
                                 .with_strength(6)
@@ -11,6 +11,8 @@ pub type JustApplied = bool; // Whether a buff or debuff was just applied.
 pub type Health = (Hp, HpMax); // Current and maximum hit points.
 pub type Hp = u32; // Player or enemy current hit points.
 pub type HpMax = u32; // Maximum player or enemy hit points.
+pub type Mantra = u32; // Progress towards entering Divinity.
+pub type Misc = u32; // Card-specific value that persists between combats.
 pub type OrbCount = u32; // Number of orbs of a particular type.
 pub type OrbSlots = u32; // Number of orb slots available.
 pub type PotionSlots = u32; // Number of potion slots available.
@@ -19,11 +21,13 @@ pub type StackCount = u32; // Number of stacks of a buff or debuff.
 pub type Strength = i32; // Player or enemy strength. Can be negative.
 pub type Turns = StackCount; // Number of turns a buff or debuff lasts.
 
+pub type CardInstanceId = usize; // Stable identity of a single card for the whole run.
 pub type CardRewardIndex = usize;
 pub type DeckIndex = usize;
 pub type DiscardIndex = usize;
 pub type DrawIndex = usize;
 pub type EnemyIndex = usize;
+pub type GridIndex = usize; // Position in Match and Keep's grid of face-down cards.
 pub type HandIndex = usize;
 pub type PotionIndex = usize;
 pub type RewardIndex = usize;
@@ -70,8 +70,10 @@ impl<'a> CombatClient<'a> {
                 self.player_status.hp = health.0;
                 self.player_status.hp_max = health.1;
             }
+            Notification::Mantra(mantra) => self.player_status.mantra = mantra,
             Notification::Potions(potions) => self.player_status.potions = potions,
             Notification::Relics(relics) => self.player_status.relics = relics,
+            Notification::Stance(stance) => self.player_status.stance = stance,
             Notification::Status(player_status) => self.player_status = player_status,
             Notification::Strength(strength) => self.player_status.strength = strength,
             _ => {}
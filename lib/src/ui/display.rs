@@ -6,9 +6,23 @@ use crate::data::CardDetails;
 impl fmt::Display for Prompt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Prompt::BottleCard => write!(f, "Choose a card to bottle"),
+            Prompt::ChooseCardToCreate => write!(f, "Choose a card to add to your hand"),
+            Prompt::ChooseCardToExhaust => write!(f, "Choose a card to exhaust"),
+            Prompt::ChooseCardToPlay => write!(f, "Choose a card to play"),
+            Prompt::ChooseCardToPutOnBottomOfDrawPile => {
+                write!(f, "Choose a card to put on the bottom of draw pile")
+            }
             Prompt::ChooseCardToPutOnTopOfDrawPile => {
                 write!(f, "Choose a card to put on top of draw pile")
             }
+            Prompt::ChooseCardToReturnToHand => {
+                write!(f, "Choose a card to return to your hand")
+            }
+            Prompt::ChooseCardToTakeFromDrawPile => {
+                write!(f, "Choose a card to put into your hand")
+            }
+            Prompt::ChooseCardsToRetain => write!(f, "Choose cards to retain"),
             Prompt::ChooseCombatReward => write!(f, "Choose a combat reward"),
             Prompt::ChooseForEvent => write!(f, "Choose an option for the event"),
             Prompt::ChooseNeow => write!(f, "Choose Neow's Blessing"),
@@ -21,8 +35,13 @@ impl fmt::Display for Prompt {
                 "Move up into one of the following columns, or drink/discard a potion"
             ),
             Prompt::CombatAction => write!(f, "It is your turn to act"),
+            Prompt::DuplicateCard => write!(f, "Choose a card to duplicate"),
+            Prompt::OfferCard => write!(f, "Choose a card to offer"),
             Prompt::RemoveCard => write!(f, "Choose a card to remove"),
+            Prompt::RevealCard => write!(f, "Choose a face-down card to reveal"),
+            Prompt::Scry => write!(f, "Choose cards to discard while scrying"),
             Prompt::TargetEnemy => write!(f, "Choose an enemy to target"),
+            Prompt::TransformCard => write!(f, "Choose a card to transform"),
             Prompt::UpgradeCard => write!(f, "Choose a card to upgrade"),
         }
     }
@@ -31,6 +50,8 @@ impl fmt::Display for Prompt {
 impl fmt::Display for Choice {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Choice::BottleCard(_, card) => write!(f, "Bottle \"{:?}\"", card),
+            Choice::ChooseEffect(effect) => write!(f, "{:?}", effect),
             Choice::ClimbFloor(column_index) => {
                 write!(
                     f,
@@ -38,7 +59,10 @@ impl fmt::Display for Choice {
                     (b'a' + *column_index as u8) as char
                 )
             }
+            Choice::CreateCard(card) => write!(f, "{:?}", card),
+            Choice::DuplicateCard(_, card) => write!(f, "Duplicate \"{:?}\"", card),
             Choice::Event(_, text) => write!(f, "{}", text),
+            Choice::ExhaustCard(_, card) => write!(f, "Exhaust \"{:?}\"", card),
             Choice::ExpendPotion(PotionAction::Discard(_, potion)) => {
                 write!(f, "Discard potion \"{:?}\"", potion)
             }
@@ -53,18 +77,36 @@ impl fmt::Display for Choice {
             }
             Choice::ObtainGold(gold) => write!(f, "Obtain {} gold", gold),
             Choice::ObtainPotion(potion) => write!(f, "{:?}", potion),
+            Choice::ObtainRelic(relic) => write!(f, "{:?}", relic),
+            Choice::OfferCard(_, card) => write!(f, "Offer \"{:?}\"", card),
+            Choice::PlayCardFromDrawPile(_, card) => write!(f, "Play \"{:?}\"", card),
             Choice::PlayCardFromHand(_, card, energy) => {
                 write!(f, "Play \"{:?}\" ({:?})", card, energy)
             }
+            Choice::PutBackOnDrawPile(_, card) => write!(f, "{:?}", card),
+            Choice::PutOnBottomOfDrawPile(_, card) => write!(f, "{:?}", card),
             Choice::PutOnTopOfDrawPile(_, card) => write!(f, "{:?}", card),
             Choice::RemoveCard(_, card) => write!(f, "{:?}", card),
             Choice::Rest => write!(f, "Rest"),
+            Choice::RetainCard(_, card) => write!(f, "Retain \"{:?}\"", card),
+            Choice::ReturnToHand(_, card) => write!(f, "{:?}", card),
+            Choice::RevealCard(grid_index) => write!(
+                f,
+                "Reveal the card in row {}, column {}",
+                grid_index / 4 + 1,
+                (b'a' + (grid_index % 4) as u8) as char
+            ),
+            Choice::ScryDiscard(_, card) => write!(f, "Discard \"{:?}\"", card),
             Choice::Smith => write!(f, "Smith (Upgrade a card)"),
             Choice::Skip => write!(f, "(Skip)"),
+            Choice::TakeFromDrawPile(_, card) => write!(f, "{:?}", card),
             Choice::TargetEnemy(_, enemy) => {
                 write!(f, "Target \"{:?}\"", enemy)
             }
-            Choice::UpgradeCard(_, card, _) => write!(f, "Upgrade \"{:?}\"", card),
+            Choice::TransformCard(_, card) => write!(f, "Transform \"{:?}\"", card),
+            Choice::UpgradeCard(_, card, _) | Choice::UpgradeCardInHand(_, card, _) => {
+                write!(f, "Upgrade \"{:?}\"", card)
+            }
         }
     }
 }
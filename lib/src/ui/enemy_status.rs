@@ -19,6 +19,9 @@ impl fmt::Display for EnemyStatus {
                     .join(", ")
             )?;
         }
-        write!(f, ", intent: {:?}", self.intent)
+        match &self.intent {
+            Some(intent) => write!(f, ", intent: {:?}", intent),
+            None => Ok(()),
+        }
     }
 }